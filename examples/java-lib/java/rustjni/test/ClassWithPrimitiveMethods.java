@@ -27,9 +27,8 @@ public class ClassWithPrimitiveMethods {
     return input + 5;
   }
 
-  // TODO(#25): floating point numbers don't work properly.
-  public float testFloatFunction(double input) {
-    return (float) input + 6;
+  public float testFunction(float input) {
+    return input + 6;
   }
 
   public double testFunction(double input) {
@@ -62,9 +61,8 @@ public class ClassWithPrimitiveMethods {
     return input + 5;
   }
 
-  // TODO(#25): floating point numbers don't work properly.
-  public static float testStaticFloatFunction(double input) {
-    return (float) input + 6;
+  public static float testStaticFunction(float input) {
+    return input + 6;
   }
 
   public static double testStaticFunction(double input) {
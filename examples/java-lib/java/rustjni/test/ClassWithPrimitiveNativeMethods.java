@@ -50,8 +50,8 @@ public class ClassWithPrimitiveNativeMethods {
     return testNativeFunction(input);
   }
 
-  public float testFloatFunction(double input) {
-    return testNativeFunction((float) input);
+  public float testFunction(float input) {
+    return testNativeFunction(input);
   }
 
   public double testFunction(double input) {
@@ -100,8 +100,8 @@ public class ClassWithPrimitiveNativeMethods {
     return testStaticNativeFunction(input);
   }
 
-  public static float testStaticFloatFunction(double input) {
-    return testStaticNativeFunction((float) input);
+  public static float testStaticFunction(float input) {
+    return testStaticNativeFunction(input);
   }
 
   public static double testStaticFunction(double input) {
@@ -53,17 +53,9 @@ impl<'a> ClassWithPrimitiveMethods<'a> {
         unsafe { self.call_method::<_, fn(i64) -> i64>(token, "testFunction\0", (argument,)) }
     }
 
-    pub fn test_function_f32(
-        &self,
-        token: &NoException<'a>,
-        // TODO(#25): floating point numbers don't work properly.
-        argument: f64,
-    ) -> JavaResult<'a, f32> {
+    pub fn test_function_f32(&self, token: &NoException<'a>, argument: f32) -> JavaResult<'a, f32> {
         // Safe because we ensure correct arguments and return type.
-        unsafe {
-            // TODO(#25): floating point numbers don't work properly.
-            self.call_method::<_, fn(f64) -> f32>(token, "testFloatFunction\0", (argument,))
-        }
+        unsafe { self.call_method::<_, fn(f32) -> f32>(token, "testFunction\0", (argument,)) }
     }
 
     pub fn test_function_f64(&self, token: &NoException<'a>, argument: f64) -> JavaResult<'a, f64> {
@@ -144,17 +136,12 @@ impl<'a> ClassWithPrimitiveMethods<'a> {
         }
     }
 
-    pub fn test_static_function_f32(
-        token: &NoException<'a>,
-        // TODO(#25): floating point numbers don't work properly.
-        argument: f64,
-    ) -> JavaResult<'a, f32> {
+    pub fn test_static_function_f32(token: &NoException<'a>, argument: f32) -> JavaResult<'a, f32> {
         // Safe because we ensure correct arguments and return type.
         unsafe {
-            // TODO(#25): floating point numbers don't work properly.
-            Self::call_static_method::<_, fn(f64) -> f32>(
+            Self::call_static_method::<_, fn(f32) -> f32>(
                 token,
-                "testStaticFloatFunction\0",
+                "testStaticFunction\0",
                 (argument,),
             )
         }
@@ -4,6 +4,19 @@ use proc_macro2::*;
 use std::iter;
 use std::iter::FromIterator;
 
+#[derive(Debug)]
+pub struct ThrownException {
+    pub variant: Ident,
+    pub class: TokenStream,
+}
+
+#[derive(Debug)]
+pub struct MethodError {
+    pub name: Ident,
+    pub typed_name: Ident,
+    pub exceptions: Vec<ThrownException>,
+}
+
 #[derive(Debug)]
 pub struct ClassMethod {
     pub name: Ident,
@@ -12,6 +25,10 @@ pub struct ClassMethod {
     pub argument_names: Vec<Ident>,
     pub argument_types: Vec<TokenStream>,
     pub public: bool,
+    pub error: Option<MethodError>,
+    pub super_class: Option<TokenStream>,
+    pub nullable: bool,
+    pub signature: Option<Literal>,
 }
 
 #[derive(Debug)]
@@ -28,7 +45,8 @@ pub struct InterfaceMethodImplementation {
     pub return_type: TokenStream,
     pub argument_names: Vec<Ident>,
     pub argument_types: Vec<TokenStream>,
-    pub class_has_method: bool,
+    /// Rust name of the class's own method implementing the interface method, if there is one.
+    pub class_method: Option<Ident>,
 }
 
 #[derive(Debug)]
@@ -36,6 +54,7 @@ pub struct NativeMethod {
     pub name: Ident,
     pub rust_name: Ident,
     pub java_name: Ident,
+    pub signature: Literal,
     pub return_type: TokenStream,
     pub argument_names: Vec<Ident>,
     pub argument_types: Vec<TokenStream>,
@@ -44,6 +63,17 @@ pub struct NativeMethod {
     pub code: TokenStream,
 }
 
+#[derive(Debug)]
+pub struct Field {
+    pub name: Ident,
+    pub setter_name: Option<Ident>,
+    pub java_name: Literal,
+    pub data_type: TokenStream,
+    pub return_type: TokenStream,
+    pub argument_type: TokenStream,
+    pub public: bool,
+}
+
 #[derive(Debug)]
 pub struct Constructor {
     pub name: Ident,
@@ -72,6 +102,10 @@ pub struct Class {
     pub static_methods: Vec<ClassMethod>,
     pub native_methods: Vec<NativeMethod>,
     pub static_native_methods: Vec<NativeMethod>,
+    pub fields: Vec<Field>,
+    pub static_fields: Vec<Field>,
+    pub capture_error: Option<Ident>,
+    pub builder: Option<Ident>,
 }
 
 #[derive(Debug)]
@@ -82,10 +116,17 @@ pub struct Interface {
     pub methods: Vec<InterfaceMethod>,
 }
 
+#[derive(Debug)]
+pub struct AnnotationType {
+    pub annotation: Ident,
+    pub public: bool,
+}
+
 #[derive(Debug)]
 pub enum GeneratorDefinition {
     Interface(Interface),
     Class(Class),
+    AnnotationType(AnnotationType),
 }
 
 #[derive(Debug)]
@@ -106,6 +147,15 @@ fn generate_definition(definition: &GeneratorDefinition) -> TokenStream {
     match definition {
         GeneratorDefinition::Interface(interface) => generate_interface(interface),
         GeneratorDefinition::Class(class) => generate_class(class),
+        GeneratorDefinition::AnnotationType(annotation) => generate_annotation_type(annotation),
+    }
+}
+
+fn generate_annotation_type(definition: &AnnotationType) -> TokenStream {
+    let AnnotationType { annotation, public } = definition;
+    let public = generate_public(*public);
+    quote! {
+        #public trait #annotation {}
     }
 }
 
@@ -162,22 +212,64 @@ fn generate_class(definition: &Class) -> TokenStream {
         static_methods,
         native_methods,
         static_native_methods,
+        fields,
+        static_fields,
+        capture_error,
+        builder,
     } = definition;
     let multiplied_class = iter::repeat(class);
     let transitive_extends_1 = transitive_extends.iter();
     let transitive_extends = transitive_extends.iter();
+    let method_errors = methods
+        .iter()
+        .chain(static_methods.iter())
+        .filter_map(|method| method.error.as_ref())
+        .map(|error| generate_method_error(error, *public))
+        .collect::<Vec<_>>();
     let methods = methods.iter().map(generate_class_method);
     let static_methods = static_methods.iter().map(generate_static_class_method);
+    let fields = fields.iter().map(generate_class_field);
+    let static_fields = static_fields.iter().map(generate_static_class_field);
     let native_method_functions = native_methods
         .iter()
         .map(|method| generate_class_native_method_function(method, class));
     let static_native_method_functions = static_native_methods
         .iter()
         .map(|method| generate_static_class_native_method_function(method, class));
-    let native_methods = native_methods.iter().map(generate_class_native_method);
-    let static_native_methods = static_native_methods
-        .iter()
-        .map(generate_static_class_native_method);
+    let native_implementations = if native_methods.is_empty() && static_native_methods.is_empty() {
+        quote! {}
+    } else {
+        let register_native_methods =
+            generate_register_native_methods(native_methods.iter().chain(static_native_methods));
+        let native_methods = native_methods.iter().map(generate_class_native_method);
+        let static_native_methods = static_native_methods
+            .iter()
+            .map(generate_static_class_native_method);
+        quote! {
+            /// Rust implementations of Java native methods of this class.
+            ///
+            /// These methods are called from Java. All other methods call into Java.
+            impl<'a> #class<'a> {
+                #(
+                    #native_methods
+                )*
+
+                #(
+                    #static_native_methods
+                )*
+
+                #register_native_methods
+            }
+        }
+    };
+    let capture_error = capture_error
+        .as_ref()
+        .map(|error| generate_capture_error(error, class, *public))
+        .unwrap_or(quote! {});
+    let builder = builder
+        .as_ref()
+        .map(|builder| generate_builder(builder, class, constructors, *public))
+        .unwrap_or(quote! {});
     let constructors = constructors.iter().map(generate_constructor);
     let implementations = implements
         .iter()
@@ -238,11 +330,57 @@ fn generate_class(definition: &Class) -> TokenStream {
         }
 
         impl<'a> #class<'a> {
+            /// The JNI signature of the class.
+            pub const JNI_SIGNATURE: &'static str = #full_signature;
+
             pub fn get_class(env: &'a ::rust_jni::JniEnv<'a>, token: &::rust_jni::NoException<'a>)
                 -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::Class<'a>> {
                 ::rust_jni::java::lang::Class::find(env, #signature, token)
             }
 
+            /// Wrap a raw Java object reference if the object is an instance of this class.
+            /// Returns `None` if the reference is `null` or the object is of a different class.
+            ///
+            /// Takes ownership of the local reference.
+            /// Unsafe because an incorrect object reference can be passed.
+            pub unsafe fn try_from_raw(
+                env: &'a ::rust_jni::JniEnv<'a>,
+                raw_object: <::rust_jni::java::lang::Object<'a> as ::rust_jni::JavaType>::__JniType,
+                token: &::rust_jni::NoException<'a>,
+            ) -> ::rust_jni::JavaResult<'a, Option<Self>> {
+                if raw_object.is_null() {
+                    return Ok(None);
+                }
+                let object = <::rust_jni::java::lang::Object<'a> as ::rust_jni::__generator::FromJni<'a>>::__from_jni(env, raw_object);
+                if !object.is_instance_of(token, &Self::get_class(env, token)?) {
+                    return Ok(None);
+                }
+                Ok(Some(<Self as ::rust_jni::__generator::FromJni<'a>>::__from_jni(
+                    env,
+                    ::rust_jni::java::lang::Object::take_raw_object(object).as_ptr(),
+                )))
+            }
+
+            /// View an object as an instance of this class if it is one.
+            /// Returns `None` if the object is of a different class.
+            pub fn from_object(
+                object: &::rust_jni::java::lang::Object<'a>,
+                token: &::rust_jni::NoException<'a>,
+            ) -> ::rust_jni::JavaResult<'a, Option<Self>> {
+                let env = object.env();
+                if !object.is_instance_of(token, &Self::get_class(env, token)?) {
+                    return Ok(None);
+                }
+                let object = object.clone(token)?;
+                // Safe because the object is an instance of this class.
+                Ok(Some(unsafe {
+                    <Self as ::rust_jni::__generator::FromJni<'a>>::__from_jni(
+                        env,
+                        ::rust_jni::java::lang::Object::take_raw_object(object).as_ptr(),
+                    )
+                }))
+            }
+
             pub fn clone(&self, token: &::rust_jni::NoException<'a>) -> ::rust_jni::JavaResult<'a, Self>
             where
                 Self: Sized,
@@ -257,6 +395,12 @@ fn generate_class(definition: &Class) -> TokenStream {
                 self.object.to_string(token)
             }
 
+            /// Convert into a type-erased global reference, which can be stored long-term.
+            pub fn into_global_object(self, token: &::rust_jni::NoException<'a>)
+                -> ::rust_jni::JavaResult<'a, ::rust_jni::GlobalRef<::rust_jni::java::lang::Object<'static>>> {
+                self.object.into_global_object(token)
+            }
+
             #(
                 #constructors
             )*
@@ -270,14 +414,24 @@ fn generate_class(definition: &Class) -> TokenStream {
             )*
 
             #(
-                #native_methods
+                #fields
             )*
 
             #(
-                #static_native_methods
+                #static_fields
             )*
         }
 
+        #native_implementations
+
+        #capture_error
+
+        #builder
+
+        #(
+            #method_errors
+        )*
+
         // TODO: put them into an anonymous module.
 
         #(
@@ -308,6 +462,33 @@ fn generate_class(definition: &Class) -> TokenStream {
     }
 }
 
+fn generate_capture_error(error: &Ident, class: &Ident, public: bool) -> TokenStream {
+    let public = generate_public(public);
+    quote! {
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        #public struct #error(pub ::rust_jni::CapturedJavaError);
+
+        impl ::std::fmt::Display for #error {
+            fn fmt(&self, formatter: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                self.0.fmt(formatter)
+            }
+        }
+
+        impl ::std::error::Error for #error {}
+
+        impl<'a> #class<'a> {
+            /// Capture a caught Java exception into an owned error value that doesn't
+            /// require a token to be inspected.
+            pub fn capture(
+                throwable: &::rust_jni::java::lang::Throwable<'a>,
+                token: &::rust_jni::NoException<'a>,
+            ) -> ::rust_jni::JavaResult<'a, #error> {
+                ::rust_jni::CapturedJavaError::capture(token, throwable).map(#error)
+            }
+        }
+    }
+}
+
 fn generate_constructor(method: &Constructor) -> TokenStream {
     let Constructor {
         name,
@@ -339,6 +520,87 @@ fn generate_constructor(method: &Constructor) -> TokenStream {
     }
 }
 
+fn generate_builder(
+    builder: &Ident,
+    class: &Ident,
+    constructors: &[Constructor],
+    public: bool,
+) -> TokenStream {
+    let constructors = constructors
+        .iter()
+        .map(|constructor| generate_builder_constructor(constructor, class));
+    let public = generate_public(public);
+    quote! {
+        /// A builder for calling one of the overloaded constructors of the class.
+        #public struct #builder<'a, 'b> {
+            env: &'a ::rust_jni::JniEnv<'a>,
+            constructor: ::std::option::Option<
+                Box<dyn FnOnce(&::rust_jni::NoException<'a>) -> ::rust_jni::JavaResult<'a, #class<'a>> + 'b>
+            >,
+        }
+
+        impl<'a> #class<'a> {
+            #public fn builder<'b>(env: &'a ::rust_jni::JniEnv<'a>) -> #builder<'a, 'b> {
+                #builder {
+                    env,
+                    constructor: None,
+                }
+            }
+        }
+
+        impl<'a, 'b> #builder<'a, 'b> {
+            #(
+                #constructors
+            )*
+
+            /// Call the selected constructor.
+            ///
+            /// Panics if no constructor was selected.
+            pub fn build(self, token: &::rust_jni::NoException<'a>) -> ::rust_jni::JavaResult<'a, #class<'a>> {
+                let constructor = self
+                    .constructor
+                    .expect("No constructor was selected for the builder.");
+                constructor(token)
+            }
+        }
+    }
+}
+
+fn generate_builder_constructor(constructor: &Constructor, class: &Ident) -> TokenStream {
+    let Constructor {
+        name,
+        public,
+        argument_names,
+        argument_types,
+    } = constructor;
+    let method_name = Ident::new(&format!("with_{}", name), Span::call_site());
+    let argument_types = argument_types.iter().map(generate_builder_argument_type);
+    let argument_names_1 = argument_names.iter();
+    let argument_names = argument_names.iter();
+    let public = generate_public(*public);
+    quote! {
+        #public fn #method_name(self, #(#argument_names: #argument_types,)*) -> Self {
+            let env = self.env;
+            Self {
+                env,
+                constructor: Some(Box::new(move |token| #class::#name(env, #(#argument_names_1,)* token))),
+            }
+        }
+    }
+}
+
+/// Builder arguments are stored until the constructor is called, so object references
+/// have to live as long as the builder.
+fn generate_builder_argument_type(argument_type: &TokenStream) -> TokenStream {
+    match argument_type.clone().into_iter().next() {
+        Some(TokenTree::Punct(ref punct)) if punct.as_char() == '&' => {
+            let class = TokenStream::from_iter(argument_type.clone().into_iter().skip(1));
+            quote! {&'b #class}
+        }
+        _ => argument_type.clone(),
+    }
+}
+
 fn generate_class_method(method: &ClassMethod) -> TokenStream {
     let ClassMethod {
         name,
@@ -347,31 +609,87 @@ fn generate_class_method(method: &ClassMethod) -> TokenStream {
         public,
         argument_names,
         argument_types,
+        error,
+        super_class,
+        nullable,
+        signature,
     } = method;
-    let argument_names_1 = argument_names.iter();
+    let typed_method = error
+        .as_ref()
+        .map(|error| {
+            generate_typed_method(
+                method,
+                error,
+                quote! {&self,},
+                quote! {self.#name},
+                quote! {},
+            )
+        })
+        .unwrap_or(quote! {});
+    let (parameter_types, arguments) = generate_method_arguments(argument_names, argument_types);
     let argument_names = argument_names.iter();
-    let argument_types_1 = argument_types.iter();
     let argument_types = argument_types.iter();
     let public = generate_public(*public);
-    quote! {
-        #public fn #name(
-            &self,
-            #(#argument_names: #argument_types,)*
-            token: &::rust_jni::NoException<'a>,
-        ) -> ::rust_jni::JavaResult<'a, #return_type> {
+    let result_type = generate_result_type(return_type, *nullable);
+    let throws_doc = generate_throws_doc(error);
+    let call = match (super_class, signature) {
+        (Some(_), Some(_)) => panic!("@Signature is not supported on @Super methods."),
+        (Some(super_class), None) => quote! {
+            // Safe because the method name and arguments are correct.
+            unsafe {
+                ::rust_jni::__generator::call_nonvirtual_method::<_, _, _,
+                    fn(#(#argument_types,)*) -> #return_type
+                >
+                (
+                    self,
+                    &#super_class::get_class(self.env(), token)?,
+                    #java_name,
+                    (#(#arguments,)*),
+                    token,
+                )
+            }
+        },
+        (None, Some(signature)) => quote! {
+            // Safe because the method name, signature and arguments are correct.
+            unsafe {
+                ::rust_jni::__generator::call_method_with_signature::<_, _, _,
+                    fn(#(#argument_types,)*) -> #return_type
+                >
+                (
+                    self,
+                    #java_name,
+                    #signature,
+                    (#(#arguments,)*),
+                    token,
+                )
+            }
+        },
+        (None, None) => quote! {
             // Safe because the method name and arguments are correct.
             unsafe {
                 ::rust_jni::__generator::call_method::<_, _, _,
-                    fn(#(#argument_types_1,)*) -> #return_type
+                    fn(#(#argument_types,)*) -> #return_type
                 >
                 (
                     self,
                     #java_name,
-                    (#(#argument_names_1,)*),
+                    (#(#arguments,)*),
                     token,
                 )
             }
+        },
+    };
+    quote! {
+        #throws_doc
+        #public fn #name(
+            &self,
+            #(#argument_names: #parameter_types,)*
+            token: &::rust_jni::NoException<'a>,
+        ) -> ::rust_jni::JavaResult<'a, #result_type> {
+            #call
         }
+
+        #typed_method
     }
 }
 
@@ -383,30 +701,239 @@ fn generate_static_class_method(method: &ClassMethod) -> TokenStream {
         public,
         argument_names,
         argument_types,
+        error,
+        super_class,
+        nullable,
+        signature,
     } = method;
-    let argument_names_1 = argument_names.iter();
+    if super_class.is_some() {
+        panic!("@Super is only supported on non-static methods.");
+    }
+    let typed_method = error
+        .as_ref()
+        .map(|error| {
+            generate_typed_method(
+                method,
+                error,
+                quote! {env: &'a ::rust_jni::JniEnv<'a>,},
+                quote! {Self::#name},
+                quote! {env,},
+            )
+        })
+        .unwrap_or(quote! {});
+    let (parameter_types, arguments) = generate_method_arguments(argument_names, argument_types);
     let argument_names = argument_names.iter();
-    let argument_types_1 = argument_types.iter();
     let argument_types = argument_types.iter();
     let public = generate_public(*public);
-    quote! {
-        #public fn #name(
-            env: &'a ::rust_jni::JniEnv<'a>,
-            #(#argument_names: #argument_types,)*
-            token: &::rust_jni::NoException<'a>,
-        ) -> ::rust_jni::JavaResult<'a, #return_type> {
+    let result_type = generate_result_type(return_type, *nullable);
+    let throws_doc = generate_throws_doc(error);
+    let call = match signature {
+        Some(signature) => quote! {
+            // Safe because the method name, signature and arguments are correct.
+            unsafe {
+                ::rust_jni::__generator::call_static_method_with_signature::<Self, _, _,
+                    fn(#(#argument_types,)*) -> #return_type
+                >
+                (
+                    env,
+                    #java_name,
+                    #signature,
+                    (#(#arguments,)*),
+                    token,
+                )
+            }
+        },
+        None => quote! {
             // Safe because the method name and arguments are correct.
             unsafe {
                 ::rust_jni::__generator::call_static_method::<Self, _, _,
-                    fn(#(#argument_types_1,)*) -> #return_type
+                    fn(#(#argument_types,)*) -> #return_type
                 >
                 (
                     env,
                     #java_name,
-                    (#(#argument_names_1,)*),
+                    (#(#arguments,)*),
                     token,
                 )
             }
+        },
+    };
+    quote! {
+        #throws_doc
+        #public fn #name(
+            env: &'a ::rust_jni::JniEnv<'a>,
+            #(#argument_names: #parameter_types,)*
+            token: &::rust_jni::NoException<'a>,
+        ) -> ::rust_jni::JavaResult<'a, #result_type> {
+            #call
+        }
+
+        #typed_method
+    }
+}
+
+fn generate_class_field(field: &Field) -> TokenStream {
+    let Field {
+        name,
+        setter_name,
+        java_name,
+        data_type,
+        return_type,
+        argument_type,
+        public,
+    } = field;
+    let public = generate_public(*public);
+    let setter = setter_name
+        .as_ref()
+        .map(|setter_name| {
+            quote! {
+                #public fn #setter_name(
+                    &self,
+                    value: #argument_type,
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::JavaResult<'a, ()> {
+                    // Safe because the field name and type are correct.
+                    unsafe {
+                        ::rust_jni::set_field::<#data_type, _>(self, token, #java_name, value)
+                    }
+                }
+            }
+        })
+        .unwrap_or(quote! {});
+    quote! {
+        #public fn #name(
+            &self,
+            token: &::rust_jni::NoException<'a>,
+        ) -> ::rust_jni::JavaResult<'a, #return_type> {
+            // Safe because the field name and type are correct.
+            unsafe {
+                ::rust_jni::get_field::<#data_type, _>(self, token, #java_name)
+            }
+        }
+
+        #setter
+    }
+}
+
+fn generate_static_class_field(field: &Field) -> TokenStream {
+    let Field {
+        name,
+        setter_name,
+        java_name,
+        data_type,
+        return_type,
+        argument_type,
+        public,
+    } = field;
+    let public = generate_public(*public);
+    let setter = setter_name
+        .as_ref()
+        .map(|setter_name| {
+            quote! {
+                #public fn #setter_name(
+                    value: #argument_type,
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::JavaResult<'a, ()> {
+                    // Safe because the field name and type are correct.
+                    unsafe {
+                        ::rust_jni::set_static_field::<#data_type, Self>(token, #java_name, value)
+                    }
+                }
+            }
+        })
+        .unwrap_or(quote! {});
+    quote! {
+        #public fn #name(
+            token: &::rust_jni::NoException<'a>,
+        ) -> ::rust_jni::JavaResult<'a, #return_type> {
+            // Safe because the field name and type are correct.
+            unsafe {
+                ::rust_jni::get_static_field::<#data_type, Self>(token, #java_name)
+            }
+        }
+
+        #setter
+    }
+}
+
+fn generate_typed_method(
+    method: &ClassMethod,
+    error: &MethodError,
+    receiver: TokenStream,
+    call: TokenStream,
+    receiver_argument: TokenStream,
+) -> TokenStream {
+    let ClassMethod {
+        return_type,
+        public,
+        argument_names,
+        argument_types,
+        nullable,
+        ..
+    } = method;
+    let MethodError {
+        name, typed_name, ..
+    } = error;
+    let (parameter_types, _) = generate_method_arguments(argument_names, argument_types);
+    let argument_names_1 = argument_names.iter();
+    let argument_names = argument_names.iter();
+    let public = generate_public(*public);
+    let result_type = generate_result_type(return_type, *nullable);
+    quote! {
+        #public fn #typed_name(
+            #receiver
+            #(#argument_names: #parameter_types,)*
+            token: &::rust_jni::NoException<'a>,
+        ) -> ::std::result::Result<#result_type, #name<'a>> {
+            #call(#receiver_argument #(#argument_names_1,)* token)
+                .map_err(|throwable| #name::from_throwable(throwable, token))
+        }
+    }
+}
+
+fn generate_method_error(error: &MethodError, public: bool) -> TokenStream {
+    let MethodError {
+        name, exceptions, ..
+    } = error;
+    let multiplied_name = iter::repeat(name);
+    let variants = exceptions.iter().map(|exception| &exception.variant);
+    let variants_1 = exceptions.iter().map(|exception| &exception.variant);
+    let classes = exceptions.iter().map(|exception| &exception.class);
+    let classes_1 = exceptions.iter().map(|exception| &exception.class);
+    let classes_2 = exceptions.iter().map(|exception| &exception.class);
+    let public = generate_public(public);
+    quote! {
+        #[derive(Debug)]
+        #public enum #name<'a> {
+            #(
+                #variants(#classes<'a>),
+            )*
+            Other(::rust_jni::java::lang::Throwable<'a>),
+        }
+
+        impl<'a> #name<'a> {
+            /// Convert a caught exception into the variant of its declared type.
+            /// Exceptions of other types are stored in the `Other` variant.
+            pub fn from_throwable(
+                throwable: ::rust_jni::java::lang::Throwable<'a>,
+                token: &::rust_jni::NoException<'a>,
+            ) -> Self {
+                #(
+                    let is_instance = #classes_1::get_class(throwable.env(), token)
+                        .map(|class| throwable.is_instance_of(token, &class))
+                        .unwrap_or(false);
+                    if is_instance {
+                        // Safe because the exception was checked to be an instance of the class.
+                        return #multiplied_name::#variants_1(unsafe {
+                            <#classes_2<'a> as ::rust_jni::__generator::FromJni<'a>>::__from_jni(
+                                throwable.env(),
+                                ::rust_jni::java::lang::Object::take_raw_object(throwable).as_ptr(),
+                            )
+                        });
+                    }
+                )*
+                #name::Other(throwable)
+            }
         }
     }
 }
@@ -455,6 +982,40 @@ fn generate_static_class_native_method(method: &NativeMethod) -> TokenStream {
     }
 }
 
+fn generate_register_native_methods<'a>(
+    methods: impl Iterator<Item = &'a NativeMethod>,
+) -> TokenStream {
+    let (names, (signatures, functions)): (Vec<_>, (Vec<_>, Vec<_>)) = methods
+        .map(|method| {
+            (
+                Literal::string(&method.name.to_string()),
+                (method.signature.clone(), method.java_name.clone()),
+            )
+        })
+        .unzip();
+    quote! {
+        /// Register native methods of this class with the JVM.
+        ///
+        /// Only needed when the library is linked statically and the JVM can't find
+        /// the native method implementations dynamically.
+        pub fn register_native_methods(
+            env: &'a ::rust_jni::JniEnv<'a>,
+            token: &::rust_jni::NoException<'a>,
+        ) -> ::rust_jni::JavaResult<'a, ()> {
+            let class = Self::get_class(env, token)?;
+            // Safe because the native functions are generated to match the method signatures.
+            unsafe {
+                class.register_natives(
+                    token,
+                    &[
+                        #((#names, #signatures, #functions as *mut ::std::os::raw::c_void),)*
+                    ],
+                )
+            }
+        }
+    }
+}
+
 fn generate_class_native_method_function(method: &NativeMethod, class_name: &Ident) -> TokenStream {
     let NativeMethod {
         rust_name,
@@ -561,7 +1122,7 @@ fn generate_static_class_native_method_function(
 
                 let class = #class_name::get_class(env, &token)?;
                 let raw_class = <::rust_jni::java::lang::Class as ::rust_jni::__generator::FromJni>::__from_jni(env, raw_class);
-                if !class.is_same_as(&raw_class, &token) {
+                if !class.is_same_class(&token, &raw_class) {
                     // This should never happen, as native method's link name has the class,
                     // so it must be bound to a correct clas by the JVM.
                     // Still, this is a good test to ensure that the system
@@ -595,14 +1156,13 @@ fn generate_interface_method_implementation(
         argument_names,
         argument_types,
         return_type,
-        class_has_method,
+        class_method,
     } = method;
     let argument_names_1 = argument_names.iter();
     let argument_names = argument_names.iter();
-    let class_cast = if *class_has_method {
-        quote! {Self}
-    } else {
-        quote! { <#super_class as #interface> }
+    let implementation = match class_method {
+        Some(class_method) => quote! { Self::#class_method },
+        None => quote! { <#super_class as #interface>::#name },
     };
     quote! {
         fn #name(
@@ -610,7 +1170,7 @@ fn generate_interface_method_implementation(
             #(#argument_names: #argument_types,)*
             token: &::rust_jni::NoException<'a>,
         ) -> ::rust_jni::JavaResult<'a, #return_type> {
-            #class_cast::#name(
+            #implementation(
                 self, #(#argument_names_1,)* token
             )
         }
@@ -635,6 +1195,63 @@ fn generate_interface_implementation(
     }
 }
 
+/// Object arguments of generated methods accept any type that can be cast to the declared
+/// class, so that subclasses can be passed without an explicit cast. The declared types are
+/// still used for computing the Java method signature.
+///
+/// Returns the parameter types of the generated method and the arguments to pass to Java.
+fn generate_method_arguments(
+    argument_names: &[Ident],
+    argument_types: &[TokenStream],
+) -> (Vec<TokenStream>, Vec<TokenStream>) {
+    argument_names
+        .iter()
+        .zip(argument_types.iter())
+        .map(
+            |(name, argument_type)| match argument_type.clone().into_iter().next() {
+                Some(TokenTree::Punct(ref punct)) if punct.as_char() == '&' => {
+                    let class = TokenStream::from_iter(argument_type.clone().into_iter().skip(1));
+                    (
+                        quote! {&impl ::rust_jni::Cast<'a, #class>},
+                        quote! {::rust_jni::Cast::<'a, #class>::cast(#name)},
+                    )
+                }
+                _ => (argument_type.clone(), quote! {#name}),
+            },
+        )
+        .unzip()
+}
+
+/// Get the type a generated method returns for a Java method returning `return_type`.
+///
+/// `@Nullable` methods return an `Option` so that a Java `null` is not mistaken for an object.
+fn generate_result_type(return_type: &TokenStream, nullable: bool) -> TokenStream {
+    if nullable {
+        quote! {::std::option::Option<#return_type>}
+    } else {
+        return_type.clone()
+    }
+}
+
+/// Methods with a `throws` clause list the checked exceptions Java declares for them.
+fn generate_throws_doc(error: &Option<MethodError>) -> TokenStream {
+    match error {
+        None => quote! {},
+        Some(error) => {
+            let exceptions = error
+                .exceptions
+                .iter()
+                .map(|exception| format!("`{}`", exception.class.to_string().replace(" ", "")))
+                .collect::<Vec<_>>()
+                .join(", ");
+            let doc = Literal::string(&format!("Declared to throw {}.", exceptions));
+            quote! {
+                #[doc = #doc]
+            }
+        }
+    }
+}
+
 fn generate_public(public: bool) -> TokenStream {
     if public {
         quote! {pub}
@@ -685,6 +1302,10 @@ mod generate_tests {
                     constructors: vec![],
                     native_methods: vec![],
                     static_native_methods: vec![],
+                    fields: vec![],
+                    static_fields: vec![],
+                    capture_error: None,
+                    builder: None,
                 }),
                 GeneratorDefinition::Class(Class {
                     class: Ident::new("test2", Span::call_site()),
@@ -698,7 +1319,11 @@ mod generate_tests {
                     static_methods: vec![],
                     native_methods: vec![],
                     static_native_methods: vec![],
+                    fields: vec![],
+                    static_fields: vec![],
                     constructors: vec![],
+                    capture_error: None,
+                    builder: None,
                 }),
             ],
         };
@@ -754,11 +1379,57 @@ mod generate_tests {
             }
 
             impl<'a> test1<'a> {
+                /// The JNI signature of the class.
+                pub const JNI_SIGNATURE: &'static str = "test/signature1";
+
                 pub fn get_class(env: &'a ::rust_jni::JniEnv<'a>, token: &::rust_jni::NoException<'a>)
                     -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::Class<'a>> {
                     ::rust_jni::java::lang::Class::find(env, "test/sign1", token)
                 }
 
+                /// Wrap a raw Java object reference if the object is an instance of this class.
+                /// Returns `None` if the reference is `null` or the object is of a different class.
+                ///
+                /// Takes ownership of the local reference.
+                /// Unsafe because an incorrect object reference can be passed.
+                pub unsafe fn try_from_raw(
+                    env: &'a ::rust_jni::JniEnv<'a>,
+                    raw_object: <::rust_jni::java::lang::Object<'a> as ::rust_jni::JavaType>::__JniType,
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::JavaResult<'a, Option<Self>> {
+                    if raw_object.is_null() {
+                        return Ok(None);
+                    }
+                    let object = <::rust_jni::java::lang::Object<'a> as ::rust_jni::__generator::FromJni<'a>>::__from_jni(env, raw_object);
+                    if !object.is_instance_of(token, &Self::get_class(env, token)?) {
+                        return Ok(None);
+                    }
+                    Ok(Some(<Self as ::rust_jni::__generator::FromJni<'a>>::__from_jni(
+                        env,
+                        ::rust_jni::java::lang::Object::take_raw_object(object).as_ptr(),
+                    )))
+                }
+
+                /// View an object as an instance of this class if it is one.
+                /// Returns `None` if the object is of a different class.
+                pub fn from_object(
+                    object: &::rust_jni::java::lang::Object<'a>,
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::JavaResult<'a, Option<Self>> {
+                    let env = object.env();
+                    if !object.is_instance_of(token, &Self::get_class(env, token)?) {
+                        return Ok(None);
+                    }
+                    let object = object.clone(token)?;
+                    // Safe because the object is an instance of this class.
+                    Ok(Some(unsafe {
+                        <Self as ::rust_jni::__generator::FromJni<'a>>::__from_jni(
+                            env,
+                            ::rust_jni::java::lang::Object::take_raw_object(object).as_ptr(),
+                        )
+                    }))
+                }
+
                 pub fn clone(&self, token: &::rust_jni::NoException<'a>) -> ::rust_jni::JavaResult<'a, Self>
                 where
                     Self: Sized,
@@ -772,6 +1443,12 @@ mod generate_tests {
                     -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::String<'a>> {
                     self.object.to_string(token)
                 }
+
+                /// Convert into a type-erased global reference, which can be stored long-term.
+                pub fn into_global_object(self, token: &::rust_jni::NoException<'a>)
+                    -> ::rust_jni::JavaResult<'a, ::rust_jni::GlobalRef<::rust_jni::java::lang::Object<'static>>> {
+                    self.object.into_global_object(token)
+                }
             }
 
             impl<'a> ::std::fmt::Display for test1<'a> {
@@ -833,11 +1510,57 @@ mod generate_tests {
             }
 
             impl<'a> test2<'a> {
+                /// The JNI signature of the class.
+                pub const JNI_SIGNATURE: &'static str = "test/signature2";
+
                 pub fn get_class(env: &'a ::rust_jni::JniEnv<'a>, token: &::rust_jni::NoException<'a>)
                     -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::Class<'a>> {
                     ::rust_jni::java::lang::Class::find(env, "test/sign2", token)
                 }
 
+                /// Wrap a raw Java object reference if the object is an instance of this class.
+                /// Returns `None` if the reference is `null` or the object is of a different class.
+                ///
+                /// Takes ownership of the local reference.
+                /// Unsafe because an incorrect object reference can be passed.
+                pub unsafe fn try_from_raw(
+                    env: &'a ::rust_jni::JniEnv<'a>,
+                    raw_object: <::rust_jni::java::lang::Object<'a> as ::rust_jni::JavaType>::__JniType,
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::JavaResult<'a, Option<Self>> {
+                    if raw_object.is_null() {
+                        return Ok(None);
+                    }
+                    let object = <::rust_jni::java::lang::Object<'a> as ::rust_jni::__generator::FromJni<'a>>::__from_jni(env, raw_object);
+                    if !object.is_instance_of(token, &Self::get_class(env, token)?) {
+                        return Ok(None);
+                    }
+                    Ok(Some(<Self as ::rust_jni::__generator::FromJni<'a>>::__from_jni(
+                        env,
+                        ::rust_jni::java::lang::Object::take_raw_object(object).as_ptr(),
+                    )))
+                }
+
+                /// View an object as an instance of this class if it is one.
+                /// Returns `None` if the object is of a different class.
+                pub fn from_object(
+                    object: &::rust_jni::java::lang::Object<'a>,
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::JavaResult<'a, Option<Self>> {
+                    let env = object.env();
+                    if !object.is_instance_of(token, &Self::get_class(env, token)?) {
+                        return Ok(None);
+                    }
+                    let object = object.clone(token)?;
+                    // Safe because the object is an instance of this class.
+                    Ok(Some(unsafe {
+                        <Self as ::rust_jni::__generator::FromJni<'a>>::__from_jni(
+                            env,
+                            ::rust_jni::java::lang::Object::take_raw_object(object).as_ptr(),
+                        )
+                    }))
+                }
+
                 pub fn clone(&self, token: &::rust_jni::NoException<'a>) -> ::rust_jni::JavaResult<'a, Self>
                 where
                     Self: Sized,
@@ -851,6 +1574,12 @@ mod generate_tests {
                     -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::String<'a>> {
                     self.object.to_string(token)
                 }
+
+                /// Convert into a type-erased global reference, which can be stored long-term.
+                pub fn into_global_object(self, token: &::rust_jni::NoException<'a>)
+                    -> ::rust_jni::JavaResult<'a, ::rust_jni::GlobalRef<::rust_jni::java::lang::Object<'static>>> {
+                    self.object.into_global_object(token)
+                }
             }
 
             impl<'a> ::std::fmt::Display for test2<'a> {
@@ -959,23 +1688,771 @@ mod generate_interface_tests {
                     arg1: type1,
                     arg2: type2,
                     token: &::rust_jni::NoException<'a>,
-                ) -> ::rust_jni::JavaResult<'a, return_type_1>;
-                fn test_method_2(
-                    &self,
+                ) -> ::rust_jni::JavaResult<'a, return_type_1>;
+                fn test_method_2(
+                    &self,
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::JavaResult<'a, return_type_2>;
+            }
+        };
+        assert_tokens_equals(generate(&input), expected);
+    }
+}
+
+#[cfg(test)]
+mod generate_annotation_type_tests {
+    use super::*;
+
+    #[test]
+    fn annotation_type() {
+        let input = AnnotationType {
+            annotation: Ident::new("test1", Span::call_site()),
+            public: false,
+        };
+        let expected = quote! {
+            trait test1 {}
+        };
+        assert_tokens_equals(generate_annotation_type(&input), expected);
+    }
+
+    #[test]
+    fn public() {
+        let input = AnnotationType {
+            annotation: Ident::new("test1", Span::call_site()),
+            public: true,
+        };
+        let expected = quote! {
+            pub trait test1 {}
+        };
+        assert_tokens_equals(generate_annotation_type(&input), expected);
+    }
+}
+
+#[cfg(test)]
+mod generate_method_error_tests {
+    use super::*;
+
+    fn test_error() -> MethodError {
+        MethodError {
+            name: Ident::new("TestMethodError", Span::call_site()),
+            typed_name: Ident::new("test_method_typed", Span::call_site()),
+            exceptions: vec![
+                ThrownException {
+                    variant: Ident::new("Io", Span::call_site()),
+                    class: quote! {::java::io::IOException},
+                },
+                ThrownException {
+                    variant: Ident::new("Sql", Span::call_site()),
+                    class: quote! {::java::sql::SQLException},
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn method_error() {
+        let expected = quote! {
+            #[derive(Debug)]
+            pub enum TestMethodError<'a> {
+                Io(::java::io::IOException<'a>),
+                Sql(::java::sql::SQLException<'a>),
+                Other(::rust_jni::java::lang::Throwable<'a>),
+            }
+
+            impl<'a> TestMethodError<'a> {
+                /// Convert a caught exception into the variant of its declared type.
+                /// Exceptions of other types are stored in the `Other` variant.
+                pub fn from_throwable(
+                    throwable: ::rust_jni::java::lang::Throwable<'a>,
+                    token: &::rust_jni::NoException<'a>,
+                ) -> Self {
+                    let is_instance = ::java::io::IOException::get_class(throwable.env(), token)
+                        .map(|class| throwable.is_instance_of(token, &class))
+                        .unwrap_or(false);
+                    if is_instance {
+                        // Safe because the exception was checked to be an instance of the class.
+                        return TestMethodError::Io(unsafe {
+                            <::java::io::IOException<'a> as ::rust_jni::__generator::FromJni<'a>>::__from_jni(
+                                throwable.env(),
+                                ::rust_jni::java::lang::Object::take_raw_object(throwable).as_ptr(),
+                            )
+                        });
+                    }
+                    let is_instance = ::java::sql::SQLException::get_class(throwable.env(), token)
+                        .map(|class| throwable.is_instance_of(token, &class))
+                        .unwrap_or(false);
+                    if is_instance {
+                        // Safe because the exception was checked to be an instance of the class.
+                        return TestMethodError::Sql(unsafe {
+                            <::java::sql::SQLException<'a> as ::rust_jni::__generator::FromJni<'a>>::__from_jni(
+                                throwable.env(),
+                                ::rust_jni::java::lang::Object::take_raw_object(throwable).as_ptr(),
+                            )
+                        });
+                    }
+                    TestMethodError::Other(throwable)
+                }
+            }
+        };
+        assert_tokens_equals(generate_method_error(&test_error(), true), expected);
+    }
+
+    #[test]
+    fn typed_method() {
+        let input = ClassMethod {
+            name: Ident::new("test_method", Span::call_site()),
+            java_name: Literal::string("testMethod"),
+            return_type: quote! {i32},
+            public: true,
+            argument_names: vec![Ident::new("arg1", Span::call_site())],
+            argument_types: vec![quote! {i64}],
+            error: Some(test_error()),
+            super_class: None,
+            nullable: false,
+            signature: None,
+        };
+        let expected = quote! {
+            #[doc = "Declared to throw `::java::io::IOException`, `::java::sql::SQLException`."]
+            pub fn test_method(
+                &self,
+                arg1: i64,
+                token: &::rust_jni::NoException<'a>,
+            ) -> ::rust_jni::JavaResult<'a, i32> {
+                // Safe because the method name and arguments are correct.
+                unsafe {
+                    ::rust_jni::__generator::call_method::<_, _, _,
+                        fn(i64,) -> i32
+                    >
+                    (
+                        self,
+                        "testMethod",
+                        (arg1,),
+                        token,
+                    )
+                }
+            }
+
+            pub fn test_method_typed(
+                &self,
+                arg1: i64,
+                token: &::rust_jni::NoException<'a>,
+            ) -> ::std::result::Result<i32, TestMethodError<'a>> {
+                self.test_method(arg1, token)
+                    .map_err(|throwable| TestMethodError::from_throwable(throwable, token))
+            }
+        };
+        assert_tokens_equals(generate_class_method(&input), expected);
+    }
+
+    #[test]
+    fn static_typed_method() {
+        let input = ClassMethod {
+            name: Ident::new("test_method", Span::call_site()),
+            java_name: Literal::string("testMethod"),
+            return_type: quote! {i32},
+            public: false,
+            argument_names: vec![Ident::new("arg1", Span::call_site())],
+            argument_types: vec![quote! {i64}],
+            error: Some(test_error()),
+            super_class: None,
+            nullable: false,
+            signature: None,
+        };
+        let expected = quote! {
+            #[doc = "Declared to throw `::java::io::IOException`, `::java::sql::SQLException`."]
+            fn test_method(
+                env: &'a ::rust_jni::JniEnv<'a>,
+                arg1: i64,
+                token: &::rust_jni::NoException<'a>,
+            ) -> ::rust_jni::JavaResult<'a, i32> {
+                // Safe because the method name and arguments are correct.
+                unsafe {
+                    ::rust_jni::__generator::call_static_method::<Self, _, _,
+                        fn(i64,) -> i32
+                    >
+                    (
+                        env,
+                        "testMethod",
+                        (arg1,),
+                        token,
+                    )
+                }
+            }
+
+            fn test_method_typed(
+                env: &'a ::rust_jni::JniEnv<'a>,
+                arg1: i64,
+                token: &::rust_jni::NoException<'a>,
+            ) -> ::std::result::Result<i32, TestMethodError<'a>> {
+                Self::test_method(env, arg1, token)
+                    .map_err(|throwable| TestMethodError::from_throwable(throwable, token))
+            }
+        };
+        assert_tokens_equals(generate_static_class_method(&input), expected);
+    }
+}
+
+#[cfg(test)]
+mod generate_class_method_tests {
+    use super::*;
+
+    fn test_method() -> ClassMethod {
+        ClassMethod {
+            name: Ident::new("test_method", Span::call_site()),
+            java_name: Literal::string("testMethod"),
+            return_type: quote! {i32},
+            public: true,
+            argument_names: vec![
+                Ident::new("arg1", Span::call_site()),
+                Ident::new("arg2", Span::call_site()),
+            ],
+            argument_types: vec![quote! {i64}, quote! {&c::d::test2<'a>}],
+            error: None,
+            super_class: None,
+            nullable: false,
+            signature: None,
+        }
+    }
+
+    #[test]
+    fn object_argument() {
+        let expected = quote! {
+            pub fn test_method(
+                &self,
+                arg1: i64,
+                arg2: &impl ::rust_jni::Cast<'a, c::d::test2<'a> >,
+                token: &::rust_jni::NoException<'a>,
+            ) -> ::rust_jni::JavaResult<'a, i32> {
+                // Safe because the method name and arguments are correct.
+                unsafe {
+                    ::rust_jni::__generator::call_method::<_, _, _,
+                        fn(i64, &c::d::test2<'a>,) -> i32
+                    >
+                    (
+                        self,
+                        "testMethod",
+                        (arg1, ::rust_jni::Cast::<'a, c::d::test2<'a> >::cast(arg2),),
+                        token,
+                    )
+                }
+            }
+        };
+        assert_tokens_equals(generate_class_method(&test_method()), expected);
+    }
+
+    #[test]
+    fn static_object_argument() {
+        let expected = quote! {
+            pub fn test_method(
+                env: &'a ::rust_jni::JniEnv<'a>,
+                arg1: i64,
+                arg2: &impl ::rust_jni::Cast<'a, c::d::test2<'a> >,
+                token: &::rust_jni::NoException<'a>,
+            ) -> ::rust_jni::JavaResult<'a, i32> {
+                // Safe because the method name and arguments are correct.
+                unsafe {
+                    ::rust_jni::__generator::call_static_method::<Self, _, _,
+                        fn(i64, &c::d::test2<'a>,) -> i32
+                    >
+                    (
+                        env,
+                        "testMethod",
+                        (arg1, ::rust_jni::Cast::<'a, c::d::test2<'a> >::cast(arg2),),
+                        token,
+                    )
+                }
+            }
+        };
+        assert_tokens_equals(generate_static_class_method(&test_method()), expected);
+    }
+
+    #[test]
+    fn super_method() {
+        let input = ClassMethod {
+            super_class: Some(quote! {::c::d::test2}),
+            ..test_method()
+        };
+        let expected = quote! {
+            pub fn test_method(
+                &self,
+                arg1: i64,
+                arg2: &impl ::rust_jni::Cast<'a, c::d::test2<'a> >,
+                token: &::rust_jni::NoException<'a>,
+            ) -> ::rust_jni::JavaResult<'a, i32> {
+                // Safe because the method name and arguments are correct.
+                unsafe {
+                    ::rust_jni::__generator::call_nonvirtual_method::<_, _, _,
+                        fn(i64, &c::d::test2<'a>,) -> i32
+                    >
+                    (
+                        self,
+                        &::c::d::test2::get_class(self.env(), token)?,
+                        "testMethod",
+                        (arg1, ::rust_jni::Cast::<'a, c::d::test2<'a> >::cast(arg2),),
+                        token,
+                    )
+                }
+            }
+        };
+        assert_tokens_equals(generate_class_method(&input), expected);
+    }
+
+    #[test]
+    fn nullable_method() {
+        let input = ClassMethod {
+            return_type: quote! {c::d::test3<'a>},
+            nullable: true,
+            ..test_method()
+        };
+        let expected = quote! {
+            pub fn test_method(
+                &self,
+                arg1: i64,
+                arg2: &impl ::rust_jni::Cast<'a, c::d::test2<'a> >,
+                token: &::rust_jni::NoException<'a>,
+            ) -> ::rust_jni::JavaResult<'a, ::std::option::Option<c::d::test3<'a> > > {
+                // Safe because the method name and arguments are correct.
+                unsafe {
+                    ::rust_jni::__generator::call_method::<_, _, _,
+                        fn(i64, &c::d::test2<'a>,) -> c::d::test3<'a>
+                    >
+                    (
+                        self,
+                        "testMethod",
+                        (arg1, ::rust_jni::Cast::<'a, c::d::test2<'a> >::cast(arg2),),
+                        token,
+                    )
+                }
+            }
+        };
+        assert_tokens_equals(generate_class_method(&input), expected);
+    }
+
+    #[test]
+    fn signature_method() {
+        let input = ClassMethod {
+            signature: Some(Literal::string("(JLc/d/test2;)I")),
+            ..test_method()
+        };
+        let expected = quote! {
+            pub fn test_method(
+                &self,
+                arg1: i64,
+                arg2: &impl ::rust_jni::Cast<'a, c::d::test2<'a> >,
+                token: &::rust_jni::NoException<'a>,
+            ) -> ::rust_jni::JavaResult<'a, i32> {
+                // Safe because the method name, signature and arguments are correct.
+                unsafe {
+                    ::rust_jni::__generator::call_method_with_signature::<_, _, _,
+                        fn(i64, &c::d::test2<'a>,) -> i32
+                    >
+                    (
+                        self,
+                        "testMethod",
+                        "(JLc/d/test2;)I",
+                        (arg1, ::rust_jni::Cast::<'a, c::d::test2<'a> >::cast(arg2),),
+                        token,
+                    )
+                }
+            }
+        };
+        assert_tokens_equals(generate_class_method(&input), expected);
+    }
+
+    #[test]
+    fn static_signature_method() {
+        let input = ClassMethod {
+            signature: Some(Literal::string("(JLc/d/test2;)I")),
+            ..test_method()
+        };
+        let expected = quote! {
+            pub fn test_method(
+                env: &'a ::rust_jni::JniEnv<'a>,
+                arg1: i64,
+                arg2: &impl ::rust_jni::Cast<'a, c::d::test2<'a> >,
+                token: &::rust_jni::NoException<'a>,
+            ) -> ::rust_jni::JavaResult<'a, i32> {
+                // Safe because the method name, signature and arguments are correct.
+                unsafe {
+                    ::rust_jni::__generator::call_static_method_with_signature::<Self, _, _,
+                        fn(i64, &c::d::test2<'a>,) -> i32
+                    >
+                    (
+                        env,
+                        "testMethod",
+                        "(JLc/d/test2;)I",
+                        (arg1, ::rust_jni::Cast::<'a, c::d::test2<'a> >::cast(arg2),),
+                        token,
+                    )
+                }
+            }
+        };
+        assert_tokens_equals(generate_static_class_method(&input), expected);
+    }
+
+    #[test]
+    #[should_panic(expected = "@Signature is not supported on @Super methods.")]
+    fn super_signature_method() {
+        let input = ClassMethod {
+            super_class: Some(quote! {::c::d::test2}),
+            signature: Some(Literal::string("(JLc/d/test2;)I")),
+            ..test_method()
+        };
+        generate_class_method(&input);
+    }
+
+    #[test]
+    #[should_panic(expected = "@Super is only supported on non-static methods.")]
+    fn static_super_method() {
+        let input = ClassMethod {
+            super_class: Some(quote! {::c::d::test2}),
+            ..test_method()
+        };
+        generate_static_class_method(&input);
+    }
+}
+
+#[cfg(test)]
+mod generate_interface_method_implementation_tests {
+    use super::*;
+
+    #[test]
+    fn renamed() {
+        let method = InterfaceMethodImplementation {
+            name: Ident::new("foo", Span::call_site()),
+            return_type: quote! {i64},
+            argument_names: vec![Ident::new("arg1", Span::call_site())],
+            argument_types: vec![quote! {i32}],
+            class_method: Some(Ident::new("java_foo", Span::call_site())),
+        };
+        let expected = quote! {
+            fn foo(
+                &self,
+                arg1: i32,
+                token: &::rust_jni::NoException<'a>,
+            ) -> ::rust_jni::JavaResult<'a, i64> {
+                Self::java_foo(
+                    self, arg1, token
+                )
+            }
+        };
+        assert_tokens_equals(
+            generate_interface_method_implementation(
+                &method,
+                &quote! {e::f::test3},
+                &quote! {c::d::test2},
+            ),
+            expected,
+        );
+    }
+
+    #[test]
+    fn renamed_inherited() {
+        let method = InterfaceMethodImplementation {
+            name: Ident::new("foo", Span::call_site()),
+            return_type: quote! {i64},
+            argument_names: vec![],
+            argument_types: vec![],
+            class_method: None,
+        };
+        let expected = quote! {
+            fn foo(
+                &self,
+                token: &::rust_jni::NoException<'a>,
+            ) -> ::rust_jni::JavaResult<'a, i64> {
+                <c::d::test2 as e::f::test3>::foo(
+                    self, token
+                )
+            }
+        };
+        assert_tokens_equals(
+            generate_interface_method_implementation(
+                &method,
+                &quote! {e::f::test3},
+                &quote! {c::d::test2},
+            ),
+            expected,
+        );
+    }
+}
+
+#[cfg(test)]
+mod generate_constructor_tests {
+    use super::*;
+
+    #[test]
+    fn private_constructor() {
+        let constructor = Constructor {
+            name: Ident::new("init", Span::call_site()),
+            public: false,
+            argument_names: vec![Ident::new("arg1", Span::call_site())],
+            argument_types: vec![quote! {i32}],
+        };
+        let expected = quote! {
+            fn init(
+                env: &'a ::rust_jni::JniEnv<'a>,
+                arg1: i32,
+                token: &::rust_jni::NoException<'a>,
+            ) -> ::rust_jni::JavaResult<'a, Self> {
+                // Safe because the method name and arguments are correct.
+                unsafe {
+                    ::rust_jni::__generator::call_constructor::<Self, _, fn(i32,)>
+                    (
+                        env,
+                        (arg1,),
+                        token,
+                    )
+                }
+            }
+        };
+        assert_tokens_equals(generate_constructor(&constructor), expected);
+    }
+}
+
+#[cfg(test)]
+mod generate_builder_tests {
+    use super::*;
+
+    #[test]
+    fn builder() {
+        let constructors = vec![
+            Constructor {
+                name: Ident::new("init", Span::call_site()),
+                public: true,
+                argument_names: vec![
+                    Ident::new("arg1", Span::call_site()),
+                    Ident::new("arg2", Span::call_site()),
+                ],
+                argument_types: vec![quote! {i32}, quote! {&c::d::test2<'a>}],
+            },
+            Constructor {
+                name: Ident::new("init_empty", Span::call_site()),
+                public: false,
+                argument_names: vec![],
+                argument_types: vec![],
+            },
+        ];
+        let expected = quote! {
+            /// A builder for calling one of the overloaded constructors of the class.
+            pub struct TestClass3Builder<'a, 'b> {
+                env: &'a ::rust_jni::JniEnv<'a>,
+                constructor: ::std::option::Option<
+                    Box<dyn FnOnce(&::rust_jni::NoException<'a>) -> ::rust_jni::JavaResult<'a, TestClass3<'a>> + 'b>
+                >,
+            }
+
+            impl<'a> TestClass3<'a> {
+                pub fn builder<'b>(env: &'a ::rust_jni::JniEnv<'a>) -> TestClass3Builder<'a, 'b> {
+                    TestClass3Builder {
+                        env,
+                        constructor: None,
+                    }
+                }
+            }
+
+            impl<'a, 'b> TestClass3Builder<'a, 'b> {
+                pub fn with_init(self, arg1: i32, arg2: &'b c::d::test2<'a>,) -> Self {
+                    let env = self.env;
+                    Self {
+                        env,
+                        constructor: Some(Box::new(move |token| TestClass3::init(env, arg1, arg2, token))),
+                    }
+                }
+
+                fn with_init_empty(self,) -> Self {
+                    let env = self.env;
+                    Self {
+                        env,
+                        constructor: Some(Box::new(move |token| TestClass3::init_empty(env, token))),
+                    }
+                }
+
+                /// Call the selected constructor.
+                ///
+                /// Panics if no constructor was selected.
+                pub fn build(self, token: &::rust_jni::NoException<'a>) -> ::rust_jni::JavaResult<'a, TestClass3<'a>> {
+                    let constructor = self
+                        .constructor
+                        .expect("No constructor was selected for the builder.");
+                    constructor(token)
+                }
+            }
+        };
+        assert_tokens_equals(
+            generate_builder(
+                &Ident::new("TestClass3Builder", Span::call_site()),
+                &Ident::new("TestClass3", Span::call_site()),
+                &constructors,
+                true,
+            ),
+            expected,
+        );
+    }
+}
+
+#[cfg(test)]
+mod generate_class_tests {
+    use super::*;
+
+    #[test]
+    fn class() {
+        let input = GeneratorData {
+            definitions: vec![GeneratorDefinition::Class(Class {
+                class: Ident::new("test1", Span::call_site()),
+                public: false,
+                super_class: quote! {c::d::test2},
+                transitive_extends: vec![],
+                implements: vec![],
+                signature: Literal::string("test/sign1"),
+                full_signature: Literal::string("test/signature1"),
+                methods: vec![],
+                static_methods: vec![],
+                native_methods: vec![],
+                static_native_methods: vec![],
+                fields: vec![],
+                static_fields: vec![],
+                constructors: vec![],
+                capture_error: None,
+                builder: None,
+            })],
+        };
+        let expected = quote! {
+            #[derive(Debug)]
+            struct test1<'env> {
+                object: c::d::test2<'env>,
+            }
+
+            impl<'a> ::rust_jni::JavaType for test1<'a> {
+                #[doc(hidden)]
+                type __JniType = <::rust_jni::java::lang::Object<'a> as ::rust_jni::JavaType>::__JniType;
+
+                #[doc(hidden)]
+                fn __signature() -> &'static str {
+                    "test/signature1"
+                }
+            }
+
+            impl<'a> ::rust_jni::__generator::ToJni for test1<'a> {
+                unsafe fn __to_jni(&self) -> Self::__JniType {
+                    self.raw_object()
+                }
+            }
+
+            impl<'a> ::rust_jni::__generator::FromJni<'a> for test1<'a> {
+                unsafe fn __from_jni(env: &'a ::rust_jni::JniEnv<'a>, value: Self::__JniType) -> Self {
+                    Self {
+                        object: <c::d::test2 as ::rust_jni::__generator::FromJni<'a>>::__from_jni(env, value),
+                    }
+                }
+            }
+
+            impl<'a> ::rust_jni::Cast<'a, test1<'a>> for test1<'a> {
+                #[doc(hidden)]
+                fn cast<'b>(&'b self) -> &'b test1<'a> {
+                    self
+                }
+            }
+
+            impl<'a> ::std::ops::Deref for test1<'a> {
+                type Target = c::d::test2<'a>;
+
+                fn deref(&self) -> &Self::Target {
+                    &self.object
+                }
+            }
+
+            impl<'a> test1<'a> {
+                /// The JNI signature of the class.
+                pub const JNI_SIGNATURE: &'static str = "test/signature1";
+
+                pub fn get_class(env: &'a ::rust_jni::JniEnv<'a>, token: &::rust_jni::NoException<'a>)
+                    -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::Class<'a>> {
+                    ::rust_jni::java::lang::Class::find(env, "test/sign1", token)
+                }
+
+                /// Wrap a raw Java object reference if the object is an instance of this class.
+                /// Returns `None` if the reference is `null` or the object is of a different class.
+                ///
+                /// Takes ownership of the local reference.
+                /// Unsafe because an incorrect object reference can be passed.
+                pub unsafe fn try_from_raw(
+                    env: &'a ::rust_jni::JniEnv<'a>,
+                    raw_object: <::rust_jni::java::lang::Object<'a> as ::rust_jni::JavaType>::__JniType,
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::JavaResult<'a, Option<Self>> {
+                    if raw_object.is_null() {
+                        return Ok(None);
+                    }
+                    let object = <::rust_jni::java::lang::Object<'a> as ::rust_jni::__generator::FromJni<'a>>::__from_jni(env, raw_object);
+                    if !object.is_instance_of(token, &Self::get_class(env, token)?) {
+                        return Ok(None);
+                    }
+                    Ok(Some(<Self as ::rust_jni::__generator::FromJni<'a>>::__from_jni(
+                        env,
+                        ::rust_jni::java::lang::Object::take_raw_object(object).as_ptr(),
+                    )))
+                }
+
+                /// View an object as an instance of this class if it is one.
+                /// Returns `None` if the object is of a different class.
+                pub fn from_object(
+                    object: &::rust_jni::java::lang::Object<'a>,
                     token: &::rust_jni::NoException<'a>,
-                ) -> ::rust_jni::JavaResult<'a, return_type_2>;
+                ) -> ::rust_jni::JavaResult<'a, Option<Self>> {
+                    let env = object.env();
+                    if !object.is_instance_of(token, &Self::get_class(env, token)?) {
+                        return Ok(None);
+                    }
+                    let object = object.clone(token)?;
+                    // Safe because the object is an instance of this class.
+                    Ok(Some(unsafe {
+                        <Self as ::rust_jni::__generator::FromJni<'a>>::__from_jni(
+                            env,
+                            ::rust_jni::java::lang::Object::take_raw_object(object).as_ptr(),
+                        )
+                    }))
+                }
+
+                pub fn clone(&self, token: &::rust_jni::NoException<'a>) -> ::rust_jni::JavaResult<'a, Self>
+                where
+                    Self: Sized,
+                {
+                    self.object
+                        .clone(token)
+                        .map(|object| Self { object })
+                }
+
+                pub fn to_string(&self, token: &::rust_jni::NoException<'a>)
+                    -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::String<'a>> {
+                    self.object.to_string(token)
+                }
+
+                /// Convert into a type-erased global reference, which can be stored long-term.
+                pub fn into_global_object(self, token: &::rust_jni::NoException<'a>)
+                    -> ::rust_jni::JavaResult<'a, ::rust_jni::GlobalRef<::rust_jni::java::lang::Object<'static>>> {
+                    self.object.into_global_object(token)
+                }
+            }
+
+            impl<'a> ::std::fmt::Display for test1<'a> {
+                fn fmt(&self, formatter: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                    self.object.fmt(formatter)
+                }
+            }
+
+            impl<'a, T> PartialEq<T> for test1<'a> where T: ::rust_jni::Cast<'a, ::rust_jni::java::lang::Object<'a>> {
+                fn eq(&self, other: &T) -> bool {
+                    self.object.eq(other)
+                }
             }
+
+            impl<'a> Eq for test1<'a> {}
         };
         assert_tokens_equals(generate(&input), expected);
     }
-}
-
-#[cfg(test)]
-mod generate_class_tests {
-    use super::*;
 
     #[test]
-    fn class() {
+    fn class_capture_error() {
         let input = GeneratorData {
             definitions: vec![GeneratorDefinition::Class(Class {
                 class: Ident::new("test1", Span::call_site()),
@@ -989,7 +2466,11 @@ mod generate_class_tests {
                 static_methods: vec![],
                 native_methods: vec![],
                 static_native_methods: vec![],
+                fields: vec![],
+                static_fields: vec![],
                 constructors: vec![],
+                capture_error: Some(Ident::new("TestError", Span::call_site())),
+                builder: None,
             })],
         };
         let expected = quote! {
@@ -1038,11 +2519,57 @@ mod generate_class_tests {
             }
 
             impl<'a> test1<'a> {
+                /// The JNI signature of the class.
+                pub const JNI_SIGNATURE: &'static str = "test/signature1";
+
                 pub fn get_class(env: &'a ::rust_jni::JniEnv<'a>, token: &::rust_jni::NoException<'a>)
                     -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::Class<'a>> {
                     ::rust_jni::java::lang::Class::find(env, "test/sign1", token)
                 }
 
+                /// Wrap a raw Java object reference if the object is an instance of this class.
+                /// Returns `None` if the reference is `null` or the object is of a different class.
+                ///
+                /// Takes ownership of the local reference.
+                /// Unsafe because an incorrect object reference can be passed.
+                pub unsafe fn try_from_raw(
+                    env: &'a ::rust_jni::JniEnv<'a>,
+                    raw_object: <::rust_jni::java::lang::Object<'a> as ::rust_jni::JavaType>::__JniType,
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::JavaResult<'a, Option<Self>> {
+                    if raw_object.is_null() {
+                        return Ok(None);
+                    }
+                    let object = <::rust_jni::java::lang::Object<'a> as ::rust_jni::__generator::FromJni<'a>>::__from_jni(env, raw_object);
+                    if !object.is_instance_of(token, &Self::get_class(env, token)?) {
+                        return Ok(None);
+                    }
+                    Ok(Some(<Self as ::rust_jni::__generator::FromJni<'a>>::__from_jni(
+                        env,
+                        ::rust_jni::java::lang::Object::take_raw_object(object).as_ptr(),
+                    )))
+                }
+
+                /// View an object as an instance of this class if it is one.
+                /// Returns `None` if the object is of a different class.
+                pub fn from_object(
+                    object: &::rust_jni::java::lang::Object<'a>,
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::JavaResult<'a, Option<Self>> {
+                    let env = object.env();
+                    if !object.is_instance_of(token, &Self::get_class(env, token)?) {
+                        return Ok(None);
+                    }
+                    let object = object.clone(token)?;
+                    // Safe because the object is an instance of this class.
+                    Ok(Some(unsafe {
+                        <Self as ::rust_jni::__generator::FromJni<'a>>::__from_jni(
+                            env,
+                            ::rust_jni::java::lang::Object::take_raw_object(object).as_ptr(),
+                        )
+                    }))
+                }
+
                 pub fn clone(&self, token: &::rust_jni::NoException<'a>) -> ::rust_jni::JavaResult<'a, Self>
                 where
                     Self: Sized,
@@ -1056,6 +2583,34 @@ mod generate_class_tests {
                     -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::String<'a>> {
                     self.object.to_string(token)
                 }
+
+                /// Convert into a type-erased global reference, which can be stored long-term.
+                pub fn into_global_object(self, token: &::rust_jni::NoException<'a>)
+                    -> ::rust_jni::JavaResult<'a, ::rust_jni::GlobalRef<::rust_jni::java::lang::Object<'static>>> {
+                    self.object.into_global_object(token)
+                }
+            }
+
+            #[derive(Debug, Clone, PartialEq, Eq)]
+            struct TestError(pub ::rust_jni::CapturedJavaError);
+
+            impl ::std::fmt::Display for TestError {
+                fn fmt(&self, formatter: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                    self.0.fmt(formatter)
+                }
+            }
+
+            impl ::std::error::Error for TestError {}
+
+            impl<'a> test1<'a> {
+                /// Capture a caught Java exception into an owned error value that doesn't
+                /// require a token to be inspected.
+                pub fn capture(
+                    throwable: &::rust_jni::java::lang::Throwable<'a>,
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::JavaResult<'a, TestError> {
+                    ::rust_jni::CapturedJavaError::capture(token, throwable).map(TestError)
+                }
             }
 
             impl<'a> ::std::fmt::Display for test1<'a> {
@@ -1090,7 +2645,11 @@ mod generate_class_tests {
                 static_methods: vec![],
                 native_methods: vec![],
                 static_native_methods: vec![],
+                fields: vec![],
+                static_fields: vec![],
                 constructors: vec![],
+                capture_error: None,
+                builder: None,
             })],
         };
         let expected = quote! {
@@ -1139,11 +2698,57 @@ mod generate_class_tests {
             }
 
             impl<'a> test1<'a> {
+                /// The JNI signature of the class.
+                pub const JNI_SIGNATURE: &'static str = "test/signature1";
+
                 pub fn get_class(env: &'a ::rust_jni::JniEnv<'a>, token: &::rust_jni::NoException<'a>)
                     -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::Class<'a>> {
                     ::rust_jni::java::lang::Class::find(env, "test/sign1", token)
                 }
 
+                /// Wrap a raw Java object reference if the object is an instance of this class.
+                /// Returns `None` if the reference is `null` or the object is of a different class.
+                ///
+                /// Takes ownership of the local reference.
+                /// Unsafe because an incorrect object reference can be passed.
+                pub unsafe fn try_from_raw(
+                    env: &'a ::rust_jni::JniEnv<'a>,
+                    raw_object: <::rust_jni::java::lang::Object<'a> as ::rust_jni::JavaType>::__JniType,
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::JavaResult<'a, Option<Self>> {
+                    if raw_object.is_null() {
+                        return Ok(None);
+                    }
+                    let object = <::rust_jni::java::lang::Object<'a> as ::rust_jni::__generator::FromJni<'a>>::__from_jni(env, raw_object);
+                    if !object.is_instance_of(token, &Self::get_class(env, token)?) {
+                        return Ok(None);
+                    }
+                    Ok(Some(<Self as ::rust_jni::__generator::FromJni<'a>>::__from_jni(
+                        env,
+                        ::rust_jni::java::lang::Object::take_raw_object(object).as_ptr(),
+                    )))
+                }
+
+                /// View an object as an instance of this class if it is one.
+                /// Returns `None` if the object is of a different class.
+                pub fn from_object(
+                    object: &::rust_jni::java::lang::Object<'a>,
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::JavaResult<'a, Option<Self>> {
+                    let env = object.env();
+                    if !object.is_instance_of(token, &Self::get_class(env, token)?) {
+                        return Ok(None);
+                    }
+                    let object = object.clone(token)?;
+                    // Safe because the object is an instance of this class.
+                    Ok(Some(unsafe {
+                        <Self as ::rust_jni::__generator::FromJni<'a>>::__from_jni(
+                            env,
+                            ::rust_jni::java::lang::Object::take_raw_object(object).as_ptr(),
+                        )
+                    }))
+                }
+
                 pub fn clone(&self, token: &::rust_jni::NoException<'a>) -> ::rust_jni::JavaResult<'a, Self>
                 where
                     Self: Sized,
@@ -1157,6 +2762,12 @@ mod generate_class_tests {
                     -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::String<'a>> {
                     self.object.to_string(token)
                 }
+
+                /// Convert into a type-erased global reference, which can be stored long-term.
+                pub fn into_global_object(self, token: &::rust_jni::NoException<'a>)
+                    -> ::rust_jni::JavaResult<'a, ::rust_jni::GlobalRef<::rust_jni::java::lang::Object<'static>>> {
+                    self.object.into_global_object(token)
+                }
             }
 
             impl<'a> ::std::fmt::Display for test1<'a> {
@@ -1191,7 +2802,11 @@ mod generate_class_tests {
                 static_methods: vec![],
                 native_methods: vec![],
                 static_native_methods: vec![],
+                fields: vec![],
+                static_fields: vec![],
                 constructors: vec![],
+                capture_error: None,
+                builder: None,
             })],
         };
         let expected = quote! {
@@ -1254,11 +2869,57 @@ mod generate_class_tests {
             }
 
             impl<'a> test1<'a> {
+                /// The JNI signature of the class.
+                pub const JNI_SIGNATURE: &'static str = "test/signature1";
+
                 pub fn get_class(env: &'a ::rust_jni::JniEnv<'a>, token: &::rust_jni::NoException<'a>)
                     -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::Class<'a>> {
                     ::rust_jni::java::lang::Class::find(env, "test/sign1", token)
                 }
 
+                /// Wrap a raw Java object reference if the object is an instance of this class.
+                /// Returns `None` if the reference is `null` or the object is of a different class.
+                ///
+                /// Takes ownership of the local reference.
+                /// Unsafe because an incorrect object reference can be passed.
+                pub unsafe fn try_from_raw(
+                    env: &'a ::rust_jni::JniEnv<'a>,
+                    raw_object: <::rust_jni::java::lang::Object<'a> as ::rust_jni::JavaType>::__JniType,
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::JavaResult<'a, Option<Self>> {
+                    if raw_object.is_null() {
+                        return Ok(None);
+                    }
+                    let object = <::rust_jni::java::lang::Object<'a> as ::rust_jni::__generator::FromJni<'a>>::__from_jni(env, raw_object);
+                    if !object.is_instance_of(token, &Self::get_class(env, token)?) {
+                        return Ok(None);
+                    }
+                    Ok(Some(<Self as ::rust_jni::__generator::FromJni<'a>>::__from_jni(
+                        env,
+                        ::rust_jni::java::lang::Object::take_raw_object(object).as_ptr(),
+                    )))
+                }
+
+                /// View an object as an instance of this class if it is one.
+                /// Returns `None` if the object is of a different class.
+                pub fn from_object(
+                    object: &::rust_jni::java::lang::Object<'a>,
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::JavaResult<'a, Option<Self>> {
+                    let env = object.env();
+                    if !object.is_instance_of(token, &Self::get_class(env, token)?) {
+                        return Ok(None);
+                    }
+                    let object = object.clone(token)?;
+                    // Safe because the object is an instance of this class.
+                    Ok(Some(unsafe {
+                        <Self as ::rust_jni::__generator::FromJni<'a>>::__from_jni(
+                            env,
+                            ::rust_jni::java::lang::Object::take_raw_object(object).as_ptr(),
+                        )
+                    }))
+                }
+
                 pub fn clone(&self, token: &::rust_jni::NoException<'a>) -> ::rust_jni::JavaResult<'a, Self>
                 where
                     Self: Sized,
@@ -1272,6 +2933,12 @@ mod generate_class_tests {
                     -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::String<'a>> {
                     self.object.to_string(token)
                 }
+
+                /// Convert into a type-erased global reference, which can be stored long-term.
+                pub fn into_global_object(self, token: &::rust_jni::NoException<'a>)
+                    -> ::rust_jni::JavaResult<'a, ::rust_jni::GlobalRef<::rust_jni::java::lang::Object<'static>>> {
+                    self.object.into_global_object(token)
+                }
             }
 
             impl<'a> ::std::fmt::Display for test1<'a> {
@@ -1313,6 +2980,10 @@ mod generate_class_tests {
                             Ident::new("arg2", Span::call_site()),
                         ],
                         argument_types: vec![quote! {type1}, quote! {type2}],
+                        error: None,
+                        super_class: None,
+                        nullable: false,
+                        signature: None,
                     },
                     ClassMethod {
                         name: Ident::new("test_method_2", Span::call_site()),
@@ -1321,12 +2992,20 @@ mod generate_class_tests {
                         public: true,
                         argument_names: vec![],
                         argument_types: vec![],
+                        error: None,
+                        super_class: None,
+                        nullable: false,
+                        signature: None,
                     },
                 ],
                 static_methods: vec![],
                 native_methods: vec![],
                 static_native_methods: vec![],
+                fields: vec![],
+                static_fields: vec![],
                 constructors: vec![],
+                capture_error: None,
+                builder: None,
             })],
         };
         let expected = quote! {
@@ -1375,11 +3054,57 @@ mod generate_class_tests {
             }
 
             impl<'a> test1<'a> {
+                /// The JNI signature of the class.
+                pub const JNI_SIGNATURE: &'static str = "test/signature1";
+
                 pub fn get_class(env: &'a ::rust_jni::JniEnv<'a>, token: &::rust_jni::NoException<'a>)
                     -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::Class<'a>> {
                     ::rust_jni::java::lang::Class::find(env, "test/sign1", token)
                 }
 
+                /// Wrap a raw Java object reference if the object is an instance of this class.
+                /// Returns `None` if the reference is `null` or the object is of a different class.
+                ///
+                /// Takes ownership of the local reference.
+                /// Unsafe because an incorrect object reference can be passed.
+                pub unsafe fn try_from_raw(
+                    env: &'a ::rust_jni::JniEnv<'a>,
+                    raw_object: <::rust_jni::java::lang::Object<'a> as ::rust_jni::JavaType>::__JniType,
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::JavaResult<'a, Option<Self>> {
+                    if raw_object.is_null() {
+                        return Ok(None);
+                    }
+                    let object = <::rust_jni::java::lang::Object<'a> as ::rust_jni::__generator::FromJni<'a>>::__from_jni(env, raw_object);
+                    if !object.is_instance_of(token, &Self::get_class(env, token)?) {
+                        return Ok(None);
+                    }
+                    Ok(Some(<Self as ::rust_jni::__generator::FromJni<'a>>::__from_jni(
+                        env,
+                        ::rust_jni::java::lang::Object::take_raw_object(object).as_ptr(),
+                    )))
+                }
+
+                /// View an object as an instance of this class if it is one.
+                /// Returns `None` if the object is of a different class.
+                pub fn from_object(
+                    object: &::rust_jni::java::lang::Object<'a>,
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::JavaResult<'a, Option<Self>> {
+                    let env = object.env();
+                    if !object.is_instance_of(token, &Self::get_class(env, token)?) {
+                        return Ok(None);
+                    }
+                    let object = object.clone(token)?;
+                    // Safe because the object is an instance of this class.
+                    Ok(Some(unsafe {
+                        <Self as ::rust_jni::__generator::FromJni<'a>>::__from_jni(
+                            env,
+                            ::rust_jni::java::lang::Object::take_raw_object(object).as_ptr(),
+                        )
+                    }))
+                }
+
                 pub fn clone(&self, token: &::rust_jni::NoException<'a>) -> ::rust_jni::JavaResult<'a, Self>
                 where
                     Self: Sized,
@@ -1394,6 +3119,12 @@ mod generate_class_tests {
                     self.object.to_string(token)
                 }
 
+                /// Convert into a type-erased global reference, which can be stored long-term.
+                pub fn into_global_object(self, token: &::rust_jni::NoException<'a>)
+                    -> ::rust_jni::JavaResult<'a, ::rust_jni::GlobalRef<::rust_jni::java::lang::Object<'static>>> {
+                    self.object.into_global_object(token)
+                }
+
                 fn test_method_1(
                     &self,
                     arg1: type1,
@@ -1471,6 +3202,10 @@ mod generate_class_tests {
                             Ident::new("arg2", Span::call_site()),
                         ],
                         argument_types: vec![quote! {type1}, quote! {type2}],
+                        error: None,
+                        super_class: None,
+                        nullable: false,
+                        signature: None,
                     },
                     ClassMethod {
                         name: Ident::new("test_method_2", Span::call_site()),
@@ -1479,11 +3214,19 @@ mod generate_class_tests {
                         public: true,
                         argument_names: vec![],
                         argument_types: vec![],
+                        error: None,
+                        super_class: None,
+                        nullable: false,
+                        signature: None,
                     },
                 ],
                 native_methods: vec![],
                 static_native_methods: vec![],
+                fields: vec![],
+                static_fields: vec![],
                 constructors: vec![],
+                capture_error: None,
+                builder: None,
             })],
         };
         let expected = quote! {
@@ -1532,11 +3275,57 @@ mod generate_class_tests {
             }
 
             impl<'a> test1<'a> {
+                /// The JNI signature of the class.
+                pub const JNI_SIGNATURE: &'static str = "test/signature1";
+
                 pub fn get_class(env: &'a ::rust_jni::JniEnv<'a>, token: &::rust_jni::NoException<'a>)
                     -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::Class<'a>> {
                     ::rust_jni::java::lang::Class::find(env, "test/sign1", token)
                 }
 
+                /// Wrap a raw Java object reference if the object is an instance of this class.
+                /// Returns `None` if the reference is `null` or the object is of a different class.
+                ///
+                /// Takes ownership of the local reference.
+                /// Unsafe because an incorrect object reference can be passed.
+                pub unsafe fn try_from_raw(
+                    env: &'a ::rust_jni::JniEnv<'a>,
+                    raw_object: <::rust_jni::java::lang::Object<'a> as ::rust_jni::JavaType>::__JniType,
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::JavaResult<'a, Option<Self>> {
+                    if raw_object.is_null() {
+                        return Ok(None);
+                    }
+                    let object = <::rust_jni::java::lang::Object<'a> as ::rust_jni::__generator::FromJni<'a>>::__from_jni(env, raw_object);
+                    if !object.is_instance_of(token, &Self::get_class(env, token)?) {
+                        return Ok(None);
+                    }
+                    Ok(Some(<Self as ::rust_jni::__generator::FromJni<'a>>::__from_jni(
+                        env,
+                        ::rust_jni::java::lang::Object::take_raw_object(object).as_ptr(),
+                    )))
+                }
+
+                /// View an object as an instance of this class if it is one.
+                /// Returns `None` if the object is of a different class.
+                pub fn from_object(
+                    object: &::rust_jni::java::lang::Object<'a>,
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::JavaResult<'a, Option<Self>> {
+                    let env = object.env();
+                    if !object.is_instance_of(token, &Self::get_class(env, token)?) {
+                        return Ok(None);
+                    }
+                    let object = object.clone(token)?;
+                    // Safe because the object is an instance of this class.
+                    Ok(Some(unsafe {
+                        <Self as ::rust_jni::__generator::FromJni<'a>>::__from_jni(
+                            env,
+                            ::rust_jni::java::lang::Object::take_raw_object(object).as_ptr(),
+                        )
+                    }))
+                }
+
                 pub fn clone(&self, token: &::rust_jni::NoException<'a>) -> ::rust_jni::JavaResult<'a, Self>
                 where
                     Self: Sized,
@@ -1551,6 +3340,12 @@ mod generate_class_tests {
                     self.object.to_string(token)
                 }
 
+                /// Convert into a type-erased global reference, which can be stored long-term.
+                pub fn into_global_object(self, token: &::rust_jni::NoException<'a>)
+                    -> ::rust_jni::JavaResult<'a, ::rust_jni::GlobalRef<::rust_jni::java::lang::Object<'static>>> {
+                    self.object.into_global_object(token)
+                }
+
                 fn test_method_1(
                     env: &'a ::rust_jni::JniEnv<'a>,
                     arg1: type1,
@@ -1620,6 +3415,8 @@ mod generate_class_tests {
                 static_methods: vec![],
                 native_methods: vec![],
                 static_native_methods: vec![],
+                fields: vec![],
+                static_fields: vec![],
                 constructors: vec![
                     Constructor {
                         name: Ident::new("test_method_1", Span::call_site()),
@@ -1637,6 +3434,8 @@ mod generate_class_tests {
                         argument_types: vec![],
                     },
                 ],
+                capture_error: None,
+                builder: None,
             })],
         };
         let expected = quote! {
@@ -1685,11 +3484,57 @@ mod generate_class_tests {
             }
 
             impl<'a> test1<'a> {
+                /// The JNI signature of the class.
+                pub const JNI_SIGNATURE: &'static str = "test/signature1";
+
                 pub fn get_class(env: &'a ::rust_jni::JniEnv<'a>, token: &::rust_jni::NoException<'a>)
                     -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::Class<'a>> {
                     ::rust_jni::java::lang::Class::find(env, "test/sign1", token)
                 }
 
+                /// Wrap a raw Java object reference if the object is an instance of this class.
+                /// Returns `None` if the reference is `null` or the object is of a different class.
+                ///
+                /// Takes ownership of the local reference.
+                /// Unsafe because an incorrect object reference can be passed.
+                pub unsafe fn try_from_raw(
+                    env: &'a ::rust_jni::JniEnv<'a>,
+                    raw_object: <::rust_jni::java::lang::Object<'a> as ::rust_jni::JavaType>::__JniType,
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::JavaResult<'a, Option<Self>> {
+                    if raw_object.is_null() {
+                        return Ok(None);
+                    }
+                    let object = <::rust_jni::java::lang::Object<'a> as ::rust_jni::__generator::FromJni<'a>>::__from_jni(env, raw_object);
+                    if !object.is_instance_of(token, &Self::get_class(env, token)?) {
+                        return Ok(None);
+                    }
+                    Ok(Some(<Self as ::rust_jni::__generator::FromJni<'a>>::__from_jni(
+                        env,
+                        ::rust_jni::java::lang::Object::take_raw_object(object).as_ptr(),
+                    )))
+                }
+
+                /// View an object as an instance of this class if it is one.
+                /// Returns `None` if the object is of a different class.
+                pub fn from_object(
+                    object: &::rust_jni::java::lang::Object<'a>,
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::JavaResult<'a, Option<Self>> {
+                    let env = object.env();
+                    if !object.is_instance_of(token, &Self::get_class(env, token)?) {
+                        return Ok(None);
+                    }
+                    let object = object.clone(token)?;
+                    // Safe because the object is an instance of this class.
+                    Ok(Some(unsafe {
+                        <Self as ::rust_jni::__generator::FromJni<'a>>::__from_jni(
+                            env,
+                            ::rust_jni::java::lang::Object::take_raw_object(object).as_ptr(),
+                        )
+                    }))
+                }
+
                 pub fn clone(&self, token: &::rust_jni::NoException<'a>) -> ::rust_jni::JavaResult<'a, Self>
                 where
                     Self: Sized,
@@ -1704,6 +3549,12 @@ mod generate_class_tests {
                     self.object.to_string(token)
                 }
 
+                /// Convert into a type-erased global reference, which can be stored long-term.
+                pub fn into_global_object(self, token: &::rust_jni::NoException<'a>)
+                    -> ::rust_jni::JavaResult<'a, ::rust_jni::GlobalRef<::rust_jni::java::lang::Object<'static>>> {
+                    self.object.into_global_object(token)
+                }
+
                 fn test_method_1(
                     env: &'a ::rust_jni::JniEnv<'a>,
                     arg1: type1,
@@ -1774,6 +3625,7 @@ mod generate_class_tests {
                         name: Ident::new("test_method_1", Span::call_site()),
                         rust_name: Ident::new("test_method_1_rust", Span::call_site()),
                         java_name: Ident::new("testMethod1", Span::call_site()),
+                        signature: Literal::string("(II)J"),
                         return_type: quote! {return_type_1},
                         public: false,
                         argument_names: vec![
@@ -1788,6 +3640,7 @@ mod generate_class_tests {
                         name: Ident::new("test_method_2", Span::call_site()),
                         rust_name: Ident::new("test_method_2_rust", Span::call_site()),
                         java_name: Ident::new("testMethod2", Span::call_site()),
+                        signature: Literal::string("()V"),
                         return_type: quote! {return_type_2},
                         public: true,
                         argument_names: vec![],
@@ -1797,7 +3650,11 @@ mod generate_class_tests {
                     },
                 ],
                 static_native_methods: vec![],
+                fields: vec![],
+                static_fields: vec![],
                 constructors: vec![],
+                capture_error: None,
+                builder: None,
             })],
         };
         let expected = quote! {
@@ -1846,11 +3703,57 @@ mod generate_class_tests {
             }
 
             impl<'a> test1<'a> {
+                /// The JNI signature of the class.
+                pub const JNI_SIGNATURE: &'static str = "test/signature1";
+
                 pub fn get_class(env: &'a ::rust_jni::JniEnv<'a>, token: &::rust_jni::NoException<'a>)
                     -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::Class<'a>> {
                     ::rust_jni::java::lang::Class::find(env, "test/sign1", token)
                 }
 
+                /// Wrap a raw Java object reference if the object is an instance of this class.
+                /// Returns `None` if the reference is `null` or the object is of a different class.
+                ///
+                /// Takes ownership of the local reference.
+                /// Unsafe because an incorrect object reference can be passed.
+                pub unsafe fn try_from_raw(
+                    env: &'a ::rust_jni::JniEnv<'a>,
+                    raw_object: <::rust_jni::java::lang::Object<'a> as ::rust_jni::JavaType>::__JniType,
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::JavaResult<'a, Option<Self>> {
+                    if raw_object.is_null() {
+                        return Ok(None);
+                    }
+                    let object = <::rust_jni::java::lang::Object<'a> as ::rust_jni::__generator::FromJni<'a>>::__from_jni(env, raw_object);
+                    if !object.is_instance_of(token, &Self::get_class(env, token)?) {
+                        return Ok(None);
+                    }
+                    Ok(Some(<Self as ::rust_jni::__generator::FromJni<'a>>::__from_jni(
+                        env,
+                        ::rust_jni::java::lang::Object::take_raw_object(object).as_ptr(),
+                    )))
+                }
+
+                /// View an object as an instance of this class if it is one.
+                /// Returns `None` if the object is of a different class.
+                pub fn from_object(
+                    object: &::rust_jni::java::lang::Object<'a>,
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::JavaResult<'a, Option<Self>> {
+                    let env = object.env();
+                    if !object.is_instance_of(token, &Self::get_class(env, token)?) {
+                        return Ok(None);
+                    }
+                    let object = object.clone(token)?;
+                    // Safe because the object is an instance of this class.
+                    Ok(Some(unsafe {
+                        <Self as ::rust_jni::__generator::FromJni<'a>>::__from_jni(
+                            env,
+                            ::rust_jni::java::lang::Object::take_raw_object(object).as_ptr(),
+                        )
+                    }))
+                }
+
                 pub fn clone(&self, token: &::rust_jni::NoException<'a>) -> ::rust_jni::JavaResult<'a, Self>
                 where
                     Self: Sized,
@@ -1865,6 +3768,17 @@ mod generate_class_tests {
                     self.object.to_string(token)
                 }
 
+                /// Convert into a type-erased global reference, which can be stored long-term.
+                pub fn into_global_object(self, token: &::rust_jni::NoException<'a>)
+                    -> ::rust_jni::JavaResult<'a, ::rust_jni::GlobalRef<::rust_jni::java::lang::Object<'static>>> {
+                    self.object.into_global_object(token)
+                }
+            }
+
+            /// Rust implementations of Java native methods of this class.
+            ///
+            /// These methods are called from Java. All other methods call into Java.
+            impl<'a> test1<'a> {
                 fn test_method_1_rust(
                     &self,
                     arg1: type1<'a>,
@@ -1880,6 +3794,26 @@ mod generate_class_tests {
                 ) -> ::rust_jni::JavaResult<'a, return_type_2> {
                     test code 2
                 }
+
+                /// Register native methods of this class with the JVM.
+                ///
+                /// Only needed when the library is linked statically and the JVM can't find
+                /// the native method implementations dynamically.
+                pub fn register_native_methods(
+                    env: &'a ::rust_jni::JniEnv<'a>,
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::JavaResult<'a, ()> {
+                    let class = Self::get_class(env, token)?;
+                    unsafe {
+                        class.register_natives(
+                            token,
+                            &[
+                                ("test_method_1", "(II)J", testMethod1 as *mut ::std::os::raw::c_void),
+                                ("test_method_2", "()V", testMethod2 as *mut ::std::os::raw::c_void),
+                            ],
+                        )
+                    }
+                }
             }
 
             #[no_mangle]
@@ -1979,6 +3913,7 @@ mod generate_class_tests {
                         name: Ident::new("test_method_1", Span::call_site()),
                         rust_name: Ident::new("test_method_1_rust", Span::call_site()),
                         java_name: Ident::new("testMethod1", Span::call_site()),
+                        signature: Literal::string("(II)J"),
                         return_type: quote! {return_type_1},
                         public: false,
                         argument_names: vec![
@@ -1993,6 +3928,7 @@ mod generate_class_tests {
                         name: Ident::new("test_method_2", Span::call_site()),
                         rust_name: Ident::new("test_method_2_rust", Span::call_site()),
                         java_name: Ident::new("testMethod2", Span::call_site()),
+                        signature: Literal::string("()V"),
                         return_type: quote! {return_type_2},
                         public: true,
                         argument_names: vec![],
@@ -2001,7 +3937,11 @@ mod generate_class_tests {
                         code: quote! {test code 2},
                     },
                 ],
+                fields: vec![],
+                static_fields: vec![],
                 constructors: vec![],
+                capture_error: None,
+                builder: None,
             })],
         };
         let expected = quote! {
@@ -2050,11 +3990,57 @@ mod generate_class_tests {
             }
 
             impl<'a> test1<'a> {
+                /// The JNI signature of the class.
+                pub const JNI_SIGNATURE: &'static str = "test/signature1";
+
                 pub fn get_class(env: &'a ::rust_jni::JniEnv<'a>, token: &::rust_jni::NoException<'a>)
                     -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::Class<'a>> {
                     ::rust_jni::java::lang::Class::find(env, "test/sign1", token)
                 }
 
+                /// Wrap a raw Java object reference if the object is an instance of this class.
+                /// Returns `None` if the reference is `null` or the object is of a different class.
+                ///
+                /// Takes ownership of the local reference.
+                /// Unsafe because an incorrect object reference can be passed.
+                pub unsafe fn try_from_raw(
+                    env: &'a ::rust_jni::JniEnv<'a>,
+                    raw_object: <::rust_jni::java::lang::Object<'a> as ::rust_jni::JavaType>::__JniType,
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::JavaResult<'a, Option<Self>> {
+                    if raw_object.is_null() {
+                        return Ok(None);
+                    }
+                    let object = <::rust_jni::java::lang::Object<'a> as ::rust_jni::__generator::FromJni<'a>>::__from_jni(env, raw_object);
+                    if !object.is_instance_of(token, &Self::get_class(env, token)?) {
+                        return Ok(None);
+                    }
+                    Ok(Some(<Self as ::rust_jni::__generator::FromJni<'a>>::__from_jni(
+                        env,
+                        ::rust_jni::java::lang::Object::take_raw_object(object).as_ptr(),
+                    )))
+                }
+
+                /// View an object as an instance of this class if it is one.
+                /// Returns `None` if the object is of a different class.
+                pub fn from_object(
+                    object: &::rust_jni::java::lang::Object<'a>,
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::JavaResult<'a, Option<Self>> {
+                    let env = object.env();
+                    if !object.is_instance_of(token, &Self::get_class(env, token)?) {
+                        return Ok(None);
+                    }
+                    let object = object.clone(token)?;
+                    // Safe because the object is an instance of this class.
+                    Ok(Some(unsafe {
+                        <Self as ::rust_jni::__generator::FromJni<'a>>::__from_jni(
+                            env,
+                            ::rust_jni::java::lang::Object::take_raw_object(object).as_ptr(),
+                        )
+                    }))
+                }
+
                 pub fn clone(&self, token: &::rust_jni::NoException<'a>) -> ::rust_jni::JavaResult<'a, Self>
                 where
                     Self: Sized,
@@ -2069,6 +4055,17 @@ mod generate_class_tests {
                     self.object.to_string(token)
                 }
 
+                /// Convert into a type-erased global reference, which can be stored long-term.
+                pub fn into_global_object(self, token: &::rust_jni::NoException<'a>)
+                    -> ::rust_jni::JavaResult<'a, ::rust_jni::GlobalRef<::rust_jni::java::lang::Object<'static>>> {
+                    self.object.into_global_object(token)
+                }
+            }
+
+            /// Rust implementations of Java native methods of this class.
+            ///
+            /// These methods are called from Java. All other methods call into Java.
+            impl<'a> test1<'a> {
                 fn test_method_1_rust(
                     env: &'a ::rust_jni::JniEnv<'a>,
                     arg1: type1<'a>,
@@ -2084,6 +4081,26 @@ mod generate_class_tests {
                 ) -> ::rust_jni::JavaResult<'a, return_type_2> {
                     test code 2
                 }
+
+                /// Register native methods of this class with the JVM.
+                ///
+                /// Only needed when the library is linked statically and the JVM can't find
+                /// the native method implementations dynamically.
+                pub fn register_native_methods(
+                    env: &'a ::rust_jni::JniEnv<'a>,
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::JavaResult<'a, ()> {
+                    let class = Self::get_class(env, token)?;
+                    unsafe {
+                        class.register_natives(
+                            token,
+                            &[
+                                ("test_method_1", "(II)J", testMethod1 as *mut ::std::os::raw::c_void),
+                                ("test_method_2", "()V", testMethod2 as *mut ::std::os::raw::c_void),
+                            ],
+                        )
+                    }
+                }
             }
 
             #[no_mangle]
@@ -2114,7 +4131,7 @@ mod generate_class_tests {
 
                     let class = test1::get_class(env, &token)?;
                     let raw_class = <::rust_jni::java::lang::Class as ::rust_jni::__generator::FromJni>::__from_jni(env, raw_class);
-                    if !class.is_same_as(&raw_class, &token) {
+                    if !class.is_same_class(&token, &raw_class) {
                         panic!("Native method test_method_1 does not belong to class test1");
                     }
 
@@ -2141,7 +4158,7 @@ mod generate_class_tests {
                 ::rust_jni::__generator::native_method_wrapper(raw_env, |env, token| {
                     let class = test1::get_class(env, &token)?;
                     let raw_class = <::rust_jni::java::lang::Class as ::rust_jni::__generator::FromJni>::__from_jni(env, raw_class);
-                    if !class.is_same_as(&raw_class, &token) {
+                    if !class.is_same_class(&token, &raw_class) {
                         panic!("Native method test_method_2 does not belong to class test1");
                     }
 
@@ -2198,7 +4215,11 @@ mod generate_class_tests {
                 static_methods: vec![],
                 native_methods: vec![],
                 static_native_methods: vec![],
+                fields: vec![],
+                static_fields: vec![],
                 constructors: vec![],
+                capture_error: None,
+                builder: None,
             })],
         };
         let expected = quote! {
@@ -2247,11 +4268,57 @@ mod generate_class_tests {
             }
 
             impl<'a> test1<'a> {
+                /// The JNI signature of the class.
+                pub const JNI_SIGNATURE: &'static str = "test/signature1";
+
                 pub fn get_class(env: &'a ::rust_jni::JniEnv<'a>, token: &::rust_jni::NoException<'a>)
                     -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::Class<'a>> {
                     ::rust_jni::java::lang::Class::find(env, "test/sign1", token)
                 }
 
+                /// Wrap a raw Java object reference if the object is an instance of this class.
+                /// Returns `None` if the reference is `null` or the object is of a different class.
+                ///
+                /// Takes ownership of the local reference.
+                /// Unsafe because an incorrect object reference can be passed.
+                pub unsafe fn try_from_raw(
+                    env: &'a ::rust_jni::JniEnv<'a>,
+                    raw_object: <::rust_jni::java::lang::Object<'a> as ::rust_jni::JavaType>::__JniType,
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::JavaResult<'a, Option<Self>> {
+                    if raw_object.is_null() {
+                        return Ok(None);
+                    }
+                    let object = <::rust_jni::java::lang::Object<'a> as ::rust_jni::__generator::FromJni<'a>>::__from_jni(env, raw_object);
+                    if !object.is_instance_of(token, &Self::get_class(env, token)?) {
+                        return Ok(None);
+                    }
+                    Ok(Some(<Self as ::rust_jni::__generator::FromJni<'a>>::__from_jni(
+                        env,
+                        ::rust_jni::java::lang::Object::take_raw_object(object).as_ptr(),
+                    )))
+                }
+
+                /// View an object as an instance of this class if it is one.
+                /// Returns `None` if the object is of a different class.
+                pub fn from_object(
+                    object: &::rust_jni::java::lang::Object<'a>,
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::JavaResult<'a, Option<Self>> {
+                    let env = object.env();
+                    if !object.is_instance_of(token, &Self::get_class(env, token)?) {
+                        return Ok(None);
+                    }
+                    let object = object.clone(token)?;
+                    // Safe because the object is an instance of this class.
+                    Ok(Some(unsafe {
+                        <Self as ::rust_jni::__generator::FromJni<'a>>::__from_jni(
+                            env,
+                            ::rust_jni::java::lang::Object::take_raw_object(object).as_ptr(),
+                        )
+                    }))
+                }
+
                 pub fn clone(&self, token: &::rust_jni::NoException<'a>) -> ::rust_jni::JavaResult<'a, Self>
                 where
                     Self: Sized,
@@ -2265,6 +4332,12 @@ mod generate_class_tests {
                     -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::String<'a>> {
                     self.object.to_string(token)
                 }
+
+                /// Convert into a type-erased global reference, which can be stored long-term.
+                pub fn into_global_object(self, token: &::rust_jni::NoException<'a>)
+                    -> ::rust_jni::JavaResult<'a, ::rust_jni::GlobalRef<::rust_jni::java::lang::Object<'static>>> {
+                    self.object.into_global_object(token)
+                }
             }
 
             impl<'a> ::std::fmt::Display for test1<'a> {
@@ -2309,14 +4382,14 @@ mod generate_class_tests {
                                 Ident::new("arg2", Span::call_site()),
                             ],
                             argument_types: vec![quote! {type1}, quote! {type2}],
-                            class_has_method: false,
+                            class_method: None,
                         },
                         InterfaceMethodImplementation {
                             name: Ident::new("test_method_2", Span::call_site()),
                             return_type: quote! {return_type_2},
                             argument_names: vec![],
                             argument_types: vec![],
-                            class_has_method: true,
+                            class_method: Some(Ident::new("test_method_2", Span::call_site())),
                         },
                     ],
                 }],
@@ -2326,7 +4399,11 @@ mod generate_class_tests {
                 static_methods: vec![],
                 native_methods: vec![],
                 static_native_methods: vec![],
+                fields: vec![],
+                static_fields: vec![],
                 constructors: vec![],
+                capture_error: None,
+                builder: None,
             })],
         };
         let expected = quote! {
@@ -2375,11 +4452,57 @@ mod generate_class_tests {
             }
 
             impl<'a> test1<'a> {
+                /// The JNI signature of the class.
+                pub const JNI_SIGNATURE: &'static str = "test/signature1";
+
                 pub fn get_class(env: &'a ::rust_jni::JniEnv<'a>, token: &::rust_jni::NoException<'a>)
                     -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::Class<'a>> {
                     ::rust_jni::java::lang::Class::find(env, "test/sign1", token)
                 }
 
+                /// Wrap a raw Java object reference if the object is an instance of this class.
+                /// Returns `None` if the reference is `null` or the object is of a different class.
+                ///
+                /// Takes ownership of the local reference.
+                /// Unsafe because an incorrect object reference can be passed.
+                pub unsafe fn try_from_raw(
+                    env: &'a ::rust_jni::JniEnv<'a>,
+                    raw_object: <::rust_jni::java::lang::Object<'a> as ::rust_jni::JavaType>::__JniType,
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::JavaResult<'a, Option<Self>> {
+                    if raw_object.is_null() {
+                        return Ok(None);
+                    }
+                    let object = <::rust_jni::java::lang::Object<'a> as ::rust_jni::__generator::FromJni<'a>>::__from_jni(env, raw_object);
+                    if !object.is_instance_of(token, &Self::get_class(env, token)?) {
+                        return Ok(None);
+                    }
+                    Ok(Some(<Self as ::rust_jni::__generator::FromJni<'a>>::__from_jni(
+                        env,
+                        ::rust_jni::java::lang::Object::take_raw_object(object).as_ptr(),
+                    )))
+                }
+
+                /// View an object as an instance of this class if it is one.
+                /// Returns `None` if the object is of a different class.
+                pub fn from_object(
+                    object: &::rust_jni::java::lang::Object<'a>,
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::JavaResult<'a, Option<Self>> {
+                    let env = object.env();
+                    if !object.is_instance_of(token, &Self::get_class(env, token)?) {
+                        return Ok(None);
+                    }
+                    let object = object.clone(token)?;
+                    // Safe because the object is an instance of this class.
+                    Ok(Some(unsafe {
+                        <Self as ::rust_jni::__generator::FromJni<'a>>::__from_jni(
+                            env,
+                            ::rust_jni::java::lang::Object::take_raw_object(object).as_ptr(),
+                        )
+                    }))
+                }
+
                 pub fn clone(&self, token: &::rust_jni::NoException<'a>) -> ::rust_jni::JavaResult<'a, Self>
                 where
                     Self: Sized,
@@ -2393,6 +4516,12 @@ mod generate_class_tests {
                     -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::String<'a>> {
                     self.object.to_string(token)
                 }
+
+                /// Convert into a type-erased global reference, which can be stored long-term.
+                pub fn into_global_object(self, token: &::rust_jni::NoException<'a>)
+                    -> ::rust_jni::JavaResult<'a, ::rust_jni::GlobalRef<::rust_jni::java::lang::Object<'static>>> {
+                    self.object.into_global_object(token)
+                }
             }
 
             impl<'a> ::std::fmt::Display for test1<'a> {
@@ -75,8 +75,17 @@ where
 
 impl JavaName {
     pub fn from_tokens<'a>(tokens: impl Iterator<Item = &'a TokenTree>) -> JavaName {
-        let tokens = flat_map_threaded(tokens, false, |token, was_identifier| {
-            match (token, was_identifier) {
+        let tokens = tokens.collect::<Vec<_>>();
+        // Array types are names followed by one or more empty `[]` groups.
+        let name_length = tokens
+            .iter()
+            .rposition(|token| !is_array_brackets(token))
+            .map_or(0, |position| position + 1);
+        let (tokens, brackets) = tokens.split_at(name_length);
+        let tokens = flat_map_threaded(
+            tokens.iter().cloned(),
+            false,
+            |token, was_identifier| match (token, was_identifier) {
                 (TokenTree::Ident(_), false) => true,
                 (TokenTree::Punct(punct), true) => {
                     if punct.as_char() != '.' {
@@ -90,19 +99,31 @@ impl JavaName {
                 (token, false) => {
                     panic!("Expected an identifier, got {:?}.", token);
                 }
-            }
-        })
+            },
+        )
         .filter(|token| match token {
             TokenTree::Ident(_) => true,
             _ => false,
         });
-        let tokens = TokenStream::from_iter(tokens.cloned());
+        let mut tokens = TokenStream::from_iter(tokens.cloned());
         if tokens.is_empty() {
             panic!("Expected a Java name, got no tokens.");
         }
+        tokens.extend(brackets.iter().cloned().cloned());
         JavaName(tokens)
     }
 
+    /// Get the element type name if the name is an array type name.
+    pub fn as_array_element(&self) -> Option<JavaName> {
+        let mut tokens = self.clone().0.into_iter().collect::<Vec<_>>();
+        match tokens.pop() {
+            Some(ref token) if is_array_brackets(token) => {
+                Some(JavaName(TokenStream::from_iter(tokens)))
+            }
+            _ => None,
+        }
+    }
+
     pub fn name(self) -> Ident {
         match self.0.into_iter().last().unwrap() {
             TokenTree::Ident(identifier) => identifier,
@@ -110,20 +131,37 @@ impl JavaName {
         }
     }
 
+    /// Get the JNI binary name: packages are separated with slashes and nested classes
+    /// with `$`, like in `a/b/Outer$Inner`.
     pub fn with_slashes(self) -> String {
-        self.0
-            .into_iter()
-            .map(|token| token.to_string())
-            .collect::<Vec<_>>()
-            .join("/")
+        self.join_binary_name("/", "$")
     }
 
+    /// Get the binary name mangled for use in native method names, where `$` is `_00024`.
     pub fn with_underscores(self) -> String {
-        self.0
-            .into_iter()
-            .map(|token| token.to_string())
-            .collect::<Vec<_>>()
-            .join("_")
+        self.join_binary_name("_", "_00024")
+    }
+
+    /// Join the name segments with separators.
+    ///
+    /// A segment following a capitalized segment is treated as a nested class, since
+    /// Java package names are lowercase by convention while class names are capitalized.
+    fn join_binary_name(self, package_separator: &str, nested_separator: &str) -> String {
+        let mut result = String::new();
+        let mut in_class = false;
+        for token in self.0.into_iter() {
+            let segment = token.to_string();
+            if !result.is_empty() {
+                result.push_str(if in_class {
+                    nested_separator
+                } else {
+                    package_separator
+                });
+            }
+            in_class = in_class || segment.chars().next().map_or(false, char::is_uppercase);
+            result.push_str(&segment);
+        }
+        result
     }
 
     pub fn with_double_colons(self) -> TokenStream {
@@ -179,7 +217,22 @@ impl JavaName {
         }
     }
 
+    /// Rust element type of a primitive array. Java `char`-s in arrays can be unpaired
+    /// surrogates, so `char[]` elements are `u16`.
+    fn as_array_element_type(&self) -> Option<TokenStream> {
+        let tokens = self.clone().0.into_iter().collect::<Vec<_>>();
+        if tokens.len() == 1 && is_identifier(&tokens[0], "char") {
+            Some(quote! {u16})
+        } else {
+            self.as_primitive_type()
+        }
+    }
+
     pub fn get_jni_signature(&self) -> String {
+        if let Some(element) = self.as_array_element() {
+            // `[` is mangled as `_3` in native method names.
+            return format!("_3{}", element.get_jni_signature());
+        }
         let tokens = self.clone().0.into_iter().collect::<Vec<_>>();
         if tokens.len() == 1 {
             let token = &tokens[0];
@@ -194,10 +247,7 @@ impl JavaName {
             } else if is_identifier(&token, "boolean") {
                 <bool as rust_jni::JavaType>::__signature().to_owned()
             } else if is_identifier(&token, "float") {
-                panic!(
-                    "float values are not supported for not. \
-                     See https://github.com/Monnoroch/rust-jni/issues/25 for more details"
-                )
+                <f32 as rust_jni::JavaType>::__signature().to_owned()
             } else if is_identifier(&token, "double") {
                 <f64 as rust_jni::JavaType>::__signature().to_owned()
             } else if is_identifier(&token, "void") {
@@ -212,25 +262,68 @@ impl JavaName {
         }
     }
 
+    /// Get the JNI type signature, like `I`, `[I` or `La/b/Test;`.
+    pub fn get_type_signature(&self) -> String {
+        if let Some(element) = self.as_array_element() {
+            return format!("[{}", element.get_type_signature());
+        }
+        match self.as_primitive_type() {
+            // Mangled signatures of primitive types are the same as the unmangled ones.
+            Some(_) => self.get_jni_signature(),
+            None => format!("L{};", self.clone().with_slashes()),
+        }
+    }
+
     pub fn as_rust_type(self) -> TokenStream {
+        if let Some(element) = self.as_array_element() {
+            return match element.as_array_element_type() {
+                Some(primitive) => quote! {::rust_jni::PrimitiveArray<'a, #primitive>},
+                None => {
+                    let element = element.as_rust_type();
+                    quote! {::rust_jni::ObjectArray<'a, #element>}
+                }
+            };
+        }
         let primitive = self.as_primitive_type();
         let with_double_colons = self.with_double_colons();
         primitive.unwrap_or(quote! {#with_double_colons <'a>})
     }
 
     pub fn as_rust_type_no_lifetime(self) -> TokenStream {
+        if let Some(element) = self.as_array_element() {
+            return match element.as_array_element_type() {
+                Some(primitive) => quote! {::rust_jni::PrimitiveArray<#primitive>},
+                None => {
+                    let element = element.as_rust_type_no_lifetime();
+                    quote! {::rust_jni::ObjectArray<#element>}
+                }
+            };
+        }
         let primitive = self.as_primitive_type();
         let with_double_colons = self.with_double_colons();
         primitive.unwrap_or(quote! {#with_double_colons})
     }
 
     pub fn as_rust_type_reference(self) -> TokenStream {
+        if self.as_array_element().is_some() {
+            let rust_type = self.as_rust_type();
+            return quote! {& #rust_type};
+        }
         let primitive = self.as_primitive_type();
         let with_double_colons = self.with_double_colons();
         primitive.unwrap_or(quote! {& #with_double_colons <'a>})
     }
 }
 
+fn is_array_brackets(token: &TokenTree) -> bool {
+    match token {
+        TokenTree::Group(group) => {
+            group.delimiter() == Delimiter::Bracket && group.stream().is_empty()
+        }
+        _ => false,
+    }
+}
+
 fn is_identifier(token: &TokenTree, name: &str) -> bool {
     match token {
         TokenTree::Ident(identifier) => identifier == name,
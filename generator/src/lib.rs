@@ -98,9 +98,806 @@ mod java_generate_tests {
             }
 
             impl<'a> TestClass1<'a> {
+                /// The JNI signature of the class.
+                pub const JNI_SIGNATURE: &'static str = "LTestClass1;";
+
+                pub fn get_class(env: &'a ::rust_jni::JniEnv<'a>, token: &::rust_jni::NoException<'a>)
+                    -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::Class<'a>> {
+                    ::rust_jni::java::lang::Class::find(env, "TestClass1", token)
+                }
+
+                /// Wrap a raw Java object reference if the object is an instance of this class.
+                /// Returns `None` if the reference is `null` or the object is of a different class.
+                ///
+                /// Takes ownership of the local reference.
+                /// Unsafe because an incorrect object reference can be passed.
+                pub unsafe fn try_from_raw(
+                    env: &'a ::rust_jni::JniEnv<'a>,
+                    raw_object: <::rust_jni::java::lang::Object<'a> as ::rust_jni::JavaType>::__JniType,
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::JavaResult<'a, Option<Self>> {
+                    if raw_object.is_null() {
+                        return Ok(None);
+                    }
+                    let object = <::rust_jni::java::lang::Object<'a> as ::rust_jni::__generator::FromJni<'a>>::__from_jni(env, raw_object);
+                    if !object.is_instance_of(token, &Self::get_class(env, token)?) {
+                        return Ok(None);
+                    }
+                    Ok(Some(<Self as ::rust_jni::__generator::FromJni<'a>>::__from_jni(
+                        env,
+                        ::rust_jni::java::lang::Object::take_raw_object(object).as_ptr(),
+                    )))
+                }
+
+                /// View an object as an instance of this class if it is one.
+                /// Returns `None` if the object is of a different class.
+                pub fn from_object(
+                    object: &::rust_jni::java::lang::Object<'a>,
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::JavaResult<'a, Option<Self>> {
+                    let env = object.env();
+                    if !object.is_instance_of(token, &Self::get_class(env, token)?) {
+                        return Ok(None);
+                    }
+                    let object = object.clone(token)?;
+                    // Safe because the object is an instance of this class.
+                    Ok(Some(unsafe {
+                        <Self as ::rust_jni::__generator::FromJni<'a>>::__from_jni(
+                            env,
+                            ::rust_jni::java::lang::Object::take_raw_object(object).as_ptr(),
+                        )
+                    }))
+                }
+
+                pub fn clone(&self, token: &::rust_jni::NoException<'a>) -> ::rust_jni::JavaResult<'a, Self>
+                where
+                    Self: Sized,
+                {
+                    self.object
+                        .clone(token)
+                        .map(|object| Self { object })
+                }
+
+                pub fn to_string(&self, token: &::rust_jni::NoException<'a>)
+                    -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::String<'a>> {
+                    self.object.to_string(token)
+                }
+
+                /// Convert into a type-erased global reference, which can be stored long-term.
+                pub fn into_global_object(self, token: &::rust_jni::NoException<'a>)
+                    -> ::rust_jni::JavaResult<'a, ::rust_jni::GlobalRef<::rust_jni::java::lang::Object<'static>>> {
+                    self.object.into_global_object(token)
+                }
+            }
+
+            impl<'a> ::std::fmt::Display for TestClass1<'a> {
+                fn fmt(&self, formatter: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                    self.object.fmt(formatter)
+                }
+            }
+
+            impl<'a, T> PartialEq<T> for TestClass1<'a> where T: ::rust_jni::Cast<'a, ::rust_jni::java::lang::Object<'a>> {
+                fn eq(&self, other: &T) -> bool {
+                    self.object.eq(other)
+                }
+            }
+
+            impl<'a> Eq for TestClass1<'a> {}
+        };
+        assert_tokens_equals(java_generate_impl(input), expected);
+    }
+
+    #[test]
+    fn one_record() {
+        let input = quote! {
+            record a.b.Point(int x, int y) {}
+        };
+        let expected = quote! {
+            #[derive(Debug)]
+            struct Point<'env> {
+                object: ::java::lang::Object<'env>,
+            }
+
+            impl<'a> ::rust_jni::JavaType for Point<'a> {
+                #[doc(hidden)]
+                type __JniType = <::rust_jni::java::lang::Object<'a> as ::rust_jni::JavaType>::__JniType;
+
+                #[doc(hidden)]
+                fn __signature() -> &'static str {
+                    "La/b/Point;"
+                }
+            }
+
+            impl<'a> ::rust_jni::__generator::ToJni for Point<'a> {
+                unsafe fn __to_jni(&self) -> Self::__JniType {
+                    self.raw_object()
+                }
+            }
+
+            impl<'a> ::rust_jni::__generator::FromJni<'a> for Point<'a> {
+                unsafe fn __from_jni(env: &'a ::rust_jni::JniEnv<'a>, value: Self::__JniType) -> Self {
+                    Self {
+                        object: <::java::lang::Object as ::rust_jni::__generator::FromJni<'a>>::__from_jni(env, value),
+                    }
+                }
+            }
+
+            impl<'a> ::rust_jni::Cast<'a, Point<'a>> for Point<'a> {
+                #[doc(hidden)]
+                fn cast<'b>(&'b self) -> &'b Point<'a> {
+                    self
+                }
+            }
+
+            impl<'a> ::rust_jni::Cast<'a, ::java::lang::Object<'a>> for Point<'a> {
+                #[doc(hidden)]
+                fn cast<'b>(&'b self) -> &'b ::java::lang::Object<'a> {
+                    self
+                }
+            }
+
+            impl<'a> ::std::ops::Deref for Point<'a> {
+                type Target = ::java::lang::Object<'a>;
+
+                fn deref(&self) -> &Self::Target {
+                    &self.object
+                }
+            }
+
+            impl<'a> Point<'a> {
+                /// The JNI signature of the class.
+                pub const JNI_SIGNATURE: &'static str = "La/b/Point;";
+
+                pub fn get_class(env: &'a ::rust_jni::JniEnv<'a>, token: &::rust_jni::NoException<'a>)
+                    -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::Class<'a>> {
+                    ::rust_jni::java::lang::Class::find(env, "a/b/Point", token)
+                }
+
+                /// Wrap a raw Java object reference if the object is an instance of this class.
+                /// Returns `None` if the reference is `null` or the object is of a different class.
+                ///
+                /// Takes ownership of the local reference.
+                /// Unsafe because an incorrect object reference can be passed.
+                pub unsafe fn try_from_raw(
+                    env: &'a ::rust_jni::JniEnv<'a>,
+                    raw_object: <::rust_jni::java::lang::Object<'a> as ::rust_jni::JavaType>::__JniType,
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::JavaResult<'a, Option<Self>> {
+                    if raw_object.is_null() {
+                        return Ok(None);
+                    }
+                    let object = <::rust_jni::java::lang::Object<'a> as ::rust_jni::__generator::FromJni<'a>>::__from_jni(env, raw_object);
+                    if !object.is_instance_of(token, &Self::get_class(env, token)?) {
+                        return Ok(None);
+                    }
+                    Ok(Some(<Self as ::rust_jni::__generator::FromJni<'a>>::__from_jni(
+                        env,
+                        ::rust_jni::java::lang::Object::take_raw_object(object).as_ptr(),
+                    )))
+                }
+
+                /// View an object as an instance of this class if it is one.
+                /// Returns `None` if the object is of a different class.
+                pub fn from_object(
+                    object: &::rust_jni::java::lang::Object<'a>,
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::JavaResult<'a, Option<Self>> {
+                    let env = object.env();
+                    if !object.is_instance_of(token, &Self::get_class(env, token)?) {
+                        return Ok(None);
+                    }
+                    let object = object.clone(token)?;
+                    // Safe because the object is an instance of this class.
+                    Ok(Some(unsafe {
+                        <Self as ::rust_jni::__generator::FromJni<'a>>::__from_jni(
+                            env,
+                            ::rust_jni::java::lang::Object::take_raw_object(object).as_ptr(),
+                        )
+                    }))
+                }
+
+                pub fn clone(&self, token: &::rust_jni::NoException<'a>) -> ::rust_jni::JavaResult<'a, Self>
+                where
+                    Self: Sized,
+                {
+                    self.object
+                        .clone(token)
+                        .map(|object| Self { object })
+                }
+
+                pub fn to_string(&self, token: &::rust_jni::NoException<'a>)
+                    -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::String<'a>> {
+                    self.object.to_string(token)
+                }
+
+                /// Convert into a type-erased global reference, which can be stored long-term.
+                pub fn into_global_object(self, token: &::rust_jni::NoException<'a>)
+                    -> ::rust_jni::JavaResult<'a, ::rust_jni::GlobalRef<::rust_jni::java::lang::Object<'static>>> {
+                    self.object.into_global_object(token)
+                }
+
+                pub fn init(
+                    env: &'a ::rust_jni::JniEnv<'a>,
+                    x: i32,
+                    y: i32,
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::JavaResult<'a, Self> {
+                    // Safe because the method name and arguments are correct.
+                    unsafe {
+                        ::rust_jni::__generator::call_constructor::<Self, _, fn(i32, i32,)>
+                        (
+                            env,
+                            (x, y,),
+                            token,
+                        )
+                    }
+                }
+
+                pub fn x(
+                    &self,
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::JavaResult<'a, i32> {
+                    // Safe because the method name and arguments are correct.
+                    unsafe {
+                        ::rust_jni::__generator::call_method::<_, _, _,
+                            fn() -> i32
+                        >
+                        (
+                            self,
+                            "x",
+                            (),
+                            token,
+                        )
+                    }
+                }
+
+                pub fn y(
+                    &self,
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::JavaResult<'a, i32> {
+                    // Safe because the method name and arguments are correct.
+                    unsafe {
+                        ::rust_jni::__generator::call_method::<_, _, _,
+                            fn() -> i32
+                        >
+                        (
+                            self,
+                            "y",
+                            (),
+                            token,
+                        )
+                    }
+                }
+            }
+
+            impl<'a> ::std::fmt::Display for Point<'a> {
+                fn fmt(&self, formatter: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                    self.object.fmt(formatter)
+                }
+            }
+
+            impl<'a, T> PartialEq<T> for Point<'a> where T: ::rust_jni::Cast<'a, ::rust_jni::java::lang::Object<'a>> {
+                fn eq(&self, other: &T) -> bool {
+                    self.object.eq(other)
+                }
+            }
+
+            impl<'a> Eq for Point<'a> {}
+        };
+        assert_tokens_equals(java_generate_impl(input), expected);
+    }
+
+    #[test]
+    fn one_enum() {
+        let input = quote! {
+            public enum a.b.Color {
+                RED, GREEN;
+            }
+        };
+        let red_name = Literal::string("RED\0");
+        let green_name = Literal::string("GREEN\0");
+        let expected = quote! {
+            #[derive(Debug)]
+            pub struct Color<'env> {
+                object: ::java::lang::Enum<'env>,
+            }
+
+            impl<'a> ::rust_jni::JavaType for Color<'a> {
+                #[doc(hidden)]
+                type __JniType = <::rust_jni::java::lang::Object<'a> as ::rust_jni::JavaType>::__JniType;
+
+                #[doc(hidden)]
+                fn __signature() -> &'static str {
+                    "La/b/Color;"
+                }
+            }
+
+            impl<'a> ::rust_jni::__generator::ToJni for Color<'a> {
+                unsafe fn __to_jni(&self) -> Self::__JniType {
+                    self.raw_object()
+                }
+            }
+
+            impl<'a> ::rust_jni::__generator::FromJni<'a> for Color<'a> {
+                unsafe fn __from_jni(env: &'a ::rust_jni::JniEnv<'a>, value: Self::__JniType) -> Self {
+                    Self {
+                        object: <::java::lang::Enum as ::rust_jni::__generator::FromJni<'a>>::__from_jni(env, value),
+                    }
+                }
+            }
+
+            impl<'a> ::rust_jni::Cast<'a, Color<'a>> for Color<'a> {
+                #[doc(hidden)]
+                fn cast<'b>(&'b self) -> &'b Color<'a> {
+                    self
+                }
+            }
+
+            impl<'a> ::rust_jni::Cast<'a, ::java::lang::Enum<'a>> for Color<'a> {
+                #[doc(hidden)]
+                fn cast<'b>(&'b self) -> &'b ::java::lang::Enum<'a> {
+                    self
+                }
+            }
+
+            impl<'a> ::rust_jni::Cast<'a, ::java::lang::Object<'a>> for Color<'a> {
+                #[doc(hidden)]
+                fn cast<'b>(&'b self) -> &'b ::java::lang::Object<'a> {
+                    self
+                }
+            }
+
+            impl<'a> ::std::ops::Deref for Color<'a> {
+                type Target = ::java::lang::Enum<'a>;
+
+                fn deref(&self) -> &Self::Target {
+                    &self.object
+                }
+            }
+
+            impl<'a> Color<'a> {
+                /// The JNI signature of the class.
+                pub const JNI_SIGNATURE: &'static str = "La/b/Color;";
+
+                pub fn get_class(env: &'a ::rust_jni::JniEnv<'a>, token: &::rust_jni::NoException<'a>)
+                    -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::Class<'a>> {
+                    ::rust_jni::java::lang::Class::find(env, "a/b/Color", token)
+                }
+
+                /// Wrap a raw Java object reference if the object is an instance of this class.
+                /// Returns `None` if the reference is `null` or the object is of a different class.
+                ///
+                /// Takes ownership of the local reference.
+                /// Unsafe because an incorrect object reference can be passed.
+                pub unsafe fn try_from_raw(
+                    env: &'a ::rust_jni::JniEnv<'a>,
+                    raw_object: <::rust_jni::java::lang::Object<'a> as ::rust_jni::JavaType>::__JniType,
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::JavaResult<'a, Option<Self>> {
+                    if raw_object.is_null() {
+                        return Ok(None);
+                    }
+                    let object = <::rust_jni::java::lang::Object<'a> as ::rust_jni::__generator::FromJni<'a>>::__from_jni(env, raw_object);
+                    if !object.is_instance_of(token, &Self::get_class(env, token)?) {
+                        return Ok(None);
+                    }
+                    Ok(Some(<Self as ::rust_jni::__generator::FromJni<'a>>::__from_jni(
+                        env,
+                        ::rust_jni::java::lang::Object::take_raw_object(object).as_ptr(),
+                    )))
+                }
+
+                /// View an object as an instance of this class if it is one.
+                /// Returns `None` if the object is of a different class.
+                pub fn from_object(
+                    object: &::rust_jni::java::lang::Object<'a>,
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::JavaResult<'a, Option<Self>> {
+                    let env = object.env();
+                    if !object.is_instance_of(token, &Self::get_class(env, token)?) {
+                        return Ok(None);
+                    }
+                    let object = object.clone(token)?;
+                    // Safe because the object is an instance of this class.
+                    Ok(Some(unsafe {
+                        <Self as ::rust_jni::__generator::FromJni<'a>>::__from_jni(
+                            env,
+                            ::rust_jni::java::lang::Object::take_raw_object(object).as_ptr(),
+                        )
+                    }))
+                }
+
+                pub fn clone(&self, token: &::rust_jni::NoException<'a>) -> ::rust_jni::JavaResult<'a, Self>
+                where
+                    Self: Sized,
+                {
+                    self.object
+                        .clone(token)
+                        .map(|object| Self { object })
+                }
+
+                pub fn to_string(&self, token: &::rust_jni::NoException<'a>)
+                    -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::String<'a>> {
+                    self.object.to_string(token)
+                }
+
+                /// Convert into a type-erased global reference, which can be stored long-term.
+                pub fn into_global_object(self, token: &::rust_jni::NoException<'a>)
+                    -> ::rust_jni::JavaResult<'a, ::rust_jni::GlobalRef<::rust_jni::java::lang::Object<'static>>> {
+                    self.object.into_global_object(token)
+                }
+
+                pub fn name(
+                    &self,
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::JavaResult<'a, ::java::lang::String<'a> > {
+                    // Safe because the method name and arguments are correct.
+                    unsafe {
+                        ::rust_jni::__generator::call_method::<_, _, _,
+                            fn() -> ::java::lang::String<'a>
+                        >
+                        (
+                            self,
+                            "name",
+                            (),
+                            token,
+                        )
+                    }
+                }
+
+                pub fn values(
+                    env: &'a ::rust_jni::JniEnv<'a>,
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::JavaResult<'a, ::rust_jni::ObjectArray<'a, ::a::b::Color<'a> > > {
+                    // Safe because the method name and arguments are correct.
+                    unsafe {
+                        ::rust_jni::__generator::call_static_method::<Self, _, _,
+                            fn() -> ::rust_jni::ObjectArray<'a, ::a::b::Color<'a> >
+                        >
+                        (
+                            env,
+                            "values",
+                            (),
+                            token,
+                        )
+                    }
+                }
+
+                pub fn RED(
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::JavaResult<'a, ::std::option::Option<::a::b::Color<'a> > > {
+                    // Safe because the field name and type are correct.
+                    unsafe {
+                        ::rust_jni::get_static_field::<::a::b::Color<'a>, Self>(token, #red_name)
+                    }
+                }
+
+                pub fn GREEN(
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::JavaResult<'a, ::std::option::Option<::a::b::Color<'a> > > {
+                    // Safe because the field name and type are correct.
+                    unsafe {
+                        ::rust_jni::get_static_field::<::a::b::Color<'a>, Self>(token, #green_name)
+                    }
+                }
+            }
+
+            impl<'a> ::std::fmt::Display for Color<'a> {
+                fn fmt(&self, formatter: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                    self.object.fmt(formatter)
+                }
+            }
+
+            impl<'a, T> PartialEq<T> for Color<'a> where T: ::rust_jni::Cast<'a, ::rust_jni::java::lang::Object<'a>> {
+                fn eq(&self, other: &T) -> bool {
+                    self.object.eq(other)
+                }
+            }
+
+            impl<'a> Eq for Color<'a> {}
+        };
+        assert_tokens_equals(java_generate_impl(input), expected);
+    }
+
+    #[test]
+    fn one_class_arrays() {
+        let input = quote! {
+            class a.b.Test {
+                long sum(int[] values);
+                a.b.Test[] items();
+            }
+        };
+        let expected = quote! {
+            #[derive(Debug)]
+            struct Test<'env> {
+                object: ::java::lang::Object<'env>,
+            }
+
+            impl<'a> ::rust_jni::JavaType for Test<'a> {
+                #[doc(hidden)]
+                type __JniType = <::rust_jni::java::lang::Object<'a> as ::rust_jni::JavaType>::__JniType;
+
+                #[doc(hidden)]
+                fn __signature() -> &'static str {
+                    "La/b/Test;"
+                }
+            }
+
+            impl<'a> ::rust_jni::__generator::ToJni for Test<'a> {
+                unsafe fn __to_jni(&self) -> Self::__JniType {
+                    self.raw_object()
+                }
+            }
+
+            impl<'a> ::rust_jni::__generator::FromJni<'a> for Test<'a> {
+                unsafe fn __from_jni(env: &'a ::rust_jni::JniEnv<'a>, value: Self::__JniType) -> Self {
+                    Self {
+                        object: <::java::lang::Object as ::rust_jni::__generator::FromJni<'a>>::__from_jni(env, value),
+                    }
+                }
+            }
+
+            impl<'a> ::rust_jni::Cast<'a, Test<'a>> for Test<'a> {
+                #[doc(hidden)]
+                fn cast<'b>(&'b self) -> &'b Test<'a> {
+                    self
+                }
+            }
+
+            impl<'a> ::rust_jni::Cast<'a, ::java::lang::Object<'a>> for Test<'a> {
+                #[doc(hidden)]
+                fn cast<'b>(&'b self) -> &'b ::java::lang::Object<'a> {
+                    self
+                }
+            }
+
+            impl<'a> ::std::ops::Deref for Test<'a> {
+                type Target = ::java::lang::Object<'a>;
+
+                fn deref(&self) -> &Self::Target {
+                    &self.object
+                }
+            }
+
+            impl<'a> Test<'a> {
+                /// The JNI signature of the class.
+                pub const JNI_SIGNATURE: &'static str = "La/b/Test;";
+
+                pub fn get_class(env: &'a ::rust_jni::JniEnv<'a>, token: &::rust_jni::NoException<'a>)
+                    -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::Class<'a>> {
+                    ::rust_jni::java::lang::Class::find(env, "a/b/Test", token)
+                }
+
+                /// Wrap a raw Java object reference if the object is an instance of this class.
+                /// Returns `None` if the reference is `null` or the object is of a different class.
+                ///
+                /// Takes ownership of the local reference.
+                /// Unsafe because an incorrect object reference can be passed.
+                pub unsafe fn try_from_raw(
+                    env: &'a ::rust_jni::JniEnv<'a>,
+                    raw_object: <::rust_jni::java::lang::Object<'a> as ::rust_jni::JavaType>::__JniType,
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::JavaResult<'a, Option<Self>> {
+                    if raw_object.is_null() {
+                        return Ok(None);
+                    }
+                    let object = <::rust_jni::java::lang::Object<'a> as ::rust_jni::__generator::FromJni<'a>>::__from_jni(env, raw_object);
+                    if !object.is_instance_of(token, &Self::get_class(env, token)?) {
+                        return Ok(None);
+                    }
+                    Ok(Some(<Self as ::rust_jni::__generator::FromJni<'a>>::__from_jni(
+                        env,
+                        ::rust_jni::java::lang::Object::take_raw_object(object).as_ptr(),
+                    )))
+                }
+
+                /// View an object as an instance of this class if it is one.
+                /// Returns `None` if the object is of a different class.
+                pub fn from_object(
+                    object: &::rust_jni::java::lang::Object<'a>,
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::JavaResult<'a, Option<Self>> {
+                    let env = object.env();
+                    if !object.is_instance_of(token, &Self::get_class(env, token)?) {
+                        return Ok(None);
+                    }
+                    let object = object.clone(token)?;
+                    // Safe because the object is an instance of this class.
+                    Ok(Some(unsafe {
+                        <Self as ::rust_jni::__generator::FromJni<'a>>::__from_jni(
+                            env,
+                            ::rust_jni::java::lang::Object::take_raw_object(object).as_ptr(),
+                        )
+                    }))
+                }
+
+                pub fn clone(&self, token: &::rust_jni::NoException<'a>) -> ::rust_jni::JavaResult<'a, Self>
+                where
+                    Self: Sized,
+                {
+                    self.object
+                        .clone(token)
+                        .map(|object| Self { object })
+                }
+
+                pub fn to_string(&self, token: &::rust_jni::NoException<'a>)
+                    -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::String<'a>> {
+                    self.object.to_string(token)
+                }
+
+                /// Convert into a type-erased global reference, which can be stored long-term.
+                pub fn into_global_object(self, token: &::rust_jni::NoException<'a>)
+                    -> ::rust_jni::JavaResult<'a, ::rust_jni::GlobalRef<::rust_jni::java::lang::Object<'static>>> {
+                    self.object.into_global_object(token)
+                }
+
+                fn sum(
+                    &self,
+                    values: &impl ::rust_jni::Cast<'a, ::rust_jni::PrimitiveArray<'a, i32> >,
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::JavaResult<'a, i64> {
+                    // Safe because the method name and arguments are correct.
+                    unsafe {
+                        ::rust_jni::__generator::call_method::<_, _, _,
+                            fn(&::rust_jni::PrimitiveArray<'a, i32>,) -> i64
+                        >
+                        (
+                            self,
+                            "sum",
+                            (::rust_jni::Cast::<'a, ::rust_jni::PrimitiveArray<'a, i32> >::cast(values),),
+                            token,
+                        )
+                    }
+                }
+
+                fn items(
+                    &self,
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::JavaResult<'a, ::rust_jni::ObjectArray<'a, ::a::b::Test<'a> > > {
+                    // Safe because the method name and arguments are correct.
+                    unsafe {
+                        ::rust_jni::__generator::call_method::<_, _, _,
+                            fn() -> ::rust_jni::ObjectArray<'a, ::a::b::Test<'a> >
+                        >
+                        (
+                            self,
+                            "items",
+                            (),
+                            token,
+                        )
+                    }
+                }
+            }
+
+            impl<'a> ::std::fmt::Display for Test<'a> {
+                fn fmt(&self, formatter: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                    self.object.fmt(formatter)
+                }
+            }
+
+            impl<'a, T> PartialEq<T> for Test<'a> where T: ::rust_jni::Cast<'a, ::rust_jni::java::lang::Object<'a>> {
+                fn eq(&self, other: &T) -> bool {
+                    self.object.eq(other)
+                }
+            }
+
+            impl<'a> Eq for Test<'a> {}
+        };
+        assert_tokens_equals(java_generate_impl(input), expected);
+    }
+
+    #[test]
+    fn one_class_fields() {
+        let input = quote! {
+            class a.b.Test {
+                public int value;
+                @RustName(max) public static final int MAX_VALUE;
+            }
+        };
+        let value_name = Literal::string("value\0");
+        let max_value_name = Literal::string("MAX_VALUE\0");
+        let expected = quote! {
+            #[derive(Debug)]
+            struct Test<'env> {
+                object: ::java::lang::Object<'env>,
+            }
+
+            impl<'a> ::rust_jni::JavaType for Test<'a> {
+                #[doc(hidden)]
+                type __JniType = <::rust_jni::java::lang::Object<'a> as ::rust_jni::JavaType>::__JniType;
+
+                #[doc(hidden)]
+                fn __signature() -> &'static str {
+                    "La/b/Test;"
+                }
+            }
+
+            impl<'a> ::rust_jni::__generator::ToJni for Test<'a> {
+                unsafe fn __to_jni(&self) -> Self::__JniType {
+                    self.raw_object()
+                }
+            }
+
+            impl<'a> ::rust_jni::__generator::FromJni<'a> for Test<'a> {
+                unsafe fn __from_jni(env: &'a ::rust_jni::JniEnv<'a>, value: Self::__JniType) -> Self {
+                    Self {
+                        object: <::java::lang::Object as ::rust_jni::__generator::FromJni<'a>>::__from_jni(env, value),
+                    }
+                }
+            }
+
+            impl<'a> ::rust_jni::Cast<'a, Test<'a>> for Test<'a> {
+                #[doc(hidden)]
+                fn cast<'b>(&'b self) -> &'b Test<'a> {
+                    self
+                }
+            }
+
+            impl<'a> ::rust_jni::Cast<'a, ::java::lang::Object<'a>> for Test<'a> {
+                #[doc(hidden)]
+                fn cast<'b>(&'b self) -> &'b ::java::lang::Object<'a> {
+                    self
+                }
+            }
+
+            impl<'a> ::std::ops::Deref for Test<'a> {
+                type Target = ::java::lang::Object<'a>;
+
+                fn deref(&self) -> &Self::Target {
+                    &self.object
+                }
+            }
+
+            impl<'a> Test<'a> {
+                /// The JNI signature of the class.
+                pub const JNI_SIGNATURE: &'static str = "La/b/Test;";
+
                 pub fn get_class(env: &'a ::rust_jni::JniEnv<'a>, token: &::rust_jni::NoException<'a>)
                     -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::Class<'a>> {
-                    ::rust_jni::java::lang::Class::find(env, "TestClass1", token)
+                    ::rust_jni::java::lang::Class::find(env, "a/b/Test", token)
+                }
+
+                /// Wrap a raw Java object reference if the object is an instance of this class.
+                /// Returns `None` if the reference is `null` or the object is of a different class.
+                ///
+                /// Takes ownership of the local reference.
+                /// Unsafe because an incorrect object reference can be passed.
+                pub unsafe fn try_from_raw(
+                    env: &'a ::rust_jni::JniEnv<'a>,
+                    raw_object: <::rust_jni::java::lang::Object<'a> as ::rust_jni::JavaType>::__JniType,
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::JavaResult<'a, Option<Self>> {
+                    if raw_object.is_null() {
+                        return Ok(None);
+                    }
+                    let object = <::rust_jni::java::lang::Object<'a> as ::rust_jni::__generator::FromJni<'a>>::__from_jni(env, raw_object);
+                    if !object.is_instance_of(token, &Self::get_class(env, token)?) {
+                        return Ok(None);
+                    }
+                    Ok(Some(<Self as ::rust_jni::__generator::FromJni<'a>>::__from_jni(
+                        env,
+                        ::rust_jni::java::lang::Object::take_raw_object(object).as_ptr(),
+                    )))
+                }
+
+                /// View an object as an instance of this class if it is one.
+                /// Returns `None` if the object is of a different class.
+                pub fn from_object(
+                    object: &::rust_jni::java::lang::Object<'a>,
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::JavaResult<'a, Option<Self>> {
+                    let env = object.env();
+                    if !object.is_instance_of(token, &Self::get_class(env, token)?) {
+                        return Ok(None);
+                    }
+                    let object = object.clone(token)?;
+                    // Safe because the object is an instance of this class.
+                    Ok(Some(unsafe {
+                        <Self as ::rust_jni::__generator::FromJni<'a>>::__from_jni(
+                            env,
+                            ::rust_jni::java::lang::Object::take_raw_object(object).as_ptr(),
+                        )
+                    }))
                 }
 
                 pub fn clone(&self, token: &::rust_jni::NoException<'a>) -> ::rust_jni::JavaResult<'a, Self>
@@ -116,21 +913,57 @@ mod java_generate_tests {
                     -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::String<'a>> {
                     self.object.to_string(token)
                 }
+
+                /// Convert into a type-erased global reference, which can be stored long-term.
+                pub fn into_global_object(self, token: &::rust_jni::NoException<'a>)
+                    -> ::rust_jni::JavaResult<'a, ::rust_jni::GlobalRef<::rust_jni::java::lang::Object<'static>>> {
+                    self.object.into_global_object(token)
+                }
+
+                pub fn value(
+                    &self,
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::JavaResult<'a, i32> {
+                    // Safe because the field name and type are correct.
+                    unsafe {
+                        ::rust_jni::get_field::<i32, _>(self, token, #value_name)
+                    }
+                }
+
+                pub fn set_value(
+                    &self,
+                    value: i32,
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::JavaResult<'a, ()> {
+                    // Safe because the field name and type are correct.
+                    unsafe {
+                        ::rust_jni::set_field::<i32, _>(self, token, #value_name, value)
+                    }
+                }
+
+                pub fn max(
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::JavaResult<'a, i32> {
+                    // Safe because the field name and type are correct.
+                    unsafe {
+                        ::rust_jni::get_static_field::<i32, Self>(token, #max_value_name)
+                    }
+                }
             }
 
-            impl<'a> ::std::fmt::Display for TestClass1<'a> {
+            impl<'a> ::std::fmt::Display for Test<'a> {
                 fn fmt(&self, formatter: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
                     self.object.fmt(formatter)
                 }
             }
 
-            impl<'a, T> PartialEq<T> for TestClass1<'a> where T: ::rust_jni::Cast<'a, ::rust_jni::java::lang::Object<'a>> {
+            impl<'a, T> PartialEq<T> for Test<'a> where T: ::rust_jni::Cast<'a, ::rust_jni::java::lang::Object<'a>> {
                 fn eq(&self, other: &T) -> bool {
                     self.object.eq(other)
                 }
             }
 
-            impl<'a> Eq for TestClass1<'a> {}
+            impl<'a> Eq for Test<'a> {}
         };
         assert_tokens_equals(java_generate_impl(input), expected);
     }
@@ -201,11 +1034,57 @@ mod java_generate_tests {
             }
 
             impl<'a> TestClass1<'a> {
+                /// The JNI signature of the class.
+                pub const JNI_SIGNATURE: &'static str = "LTestClass1;";
+
                 pub fn get_class(env: &'a ::rust_jni::JniEnv<'a>, token: &::rust_jni::NoException<'a>)
                     -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::Class<'a>> {
                     ::rust_jni::java::lang::Class::find(env, "TestClass1", token)
                 }
 
+                /// Wrap a raw Java object reference if the object is an instance of this class.
+                /// Returns `None` if the reference is `null` or the object is of a different class.
+                ///
+                /// Takes ownership of the local reference.
+                /// Unsafe because an incorrect object reference can be passed.
+                pub unsafe fn try_from_raw(
+                    env: &'a ::rust_jni::JniEnv<'a>,
+                    raw_object: <::rust_jni::java::lang::Object<'a> as ::rust_jni::JavaType>::__JniType,
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::JavaResult<'a, Option<Self>> {
+                    if raw_object.is_null() {
+                        return Ok(None);
+                    }
+                    let object = <::rust_jni::java::lang::Object<'a> as ::rust_jni::__generator::FromJni<'a>>::__from_jni(env, raw_object);
+                    if !object.is_instance_of(token, &Self::get_class(env, token)?) {
+                        return Ok(None);
+                    }
+                    Ok(Some(<Self as ::rust_jni::__generator::FromJni<'a>>::__from_jni(
+                        env,
+                        ::rust_jni::java::lang::Object::take_raw_object(object).as_ptr(),
+                    )))
+                }
+
+                /// View an object as an instance of this class if it is one.
+                /// Returns `None` if the object is of a different class.
+                pub fn from_object(
+                    object: &::rust_jni::java::lang::Object<'a>,
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::JavaResult<'a, Option<Self>> {
+                    let env = object.env();
+                    if !object.is_instance_of(token, &Self::get_class(env, token)?) {
+                        return Ok(None);
+                    }
+                    let object = object.clone(token)?;
+                    // Safe because the object is an instance of this class.
+                    Ok(Some(unsafe {
+                        <Self as ::rust_jni::__generator::FromJni<'a>>::__from_jni(
+                            env,
+                            ::rust_jni::java::lang::Object::take_raw_object(object).as_ptr(),
+                        )
+                    }))
+                }
+
                 pub fn clone(&self, token: &::rust_jni::NoException<'a>) -> ::rust_jni::JavaResult<'a, Self>
                 where
                     Self: Sized,
@@ -219,6 +1098,12 @@ mod java_generate_tests {
                     -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::String<'a>> {
                     self.object.to_string(token)
                 }
+
+                /// Convert into a type-erased global reference, which can be stored long-term.
+                pub fn into_global_object(self, token: &::rust_jni::NoException<'a>)
+                    -> ::rust_jni::JavaResult<'a, ::rust_jni::GlobalRef<::rust_jni::java::lang::Object<'static>>> {
+                    self.object.into_global_object(token)
+                }
             }
 
             impl<'a> ::std::fmt::Display for TestClass1<'a> {
@@ -302,11 +1187,57 @@ mod java_generate_tests {
             }
 
             impl<'a> TestClass1<'a> {
+                /// The JNI signature of the class.
+                pub const JNI_SIGNATURE: &'static str = "La/b/TestClass1;";
+
                 pub fn get_class(env: &'a ::rust_jni::JniEnv<'a>, token: &::rust_jni::NoException<'a>)
                     -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::Class<'a>> {
                     ::rust_jni::java::lang::Class::find(env, "a/b/TestClass1", token)
                 }
 
+                /// Wrap a raw Java object reference if the object is an instance of this class.
+                /// Returns `None` if the reference is `null` or the object is of a different class.
+                ///
+                /// Takes ownership of the local reference.
+                /// Unsafe because an incorrect object reference can be passed.
+                pub unsafe fn try_from_raw(
+                    env: &'a ::rust_jni::JniEnv<'a>,
+                    raw_object: <::rust_jni::java::lang::Object<'a> as ::rust_jni::JavaType>::__JniType,
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::JavaResult<'a, Option<Self>> {
+                    if raw_object.is_null() {
+                        return Ok(None);
+                    }
+                    let object = <::rust_jni::java::lang::Object<'a> as ::rust_jni::__generator::FromJni<'a>>::__from_jni(env, raw_object);
+                    if !object.is_instance_of(token, &Self::get_class(env, token)?) {
+                        return Ok(None);
+                    }
+                    Ok(Some(<Self as ::rust_jni::__generator::FromJni<'a>>::__from_jni(
+                        env,
+                        ::rust_jni::java::lang::Object::take_raw_object(object).as_ptr(),
+                    )))
+                }
+
+                /// View an object as an instance of this class if it is one.
+                /// Returns `None` if the object is of a different class.
+                pub fn from_object(
+                    object: &::rust_jni::java::lang::Object<'a>,
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::JavaResult<'a, Option<Self>> {
+                    let env = object.env();
+                    if !object.is_instance_of(token, &Self::get_class(env, token)?) {
+                        return Ok(None);
+                    }
+                    let object = object.clone(token)?;
+                    // Safe because the object is an instance of this class.
+                    Ok(Some(unsafe {
+                        <Self as ::rust_jni::__generator::FromJni<'a>>::__from_jni(
+                            env,
+                            ::rust_jni::java::lang::Object::take_raw_object(object).as_ptr(),
+                        )
+                    }))
+                }
+
                 pub fn clone(&self, token: &::rust_jni::NoException<'a>) -> ::rust_jni::JavaResult<'a, Self>
                 where
                     Self: Sized,
@@ -320,6 +1251,12 @@ mod java_generate_tests {
                     -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::String<'a>> {
                     self.object.to_string(token)
                 }
+
+                /// Convert into a type-erased global reference, which can be stored long-term.
+                pub fn into_global_object(self, token: &::rust_jni::NoException<'a>)
+                    -> ::rust_jni::JavaResult<'a, ::rust_jni::GlobalRef<::rust_jni::java::lang::Object<'static>>> {
+                    self.object.into_global_object(token)
+                }
             }
 
             impl<'a> ::std::fmt::Display for TestClass1<'a> {
@@ -397,11 +1334,57 @@ mod java_generate_tests {
             }
 
             impl<'a> TestClass1<'a> {
+                /// The JNI signature of the class.
+                pub const JNI_SIGNATURE: &'static str = "LTestClass1;";
+
                 pub fn get_class(env: &'a ::rust_jni::JniEnv<'a>, token: &::rust_jni::NoException<'a>)
                     -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::Class<'a>> {
                     ::rust_jni::java::lang::Class::find(env, "TestClass1", token)
                 }
 
+                /// Wrap a raw Java object reference if the object is an instance of this class.
+                /// Returns `None` if the reference is `null` or the object is of a different class.
+                ///
+                /// Takes ownership of the local reference.
+                /// Unsafe because an incorrect object reference can be passed.
+                pub unsafe fn try_from_raw(
+                    env: &'a ::rust_jni::JniEnv<'a>,
+                    raw_object: <::rust_jni::java::lang::Object<'a> as ::rust_jni::JavaType>::__JniType,
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::JavaResult<'a, Option<Self>> {
+                    if raw_object.is_null() {
+                        return Ok(None);
+                    }
+                    let object = <::rust_jni::java::lang::Object<'a> as ::rust_jni::__generator::FromJni<'a>>::__from_jni(env, raw_object);
+                    if !object.is_instance_of(token, &Self::get_class(env, token)?) {
+                        return Ok(None);
+                    }
+                    Ok(Some(<Self as ::rust_jni::__generator::FromJni<'a>>::__from_jni(
+                        env,
+                        ::rust_jni::java::lang::Object::take_raw_object(object).as_ptr(),
+                    )))
+                }
+
+                /// View an object as an instance of this class if it is one.
+                /// Returns `None` if the object is of a different class.
+                pub fn from_object(
+                    object: &::rust_jni::java::lang::Object<'a>,
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::JavaResult<'a, Option<Self>> {
+                    let env = object.env();
+                    if !object.is_instance_of(token, &Self::get_class(env, token)?) {
+                        return Ok(None);
+                    }
+                    let object = object.clone(token)?;
+                    // Safe because the object is an instance of this class.
+                    Ok(Some(unsafe {
+                        <Self as ::rust_jni::__generator::FromJni<'a>>::__from_jni(
+                            env,
+                            ::rust_jni::java::lang::Object::take_raw_object(object).as_ptr(),
+                        )
+                    }))
+                }
+
                 pub fn clone(&self, token: &::rust_jni::NoException<'a>) -> ::rust_jni::JavaResult<'a, Self>
                 where
                     Self: Sized,
@@ -415,6 +1398,12 @@ mod java_generate_tests {
                     -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::String<'a>> {
                     self.object.to_string(token)
                 }
+
+                /// Convert into a type-erased global reference, which can be stored long-term.
+                pub fn into_global_object(self, token: &::rust_jni::NoException<'a>)
+                    -> ::rust_jni::JavaResult<'a, ::rust_jni::GlobalRef<::rust_jni::java::lang::Object<'static>>> {
+                    self.object.into_global_object(token)
+                }
             }
 
             impl<'a> ::std::fmt::Display for TestClass1<'a> {
@@ -434,6 +1423,19 @@ mod java_generate_tests {
         assert_tokens_equals(java_generate_impl(input), expected);
     }
 
+    #[test]
+    fn one_annotation_type() {
+        let input = quote! {
+            public @interface a.b.TestAnnotation1 {
+                String value();
+            }
+        };
+        let expected = quote! {
+            pub trait TestAnnotation1 {}
+        };
+        assert_tokens_equals(java_generate_impl(input), expected);
+    }
+
     #[test]
     fn one_interface() {
         let input = quote! {
@@ -562,11 +1564,57 @@ mod java_generate_tests {
             }
 
             impl<'a> TestClass1<'a> {
+                /// The JNI signature of the class.
+                pub const JNI_SIGNATURE: &'static str = "LTestClass1;";
+
                 pub fn get_class(env: &'a ::rust_jni::JniEnv<'a>, token: &::rust_jni::NoException<'a>)
                     -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::Class<'a>> {
                     ::rust_jni::java::lang::Class::find(env, "TestClass1", token)
                 }
 
+                /// Wrap a raw Java object reference if the object is an instance of this class.
+                /// Returns `None` if the reference is `null` or the object is of a different class.
+                ///
+                /// Takes ownership of the local reference.
+                /// Unsafe because an incorrect object reference can be passed.
+                pub unsafe fn try_from_raw(
+                    env: &'a ::rust_jni::JniEnv<'a>,
+                    raw_object: <::rust_jni::java::lang::Object<'a> as ::rust_jni::JavaType>::__JniType,
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::JavaResult<'a, Option<Self>> {
+                    if raw_object.is_null() {
+                        return Ok(None);
+                    }
+                    let object = <::rust_jni::java::lang::Object<'a> as ::rust_jni::__generator::FromJni<'a>>::__from_jni(env, raw_object);
+                    if !object.is_instance_of(token, &Self::get_class(env, token)?) {
+                        return Ok(None);
+                    }
+                    Ok(Some(<Self as ::rust_jni::__generator::FromJni<'a>>::__from_jni(
+                        env,
+                        ::rust_jni::java::lang::Object::take_raw_object(object).as_ptr(),
+                    )))
+                }
+
+                /// View an object as an instance of this class if it is one.
+                /// Returns `None` if the object is of a different class.
+                pub fn from_object(
+                    object: &::rust_jni::java::lang::Object<'a>,
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::JavaResult<'a, Option<Self>> {
+                    let env = object.env();
+                    if !object.is_instance_of(token, &Self::get_class(env, token)?) {
+                        return Ok(None);
+                    }
+                    let object = object.clone(token)?;
+                    // Safe because the object is an instance of this class.
+                    Ok(Some(unsafe {
+                        <Self as ::rust_jni::__generator::FromJni<'a>>::__from_jni(
+                            env,
+                            ::rust_jni::java::lang::Object::take_raw_object(object).as_ptr(),
+                        )
+                    }))
+                }
+
                 pub fn clone(&self, token: &::rust_jni::NoException<'a>) -> ::rust_jni::JavaResult<'a, Self>
                 where
                     Self: Sized,
@@ -580,6 +1628,12 @@ mod java_generate_tests {
                     -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::String<'a>> {
                     self.object.to_string(token)
                 }
+
+                /// Convert into a type-erased global reference, which can be stored long-term.
+                pub fn into_global_object(self, token: &::rust_jni::NoException<'a>)
+                    -> ::rust_jni::JavaResult<'a, ::rust_jni::GlobalRef<::rust_jni::java::lang::Object<'static>>> {
+                    self.object.into_global_object(token)
+                }
             }
 
             impl<'a> ::std::fmt::Display for TestClass1<'a> {
@@ -648,11 +1702,57 @@ mod java_generate_tests {
             }
 
             impl<'a> TestClass2<'a> {
+                /// The JNI signature of the class.
+                pub const JNI_SIGNATURE: &'static str = "LTestClass2;";
+
                 pub fn get_class(env: &'a ::rust_jni::JniEnv<'a>, token: &::rust_jni::NoException<'a>)
                     -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::Class<'a>> {
                     ::rust_jni::java::lang::Class::find(env, "TestClass2", token)
                 }
 
+                /// Wrap a raw Java object reference if the object is an instance of this class.
+                /// Returns `None` if the reference is `null` or the object is of a different class.
+                ///
+                /// Takes ownership of the local reference.
+                /// Unsafe because an incorrect object reference can be passed.
+                pub unsafe fn try_from_raw(
+                    env: &'a ::rust_jni::JniEnv<'a>,
+                    raw_object: <::rust_jni::java::lang::Object<'a> as ::rust_jni::JavaType>::__JniType,
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::JavaResult<'a, Option<Self>> {
+                    if raw_object.is_null() {
+                        return Ok(None);
+                    }
+                    let object = <::rust_jni::java::lang::Object<'a> as ::rust_jni::__generator::FromJni<'a>>::__from_jni(env, raw_object);
+                    if !object.is_instance_of(token, &Self::get_class(env, token)?) {
+                        return Ok(None);
+                    }
+                    Ok(Some(<Self as ::rust_jni::__generator::FromJni<'a>>::__from_jni(
+                        env,
+                        ::rust_jni::java::lang::Object::take_raw_object(object).as_ptr(),
+                    )))
+                }
+
+                /// View an object as an instance of this class if it is one.
+                /// Returns `None` if the object is of a different class.
+                pub fn from_object(
+                    object: &::rust_jni::java::lang::Object<'a>,
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::JavaResult<'a, Option<Self>> {
+                    let env = object.env();
+                    if !object.is_instance_of(token, &Self::get_class(env, token)?) {
+                        return Ok(None);
+                    }
+                    let object = object.clone(token)?;
+                    // Safe because the object is an instance of this class.
+                    Ok(Some(unsafe {
+                        <Self as ::rust_jni::__generator::FromJni<'a>>::__from_jni(
+                            env,
+                            ::rust_jni::java::lang::Object::take_raw_object(object).as_ptr(),
+                        )
+                    }))
+                }
+
                 pub fn clone(&self, token: &::rust_jni::NoException<'a>) -> ::rust_jni::JavaResult<'a, Self>
                 where
                     Self: Sized,
@@ -666,6 +1766,12 @@ mod java_generate_tests {
                     -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::String<'a>> {
                     self.object.to_string(token)
                 }
+
+                /// Convert into a type-erased global reference, which can be stored long-term.
+                pub fn into_global_object(self, token: &::rust_jni::NoException<'a>)
+                    -> ::rust_jni::JavaResult<'a, ::rust_jni::GlobalRef<::rust_jni::java::lang::Object<'static>>> {
+                    self.object.into_global_object(token)
+                }
             }
 
             impl<'a> ::std::fmt::Display for TestClass2<'a> {
@@ -846,11 +1952,57 @@ mod java_generate_tests {
             }
 
             impl<'a> TestClass3<'a> {
+                /// The JNI signature of the class.
+                pub const JNI_SIGNATURE: &'static str = "La/b/TestClass3;";
+
                 pub fn get_class(env: &'a ::rust_jni::JniEnv<'a>, token: &::rust_jni::NoException<'a>)
                     -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::Class<'a>> {
                     ::rust_jni::java::lang::Class::find(env, "a/b/TestClass3", token)
                 }
 
+                /// Wrap a raw Java object reference if the object is an instance of this class.
+                /// Returns `None` if the reference is `null` or the object is of a different class.
+                ///
+                /// Takes ownership of the local reference.
+                /// Unsafe because an incorrect object reference can be passed.
+                pub unsafe fn try_from_raw(
+                    env: &'a ::rust_jni::JniEnv<'a>,
+                    raw_object: <::rust_jni::java::lang::Object<'a> as ::rust_jni::JavaType>::__JniType,
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::JavaResult<'a, Option<Self>> {
+                    if raw_object.is_null() {
+                        return Ok(None);
+                    }
+                    let object = <::rust_jni::java::lang::Object<'a> as ::rust_jni::__generator::FromJni<'a>>::__from_jni(env, raw_object);
+                    if !object.is_instance_of(token, &Self::get_class(env, token)?) {
+                        return Ok(None);
+                    }
+                    Ok(Some(<Self as ::rust_jni::__generator::FromJni<'a>>::__from_jni(
+                        env,
+                        ::rust_jni::java::lang::Object::take_raw_object(object).as_ptr(),
+                    )))
+                }
+
+                /// View an object as an instance of this class if it is one.
+                /// Returns `None` if the object is of a different class.
+                pub fn from_object(
+                    object: &::rust_jni::java::lang::Object<'a>,
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::JavaResult<'a, Option<Self>> {
+                    let env = object.env();
+                    if !object.is_instance_of(token, &Self::get_class(env, token)?) {
+                        return Ok(None);
+                    }
+                    let object = object.clone(token)?;
+                    // Safe because the object is an instance of this class.
+                    Ok(Some(unsafe {
+                        <Self as ::rust_jni::__generator::FromJni<'a>>::__from_jni(
+                            env,
+                            ::rust_jni::java::lang::Object::take_raw_object(object).as_ptr(),
+                        )
+                    }))
+                }
+
                 pub fn clone(&self, token: &::rust_jni::NoException<'a>) -> ::rust_jni::JavaResult<'a, Self>
                 where
                     Self: Sized,
@@ -865,6 +2017,12 @@ mod java_generate_tests {
                     self.object.to_string(token)
                 }
 
+                /// Convert into a type-erased global reference, which can be stored long-term.
+                pub fn into_global_object(self, token: &::rust_jni::NoException<'a>)
+                    -> ::rust_jni::JavaResult<'a, ::rust_jni::GlobalRef<::rust_jni::java::lang::Object<'static>>> {
+                    self.object.into_global_object(token)
+                }
+
                 pub fn init(
                     env: &'a ::rust_jni::JniEnv<'a>,
                     arg1: i32,
@@ -904,7 +2062,7 @@ mod java_generate_tests {
 
                 pub fn object_func_3(
                     &self,
-                    arg: &::a::b::TestClass3<'a>,
+                    arg: &impl ::rust_jni::Cast<'a, ::a::b::TestClass3<'a> >,
                     token: &::rust_jni::NoException<'a>,
                 ) -> ::rust_jni::JavaResult<'a, ::c::d::TestClass2<'a> > {
                     // Safe because the method name and arguments are correct.
@@ -915,7 +2073,7 @@ mod java_generate_tests {
                         (
                             self,
                             "objectFunc3",
-                            (arg,),
+                            (::rust_jni::Cast::<'a, ::a::b::TestClass3<'a> >::cast(arg),),
                             token,
                         )
                     }
@@ -943,7 +2101,7 @@ mod java_generate_tests {
 
                 fn objectInterfaceFunc3(
                     &self,
-                    arg: &::a::b::TestClass3<'a>,
+                    arg: &impl ::rust_jni::Cast<'a, ::a::b::TestClass3<'a> >,
                     token: &::rust_jni::NoException<'a>,
                 ) -> ::rust_jni::JavaResult<'a, ::a::b::TestClass3<'a> > {
                     // Safe because the method name and arguments are correct.
@@ -954,7 +2112,7 @@ mod java_generate_tests {
                         (
                             self,
                             "objectInterfaceFunc3",
-                            (arg,),
+                            (::rust_jni::Cast::<'a, ::a::b::TestClass3<'a> >::cast(arg),),
                             token,
                         )
                     }
@@ -982,7 +2140,7 @@ mod java_generate_tests {
 
                 pub fn object_static_func_3(
                     env: &'a ::rust_jni::JniEnv<'a>,
-                    arg: &::a::b::TestClass3<'a>,
+                    arg: &impl ::rust_jni::Cast<'a, ::a::b::TestClass3<'a> >,
                     token: &::rust_jni::NoException<'a>,
                 ) -> ::rust_jni::JavaResult<'a, ::c::d::TestClass2<'a> > {
                     // Safe because the method name and arguments are correct.
@@ -993,12 +2151,17 @@ mod java_generate_tests {
                         (
                             env,
                             "objectStaticFunc3",
-                            (arg,),
+                            (::rust_jni::Cast::<'a, ::a::b::TestClass3<'a> >::cast(arg),),
                             token,
                         )
                     }
                 }
+            }
 
+            /// Rust implementations of Java native methods of this class.
+            ///
+            /// These methods are called from Java. All other methods call into Java.
+            impl<'a> TestClass3<'a> {
                 pub fn primitive_native_func_3(
                     &self,
                     arg1: i32,
@@ -1036,6 +2199,44 @@ mod java_generate_tests {
                     println!("{:?} {:?} {:?}", arg, token, env);
                     Ok(arg)
                 }
+
+                /// Register native methods of this class with the JVM.
+                ///
+                /// Only needed when the library is linked statically and the JVM can't find
+                /// the native method implementations dynamically.
+                pub fn register_native_methods(
+                    env: &'a ::rust_jni::JniEnv<'a>,
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::JavaResult<'a, ()> {
+                    let class = Self::get_class(env, token)?;
+                    unsafe {
+                        class.register_natives(
+                            token,
+                            &[
+                                (
+                                    "primitiveNativeFunc3",
+                                    "(IC)J",
+                                    Java_a_b_TestClass3_primitiveNativeFunc3__IC as *mut ::std::os::raw::c_void
+                                ),
+                                (
+                                    "objectNativeFunc3",
+                                    "(La/b/TestClass3;)La/b/TestClass3;",
+                                    Java_a_b_TestClass3_objectNativeFunc3__La_b_TestClass3_2 as *mut ::std::os::raw::c_void
+                                ),
+                                (
+                                    "primitiveStaticNativeFunc3",
+                                    "(IC)J",
+                                    Java_a_b_TestClass3_primitiveStaticNativeFunc3__IC as *mut ::std::os::raw::c_void
+                                ),
+                                (
+                                    "objectStaticNativeFunc3",
+                                    "(La/b/TestClass3;)La/b/TestClass3;",
+                                    Java_a_b_TestClass3_objectStaticNativeFunc3__La_b_TestClass3_2 as *mut ::std::os::raw::c_void
+                                ),
+                            ],
+                        )
+                    }
+                }
             }
 
             #[no_mangle]
@@ -1140,7 +2341,7 @@ mod java_generate_tests {
 
                     let class = TestClass3::get_class(env, &token)?;
                     let raw_class = <::rust_jni::java::lang::Class as ::rust_jni::__generator::FromJni>::__from_jni(env, raw_class);
-                    if !class.is_same_as(&raw_class, &token) {
+                    if !class.is_same_class(&token, &raw_class) {
                         panic!("Native method primitiveStaticNativeFunc3 does not belong to class TestClass3");
                     }
 
@@ -1177,7 +2378,7 @@ mod java_generate_tests {
 
                     let class = TestClass3::get_class(env, &token)?;
                     let raw_class = <::rust_jni::java::lang::Class as ::rust_jni::__generator::FromJni>::__from_jni(env, raw_class);
-                    if !class.is_same_as(&raw_class, &token) {
+                    if !class.is_same_class(&token, &raw_class) {
                         panic!("Native method objectStaticNativeFunc3 does not belong to class TestClass3");
                     }
 
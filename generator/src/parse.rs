@@ -38,6 +38,7 @@ pub struct JavaClassMethod {
     pub public: bool,
     pub is_static: bool,
     pub annotations: Vec<Annotation>,
+    pub throws: Vec<JavaName>,
 }
 
 #[derive(Debug, Clone)]
@@ -59,6 +60,16 @@ impl PartialEq for JavaNativeMethod {
 
 impl Eq for JavaNativeMethod {}
 
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct JavaField {
+    pub name: Ident,
+    pub data_type: JavaName,
+    pub public: bool,
+    pub is_static: bool,
+    pub is_final: bool,
+    pub annotations: Vec<Annotation>,
+}
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct JavaConstructor {
     pub arguments: Vec<MethodArgument>,
@@ -72,7 +83,9 @@ pub struct JavaClass {
     pub implements: Vec<JavaName>,
     pub methods: Vec<JavaClassMethod>,
     pub native_methods: Vec<JavaNativeMethod>,
+    pub fields: Vec<JavaField>,
     pub constructors: Vec<JavaConstructor>,
+    pub annotations: Vec<Annotation>,
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
@@ -85,6 +98,7 @@ pub struct JavaInterface {
 pub enum JavaDefinitionKind {
     Class(JavaClass),
     Interface(JavaInterface),
+    AnnotationType,
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
@@ -165,6 +179,48 @@ fn parse_annotations(tokens: &[TokenTree]) -> Vec<Annotation> {
     }
 }
 
+/// Remove a marker annotation without a value, like `@Nullable`, from a declaration.
+///
+/// Marker annotations can appear anywhere among the modifiers, so they are extracted before
+/// parsing the annotations with values.
+fn take_marker_annotation(
+    tokens: Vec<TokenTree>,
+    name: &str,
+) -> (Vec<TokenTree>, Option<Annotation>) {
+    let position = tokens
+        .windows(2)
+        .position(|window| is_punctuation(&window[0], '@') && is_identifier(&window[1], name));
+    match position {
+        None => (tokens, None),
+        Some(position) => {
+            let annotation = match tokens[position + 1] {
+                TokenTree::Ident(ref identifier) => Annotation {
+                    name: identifier.clone(),
+                    value: TokenStream::new(),
+                },
+                _ => unreachable!(),
+            };
+            let tokens = tokens
+                .iter()
+                .take(position)
+                .chain(tokens.iter().skip(position + 2))
+                .cloned()
+                .collect();
+            (tokens, Some(annotation))
+        }
+    }
+}
+
+/// Remove a `throws` clause from a declaration and return the declared exceptions.
+fn take_throws(tokens: Vec<TokenTree>) -> (Vec<TokenTree>, Vec<JavaName>) {
+    let throws_position = tokens
+        .iter()
+        .position(|token| is_identifier(token, "throws"))
+        .unwrap_or(tokens.len());
+    let throws = comma_separated_names(tokens.iter().skip(throws_position + 1).cloned());
+    (tokens[0..throws_position].to_vec(), throws)
+}
+
 fn comma_separated_names(tokens: impl Iterator<Item = TokenTree>) -> Vec<JavaName> {
     let tokens = tokens.collect::<Vec<_>>();
     tokens
@@ -227,6 +283,58 @@ fn parse_class_header(header: &[TokenTree]) -> (JavaName, Option<JavaName>, Vec<
     (name, extends, implements)
 }
 
+fn parse_record_header(header: &[TokenTree]) -> (JavaName, Vec<MethodArgument>, Vec<JavaName>) {
+    let components_position = header
+        .iter()
+        .position(|token| match token {
+            TokenTree::Group(group) => group.delimiter() == Delimiter::Parenthesis,
+            _ => false,
+        })
+        .unwrap_or_else(|| panic!("Expected record components, got {:?}.", header));
+    let name = JavaName::from_tokens(header[0..components_position].iter());
+    let components = parse_method_arguments(header[components_position].clone());
+    let implements = comma_separated_names(
+        header
+            .iter()
+            .skip(components_position + 1)
+            .skip_while(|token| !is_identifier(&token, "implements"))
+            .skip(1)
+            .cloned(),
+    );
+    (name, components, implements)
+}
+
+/// Parse the constants of an enum body, returning them as public static final fields along
+/// with the remaining member declarations.
+///
+/// Enum constants are a comma-separated list of names, optionally terminated by a `;`.
+fn parse_enum_constants(
+    enum_name: &JavaName,
+    tokens: &[TokenTree],
+) -> (Vec<JavaField>, Vec<TokenTree>) {
+    let constants_end = tokens
+        .iter()
+        .position(|token| is_punctuation(token, ';'))
+        .unwrap_or(tokens.len());
+    let constants = tokens[0..constants_end]
+        .split(|token| is_punctuation(token, ','))
+        .filter(|tokens| !tokens.is_empty())
+        .map(|tokens| match tokens {
+            [TokenTree::Ident(name)] => JavaField {
+                name: name.clone(),
+                data_type: enum_name.clone(),
+                public: true,
+                is_static: true,
+                is_final: true,
+                annotations: vec![],
+            },
+            tokens => panic!("Expected an enum constant name, got {:?}.", tokens),
+        })
+        .collect();
+    let members = tokens.iter().skip(constants_end + 1).cloned().collect();
+    (constants, members)
+}
+
 fn parse_metadata(tokens: TokenStream) -> Metadata {
     let definitions = tokens.clone().into_iter().collect::<Vec<_>>();
     let definitions = definitions
@@ -292,6 +400,7 @@ fn parse_metadata(tokens: TokenStream) -> Metadata {
 }
 
 fn is_constructor(tokens: &[TokenTree], class_name: &JavaName) -> bool {
+    let (tokens, _) = take_throws(tokens.to_vec());
     let class_name_len = class_name
         .clone()
         .with_dots()
@@ -306,6 +415,23 @@ fn is_constructor(tokens: &[TokenTree], class_name: &JavaName) -> bool {
         == class_name.clone().with_dots().to_string()
 }
 
+/// Strip the `...` of a variadic argument type, returning the element type tokens.
+fn strip_varargs(tokens: &[TokenTree]) -> Option<&[TokenTree]> {
+    if tokens.len() < 4 {
+        return None;
+    }
+    let (element_type, dots) = tokens.split_at(tokens.len() - 3);
+    let is_dot = |token: &TokenTree| match token {
+        TokenTree::Punct(punct) => punct.as_char() == '.',
+        _ => false,
+    };
+    if dots.iter().all(is_dot) {
+        Some(element_type)
+    } else {
+        None
+    }
+}
+
 fn parse_method_arguments(token: TokenTree) -> Vec<MethodArgument> {
     match token {
         TokenTree::Group(group) => {
@@ -313,19 +439,36 @@ fn parse_method_arguments(token: TokenTree) -> Vec<MethodArgument> {
                 panic!("Expected method arguments in parenthesis, got {:?}.", group);
             }
             let arguments = group.stream().into_iter().collect::<Vec<_>>();
-            arguments
+            let arguments = arguments
                 .split(|token| is_punctuation(token, ','))
                 .filter(|tokens| !tokens.is_empty())
-                .map(|tokens| tokens.split_last().unwrap())
-                .map(|(last, others)| {
+                .collect::<Vec<_>>();
+            let arguments_count = arguments.len();
+            arguments
+                .into_iter()
+                .enumerate()
+                .map(|(index, tokens)| {
+                    let (last, others) = tokens.split_last().unwrap();
                     let name = match last {
                         TokenTree::Ident(ident) => ident.clone(),
                         token => panic!("Expected argument name, got {:?}.", token),
                     };
-                    MethodArgument {
-                        name,
-                        data_type: JavaName::from_tokens(others.iter()),
-                    }
+                    let data_type = match strip_varargs(others) {
+                        Some(element_type) => {
+                            if index + 1 != arguments_count {
+                                panic!("Only the last method argument can be variadic.");
+                            }
+                            // Variadic arguments are passed to Java as an array.
+                            let mut element_type = element_type.to_vec();
+                            element_type.push(TokenTree::Group(Group::new(
+                                Delimiter::Bracket,
+                                TokenStream::new(),
+                            )));
+                            JavaName::from_tokens(element_type.iter())
+                        }
+                        None => JavaName::from_tokens(others.iter()),
+                    };
+                    MethodArgument { name, data_type }
                 })
                 .collect::<Vec<_>>()
         }
@@ -341,16 +484,21 @@ fn parse_method(tokens: &[TokenTree]) -> JavaClassMethod {
         .filter(|token| !is_identifier(token, "public") && !is_identifier(token, "static"))
         .cloned()
         .collect::<Vec<_>>();
+    let (tokens, nullable) = take_marker_annotation(tokens, "Nullable");
+    let (tokens, throws) = take_throws(tokens);
     let name = match tokens[tokens.len() - 2].clone() {
         TokenTree::Ident(ident) => ident,
         token => panic!("Expected method name, got {:?}.", token),
     };
-    let annotations = parse_annotations(&tokens[0..tokens.len() - 2]);
+    let mut annotations = parse_annotations(&tokens[0..tokens.len() - 2]);
     let return_type = JavaName::from_tokens(
         tokens[0..tokens.len() - 2]
             .iter()
             .skip(3 * annotations.len()),
     );
+    if let Some(nullable) = nullable {
+        annotations.push(nullable);
+    }
     let arguments = parse_method_arguments(tokens[tokens.len() - 1].clone());
     JavaClassMethod {
         public,
@@ -359,11 +507,13 @@ fn parse_method(tokens: &[TokenTree]) -> JavaClassMethod {
         arguments,
         is_static,
         annotations,
+        throws,
     }
 }
 
 fn parse_interface_method(tokens: &[TokenTree]) -> JavaInterfaceMethod {
-    let tokens = tokens.iter().cloned().collect::<Vec<_>>();
+    // Interface methods don't generate typed errors, so declared exceptions are ignored.
+    let (tokens, _) = take_throws(tokens.to_vec());
     let name = match tokens[tokens.len() - 2].clone() {
         TokenTree::Ident(ident) => ident,
         token => panic!("Expected method name, got {:?}.", token),
@@ -427,13 +577,76 @@ fn parse_native_method(tokens: &[TokenTree]) -> JavaNativeMethod {
     }
 }
 
+fn is_field_modifier(token: &TokenTree) -> bool {
+    is_identifier(token, "public")
+        || is_identifier(token, "static")
+        || is_identifier(token, "final")
+}
+
+fn is_field(tokens: &[TokenTree]) -> bool {
+    let tokens = tokens
+        .iter()
+        .filter(|token| !is_field_modifier(token))
+        .cloned()
+        .collect::<Vec<_>>();
+    let (tokens, _) = take_marker_annotation(tokens, "Nullable");
+    let annotations = parse_annotations(&tokens);
+    // Fields are the only class members without an argument list.
+    !tokens
+        .iter()
+        .skip(3 * annotations.len())
+        .any(|token| match token {
+            TokenTree::Group(group) => group.delimiter() == Delimiter::Parenthesis,
+            _ => false,
+        })
+}
+
+fn parse_field(tokens: &[TokenTree]) -> JavaField {
+    let public = tokens.iter().any(|token| is_identifier(token, "public"));
+    let is_static = tokens.iter().any(|token| is_identifier(token, "static"));
+    let is_final = tokens.iter().any(|token| is_identifier(token, "final"));
+    let tokens = tokens
+        .iter()
+        .filter(|token| !is_field_modifier(token))
+        .cloned()
+        .collect::<Vec<_>>();
+    let name = match tokens[tokens.len() - 1].clone() {
+        TokenTree::Ident(ident) => ident,
+        token => panic!("Expected field name, got {:?}.", token),
+    };
+    let annotations = parse_annotations(&tokens[0..tokens.len() - 1]);
+    let data_type = JavaName::from_tokens(
+        tokens[0..tokens.len() - 1]
+            .iter()
+            .skip(3 * annotations.len()),
+    );
+    JavaField {
+        name,
+        data_type,
+        public,
+        is_static,
+        is_final,
+        annotations,
+    }
+}
+
+fn is_constructor_modifier(token: &TokenTree) -> bool {
+    is_identifier(token, "public")
+        || is_identifier(token, "protected")
+        || is_identifier(token, "private")
+}
+
 fn parse_constructor(tokens: &[TokenTree]) -> JavaConstructor {
+    // JNI doesn't enforce Java access control, so non-public constructors can be called
+    // all the same and only generate non-public Rust functions.
     let public = tokens.iter().any(|token| is_identifier(token, "public"));
     let tokens = tokens
         .iter()
-        .filter(|token| !is_identifier(token, "public"))
+        .filter(|token| !is_constructor_modifier(token))
         .cloned()
         .collect::<Vec<_>>();
+    // Constructors don't generate typed errors, so declared exceptions are ignored.
+    let (tokens, _) = take_throws(tokens);
     let annotations = parse_annotations(&tokens[0..tokens.len() - 1]);
     let arguments = parse_method_arguments(tokens[tokens.len() - 1].clone());
     JavaConstructor {
@@ -469,6 +682,20 @@ pub fn parse_java_definition(input: TokenStream) -> JavaDefinitions {
         .split(is_definition)
         .filter(|tokens| !tokens.is_empty())
         .map(|header| {
+            let annotation_type = header.windows(2).position(|tokens| {
+                is_punctuation(&tokens[0], '@') && is_identifier(&tokens[1], "interface")
+            });
+            let header = match annotation_type {
+                Some(position) => header
+                    .iter()
+                    .take(position)
+                    .chain(header.iter().skip(position + 1))
+                    .cloned()
+                    .collect::<Vec<_>>(),
+                None => header.to_vec(),
+            };
+            let annotations = parse_annotations(&header);
+            let header = &header[3 * annotations.len()..];
             let (token, header) = header.split_first().unwrap();
             let public = is_identifier(&token, "public");
             let (token, header) = if public {
@@ -478,11 +705,23 @@ pub fn parse_java_definition(input: TokenStream) -> JavaDefinitions {
             };
             let is_class = is_identifier(&token, "class");
             let is_interface = is_identifier(&token, "interface");
-            if !is_class && !is_interface {
-                panic!("Expected \"class\" or \"interface\", got {:?}.", token);
+            let is_record = is_identifier(&token, "record");
+            let is_enum = is_identifier(&token, "enum");
+            if !is_class && !is_interface && !is_record && !is_enum {
+                panic!(
+                    "Expected \"class\", \"interface\", \"record\" or \"enum\", got {:?}.",
+                    token
+                );
             }
 
-            if is_interface {
+            let definition = if annotation_type.is_some() {
+                let (name, _) = parse_interface_header(header);
+                JavaDefinition {
+                    name,
+                    public,
+                    definition: JavaDefinitionKind::AnnotationType,
+                }
+            } else if is_interface {
                 let (name, extends) = parse_interface_header(header);
                 JavaDefinition {
                     name,
@@ -492,6 +731,76 @@ pub fn parse_java_definition(input: TokenStream) -> JavaDefinitions {
                         extends,
                     }),
                 }
+            } else if is_record {
+                // Records are classes with a canonical constructor taking all components
+                // and a no-argument accessor method for each component.
+                let (name, components, implements) = parse_record_header(header);
+                let methods = components
+                    .iter()
+                    .map(|component| JavaClassMethod {
+                        name: component.name.clone(),
+                        return_type: component.data_type.clone(),
+                        arguments: vec![],
+                        public: true,
+                        is_static: false,
+                        annotations: vec![],
+                        throws: vec![],
+                    })
+                    .collect();
+                JavaDefinition {
+                    name,
+                    public,
+                    definition: JavaDefinitionKind::Class(JavaClass {
+                        extends: None,
+                        implements,
+                        methods,
+                        native_methods: vec![],
+                        fields: vec![],
+                        constructors: vec![JavaConstructor {
+                            arguments: components,
+                            public: true,
+                            annotations: vec![],
+                        }],
+                        annotations,
+                    }),
+                }
+            } else if is_enum {
+                // Enums are classes with a static `values()` method and a `name()` method
+                // inherited from `java.lang.Enum`. Enum constants are parsed from the body.
+                let (name, _, implements) = parse_class_header(header);
+                let methods = vec![
+                    JavaClassMethod {
+                        name: Ident::new("values", Span::call_site()),
+                        return_type: JavaName(quote! {#name []}),
+                        arguments: vec![],
+                        public: true,
+                        is_static: true,
+                        annotations: vec![],
+                        throws: vec![],
+                    },
+                    JavaClassMethod {
+                        name: Ident::new("name", Span::call_site()),
+                        return_type: JavaName(quote! {java lang String}),
+                        arguments: vec![],
+                        public: true,
+                        is_static: false,
+                        annotations: vec![],
+                        throws: vec![],
+                    },
+                ];
+                JavaDefinition {
+                    name,
+                    public,
+                    definition: JavaDefinitionKind::Class(JavaClass {
+                        extends: Some(JavaName(quote! {java lang Enum})),
+                        implements,
+                        methods,
+                        native_methods: vec![],
+                        fields: vec![],
+                        constructors: vec![],
+                        annotations,
+                    }),
+                }
             } else {
                 let (name, extends, implements) = parse_class_header(header);
                 JavaDefinition {
@@ -502,43 +811,66 @@ pub fn parse_java_definition(input: TokenStream) -> JavaDefinitions {
                         implements,
                         methods: vec![],
                         native_methods: vec![],
+                        fields: vec![],
                         constructors: vec![],
+                        annotations,
                     }),
                 }
-            }
+            };
+            (definition, is_enum)
         })
         .zip(definitions.iter().cloned().filter(is_definition))
-        .map(|(definition, token)| match token {
-            TokenTree::Group(group) => (definition, group.stream()),
+        .map(|((definition, is_enum), token)| match token {
+            TokenTree::Group(group) => (definition, is_enum, group.stream()),
             _ => unreachable!(),
         })
-        .map(|(definition, tokens)| {
+        .map(|(definition, is_enum, tokens)| {
             let methods = tokens.into_iter().collect::<Vec<_>>();
+            let (constants, methods) = if is_enum {
+                parse_enum_constants(&definition.name, &methods)
+            } else {
+                (vec![], methods)
+            };
             let java_definition = match definition.definition.clone() {
                 JavaDefinitionKind::Class(class) => {
-                    let constructors = methods
+                    let fields = methods
                         .split(|token| is_punctuation(token, ';'))
                         .filter(|tokens| !tokens.is_empty())
-                        .filter(|tokens| is_constructor(tokens, &definition.name))
-                        .map(parse_constructor)
-                        .collect::<Vec<_>>();
-                    let native_methods = methods
+                        .filter(|tokens| is_field(tokens))
+                        .map(parse_field);
+                    let fields = class
+                        .fields
+                        .into_iter()
+                        .chain(constants)
+                        .chain(fields)
+                        .collect();
+                    let methods = methods
                         .split(|token| is_punctuation(token, ';'))
                         .filter(|tokens| !tokens.is_empty())
+                        .filter(|tokens| !is_field(tokens))
+                        .map(|tokens| tokens.to_vec())
+                        .collect::<Vec<_>>();
+                    let constructors = methods
+                        .iter()
+                        .filter(|tokens| is_constructor(tokens, &definition.name))
+                        .map(|tokens| parse_constructor(tokens));
+                    let constructors = class.constructors.into_iter().chain(constructors).collect();
+                    let native_methods = methods
+                        .iter()
                         .filter(|tokens| !is_constructor(tokens, &definition.name))
                         .filter(|tokens| tokens.iter().any(|token| is_identifier(token, "native")))
-                        .map(parse_native_method)
+                        .map(|tokens| parse_native_method(tokens))
                         .collect::<Vec<_>>();
                     let methods = methods
-                        .split(|token| is_punctuation(token, ';'))
-                        .filter(|tokens| !tokens.is_empty())
+                        .iter()
                         .filter(|tokens| !is_constructor(tokens, &definition.name))
                         .filter(|tokens| !tokens.iter().any(|token| is_identifier(token, "native")))
-                        .map(parse_method)
-                        .collect::<Vec<_>>();
+                        .map(|tokens| parse_method(tokens));
+                    let methods = class.methods.into_iter().chain(methods).collect();
                     JavaDefinitionKind::Class(JavaClass {
                         methods,
                         native_methods,
+                        fields,
                         constructors,
                         ..class
                     })
@@ -554,6 +886,8 @@ pub fn parse_java_definition(input: TokenStream) -> JavaDefinitions {
                         ..interface
                     })
                 }
+                // Annotation type elements are not supported yet.
+                JavaDefinitionKind::AnnotationType => JavaDefinitionKind::AnnotationType,
             };
             JavaDefinition {
                 definition: java_definition,
@@ -623,7 +957,9 @@ mod parse_tests {
                         implements: vec![],
                         methods: vec![],
                         native_methods: vec![],
+                        fields: vec![],
                         constructors: vec![],
+                        annotations: vec![],
                     }),
                 }],
                 metadata: Metadata {
@@ -649,7 +985,9 @@ mod parse_tests {
                         implements: vec![],
                         methods: vec![],
                         native_methods: vec![],
+                        fields: vec![],
                         constructors: vec![],
+                        annotations: vec![],
                     }),
                 }],
                 metadata: Metadata {
@@ -675,7 +1013,9 @@ mod parse_tests {
                         implements: vec![],
                         methods: vec![],
                         native_methods: vec![],
+                        fields: vec![],
                         constructors: vec![],
+                        annotations: vec![],
                     }),
                 }],
                 metadata: Metadata {
@@ -701,7 +1041,9 @@ mod parse_tests {
                         implements: vec![],
                         methods: vec![],
                         native_methods: vec![],
+                        fields: vec![],
                         constructors: vec![],
+                        annotations: vec![],
                     }),
                 }],
                 metadata: Metadata {
@@ -727,7 +1069,9 @@ mod parse_tests {
                         implements: vec![JavaName(quote! {test2}), JavaName(quote! {a b test3})],
                         methods: vec![],
                         native_methods: vec![],
+                        fields: vec![],
                         constructors: vec![],
+                        annotations: vec![],
                     }),
                 }],
                 metadata: Metadata {
@@ -737,6 +1081,633 @@ mod parse_tests {
         );
     }
 
+    #[test]
+    fn one_class_annotated() {
+        let input = quote! {
+            @CaptureError(TestError) public class TestClass1 {}
+        };
+        assert_eq!(
+            parse_java_definition(input),
+            JavaDefinitions {
+                definitions: vec![JavaDefinition {
+                    name: JavaName(quote! {TestClass1}),
+                    public: true,
+                    definition: JavaDefinitionKind::Class(JavaClass {
+                        extends: None,
+                        implements: vec![],
+                        methods: vec![],
+                        native_methods: vec![],
+                        fields: vec![],
+                        constructors: vec![],
+                        annotations: vec![Annotation {
+                            name: Ident::new("CaptureError", Span::call_site()),
+                            value: quote! {TestError},
+                        }],
+                    }),
+                }],
+                metadata: Metadata {
+                    definitions: vec![],
+                },
+            }
+        );
+    }
+
+    #[test]
+    fn one_class_method_throws() {
+        let input = quote! {
+            class TestClass1 {
+                int test(long arg) throws a.b.IOException, SQLException;
+            }
+        };
+        assert_eq!(
+            parse_java_definition(input),
+            JavaDefinitions {
+                definitions: vec![JavaDefinition {
+                    name: JavaName(quote! {TestClass1}),
+                    public: false,
+                    definition: JavaDefinitionKind::Class(JavaClass {
+                        extends: None,
+                        implements: vec![],
+                        methods: vec![JavaClassMethod {
+                            name: Ident::new("test", Span::call_site()),
+                            return_type: JavaName(quote! {int}),
+                            arguments: vec![MethodArgument {
+                                name: Ident::new("arg", Span::call_site()),
+                                data_type: JavaName(quote! {long}),
+                            }],
+                            public: false,
+                            is_static: false,
+                            annotations: vec![],
+                            throws: vec![
+                                JavaName(quote! {a b IOException}),
+                                JavaName(quote! {SQLException}),
+                            ],
+                        }],
+                        native_methods: vec![],
+                        fields: vec![],
+                        constructors: vec![],
+                        annotations: vec![],
+                    }),
+                }],
+                metadata: Metadata {
+                    definitions: vec![],
+                },
+            }
+        );
+    }
+
+    #[test]
+    fn one_class_void_method_throws() {
+        let input = quote! {
+            class TestClass1 {
+                TestClass1() throws a.b.IOException;
+                void read() throws a.b.IOException;
+            }
+        };
+        assert_eq!(
+            parse_java_definition(input),
+            JavaDefinitions {
+                definitions: vec![JavaDefinition {
+                    name: JavaName(quote! {TestClass1}),
+                    public: false,
+                    definition: JavaDefinitionKind::Class(JavaClass {
+                        extends: None,
+                        implements: vec![],
+                        methods: vec![JavaClassMethod {
+                            name: Ident::new("read", Span::call_site()),
+                            return_type: JavaName(quote! {void}),
+                            arguments: vec![],
+                            public: false,
+                            is_static: false,
+                            annotations: vec![],
+                            throws: vec![JavaName(quote! {a b IOException})],
+                        }],
+                        native_methods: vec![],
+                        fields: vec![],
+                        constructors: vec![JavaConstructor {
+                            arguments: vec![],
+                            public: false,
+                            annotations: vec![],
+                        }],
+                        annotations: vec![],
+                    }),
+                }],
+                metadata: Metadata {
+                    definitions: vec![],
+                },
+            }
+        );
+    }
+
+    #[test]
+    fn one_class_private_constructor() {
+        let input = quote! {
+            class TestClass1 {
+                @RustName(init) private TestClass1(int arg);
+            }
+        };
+        assert_eq!(
+            parse_java_definition(input),
+            JavaDefinitions {
+                definitions: vec![JavaDefinition {
+                    name: JavaName(quote! {TestClass1}),
+                    public: false,
+                    definition: JavaDefinitionKind::Class(JavaClass {
+                        extends: None,
+                        implements: vec![],
+                        methods: vec![],
+                        native_methods: vec![],
+                        fields: vec![],
+                        constructors: vec![JavaConstructor {
+                            arguments: vec![MethodArgument {
+                                name: Ident::new("arg", Span::call_site()),
+                                data_type: JavaName(quote! {int}),
+                            }],
+                            public: false,
+                            annotations: vec![Annotation {
+                                name: Ident::new("RustName", Span::call_site()),
+                                value: quote! {init},
+                            }],
+                        }],
+                        annotations: vec![],
+                    }),
+                }],
+                metadata: Metadata {
+                    definitions: vec![],
+                },
+            }
+        );
+    }
+
+    #[test]
+    fn one_class_method_nullable() {
+        let input = quote! {
+            class TestClass1 {
+                @RustName(find_test) @Nullable a.b.TestClass2 test(long arg);
+            }
+        };
+        assert_eq!(
+            parse_java_definition(input),
+            JavaDefinitions {
+                definitions: vec![JavaDefinition {
+                    name: JavaName(quote! {TestClass1}),
+                    public: false,
+                    definition: JavaDefinitionKind::Class(JavaClass {
+                        extends: None,
+                        implements: vec![],
+                        methods: vec![JavaClassMethod {
+                            name: Ident::new("test", Span::call_site()),
+                            return_type: JavaName(quote! {a b TestClass2}),
+                            arguments: vec![MethodArgument {
+                                name: Ident::new("arg", Span::call_site()),
+                                data_type: JavaName(quote! {long}),
+                            }],
+                            public: false,
+                            is_static: false,
+                            annotations: vec![
+                                Annotation {
+                                    name: Ident::new("RustName", Span::call_site()),
+                                    value: quote! {find_test},
+                                },
+                                Annotation {
+                                    name: Ident::new("Nullable", Span::call_site()),
+                                    value: quote! {},
+                                },
+                            ],
+                            throws: vec![],
+                        }],
+                        native_methods: vec![],
+                        fields: vec![],
+                        constructors: vec![],
+                        annotations: vec![],
+                    }),
+                }],
+                metadata: Metadata {
+                    definitions: vec![],
+                },
+            }
+        );
+    }
+
+    #[test]
+    fn one_enum() {
+        let input = quote! {
+            public enum a.b.Color implements a.b.test2 {
+                RED, GREEN;
+                int rgb();
+            }
+        };
+        assert_eq!(
+            parse_java_definition(input),
+            JavaDefinitions {
+                definitions: vec![JavaDefinition {
+                    name: JavaName(quote! {a b Color}),
+                    public: true,
+                    definition: JavaDefinitionKind::Class(JavaClass {
+                        extends: Some(JavaName(quote! {java lang Enum})),
+                        implements: vec![JavaName(quote! {a b test2})],
+                        methods: vec![
+                            JavaClassMethod {
+                                name: Ident::new("values", Span::call_site()),
+                                return_type: JavaName(quote! {a b Color []}),
+                                arguments: vec![],
+                                public: true,
+                                is_static: true,
+                                annotations: vec![],
+                                throws: vec![],
+                            },
+                            JavaClassMethod {
+                                name: Ident::new("name", Span::call_site()),
+                                return_type: JavaName(quote! {java lang String}),
+                                arguments: vec![],
+                                public: true,
+                                is_static: false,
+                                annotations: vec![],
+                                throws: vec![],
+                            },
+                            JavaClassMethod {
+                                name: Ident::new("rgb", Span::call_site()),
+                                return_type: JavaName(quote! {int}),
+                                arguments: vec![],
+                                public: false,
+                                is_static: false,
+                                annotations: vec![],
+                                throws: vec![],
+                            },
+                        ],
+                        native_methods: vec![],
+                        fields: vec![
+                            JavaField {
+                                name: Ident::new("RED", Span::call_site()),
+                                data_type: JavaName(quote! {a b Color}),
+                                public: true,
+                                is_static: true,
+                                is_final: true,
+                                annotations: vec![],
+                            },
+                            JavaField {
+                                name: Ident::new("GREEN", Span::call_site()),
+                                data_type: JavaName(quote! {a b Color}),
+                                public: true,
+                                is_static: true,
+                                is_final: true,
+                                annotations: vec![],
+                            },
+                        ],
+                        constructors: vec![],
+                        annotations: vec![],
+                    }),
+                }],
+                metadata: Metadata {
+                    definitions: vec![],
+                },
+            }
+        );
+    }
+
+    #[test]
+    fn one_record() {
+        let input = quote! {
+            public record a.b.Point(int x, a.b.Point y) implements a.b.test2 {}
+        };
+        assert_eq!(
+            parse_java_definition(input),
+            JavaDefinitions {
+                definitions: vec![JavaDefinition {
+                    name: JavaName(quote! {a b Point}),
+                    public: true,
+                    definition: JavaDefinitionKind::Class(JavaClass {
+                        extends: None,
+                        implements: vec![JavaName(quote! {a b test2})],
+                        methods: vec![
+                            JavaClassMethod {
+                                name: Ident::new("x", Span::call_site()),
+                                return_type: JavaName(quote! {int}),
+                                arguments: vec![],
+                                public: true,
+                                is_static: false,
+                                annotations: vec![],
+                                throws: vec![],
+                            },
+                            JavaClassMethod {
+                                name: Ident::new("y", Span::call_site()),
+                                return_type: JavaName(quote! {a b Point}),
+                                arguments: vec![],
+                                public: true,
+                                is_static: false,
+                                annotations: vec![],
+                                throws: vec![],
+                            },
+                        ],
+                        native_methods: vec![],
+                        fields: vec![],
+                        constructors: vec![JavaConstructor {
+                            arguments: vec![
+                                MethodArgument {
+                                    name: Ident::new("x", Span::call_site()),
+                                    data_type: JavaName(quote! {int}),
+                                },
+                                MethodArgument {
+                                    name: Ident::new("y", Span::call_site()),
+                                    data_type: JavaName(quote! {a b Point}),
+                                },
+                            ],
+                            public: true,
+                            annotations: vec![],
+                        }],
+                        annotations: vec![],
+                    }),
+                }],
+                metadata: Metadata {
+                    definitions: vec![],
+                },
+            }
+        );
+    }
+
+    #[test]
+    fn one_record_with_methods() {
+        let input = quote! {
+            record Point(int x) {
+                Point(long x);
+                long distance(a.b.Point other);
+            }
+        };
+        assert_eq!(
+            parse_java_definition(input),
+            JavaDefinitions {
+                definitions: vec![JavaDefinition {
+                    name: JavaName(quote! {Point}),
+                    public: false,
+                    definition: JavaDefinitionKind::Class(JavaClass {
+                        extends: None,
+                        implements: vec![],
+                        methods: vec![
+                            JavaClassMethod {
+                                name: Ident::new("x", Span::call_site()),
+                                return_type: JavaName(quote! {int}),
+                                arguments: vec![],
+                                public: true,
+                                is_static: false,
+                                annotations: vec![],
+                                throws: vec![],
+                            },
+                            JavaClassMethod {
+                                name: Ident::new("distance", Span::call_site()),
+                                return_type: JavaName(quote! {long}),
+                                arguments: vec![MethodArgument {
+                                    name: Ident::new("other", Span::call_site()),
+                                    data_type: JavaName(quote! {a b Point}),
+                                }],
+                                public: false,
+                                is_static: false,
+                                annotations: vec![],
+                                throws: vec![],
+                            },
+                        ],
+                        native_methods: vec![],
+                        fields: vec![],
+                        constructors: vec![
+                            JavaConstructor {
+                                arguments: vec![MethodArgument {
+                                    name: Ident::new("x", Span::call_site()),
+                                    data_type: JavaName(quote! {int}),
+                                }],
+                                public: true,
+                                annotations: vec![],
+                            },
+                            JavaConstructor {
+                                arguments: vec![MethodArgument {
+                                    name: Ident::new("x", Span::call_site()),
+                                    data_type: JavaName(quote! {long}),
+                                }],
+                                public: false,
+                                annotations: vec![],
+                            },
+                        ],
+                        annotations: vec![],
+                    }),
+                }],
+                metadata: Metadata {
+                    definitions: vec![],
+                },
+            }
+        );
+    }
+
+    #[test]
+    fn one_class_with_array_types() {
+        let input = quote! {
+            class a.b.Test {
+                long sum(int[] values);
+                a.b.Test[][] items();
+            }
+        };
+        assert_eq!(
+            parse_java_definition(input),
+            JavaDefinitions {
+                definitions: vec![JavaDefinition {
+                    name: JavaName(quote! {a b Test}),
+                    public: false,
+                    definition: JavaDefinitionKind::Class(JavaClass {
+                        extends: None,
+                        implements: vec![],
+                        methods: vec![
+                            JavaClassMethod {
+                                name: Ident::new("sum", Span::call_site()),
+                                return_type: JavaName(quote! {long}),
+                                arguments: vec![MethodArgument {
+                                    name: Ident::new("values", Span::call_site()),
+                                    data_type: JavaName(quote! {int[]}),
+                                }],
+                                public: false,
+                                is_static: false,
+                                annotations: vec![],
+                                throws: vec![],
+                            },
+                            JavaClassMethod {
+                                name: Ident::new("items", Span::call_site()),
+                                return_type: JavaName(quote! {a b Test[][]}),
+                                arguments: vec![],
+                                public: false,
+                                is_static: false,
+                                annotations: vec![],
+                                throws: vec![],
+                            },
+                        ],
+                        native_methods: vec![],
+                        fields: vec![],
+                        constructors: vec![],
+                        annotations: vec![],
+                    }),
+                }],
+                metadata: Metadata {
+                    definitions: vec![],
+                },
+            }
+        );
+    }
+
+    #[test]
+    fn one_class_with_varargs() {
+        let input = quote! {
+            class a.b.Test {
+                java.lang.String format(java.lang.String format, java.lang.Object... arguments);
+            }
+        };
+        assert_eq!(
+            parse_java_definition(input),
+            JavaDefinitions {
+                definitions: vec![JavaDefinition {
+                    name: JavaName(quote! {a b Test}),
+                    public: false,
+                    definition: JavaDefinitionKind::Class(JavaClass {
+                        extends: None,
+                        implements: vec![],
+                        methods: vec![JavaClassMethod {
+                            name: Ident::new("format", Span::call_site()),
+                            return_type: JavaName(quote! {java lang String}),
+                            arguments: vec![
+                                MethodArgument {
+                                    name: Ident::new("format", Span::call_site()),
+                                    data_type: JavaName(quote! {java lang String}),
+                                },
+                                MethodArgument {
+                                    name: Ident::new("arguments", Span::call_site()),
+                                    data_type: JavaName(quote! {java lang Object[]}),
+                                },
+                            ],
+                            public: false,
+                            is_static: false,
+                            annotations: vec![],
+                            throws: vec![],
+                        }],
+                        native_methods: vec![],
+                        fields: vec![],
+                        constructors: vec![],
+                        annotations: vec![],
+                    }),
+                }],
+                metadata: Metadata {
+                    definitions: vec![],
+                },
+            }
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Only the last method argument can be variadic.")]
+    fn one_class_with_varargs_not_last() {
+        let input = quote! {
+            class a.b.Test {
+                void test(java.lang.Object... arguments, int value);
+            }
+        };
+        parse_java_definition(input);
+    }
+
+    #[test]
+    fn one_class_with_fields() {
+        let input = quote! {
+            class a.b.Test {
+                public int value;
+                @RustName(max) public static final int MAX_VALUE;
+                a.b.Test next;
+            }
+        };
+        assert_eq!(
+            parse_java_definition(input),
+            JavaDefinitions {
+                definitions: vec![JavaDefinition {
+                    name: JavaName(quote! {a b Test}),
+                    public: false,
+                    definition: JavaDefinitionKind::Class(JavaClass {
+                        extends: None,
+                        implements: vec![],
+                        methods: vec![],
+                        native_methods: vec![],
+                        fields: vec![
+                            JavaField {
+                                name: Ident::new("value", Span::call_site()),
+                                data_type: JavaName(quote! {int}),
+                                public: true,
+                                is_static: false,
+                                is_final: false,
+                                annotations: vec![],
+                            },
+                            JavaField {
+                                name: Ident::new("MAX_VALUE", Span::call_site()),
+                                data_type: JavaName(quote! {int}),
+                                public: true,
+                                is_static: true,
+                                is_final: true,
+                                annotations: vec![Annotation {
+                                    name: Ident::new("RustName", Span::call_site()),
+                                    value: quote! {max},
+                                }],
+                            },
+                            JavaField {
+                                name: Ident::new("next", Span::call_site()),
+                                data_type: JavaName(quote! {a b Test}),
+                                public: false,
+                                is_static: false,
+                                is_final: false,
+                                annotations: vec![],
+                            },
+                        ],
+                        constructors: vec![],
+                        annotations: vec![],
+                    }),
+                }],
+                metadata: Metadata {
+                    definitions: vec![],
+                },
+            }
+        );
+    }
+
+    #[test]
+    fn one_annotation_type() {
+        let input = quote! {
+            @interface a.b.TestAnnotation1 {
+                String value();
+            }
+        };
+        assert_eq!(
+            parse_java_definition(input),
+            JavaDefinitions {
+                definitions: vec![JavaDefinition {
+                    name: JavaName(quote! {a b TestAnnotation1}),
+                    public: false,
+                    definition: JavaDefinitionKind::AnnotationType,
+                }],
+                metadata: Metadata {
+                    definitions: vec![],
+                },
+            }
+        );
+    }
+
+    #[test]
+    fn one_annotation_type_public() {
+        let input = quote! {
+            public @interface TestAnnotation1 {}
+        };
+        assert_eq!(
+            parse_java_definition(input),
+            JavaDefinitions {
+                definitions: vec![JavaDefinition {
+                    name: JavaName(quote! {TestAnnotation1}),
+                    public: true,
+                    definition: JavaDefinitionKind::AnnotationType,
+                }],
+                metadata: Metadata {
+                    definitions: vec![],
+                },
+            }
+        );
+    }
+
     #[test]
     fn one_interface() {
         let input = quote! {
@@ -868,7 +1839,9 @@ mod parse_tests {
                             implements: vec![],
                             methods: vec![],
                             native_methods: vec![],
+                            fields: vec![],
                             constructors: vec![],
+                            annotations: vec![],
                         }),
                     },
                     JavaDefinition {
@@ -879,7 +1852,9 @@ mod parse_tests {
                             implements: vec![],
                             methods: vec![],
                             native_methods: vec![],
+                            fields: vec![],
                             constructors: vec![],
+                            annotations: vec![],
                         }),
                     },
                 ],
@@ -964,7 +1939,7 @@ mod parse_tests {
     }
 
     #[test]
-    #[should_panic(expected = "Expected \"class\" or \"interface\"")]
+    #[should_panic(expected = "Expected \"class\", \"interface\", \"record\" or \"enum\"")]
     fn invalid_definition_kind() {
         let input = quote! {
             invalid 1
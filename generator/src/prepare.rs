@@ -56,6 +56,13 @@ fn annotation_value_ident(annotations: &[Annotation], name: &str) -> Option<Iden
     })
 }
 
+fn annotation_value_literal(annotations: &[Annotation], name: &str) -> Option<Literal> {
+    annotation_value(annotations, name).map(|value| match value.into_iter().next().unwrap() {
+        TokenTree::Literal(literal) => literal,
+        token => panic!("Expected a string literal in @{}, got {:?}.", name, token),
+    })
+}
+
 fn to_generator_method(method: JavaClassMethod) -> generate::ClassMethod {
     let JavaClassMethod {
         name,
@@ -63,11 +70,18 @@ fn to_generator_method(method: JavaClassMethod) -> generate::ClassMethod {
         return_type,
         arguments,
         annotations,
+        throws,
         ..
     } = method;
     let java_name = Literal::string(&name.to_string());
+    let rust_name = annotation_value_ident(&annotations, "RustName").unwrap_or(name.clone());
+    let error = to_generator_method_error(&name, &rust_name, &throws);
+    let nullable = annotation_value(&annotations, "Nullable").is_some();
+    if nullable && return_type.as_primitive_type().is_some() {
+        panic!("@Nullable is only supported on methods returning objects.");
+    }
     generate::ClassMethod {
-        name: annotation_value_ident(&annotations, "RustName").unwrap_or(name),
+        name: rust_name,
         java_name,
         public,
         return_type: return_type.as_rust_type(),
@@ -79,9 +93,67 @@ fn to_generator_method(method: JavaClassMethod) -> generate::ClassMethod {
             .iter()
             .map(|argument| argument.data_type.clone().as_rust_type_reference())
             .collect(),
+        error,
+        super_class: annotation_value(&annotations, "Super").map(|value| {
+            JavaName::from_tokens(value.into_iter().collect::<Vec<_>>().iter()).with_double_colons()
+        }),
+        nullable,
+        signature: annotation_value_literal(&annotations, "Signature"),
     }
 }
 
+/// Convert an exception class name into an enum variant name: `IOException` becomes `Io`.
+fn exception_variant_name(name: &str) -> String {
+    let name = if name.ends_with("Exception") && name.len() > "Exception".len() {
+        &name[..name.len() - "Exception".len()]
+    } else {
+        name
+    };
+    let characters = name.chars().collect::<Vec<_>>();
+    characters
+        .iter()
+        .enumerate()
+        .map(|(index, character)| {
+            let previous_uppercase = index > 0 && characters[index - 1].is_uppercase();
+            let next_lowercase =
+                index + 1 < characters.len() && characters[index + 1].is_lowercase();
+            if previous_uppercase && !next_lowercase {
+                character.to_lowercase().collect::<String>()
+            } else {
+                character.to_string()
+            }
+        })
+        .collect()
+}
+
+fn to_generator_method_error(
+    name: &Ident,
+    rust_name: &Ident,
+    throws: &[JavaName],
+) -> Option<generate::MethodError> {
+    if throws.is_empty() {
+        return None;
+    }
+    let name = name.to_string();
+    let mut error_name = name[..1].to_uppercase();
+    error_name.push_str(&name[1..]);
+    error_name.push_str("Error");
+    Some(generate::MethodError {
+        name: Ident::new(&error_name, Span::call_site()),
+        typed_name: Ident::new(&format!("{}_typed", rust_name), Span::call_site()),
+        exceptions: throws
+            .iter()
+            .map(|exception| generate::ThrownException {
+                variant: Ident::new(
+                    &exception_variant_name(&exception.clone().name().to_string()),
+                    Span::call_site(),
+                ),
+                class: exception.clone().with_double_colons(),
+            })
+            .collect(),
+    })
+}
+
 fn to_generator_interface_method(method: JavaInterfaceMethod) -> generate::InterfaceMethod {
     let JavaInterfaceMethod {
         name,
@@ -115,11 +187,17 @@ fn to_generator_interface_method_implementation(
         annotations,
         ..
     } = method;
-    let class_has_method = class_methods.iter().any(|class_method| {
-        class_method.name == name
-            && class_method.return_type == return_type
-            && class_method.arguments == arguments
-    });
+    let class_method = class_methods
+        .iter()
+        .find(|class_method| {
+            class_method.name == name
+                && class_method.return_type == return_type
+                && class_method.arguments == arguments
+        })
+        .map(|class_method| {
+            annotation_value_ident(&class_method.annotations, "RustName")
+                .unwrap_or(class_method.name.clone())
+        });
     generate::InterfaceMethodImplementation {
         name: annotation_value_ident(&annotations, "RustName").unwrap_or(name),
         return_type: return_type.as_rust_type(),
@@ -131,7 +209,7 @@ fn to_generator_interface_method_implementation(
             .iter()
             .map(|argument| argument.data_type.clone().as_rust_type_reference())
             .collect(),
-        class_has_method,
+        class_method,
     }
 }
 
@@ -162,11 +240,23 @@ fn to_generator_native_method(
         ),
         Span::call_site(),
     );
+    let signature = annotation_value_literal(&annotations, "Signature").unwrap_or_else(|| {
+        Literal::string(&format!(
+            "({}){}",
+            arguments
+                .iter()
+                .map(|argument| argument.data_type.get_type_signature())
+                .collect::<Vec<_>>()
+                .join(""),
+            return_type.get_type_signature()
+        ))
+    });
     let rust_name = annotation_value_ident(&annotations, "RustName").unwrap_or(name.clone());
     generate::NativeMethod {
         name,
         rust_name,
         java_name,
+        signature,
         public,
         code,
         return_type: return_type.as_rust_type(),
@@ -185,6 +275,45 @@ fn to_generator_native_method(
     }
 }
 
+fn to_generator_field(field: JavaField) -> generate::Field {
+    let JavaField {
+        name,
+        data_type,
+        public,
+        is_final,
+        annotations,
+        ..
+    } = field;
+    let java_name = Literal::string(&format!("{}\0", name));
+    let name = annotation_value_ident(&annotations, "RustName").unwrap_or(name);
+    // Final fields can't be assigned, so they only get a getter.
+    let setter_name = if is_final {
+        None
+    } else {
+        Some(Ident::new(&format!("set_{}", name), Span::call_site()))
+    };
+    let is_primitive = data_type.as_primitive_type().is_some();
+    let rust_type = data_type.as_rust_type();
+    let (return_type, argument_type) = if is_primitive {
+        (rust_type.clone(), rust_type.clone())
+    } else {
+        // Object fields can be `null`.
+        (
+            quote! {::std::option::Option<#rust_type>},
+            quote! {::std::option::Option<&#rust_type>},
+        )
+    };
+    generate::Field {
+        name,
+        setter_name,
+        java_name,
+        data_type: rust_type,
+        return_type,
+        argument_type,
+        public,
+    }
+}
+
 fn to_generator_constructor(constructor: JavaConstructor) -> generate::Constructor {
     let JavaConstructor {
         public,
@@ -275,6 +404,10 @@ pub fn to_generator_data(definitions: JavaDefinitions) -> GeneratorData {
                 _ => unreachable!(),
             }
         });
+    // `java.lang.Enum` is the implicit superclass of enums.
+    extends_map
+        .entry(JavaName(quote! {java lang Enum}))
+        .or_insert(JavaName(quote! {java lang Object}));
     let mut interface_extends = HashMap::new();
     definitions
         .definitions
@@ -344,6 +477,8 @@ pub fn to_generator_data(definitions: JavaDefinitions) -> GeneratorData {
                             constructors,
                             methods,
                             native_methods,
+                            fields,
+                            annotations,
                             ..
                         } = class;
                         let mut transitive_extends = vec![];
@@ -424,6 +559,12 @@ pub fn to_generator_data(definitions: JavaDefinitions) -> GeneratorData {
                             .cloned()
                             .map(to_generator_method)
                             .collect();
+                        let builder = annotation_value_ident(&annotations, "Builder");
+                        if builder.is_some() && constructors.len() < 2 {
+                            panic!(
+                                "@Builder is only supported on classes with multiple constructors."
+                            );
+                        }
                         let constructors = constructors
                             .into_iter()
                             .map(to_generator_constructor)
@@ -440,6 +581,18 @@ pub fn to_generator_data(definitions: JavaDefinitions) -> GeneratorData {
                             .cloned()
                             .map(|method| to_generator_native_method(method, &name))
                             .collect();
+                        let static_fields = fields
+                            .iter()
+                            .filter(|field| field.is_static)
+                            .cloned()
+                            .map(to_generator_field)
+                            .collect();
+                        let fields = fields
+                            .iter()
+                            .filter(|field| !field.is_static)
+                            .cloned()
+                            .map(to_generator_field)
+                            .collect();
                         GeneratorDefinition::Class(generate::Class {
                             class: definition_name,
                             public,
@@ -453,6 +606,10 @@ pub fn to_generator_data(definitions: JavaDefinitions) -> GeneratorData {
                             static_methods,
                             native_methods,
                             static_native_methods,
+                            fields,
+                            static_fields,
+                            capture_error: annotation_value_ident(&annotations, "CaptureError"),
+                            builder,
                         })
                     }
                     JavaDefinitionKind::Interface(interface) => {
@@ -474,6 +631,12 @@ pub fn to_generator_data(definitions: JavaDefinitions) -> GeneratorData {
                                 .collect(),
                         })
                     }
+                    JavaDefinitionKind::AnnotationType => {
+                        GeneratorDefinition::AnnotationType(generate::AnnotationType {
+                            annotation: definition_name,
+                            public,
+                        })
+                    }
                 }
             })
             .collect(),
@@ -543,7 +706,9 @@ mod to_generator_data_tests {
                         implements: vec![],
                         methods: vec![],
                         native_methods: vec![],
+                        fields: vec![],
                         constructors: vec![],
+                        annotations: vec![],
                     }),
                 }],
                 metadata: Metadata {
@@ -563,7 +728,90 @@ mod to_generator_data_tests {
                     static_methods: vec![],
                     native_methods: vec![],
                     static_native_methods: vec![],
+                    fields: vec![],
+                    static_fields: vec![],
+                    constructors: vec![],
+                    capture_error: None,
+                    builder: None,
+                })],
+            },
+        );
+    }
+
+    #[test]
+    fn one_class_method_throws() {
+        assert_generator_data_equals(
+            to_generator_data(JavaDefinitions {
+                definitions: vec![JavaDefinition {
+                    name: JavaName(quote! {a b test1}),
+                    public: false,
+                    definition: JavaDefinitionKind::Class(JavaClass {
+                        extends: Some(JavaName(quote! {c d test2})),
+                        implements: vec![],
+                        methods: vec![JavaClassMethod {
+                            name: Ident::new("testMethod", Span::call_site()),
+                            return_type: JavaName(quote! {int}),
+                            arguments: vec![],
+                            public: false,
+                            is_static: false,
+                            annotations: vec![],
+                            throws: vec![
+                                JavaName(quote! {java io IOException}),
+                                JavaName(quote! {java sql SQLException}),
+                            ],
+                        }],
+                        native_methods: vec![],
+                        fields: vec![],
+                        constructors: vec![],
+                        annotations: vec![],
+                    }),
+                }],
+                metadata: Metadata {
+                    definitions: vec![],
+                },
+            }),
+            GeneratorData {
+                definitions: vec![GeneratorDefinition::Class(generate::Class {
+                    class: Ident::new("test1", Span::call_site()),
+                    public: false,
+                    super_class: quote! {::c::d::test2},
+                    transitive_extends: vec![quote! {::c::d::test2}],
+                    implements: vec![],
+                    signature: Literal::string("a/b/test1"),
+                    full_signature: Literal::string("La/b/test1;"),
+                    methods: vec![generate::ClassMethod {
+                        name: Ident::new("testMethod", Span::call_site()),
+                        java_name: Literal::string("testMethod"),
+                        return_type: quote! {i32},
+                        argument_names: vec![],
+                        argument_types: vec![],
+                        public: false,
+                        error: Some(generate::MethodError {
+                            name: Ident::new("TestMethodError", Span::call_site()),
+                            typed_name: Ident::new("testMethod_typed", Span::call_site()),
+                            exceptions: vec![
+                                generate::ThrownException {
+                                    variant: Ident::new("Io", Span::call_site()),
+                                    class: quote! {::java::io::IOException},
+                                },
+                                generate::ThrownException {
+                                    variant: Ident::new("Sql", Span::call_site()),
+                                    class: quote! {::java::sql::SQLException},
+                                },
+                            ],
+                        }),
+                        super_class: None,
+                        nullable: false,
+                        signature: None,
+                    }],
+                    static_methods: vec![],
+                    native_methods: vec![],
+                    static_native_methods: vec![],
+                    fields: vec![],
+                    static_fields: vec![],
                     constructors: vec![],
+                    capture_error: None,
+                    builder: None,
                 })],
             },
         );
@@ -581,7 +829,9 @@ mod to_generator_data_tests {
                         implements: vec![],
                         methods: vec![],
                         native_methods: vec![],
+                        fields: vec![],
                         constructors: vec![],
+                        annotations: vec![],
                     }),
                 }],
                 metadata: Metadata {
@@ -601,7 +851,55 @@ mod to_generator_data_tests {
                     static_methods: vec![],
                     native_methods: vec![],
                     static_native_methods: vec![],
+                    fields: vec![],
+                    static_fields: vec![],
                     constructors: vec![],
+                    capture_error: None,
+                    builder: None,
+                })],
+            },
+        );
+    }
+
+    #[test]
+    fn one_class_nested() {
+        assert_generator_data_equals(
+            to_generator_data(JavaDefinitions {
+                definitions: vec![JavaDefinition {
+                    name: JavaName(quote! {a b Outer Inner}),
+                    public: false,
+                    definition: JavaDefinitionKind::Class(JavaClass {
+                        extends: None,
+                        implements: vec![],
+                        methods: vec![],
+                        native_methods: vec![],
+                        fields: vec![],
+                        constructors: vec![],
+                        annotations: vec![],
+                    }),
+                }],
+                metadata: Metadata {
+                    definitions: vec![],
+                },
+            }),
+            GeneratorData {
+                definitions: vec![GeneratorDefinition::Class(generate::Class {
+                    class: Ident::new("Inner", Span::call_site()),
+                    public: false,
+                    super_class: quote! {::java::lang::Object},
+                    transitive_extends: vec![quote! {::java::lang::Object}],
+                    implements: vec![],
+                    signature: Literal::string("a/b/Outer$Inner"),
+                    full_signature: Literal::string("La/b/Outer$Inner;"),
+                    methods: vec![],
+                    static_methods: vec![],
+                    native_methods: vec![],
+                    static_native_methods: vec![],
+                    fields: vec![],
+                    static_fields: vec![],
+                    constructors: vec![],
+                    capture_error: None,
+                    builder: None,
                 })],
             },
         );
@@ -620,7 +918,9 @@ mod to_generator_data_tests {
                             implements: vec![],
                             methods: vec![],
                             native_methods: vec![],
+                            fields: vec![],
                             constructors: vec![],
+                            annotations: vec![],
                         }),
                     },
                     JavaDefinition {
@@ -631,7 +931,9 @@ mod to_generator_data_tests {
                             implements: vec![],
                             methods: vec![],
                             native_methods: vec![],
+                            fields: vec![],
                             constructors: vec![],
+                            annotations: vec![],
                         }),
                     },
                 ],
@@ -672,7 +974,11 @@ mod to_generator_data_tests {
                         static_methods: vec![],
                         native_methods: vec![],
                         static_native_methods: vec![],
+                        fields: vec![],
+                        static_fields: vec![],
                         constructors: vec![],
+                        capture_error: None,
+                        builder: None,
                     }),
                     GeneratorDefinition::Class(generate::Class {
                         class: Ident::new("test1", Span::call_site()),
@@ -691,7 +997,11 @@ mod to_generator_data_tests {
                         static_methods: vec![],
                         native_methods: vec![],
                         static_native_methods: vec![],
+                        fields: vec![],
+                        static_fields: vec![],
                         constructors: vec![],
+                        capture_error: None,
+                        builder: None,
                     }),
                 ],
             },
@@ -722,7 +1032,9 @@ mod to_generator_data_tests {
                             ],
                             methods: vec![],
                             native_methods: vec![],
+                            fields: vec![],
                             constructors: vec![],
+                            annotations: vec![],
                         }),
                     },
                 ],
@@ -765,7 +1077,11 @@ mod to_generator_data_tests {
                         static_methods: vec![],
                         native_methods: vec![],
                         static_native_methods: vec![],
+                        fields: vec![],
+                        static_fields: vec![],
                         constructors: vec![],
+                        capture_error: None,
+                        builder: None,
                     }),
                 ],
             },
@@ -793,7 +1109,9 @@ mod to_generator_data_tests {
                             implements: vec![JavaName(quote! {e f test3})],
                             methods: vec![],
                             native_methods: vec![],
+                            fields: vec![],
                             constructors: vec![],
+                            annotations: vec![],
                         }),
                     },
                 ],
@@ -853,7 +1171,11 @@ mod to_generator_data_tests {
                         static_methods: vec![],
                         native_methods: vec![],
                         static_native_methods: vec![],
+                        fields: vec![],
+                        static_fields: vec![],
                         constructors: vec![],
+                        capture_error: None,
+                        builder: None,
                     }),
                 ],
             },
@@ -892,7 +1214,9 @@ mod to_generator_data_tests {
                             ],
                             methods: vec![],
                             native_methods: vec![],
+                            fields: vec![],
                             constructors: vec![],
+                            annotations: vec![],
                         }),
                     },
                 ],
@@ -935,7 +1259,11 @@ mod to_generator_data_tests {
                         static_methods: vec![],
                         native_methods: vec![],
                         static_native_methods: vec![],
+                        fields: vec![],
+                        static_fields: vec![],
                         constructors: vec![],
+                        capture_error: None,
+                        builder: None,
                     }),
                 ],
             },
@@ -954,7 +1282,9 @@ mod to_generator_data_tests {
                         implements: vec![],
                         methods: vec![],
                         native_methods: vec![],
+                        fields: vec![],
                         constructors: vec![],
+                        annotations: vec![],
                     }),
                 }],
                 metadata: Metadata {
@@ -974,7 +1304,58 @@ mod to_generator_data_tests {
                     static_methods: vec![],
                     native_methods: vec![],
                     static_native_methods: vec![],
+                    fields: vec![],
+                    static_fields: vec![],
                     constructors: vec![],
+                    capture_error: None,
+                    builder: None,
+                })],
+            },
+        );
+    }
+
+    #[test]
+    fn one_class_capture_error() {
+        assert_generator_data_equals(
+            to_generator_data(JavaDefinitions {
+                definitions: vec![JavaDefinition {
+                    name: JavaName(quote! {a b test1}),
+                    public: false,
+                    definition: JavaDefinitionKind::Class(JavaClass {
+                        extends: None,
+                        implements: vec![],
+                        methods: vec![],
+                        native_methods: vec![],
+                        fields: vec![],
+                        constructors: vec![],
+                        annotations: vec![Annotation {
+                            name: Ident::new("CaptureError", Span::call_site()),
+                            value: quote! {TestError},
+                        }],
+                    }),
+                }],
+                metadata: Metadata {
+                    definitions: vec![],
+                },
+            }),
+            GeneratorData {
+                definitions: vec![GeneratorDefinition::Class(generate::Class {
+                    class: Ident::new("test1", Span::call_site()),
+                    public: false,
+                    super_class: quote! {::java::lang::Object},
+                    transitive_extends: vec![quote! {::java::lang::Object}],
+                    implements: vec![],
+                    signature: Literal::string("a/b/test1"),
+                    full_signature: Literal::string("La/b/test1;"),
+                    methods: vec![],
+                    static_methods: vec![],
+                    native_methods: vec![],
+                    static_native_methods: vec![],
+                    fields: vec![],
+                    static_fields: vec![],
+                    constructors: vec![],
+                    capture_error: Some(Ident::new("TestError", Span::call_site())),
+                    builder: None,
                 })],
             },
         );
@@ -1007,6 +1388,30 @@ mod to_generator_data_tests {
         );
     }
 
+    #[test]
+    fn one_annotation_type() {
+        assert_generator_data_equals(
+            to_generator_data(JavaDefinitions {
+                definitions: vec![JavaDefinition {
+                    name: JavaName(quote! {a b test1}),
+                    public: true,
+                    definition: JavaDefinitionKind::AnnotationType,
+                }],
+                metadata: Metadata {
+                    definitions: vec![],
+                },
+            }),
+            GeneratorData {
+                definitions: vec![GeneratorDefinition::AnnotationType(
+                    generate::AnnotationType {
+                        annotation: Ident::new("test1", Span::call_site()),
+                        public: true,
+                    },
+                )],
+            },
+        );
+    }
+
     #[test]
     fn one_interface_extends() {
         assert_generator_data_equals(
@@ -1130,7 +1535,9 @@ mod to_generator_data_tests {
                             implements: vec![],
                             methods: vec![],
                             native_methods: vec![],
+                            fields: vec![],
                             constructors: vec![],
+                            annotations: vec![],
                         }),
                     },
                     JavaDefinition {
@@ -1141,7 +1548,9 @@ mod to_generator_data_tests {
                             implements: vec![],
                             methods: vec![],
                             native_methods: vec![],
+                            fields: vec![],
                             constructors: vec![],
+                            annotations: vec![],
                         }),
                     },
                 ],
@@ -1175,7 +1584,11 @@ mod to_generator_data_tests {
                         static_methods: vec![],
                         native_methods: vec![],
                         static_native_methods: vec![],
+                        fields: vec![],
+                        static_fields: vec![],
                         constructors: vec![],
+                        capture_error: None,
+                        builder: None,
                     }),
                     GeneratorDefinition::Class(generate::Class {
                         class: Ident::new("test2", Span::call_site()),
@@ -1189,7 +1602,11 @@ mod to_generator_data_tests {
                         static_methods: vec![],
                         native_methods: vec![],
                         static_native_methods: vec![],
+                        fields: vec![],
+                        static_fields: vec![],
                         constructors: vec![],
+                        capture_error: None,
+                        builder: None,
                     }),
                 ],
             },
@@ -1201,3 +1618,131 @@ mod to_generator_data_tests {
 fn assert_generator_data_equals(left: GeneratorData, right: GeneratorData) {
     assert_eq!(format!("{:?}", left), format!("{:?}", right),);
 }
+
+#[cfg(test)]
+mod to_generator_native_method_tests {
+    use super::*;
+
+    #[test]
+    fn float_arguments() {
+        let method = to_generator_native_method(
+            JavaNativeMethod {
+                name: Ident::new("nativeFunc", Span::call_site()),
+                return_type: JavaName(quote! {float}),
+                arguments: vec![
+                    MethodArgument {
+                        name: Ident::new("arg1", Span::call_site()),
+                        data_type: JavaName(quote! {float}),
+                    },
+                    MethodArgument {
+                        name: Ident::new("arg2", Span::call_site()),
+                        data_type: JavaName(quote! {int}),
+                    },
+                ],
+                public: false,
+                is_static: false,
+                code: quote! {},
+                annotations: vec![],
+            },
+            &JavaName(quote! {a b test1}),
+        );
+        assert_eq!(
+            method.java_name,
+            Ident::new("Java_a_b_test1_nativeFunc__FI", Span::call_site())
+        );
+        assert_eq!(method.signature.to_string(), "\"(FI)F\"");
+        assert_eq!(
+            format!("{:?}", method.return_type),
+            format!("{:?}", quote! {f32})
+        );
+        assert_eq!(
+            format!("{:?}", method.argument_types),
+            format!("{:?}", vec![quote! {f32}, quote! {i32}])
+        );
+    }
+
+    #[test]
+    fn array_arguments() {
+        let method = to_generator_native_method(
+            JavaNativeMethod {
+                name: Ident::new("nativeFunc", Span::call_site()),
+                return_type: JavaName(quote! {int[]}),
+                arguments: vec![
+                    MethodArgument {
+                        name: Ident::new("arg1", Span::call_site()),
+                        data_type: JavaName(quote! {int[]}),
+                    },
+                    MethodArgument {
+                        name: Ident::new("arg2", Span::call_site()),
+                        data_type: JavaName(quote! {a b test1[][]}),
+                    },
+                ],
+                public: false,
+                is_static: false,
+                code: quote! {},
+                annotations: vec![],
+            },
+            &JavaName(quote! {a b test1}),
+        );
+        assert_eq!(
+            method.java_name,
+            Ident::new(
+                "Java_a_b_test1_nativeFunc___3I_3_3La_b_test1_2",
+                Span::call_site()
+            )
+        );
+        assert_eq!(method.signature.to_string(), "\"([I[[La/b/test1;)[I\"");
+        assert_eq!(
+            format!("{:?}", method.return_type),
+            format!("{:?}", quote! {::rust_jni::PrimitiveArray<'a, i32>})
+        );
+        assert_eq!(
+            format!("{:?}", method.argument_types),
+            format!(
+                "{:?}",
+                vec![
+                    quote! {::rust_jni::PrimitiveArray<'a, i32>},
+                    quote! {::rust_jni::ObjectArray<'a, ::rust_jni::ObjectArray<'a, ::a::b::test1<'a> > >},
+                ]
+            )
+        );
+        assert_eq!(
+            format!("{:?}", method.argument_types_no_lifetime),
+            format!(
+                "{:?}",
+                vec![
+                    quote! {::rust_jni::PrimitiveArray<i32>},
+                    quote! {::rust_jni::ObjectArray<::rust_jni::ObjectArray<::a::b::test1> >},
+                ]
+            )
+        );
+    }
+
+    #[test]
+    fn char_array_arguments() {
+        let method = to_generator_native_method(
+            JavaNativeMethod {
+                name: Ident::new("nativeFunc", Span::call_site()),
+                return_type: JavaName(quote! {char[]}),
+                arguments: vec![MethodArgument {
+                    name: Ident::new("arg1", Span::call_site()),
+                    data_type: JavaName(quote! {char[]}),
+                }],
+                public: false,
+                is_static: false,
+                code: quote! {},
+                annotations: vec![],
+            },
+            &JavaName(quote! {a b test1}),
+        );
+        assert_eq!(method.signature.to_string(), "\"([C)[C\"");
+        assert_eq!(
+            format!("{:?}", method.return_type),
+            format!("{:?}", quote! {::rust_jni::PrimitiveArray<'a, u16>})
+        );
+        assert_eq!(
+            format!("{:?}", method.argument_types_no_lifetime),
+            format!("{:?}", vec![quote! {::rust_jni::PrimitiveArray<u16>}])
+        );
+    }
+}
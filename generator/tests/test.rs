@@ -71,6 +71,7 @@ mod a {
 
         java_generate! {
             public interface a.b.TestInterface3 {
+                @RustName(primitive_interface_func_3)
                 long primitiveInterfaceFunc3(int arg1, char arg2);
                 a.b.TestClass3 objectInterfaceFunc3(a.b.TestClass3 arg);
             }
@@ -85,9 +86,14 @@ mod a {
             public class a.b.TestClass3 extends c.d.TestClass2 implements e.f.TestInterface1, a.b.TestInterface4 {
                 @RustName(init)
                 public a.b.TestClass3(int arg1, a.b.TestClass3 arg2);
+                @RustName(init_private)
+                private a.b.TestClass3(int arg1);
 
                 @RustName(primitive_func_3)
                 long primitiveFunc3(int arg1, char arg2);
+                @RustName(primitive_func_3_long)
+                @Signature("(JC)J")
+                long primitiveFunc3(long arg1, char arg2);
                 @RustName(object_func_3)
                 public c.d.TestClass2 objectFunc3(a.b.TestClass3 arg);
 
@@ -138,4 +144,43 @@ mod a {
 mod tests {
     #[test]
     fn test() {}
+
+    #[test]
+    fn jni_signature() {
+        assert_eq!(::c::d::TestClass1::JNI_SIGNATURE, "Lc/d/TestClass1;");
+    }
+
+    // Subclasses are passed to methods taking a parent class without an explicit cast.
+    fn pass_subclass<'a>(
+        object: &::c::d::TestClass2<'a>,
+        token: &::rust_jni::NoException<'a>,
+    ) -> ::rust_jni::JavaResult<'a, ::c::d::TestClass2<'a>> {
+        object.objectFunc2(object, token)
+    }
+
+    // Raw objects of unknown classes are checked before being wrapped.
+    unsafe fn wrap_raw<'a>(
+        env: &'a ::rust_jni::JniEnv<'a>,
+        raw_object: ::jni_sys::jobject,
+        token: &::rust_jni::NoException<'a>,
+    ) -> ::rust_jni::JavaResult<'a, Option<::c::d::TestClass1<'a>>> {
+        ::c::d::TestClass1::try_from_raw(env, raw_object, token)
+    }
+
+    // Objects of unknown classes are checked before being viewed as a subclass.
+    fn downcast<'a>(
+        object: &::rust_jni::java::lang::Object<'a>,
+        token: &::rust_jni::NoException<'a>,
+    ) -> ::rust_jni::JavaResult<'a, Option<::c::d::TestClass2<'a>>> {
+        ::c::d::TestClass2::from_object(object, token)
+    }
+
+    // Objects of any class are stored in type-erased global references.
+    fn store_global<'a>(
+        object: ::c::d::TestClass2<'a>,
+        token: &::rust_jni::NoException<'a>,
+    ) -> ::rust_jni::JavaResult<'a, ::rust_jni::GlobalRef<::rust_jni::java::lang::Object<'static>>>
+    {
+        object.into_global_object(token)
+    }
 }
@@ -27,6 +27,7 @@ use std::ptr;
 pub struct AttachArguments {
     version: JniVersion,
     thread_name: Option<String>,
+    daemon: bool,
     // TODO(#7): support thread groups.
 }
 
@@ -38,6 +39,7 @@ impl AttachArguments {
         AttachArguments {
             thread_name: None,
             version: version,
+            daemon: false,
         }
     }
 
@@ -48,6 +50,21 @@ impl AttachArguments {
         AttachArguments {
             thread_name: Some(thread_name.into()),
             version: version,
+            daemon: false,
+        }
+    }
+
+    /// Create attach arguments for attaching a daemon thread with a specified thread name.
+    ///
+    /// [`JavaVM::attach_with`](struct.JavaVM.html#method.attach_with) attaches the thread
+    /// as a daemon when given these arguments.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/invocation.html#attachcurrentthreadasdaemon)
+    pub fn daemon(version: JniVersion, thread_name: impl Into<String>) -> Self {
+        AttachArguments {
+            thread_name: Some(thread_name.into()),
+            version,
+            daemon: true,
         }
     }
 
@@ -64,6 +81,20 @@ impl AttachArguments {
     pub fn thread_name(&self) -> &Option<String> {
         &self.thread_name
     }
+
+    /// Return the JNI thread name to request when attaching a thread to a Java VM.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/invocation.html#attachcurrentthread)
+    pub fn name(&self) -> Option<&str> {
+        self.thread_name.as_deref()
+    }
+
+    /// Return whether the thread is to be attached to a Java VM as a daemon.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/invocation.html#attachcurrentthreadasdaemon)
+    pub fn is_daemon(&self) -> bool {
+        self.daemon
+    }
 }
 
 #[cfg(test)]
@@ -76,7 +107,8 @@ mod tests {
             AttachArguments::new(JniVersion::V4),
             AttachArguments {
                 thread_name: None,
-                version: JniVersion::V4
+                version: JniVersion::V4,
+                daemon: false,
             }
         );
     }
@@ -88,6 +120,19 @@ mod tests {
             AttachArguments {
                 thread_name: Some("test-name".into()),
                 version: JniVersion::V4,
+                daemon: false,
+            }
+        );
+    }
+
+    #[test]
+    fn daemon() {
+        assert_eq!(
+            AttachArguments::daemon(JniVersion::V4, "test-name"),
+            AttachArguments {
+                thread_name: Some("test-name".into()),
+                version: JniVersion::V4,
+                daemon: true,
             }
         );
     }
@@ -97,6 +142,7 @@ mod tests {
         let arguments = AttachArguments {
             version: JniVersion::V4,
             thread_name: None,
+            daemon: false,
         };
         assert_eq!(arguments.version(), JniVersion::V4);
     }
@@ -106,6 +152,7 @@ mod tests {
         let arguments = AttachArguments {
             version: JniVersion::V4,
             thread_name: Some("test-name".into()),
+            daemon: false,
         };
         assert_eq!(arguments.thread_name(), &Some("test-name".to_owned()));
     }
@@ -115,9 +162,37 @@ mod tests {
         let arguments = AttachArguments {
             version: JniVersion::V4,
             thread_name: None,
+            daemon: false,
         };
         assert_eq!(arguments.thread_name(), &None);
     }
+
+    #[test]
+    fn name() {
+        let arguments = AttachArguments {
+            version: JniVersion::V4,
+            thread_name: Some("test-name".into()),
+            daemon: false,
+        };
+        assert_eq!(arguments.name(), Some("test-name"));
+    }
+
+    #[test]
+    fn no_name() {
+        let arguments = AttachArguments {
+            version: JniVersion::V4,
+            thread_name: None,
+            daemon: false,
+        };
+        assert_eq!(arguments.name(), None);
+    }
+
+    #[test]
+    fn is_daemon() {
+        assert!(!AttachArguments::new(JniVersion::V4).is_daemon());
+        assert!(!AttachArguments::named(JniVersion::V4, "test-name").is_daemon());
+        assert!(AttachArguments::daemon(JniVersion::V4, "test-name").is_daemon());
+    }
 }
 
 /// A wrapper around `jni_sys::JavaVMAttachArgs` with a lifetime to ensure
@@ -189,4 +264,24 @@ mod to_raw_tests {
             test_name
         );
     }
+
+    #[test]
+    fn to_raw_daemon() {
+        let test_name = "test-name";
+        let arguments = AttachArguments::daemon(JniVersion::V8, test_name);
+        let mut buffer: Vec<u8> = vec![];
+        let raw_arguments = arguments.to_raw(&mut buffer);
+        assert_eq!(raw_arguments.raw_arguments.group, ptr::null_mut());
+        assert_eq!(raw_arguments.raw_arguments.version, JniVersion::V8.to_raw());
+        assert_eq!(
+            from_java_string(unsafe {
+                slice::from_raw_parts(
+                    raw_arguments.raw_arguments.name as *const u8,
+                    raw_arguments.buffer_len,
+                )
+            })
+            .unwrap(),
+            test_name
+        );
+    }
 }
@@ -2,9 +2,19 @@
 // Thus we need to disable "dead code" warnings as these macros are
 // actually used.
 
+// It's actually used.
+#[allow(unused_macros)]
+macro_rules! trace_jni_call {
+    ($method:ident) => {
+        #[cfg(feature = "trace")]
+        log::trace!("Calling JNI method {}", stringify!($method));
+    };
+}
+
 macro_rules! call_jni_method {
     ($env:expr, $method:ident) => {
         {
+            trace_jni_call!($method);
             let raw_env = $env.raw_env().as_ptr();
             let jni_fn = ((**raw_env).$method).unwrap();
             jni_fn(raw_env)
@@ -12,6 +22,7 @@ macro_rules! call_jni_method {
     };
     ($env:expr, $method:ident, $($argument:expr),*) => {
         {
+            trace_jni_call!($method);
             let raw_env = $env.raw_env().as_ptr();
             let jni_fn = ((**raw_env).$method).unwrap();
             jni_fn(raw_env, $($argument),*)
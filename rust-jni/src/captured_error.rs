@@ -0,0 +1,110 @@
+use crate::result::JavaResult;
+use crate::throwable::Throwable;
+use crate::token::NoException;
+use std::error::Error;
+use std::fmt;
+
+/// An owned snapshot of a Java exception.
+///
+/// Unlike [`Throwable`](java/lang/struct.Throwable.html), it does not reference a Java object
+/// and does not require a [`NoException`](struct.NoException.html) token to be inspected,
+/// so it can be stored in Rust error types and passed between threads.
+///
+/// Example:
+/// ```
+/// # use rust_jni::*;
+/// # use rust_jni::java::lang::{String, Throwable};
+/// #
+/// # fn jni_main<'a>(token: NoException<'a>) -> JavaResult<'a, NoException<'a>> {
+/// let message = String::new(&token, "Message")?;
+/// let throwable = Throwable::new_with_message(&token, &message)?;
+/// let error = CapturedJavaError::capture(&token, &throwable)?;
+/// assert_eq!(error.class_name(), "java.lang.Throwable");
+/// assert_eq!(error.message(), Some("Message"));
+/// assert_eq!(error.to_string(), "java.lang.Throwable: Message");
+/// # Ok(token)
+/// # }
+/// #
+/// # #[cfg(feature = "libjvm")]
+/// # fn main() {
+/// #     let init_arguments = InitArguments::default();
+/// #     let vm = JavaVM::create(&init_arguments).unwrap();
+/// #     let _ = vm.with_attached(
+/// #        &AttachArguments::new(init_arguments.version()),
+/// #        |token: NoException| {
+/// #            ((), jni_main(token).unwrap())
+/// #        },
+/// #     );
+/// # }
+/// #
+/// # #[cfg(not(feature = "libjvm"))]
+/// # fn main() {}
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CapturedJavaError {
+    class_name: std::string::String,
+    message: Option<std::string::String>,
+}
+
+impl CapturedJavaError {
+    /// Capture the class name and the message of a Java exception.
+    pub fn capture<'a>(token: &NoException<'a>, throwable: &Throwable<'a>) -> JavaResult<'a, Self> {
+        let class_name = throwable.get_class_name(token)?;
+        let message = throwable
+            .get_message(token)?
+            .map(|message| message.as_string(token));
+        Ok(CapturedJavaError {
+            class_name,
+            message,
+        })
+    }
+
+    /// The fully qualified name of the exception class, as returned by
+    /// [`Object::get_class_name`](java/lang/struct.Object.html#method.get_class_name).
+    pub fn class_name(&self) -> &str {
+        &self.class_name
+    }
+
+    /// The exception message, as returned by
+    /// [`Throwable::get_message`](java/lang/struct.Throwable.html#method.get_message).
+    pub fn message(&self) -> Option<&str> {
+        self.message.as_deref()
+    }
+}
+
+/// Format the error the same way as
+/// [`Throwable::toString`](https://docs.oracle.com/javase/10/docs/api/java/lang/Throwable.html#toString())
+/// does.
+impl fmt::Display for CapturedJavaError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match self.message {
+            Some(ref message) => write!(formatter, "{}: {}", self.class_name, message),
+            None => write!(formatter, "{}", self.class_name),
+        }
+    }
+}
+
+impl Error for CapturedJavaError {}
+
+#[cfg(test)]
+mod captured_java_error_tests {
+    use super::*;
+
+    #[test]
+    fn display() {
+        let error = CapturedJavaError {
+            class_name: "java.lang.Throwable".to_owned(),
+            message: Some("Message".to_owned()),
+        };
+        assert_eq!(error.to_string(), "java.lang.Throwable: Message");
+    }
+
+    #[test]
+    fn display_no_message() {
+        let error = CapturedJavaError {
+            class_name: "java.lang.Throwable".to_owned(),
+            message: None,
+        };
+        assert_eq!(error.to_string(), "java.lang.Throwable");
+    }
+}
@@ -1,14 +1,18 @@
+use crate::classes::array::ObjectArray;
 use crate::env::JniEnv;
+use crate::error::JniError;
 use crate::java_class::JavaClassExt;
 use crate::java_class::{FromObject, JavaClassSignature};
 use crate::java_string::*;
 use crate::jni_bool;
+use crate::jni_methods;
 use crate::object::Object;
 use crate::result::JavaResult;
 use crate::string::String;
-use crate::token::{CallOutcome, NoException};
+use crate::token::{CallOutcome, Exception, NoException};
 use jni_sys;
-use std::os::raw::c_char;
+use std::hash::{Hash, Hasher};
+use std::os::raw::{c_char, c_void};
 use std::ptr::{self, NonNull};
 
 include!("call_jni_method.rs");
@@ -37,24 +41,111 @@ impl<'env> Class<'env> {
         Ok(unsafe { Self::from_raw(token.env(), raw_class) })
     }
 
+    /// Find a Java class by it's name with the class loader `loader`.
+    /// The name is a fully qualified class name, e.g. `java.lang.String` or `java/lang/String`.
+    ///
+    /// [`find`](#method.find) resolves classes with the class loader of the native method
+    /// being called. Threads attached from native code don't have one, so `FindClass` falls back
+    /// to the system class loader there and can't find classes loaded by other class loaders,
+    /// e.g. application classes in containers. This method calls `loadClass` on the provided
+    /// loader instead, which works in any thread.
+    ///
+    /// Throws `ClassNotFoundException` if the class can't be found.
+    ///
+    /// [`ClassLoader::loadClass` javadoc](https://docs.oracle.com/javase/10/docs/api/java/lang/ClassLoader.html#loadClass(java.lang.String))
+    pub fn find_with_loader<'a>(
+        token: &NoException<'a>,
+        class_name: &str,
+        loader: &Object<'a>,
+    ) -> JavaResult<'a, Class<'a>> {
+        let class_name = String::new(token, &class_name.replace('/', "."))?;
+        // Safe because we ensure correct arguments and return type.
+        let raw_class = unsafe {
+            jni_methods::call_object_method(
+                loader,
+                token,
+                "loadClass\0",
+                "(Ljava/lang/String;)Ljava/lang/Class;\0",
+                (class_name.raw_object().as_ptr(),),
+            )
+        }?
+        .unwrap_or_else(|| panic!("Class loader {:?} returned null for a class.", loader));
+        // Safe because the argument is a valid class reference.
+        Ok(unsafe { Self::from_raw(token.env(), raw_class) })
+    }
+
+    /// Get the system class loader, which loads application classes from the class path.
+    ///
+    /// [`ClassLoader::getSystemClassLoader` javadoc](https://docs.oracle.com/javase/10/docs/api/java/lang/ClassLoader.html#getSystemClassLoader())
+    pub fn system_class_loader<'a>(token: &NoException<'a>) -> JavaResult<'a, Object<'a>> {
+        let class_loader_class = Self::find(token, "java/lang/ClassLoader")?;
+        // Safe because we ensure correct arguments and return type.
+        let raw_loader = unsafe {
+            jni_methods::call_static_object_method(
+                &class_loader_class,
+                token,
+                "getSystemClassLoader\0",
+                "()Ljava/lang/ClassLoader;\0",
+                (),
+            )
+        }?
+        .expect("The system class loader is never null.");
+        // Safe because the argument is a valid object reference.
+        Ok(unsafe { Object::from_raw(token.env(), raw_loader) })
+    }
+
     /// Define a new Java class from a `.class` file contents.
     ///
     /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#defineclass)
     pub fn define<'a>(bytes: &[u8], token: &NoException<'a>) -> JavaResult<'a, Class<'a>> {
+        // Safe because `null` is a valid class name and loader for `DefineClass`.
+        unsafe { Self::define_raw(token, ptr::null(), ptr::null_mut(), bytes) }
+    }
+
+    /// Define a new Java class with the specified name from a `.class` file contents.
+    /// The name is a fully qualified class name, e.g. `java.lang.String` or `java/lang/String`.
+    /// The class is defined by the bootstrap class loader if `loader` is
+    /// [`None`](https://doc.rust-lang.org/std/option/enum.Option.html#variant.None).
+    ///
+    /// Throws `ClassFormatError` if the bytes are not a valid class and `NoClassDefFoundError`
+    /// if the name doesn't match the name of the class in the bytes.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#defineclass)
+    pub fn define_named<'a>(
+        token: &NoException<'a>,
+        name: &str,
+        loader: Option<&Object>,
+        bytes: &[u8],
+    ) -> JavaResult<'a, Class<'a>> {
+        let name = to_java_string(&name.replace('.', "/"));
+        let loader = match loader {
+            // Safe because the argument is ensured to be a correct reference by construction.
+            Some(loader) => unsafe { loader.raw_object().as_ptr() },
+            None => ptr::null_mut(),
+        };
+        // Safe because the arguments are correct.
+        unsafe { Self::define_raw(token, name.as_ptr() as *const c_char, loader, bytes) }
+    }
+
+    /// Unsafe because an incorrect name or class loader reference can be passed.
+    unsafe fn define_raw<'a>(
+        token: &NoException<'a>,
+        name: *const c_char,
+        loader: jni_sys::jobject,
+        bytes: &[u8],
+    ) -> JavaResult<'a, Class<'a>> {
         // Safe because the arguments are correct and because `DefineClass` throws an exception
         // before returning `null`.
-        let raw_class = unsafe {
-            call_nullable_jni_method!(
-                token,
-                DefineClass,
-                ptr::null() as *const c_char,
-                ptr::null_mut() as jni_sys::jobject,
-                bytes.as_ptr() as *const jni_sys::jbyte,
-                bytes.len() as jni_sys::jsize
-            )?
-        };
+        let raw_class = call_nullable_jni_method!(
+            token,
+            DefineClass,
+            name,
+            loader,
+            bytes.as_ptr() as *const jni_sys::jbyte,
+            bytes.len() as jni_sys::jsize
+        )?;
         // Safe because the argument is a valid class reference.
-        Ok(unsafe { Self::from_raw(token.env(), raw_class) })
+        Ok(Self::from_raw(token.env(), raw_class))
     }
 
     /// Get the parent class of this class. Will return
@@ -91,6 +182,25 @@ impl<'env> Class<'env> {
         jni_bool::to_rust(assignable)
     }
 
+    /// Check if this class is the same class as the other one.
+    ///
+    /// Two class references are the same class if they refer to the same loaded class, that is
+    /// the same class name loaded by the same class loader.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#issameobject)
+    pub fn is_same_class<'a>(&self, token: &NoException, class: impl AsRef<Class<'a>>) -> bool {
+        // Safe because arguments are ensured to be the correct by construction.
+        let same = unsafe {
+            call_jni_object_method!(
+                token,
+                self,
+                IsSameObject,
+                class.as_ref().raw_object().as_ptr()
+            )
+        };
+        jni_bool::to_rust(same)
+    }
+
     /// Get class name
     ///
     /// [`Class::getName` javadoc](https://docs.oracle.com/en/java/javase/11/docs/api/java.base/java/lang/Class.html#getName())
@@ -99,6 +209,188 @@ impl<'env> Class<'env> {
         unsafe { self.call_method::<_, fn() -> String<'env>>(token, "getName\0", ()) }
     }
 
+    /// Get the interfaces directly implemented by this class or extended by this interface,
+    /// in the order they are declared.
+    ///
+    /// [`Class::getInterfaces` javadoc](https://docs.oracle.com/en/java/javase/11/docs/api/java.base/java/lang/Class.html#getInterfaces())
+    pub fn get_interfaces(&self, token: &NoException<'env>) -> JavaResult<'env, Vec<Class<'env>>> {
+        // Safe because we ensure correct arguments and return type.
+        let interfaces = unsafe {
+            self.call_method::<_, fn() -> ObjectArray<'env, Class<'env>>>(
+                token,
+                "getInterfaces\0",
+                (),
+            )
+        }?;
+        // `getInterfaces` returns an empty array rather than `null` when there are no interfaces.
+        let interfaces = match interfaces {
+            None => return Ok(vec![]),
+            Some(interfaces) => interfaces,
+        };
+        let mut result = Vec::with_capacity(interfaces.len(token));
+        for index in 0..interfaces.len(token) {
+            // The array never contains `null`-s.
+            if let Some(interface) = interfaces.get(token, index)? {
+                result.push(interface);
+            }
+        }
+        Ok(result)
+    }
+
+    /// Get the names of the methods declared by this class, excluding inherited ones.
+    ///
+    /// This is mostly useful for debugging, for example to find out which methods are available
+    /// when a method could not be found.
+    ///
+    /// [`Class::getDeclaredMethods` javadoc](https://docs.oracle.com/en/java/javase/11/docs/api/java.base/java/lang/Class.html#getDeclaredMethods())
+    pub fn get_method_names(
+        &self,
+        token: &NoException<'env>,
+    ) -> JavaResult<'env, Vec<std::string::String>> {
+        // Safe because we ensure correct arguments and return type.
+        let methods = unsafe {
+            jni_methods::call_object_method(
+                &self.object,
+                token,
+                "getDeclaredMethods\0",
+                "()[Ljava/lang/reflect/Method;\0",
+                (),
+            )
+        }?;
+        // `getDeclaredMethods` returns an empty array rather than `null` when there are no methods.
+        let methods = match methods {
+            None => return Ok(vec![]),
+            // Safe because `getDeclaredMethods` returns an array of objects.
+            Some(methods) => unsafe {
+                ObjectArray::<Object<'env>>::from_object(Object::from_raw(token.env(), methods))
+            },
+        };
+        let mut names = Vec::with_capacity(methods.len(token));
+        for index in 0..methods.len(token) {
+            // The array never contains `null`-s.
+            if let Some(method) = methods.get(token, index)? {
+                // Safe because we ensure correct arguments and return type.
+                let name = unsafe {
+                    method.call_method::<_, fn() -> String<'env>>(token, "getName\0", ())
+                }?;
+                // Method names are never `null`.
+                if let Some(name) = name {
+                    names.push(name.as_string(token));
+                }
+            }
+        }
+        Ok(names)
+    }
+
+    /// Get the identity hash code of this class.
+    ///
+    /// Unlike [`hash_code`](struct.Object.html#method.hash_code), the identity hash code is
+    /// consistent with [`is_same_class`](#method.is_same_class): all references to the same
+    /// loaded class have the same identity hash code.
+    ///
+    /// [`System::identityHashCode` javadoc](https://docs.oracle.com/javase/10/docs/api/java/lang/System.html#identityHashCode(java.lang.Object))
+    pub fn identity_hash_code(&self, token: &NoException<'env>) -> JavaResult<'env, i32> {
+        self.object.identity_hash_code(token)
+    }
+
+    /// Throw a new exception of this class with the given detail message.
+    ///
+    /// The exception is constructed with the `(String)` constructor of the class. If the
+    /// construction fails, the exception describing the failure is thrown instead.
+    ///
+    /// Panics if this class is not a subclass of
+    /// [`Throwable`](https://docs.oracle.com/javase/10/docs/api/java/lang/Throwable.html).
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#thrownew)
+    pub fn throw_new<'token>(&self, message: &str, token: NoException<'token>) -> Exception<'token>
+    where
+        'env: 'token,
+    {
+        let throwable_class = match Class::find(&token, "java/lang/Throwable") {
+            Ok(throwable_class) => throwable_class,
+            Err(_) => panic!("Failed to find the `java.lang.Throwable` class."),
+        };
+        if !self.is_subtype_of(&token, throwable_class) {
+            panic!("Trying to throw an instance of a class that is not a `Throwable`.");
+        }
+        let message = to_java_string(message);
+        // Safe because the arguments are ensured to be correct by construction.
+        // `ThrowNew` only fails when constructing the exception throws, so there is
+        // a pending exception either way.
+        unsafe {
+            call_jni_object_method!(token, self, ThrowNew, message.as_ptr() as *const c_char)
+        };
+        // Safe becuase we just threw the exception.
+        unsafe { token.exchange() }
+    }
+
+    /// Register native method implementations for methods of this class.
+    ///
+    /// Each method is described by it's name, JNI signature, e.g. `(I)V`, and a pointer to
+    /// the native function implementing it. This is needed when the native library is linked
+    /// statically, so the Java VM can't look up the `Java_*` functions dynamically.
+    ///
+    /// Throws `NoSuchMethodError` if the class doesn't have a native method with a specified
+    /// name and signature.
+    ///
+    /// Unsafe because the native functions might not match the method signatures.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#registernatives)
+    pub unsafe fn register_natives(
+        &self,
+        token: &NoException<'env>,
+        methods: &[(&str, &str, *mut c_void)],
+    ) -> JavaResult<'env, ()> {
+        let names = methods
+            .iter()
+            .map(|(name, _, _)| to_java_string(name))
+            .collect::<Vec<_>>();
+        let signatures = methods
+            .iter()
+            .map(|(_, signature, _)| to_java_string(signature))
+            .collect::<Vec<_>>();
+        let raw_methods = methods
+            .iter()
+            .zip(names.iter().zip(signatures.iter()))
+            .map(
+                |((_, _, function), (name, signature))| jni_sys::JNINativeMethod {
+                    name: name.as_ptr() as *mut c_char,
+                    signature: signature.as_ptr() as *mut c_char,
+                    fnPtr: *function,
+                },
+            )
+            .collect::<Vec<_>>();
+        token.with_owned(|token| {
+            let status = call_jni_object_method!(
+                token,
+                self,
+                RegisterNatives,
+                raw_methods.as_ptr(),
+                raw_methods.len() as jni_sys::jint
+            );
+            if status == jni_sys::JNI_OK {
+                CallOutcome::Ok(((), token))
+            } else {
+                // Safe because `RegisterNatives` throws an exception when it fails.
+                CallOutcome::Err(token.exchange())
+            }
+        })
+    }
+
+    /// Unregister all native methods of this class registered with
+    /// [`register_natives`](#method.register_natives), reverting the class to the state
+    /// before it's native methods were linked or registered.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#unregisternatives)
+    pub fn unregister_natives(&self, token: &NoException<'env>) -> Result<(), JniError> {
+        // Safe because the argument is ensured to be a correct reference by construction.
+        let status = unsafe { call_jni_object_method!(token, self, UnregisterNatives) };
+        match JniError::from_raw(status) {
+            None => Ok(()),
+            Some(error) => Err(error),
+        }
+    }
+
     /// Unsafe because the argument mught not be a valid class reference.
     #[inline(always)]
     pub(crate) unsafe fn from_raw<'a>(
@@ -174,3 +466,539 @@ where
         Object::as_ref(self).eq(other.as_ref())
     }
 }
+
+/// Allow using [`Class`](struct.Class.html) as a key in hash maps, e.g. to map Java classes
+/// to Rust handlers. Classes are hashed by their
+/// [`identity_hash_code`](struct.Class.html#method.identity_hash_code), which is consistent with
+/// comparing them by-reference with `==`.
+///
+/// Will panic if there is a pending exception in the current thread.
+impl<'env> Hash for Class<'env> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // Safe because we are not leaking the tokens anywhere.
+        unsafe {
+            match NoException::check_pending_exception(self.env()) {
+                Err(_) => {
+                    panic!("Hashing a Java class with a pending exception in the current thread")
+                }
+                Ok(token) => self.identity_hash_code(&token).unwrap().hash(state),
+            }
+        }
+    }
+}
+
+/// [`Class`](struct.Class.html)-es are compared by-reference, which is an equivalence relation.
+impl<'env> Eq for Class<'env> {}
+
+#[cfg(test)]
+mod class_tests {
+    use super::*;
+    use crate::vm::JavaVMRef;
+    use mockall::Sequence;
+    use serial_test::serial;
+    use std::collections::hash_map::DefaultHasher;
+    use std::ffi::CStr;
+    use std::mem::{self, ManuallyDrop};
+    use std::slice;
+
+    generate_jni_env_mock!(jni_mock);
+
+    #[test]
+    #[serial]
+    fn find_with_loader() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_loader = 0x1234 as jni_sys::jobject;
+        let raw_string = 0x2345 as jni_sys::jstring;
+        let raw_loader_class = 0x3456 as jni_sys::jclass;
+        let raw_method_id = 0x4567 as jni_sys::jmethodID;
+        let raw_class = 0x5678 as jni_sys::jclass;
+        let mut sequence = Sequence::new();
+        let new_string_utf_mock = jni_mock::new_string_utf_context();
+        new_string_utf_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, bytes| unsafe {
+                *env == raw_env_ptr && CStr::from_ptr(*bytes).to_str() == Ok("java.util.List")
+            })
+            .returning_st(move |_env, _bytes| raw_string)
+            .in_sequence(&mut sequence);
+        let get_object_class_mock = jni_mock::get_object_class_context();
+        get_object_class_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object| *env == raw_env_ptr && *object == raw_loader)
+            .returning_st(move |_env, _object| raw_loader_class)
+            .in_sequence(&mut sequence);
+        let get_method_id_mock = jni_mock::get_method_id_context();
+        get_method_id_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, class, name, signature| unsafe {
+                *env == raw_env_ptr
+                    && *class == raw_loader_class
+                    && CStr::from_ptr(*name).to_str() == Ok("loadClass")
+                    && CStr::from_ptr(*signature).to_str()
+                        == Ok("(Ljava/lang/String;)Ljava/lang/Class;")
+            })
+            .returning_st(move |_env, _class, _name, _signature| raw_method_id)
+            .in_sequence(&mut sequence);
+        let call_object_method_a_mock = jni_mock::call_object_method_a_context();
+        call_object_method_a_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object, method_id, arguments| unsafe {
+                *env == raw_env_ptr
+                    && *object == raw_loader
+                    && *method_id == raw_method_id
+                    && (**arguments).l == raw_string
+            })
+            .returning_st(move |_env, _object, _method_id, _arguments| raw_class)
+            .in_sequence(&mut sequence);
+        let delete_local_ref_mock = jni_mock::delete_local_ref_context();
+        delete_local_ref_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object| *env == raw_env_ptr && *object == raw_loader_class)
+            .return_const(())
+            .in_sequence(&mut sequence);
+        delete_local_ref_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object| *env == raw_env_ptr && *object == raw_string)
+            .return_const(())
+            .in_sequence(&mut sequence);
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let token = NoException::test(&env);
+        let loader =
+            ManuallyDrop::new(unsafe { Object::from_raw(&env, NonNull::new(raw_loader).unwrap()) });
+        let class = Class::find_with_loader(&token, "java/util/List", &loader).unwrap();
+        assert_eq!(unsafe { class.raw_object() }.as_ptr(), raw_class);
+        mem::forget(class);
+    }
+
+    #[test]
+    #[serial]
+    fn define() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_class = 0x1234 as jni_sys::jclass;
+        let bytes = [0xCA, 0xFE, 0xBA, 0xBE];
+        let define_class_mock = jni_mock::define_class_context();
+        define_class_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, name, loader, buffer, length| {
+                *env == raw_env_ptr
+                    && name.is_null()
+                    && loader.is_null()
+                    && unsafe { slice::from_raw_parts(*buffer as *const u8, *length as usize) }
+                        == [0xCA, 0xFE, 0xBA, 0xBE]
+            })
+            .returning_st(move |_env, _name, _loader, _buffer, _length| raw_class);
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let token = NoException::test(&env);
+        let class = Class::define(&bytes, &token).unwrap();
+        assert_eq!(unsafe { class.raw_object() }.as_ptr(), raw_class);
+        mem::forget(class);
+    }
+
+    #[test]
+    #[serial]
+    fn define_named() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_class = 0x1234 as jni_sys::jclass;
+        let raw_loader = 0x5678 as jni_sys::jobject;
+        let bytes = [0xCA, 0xFE, 0xBA, 0xBE];
+        let define_class_mock = jni_mock::define_class_context();
+        define_class_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, name, loader, buffer, length| {
+                *env == raw_env_ptr
+                    && unsafe { CStr::from_ptr(*name) }.to_str() == Ok("com/example/Proxy")
+                    && *loader == raw_loader
+                    && unsafe { slice::from_raw_parts(*buffer as *const u8, *length as usize) }
+                        == [0xCA, 0xFE, 0xBA, 0xBE]
+            })
+            .returning_st(move |_env, _name, _loader, _buffer, _length| raw_class);
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let token = NoException::test(&env);
+        let loader =
+            ManuallyDrop::new(unsafe { Object::from_raw(&env, NonNull::new(raw_loader).unwrap()) });
+        let class =
+            Class::define_named(&token, "com.example.Proxy", Some(&loader), &bytes).unwrap();
+        assert_eq!(unsafe { class.raw_object() }.as_ptr(), raw_class);
+        mem::forget(class);
+    }
+
+    #[test]
+    #[serial]
+    fn define_named_no_loader() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_class = 0x1234 as jni_sys::jclass;
+        let define_class_mock = jni_mock::define_class_context();
+        define_class_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, name, loader, _buffer, length| {
+                *env == raw_env_ptr
+                    && unsafe { CStr::from_ptr(*name) }.to_str() == Ok("com/example/Proxy")
+                    && loader.is_null()
+                    && *length == 0
+            })
+            .returning_st(move |_env, _name, _loader, _buffer, _length| raw_class);
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let token = NoException::test(&env);
+        let class = Class::define_named(&token, "com/example/Proxy", None, &[]).unwrap();
+        assert_eq!(unsafe { class.raw_object() }.as_ptr(), raw_class);
+        mem::forget(class);
+    }
+
+    #[test]
+    #[serial]
+    fn define_named_exception() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_throwable = 0x5678 as jni_sys::jthrowable;
+        let mut sequence = Sequence::new();
+        let define_class_mock = jni_mock::define_class_context();
+        define_class_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, _name, _loader, _buffer, _length| *env == raw_env_ptr)
+            .returning_st(|_env, _name, _loader, _buffer, _length| ptr::null_mut())
+            .in_sequence(&mut sequence);
+        let exception_occured_mock = jni_mock::exception_occured_context();
+        exception_occured_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env| *env == raw_env_ptr)
+            .returning_st(move |_env| raw_throwable)
+            .in_sequence(&mut sequence);
+        let exception_clear_mock = jni_mock::exception_clear_context();
+        exception_clear_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env| *env == raw_env_ptr)
+            .return_const(())
+            .in_sequence(&mut sequence);
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let token = NoException::test(&env);
+        let throwable =
+            Class::define_named(&token, "com.example.Proxy", None, &[0, 1, 2]).unwrap_err();
+        assert_eq!(unsafe { throwable.raw_object() }.as_ptr(), raw_throwable);
+        mem::forget(throwable);
+    }
+
+    #[test]
+    #[serial]
+    fn throw_new() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_class = 0x1234 as jni_sys::jclass;
+        let raw_throwable_class = 0x5678 as jni_sys::jclass;
+        let mut sequence = Sequence::new();
+        let find_class_mock = jni_mock::find_class_context();
+        find_class_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, name| unsafe {
+                *env == raw_env_ptr && CStr::from_ptr(*name).to_str() == Ok("java/lang/Throwable")
+            })
+            .returning_st(move |_env, _name| raw_throwable_class)
+            .in_sequence(&mut sequence);
+        let is_assignable_from_mock = jni_mock::is_assignable_from_context();
+        is_assignable_from_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, class1, class2| {
+                *env == raw_env_ptr && *class1 == raw_class && *class2 == raw_throwable_class
+            })
+            .return_const(jni_sys::JNI_TRUE)
+            .in_sequence(&mut sequence);
+        let delete_local_ref_mock = jni_mock::delete_local_ref_context();
+        delete_local_ref_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object| *env == raw_env_ptr && *object == raw_throwable_class)
+            .return_const(())
+            .in_sequence(&mut sequence);
+        let throw_new_mock = jni_mock::throw_new_context();
+        throw_new_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, class, message| unsafe {
+                *env == raw_env_ptr
+                    && *class == raw_class
+                    && CStr::from_ptr(*message).to_str() == Ok("test message")
+            })
+            .return_const(jni_sys::JNI_OK)
+            .in_sequence(&mut sequence);
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let token = NoException::test(&env);
+        let class =
+            ManuallyDrop::new(unsafe { Class::from_raw(&env, NonNull::new(raw_class).unwrap()) });
+        let _exception = class.throw_new("test message", token);
+    }
+
+    #[test]
+    #[serial]
+    #[should_panic(expected = "Trying to throw an instance of a class that is not a `Throwable`.")]
+    fn throw_new_not_throwable() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_class = 0x1234 as jni_sys::jclass;
+        let raw_throwable_class = 0x5678 as jni_sys::jclass;
+        let find_class_mock = jni_mock::find_class_context();
+        find_class_mock
+            .expect()
+            .times(1)
+            .returning_st(move |_env, _name| raw_throwable_class);
+        let is_assignable_from_mock = jni_mock::is_assignable_from_context();
+        is_assignable_from_mock
+            .expect()
+            .times(1)
+            .return_const(jni_sys::JNI_FALSE);
+        let delete_local_ref_mock = jni_mock::delete_local_ref_context();
+        delete_local_ref_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object| *env == raw_env_ptr && *object == raw_throwable_class)
+            .return_const(());
+        let throw_new_mock = jni_mock::throw_new_context();
+        throw_new_mock.expect().times(0);
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let token = NoException::test(&env);
+        let class =
+            ManuallyDrop::new(unsafe { Class::from_raw(&env, NonNull::new(raw_class).unwrap()) });
+        class.throw_new("test message", token);
+    }
+
+    #[test]
+    #[serial]
+    fn register_natives() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_class = 0x1234 as jni_sys::jclass;
+        let raw_function = 0x5678 as *mut c_void;
+        let register_natives_mock = jni_mock::register_natives_context();
+        register_natives_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, class, methods, methods_count| unsafe {
+                let methods = slice::from_raw_parts(*methods, *methods_count as usize);
+                *env == raw_env_ptr
+                    && *class == raw_class
+                    && methods.len() == 1
+                    && CStr::from_ptr(methods[0].name).to_str() == Ok("test")
+                    && CStr::from_ptr(methods[0].signature).to_str() == Ok("(I)V")
+                    && methods[0].fnPtr == raw_function
+            })
+            .return_const(jni_sys::JNI_OK);
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let token = NoException::test(&env);
+        let class =
+            ManuallyDrop::new(unsafe { Class::from_raw(&env, NonNull::new(raw_class).unwrap()) });
+        unsafe {
+            class
+                .register_natives(&token, &[("test", "(I)V", raw_function)])
+                .unwrap()
+        };
+    }
+
+    #[test]
+    #[serial]
+    fn register_natives_exception() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_class = 0x1234 as jni_sys::jclass;
+        let raw_throwable = 0x5678 as jni_sys::jthrowable;
+        let mut sequence = Sequence::new();
+        let register_natives_mock = jni_mock::register_natives_context();
+        register_natives_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, class, _methods, methods_count| {
+                *env == raw_env_ptr && *class == raw_class && *methods_count == 1
+            })
+            .return_const(jni_sys::JNI_ERR)
+            .in_sequence(&mut sequence);
+        let exception_occured_mock = jni_mock::exception_occured_context();
+        exception_occured_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env| *env == raw_env_ptr)
+            .returning_st(move |_env| raw_throwable)
+            .in_sequence(&mut sequence);
+        let exception_clear_mock = jni_mock::exception_clear_context();
+        exception_clear_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env| *env == raw_env_ptr)
+            .return_const(())
+            .in_sequence(&mut sequence);
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let token = NoException::test(&env);
+        let class =
+            ManuallyDrop::new(unsafe { Class::from_raw(&env, NonNull::new(raw_class).unwrap()) });
+        let throwable =
+            unsafe { class.register_natives(&token, &[("test", "(I)V", ptr::null_mut())]) }
+                .unwrap_err();
+        assert_eq!(unsafe { throwable.raw_object() }.as_ptr(), raw_throwable);
+        mem::forget(throwable);
+    }
+
+    #[test]
+    #[serial]
+    fn unregister_natives() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_class = 0x1234 as jni_sys::jclass;
+        let unregister_natives_mock = jni_mock::unregister_natives_context();
+        unregister_natives_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, class| *env == raw_env_ptr && *class == raw_class)
+            .return_const(jni_sys::JNI_OK);
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let token = NoException::test(&env);
+        let class =
+            ManuallyDrop::new(unsafe { Class::from_raw(&env, NonNull::new(raw_class).unwrap()) });
+        assert_eq!(class.unregister_natives(&token), Ok(()));
+    }
+
+    #[test]
+    #[serial]
+    fn is_same_class() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_class1 = 0x1234 as jni_sys::jobject;
+        let raw_class2 = 0x5678 as jni_sys::jobject;
+        let is_same_object_mock = jni_mock::is_same_object_context();
+        is_same_object_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object1, object2| {
+                *env == raw_env_ptr && *object1 == raw_class1 && *object2 == raw_class2
+            })
+            .return_const(jni_sys::JNI_TRUE);
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let token = NoException::test(&env);
+        let class1 =
+            ManuallyDrop::new(unsafe { Class::from_raw(&env, NonNull::new(raw_class1).unwrap()) });
+        let class2 =
+            ManuallyDrop::new(unsafe { Class::from_raw(&env, NonNull::new(raw_class2).unwrap()) });
+        assert!(class1.is_same_class(&token, &*class2));
+    }
+
+    #[test]
+    #[serial]
+    fn is_not_same_class() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_class1 = 0x1234 as jni_sys::jobject;
+        let raw_class2 = 0x5678 as jni_sys::jobject;
+        let is_same_object_mock = jni_mock::is_same_object_context();
+        is_same_object_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object1, object2| {
+                *env == raw_env_ptr && *object1 == raw_class1 && *object2 == raw_class2
+            })
+            .return_const(jni_sys::JNI_FALSE);
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let token = NoException::test(&env);
+        let class1 =
+            ManuallyDrop::new(unsafe { Class::from_raw(&env, NonNull::new(raw_class1).unwrap()) });
+        let class2 =
+            ManuallyDrop::new(unsafe { Class::from_raw(&env, NonNull::new(raw_class2).unwrap()) });
+        assert!(!class1.is_same_class(&token, &*class2));
+    }
+
+    fn hash_of(class: &Class) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        class.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    #[serial]
+    fn hash_same_class() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_class1 = 0x1234 as jni_sys::jobject;
+        let raw_class2 = 0x5678 as jni_sys::jobject;
+        let raw_system_class = 0x9012 as jni_sys::jclass;
+        let raw_method_id = 0x3456 as jni_sys::jmethodID;
+        let exception_check_mock = jni_mock::exception_check_context();
+        exception_check_mock
+            .expect()
+            .times(2)
+            .withf_st(move |env| *env == raw_env_ptr)
+            .return_const(jni_sys::JNI_FALSE);
+        let find_class_mock = jni_mock::find_class_context();
+        find_class_mock
+            .expect()
+            .times(2)
+            .withf_st(move |env, name| unsafe {
+                *env == raw_env_ptr && CStr::from_ptr(*name).to_str() == Ok("java/lang/System")
+            })
+            .returning_st(move |_env, _name| raw_system_class);
+        let get_static_method_id_mock = jni_mock::get_static_method_id_context();
+        get_static_method_id_mock
+            .expect()
+            .times(2)
+            .withf_st(move |env, class, name, signature| unsafe {
+                *env == raw_env_ptr
+                    && *class == raw_system_class
+                    && CStr::from_ptr(*name).to_str() == Ok("identityHashCode")
+                    && CStr::from_ptr(*signature).to_str() == Ok("(Ljava/lang/Object;)I")
+            })
+            .returning_st(move |_env, _class, _name, _signature| raw_method_id);
+        let call_static_int_method_a_mock = jni_mock::call_static_int_method_a_context();
+        call_static_int_method_a_mock
+            .expect()
+            .times(2)
+            .withf_st(move |env, class, method_id, arguments| unsafe {
+                *env == raw_env_ptr
+                    && *class == raw_system_class
+                    && *method_id == raw_method_id
+                    && ((**arguments).l == raw_class1 || (**arguments).l == raw_class2)
+            })
+            .return_const(17);
+        let exception_occured_mock = jni_mock::exception_occured_context();
+        exception_occured_mock
+            .expect()
+            .times(2)
+            .withf_st(move |env| *env == raw_env_ptr)
+            .returning_st(|_env| ptr::null_mut());
+        let delete_local_ref_mock = jni_mock::delete_local_ref_context();
+        delete_local_ref_mock
+            .expect()
+            .times(2)
+            .withf_st(move |env, object| *env == raw_env_ptr && *object == raw_system_class)
+            .return_const(());
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let class1 =
+            ManuallyDrop::new(unsafe { Class::from_raw(&env, NonNull::new(raw_class1).unwrap()) });
+        let class2 =
+            ManuallyDrop::new(unsafe { Class::from_raw(&env, NonNull::new(raw_class2).unwrap()) });
+        assert_eq!(hash_of(&class1), hash_of(&class2));
+    }
+}
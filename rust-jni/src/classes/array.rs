@@ -0,0 +1,1246 @@
+use crate::env::JniEnv;
+use crate::java_class::{find_class, FromObject, JavaClass, JavaClassSignature};
+use crate::java_methods::JavaObjectArgument;
+use crate::java_primitives::JavaPrimitiveType;
+use crate::jni_bool;
+use crate::object::Object;
+use crate::result::JavaResult;
+use crate::token::{CallOutcome, NoException};
+use core::ptr::{self, NonNull};
+use std::collections::BTreeMap;
+use std::marker::PhantomData;
+use std::sync::{Mutex, PoisonError};
+
+include!("../call_jni_method.rs");
+
+/// Convert an array length to `jsize`.
+///
+/// Panics if the length doesn't fit into a `jsize`.
+fn to_jni_length(length: usize) -> jni_sys::jsize {
+    jni_sys::jsize::try_from(length).expect("Array length doesn't fit into a `jsize`.")
+}
+
+/// Convert an array index to `jsize`.
+///
+/// An index that doesn't fit into a `jsize` is out of bounds of any Java array, so it is
+/// replaced with the largest `jsize` to make JNI throw `ArrayIndexOutOfBoundsException`.
+fn to_jni_index(index: usize) -> jni_sys::jsize {
+    jni_sys::jsize::try_from(index).unwrap_or(jni_sys::jsize::MAX)
+}
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// A trait for types that can be elements of a [`PrimitiveArray`](struct.PrimitiveArray.html).
+///
+/// This trait is sealed and is implemented for all Rust types that Java primitive types map to.
+pub trait ArrayElement: private::Sealed + Copy + Default + Sized {
+    /// Return the JNI signature of an array of `Self`.
+    #[doc(hidden)]
+    fn array_signature() -> &'static str;
+
+    /// Create a new Java array of the given length.
+    ///
+    /// Unsafe because it returns a raw pointer to a Java object.
+    #[doc(hidden)]
+    unsafe fn new_array<'a>(
+        token: &NoException<'a>,
+        length: jni_sys::jsize,
+    ) -> JavaResult<'a, NonNull<jni_sys::_jobject>>;
+
+    /// Copy a region of a Java array into a buffer.
+    ///
+    /// Unsafe because the array must be of the correct type.
+    #[doc(hidden)]
+    unsafe fn get_region<'a>(
+        token: &NoException<'a>,
+        array: &Object<'a>,
+        start: jni_sys::jsize,
+        buffer: &mut [Self],
+    ) -> JavaResult<'a, ()>;
+
+    /// Copy values from a buffer into a region of a Java array.
+    ///
+    /// Unsafe because the array must be of the correct type.
+    #[doc(hidden)]
+    unsafe fn set_region<'a>(
+        token: &NoException<'a>,
+        array: &Object<'a>,
+        start: jni_sys::jsize,
+        values: &[Self],
+    ) -> JavaResult<'a, ()>;
+}
+
+/// A trait for [`ArrayElement`](trait.ArrayElement.html) types which have the same memory layout
+/// as their JNI counterparts and can be accessed in place with
+/// [`PrimitiveArray::with_critical`](struct.PrimitiveArray.html#method.with_critical).
+///
+/// This trait is sealed and is not implemented for `bool`.
+pub trait CriticalArrayElement: ArrayElement {}
+
+macro_rules! array_element_trait {
+    ($type:ty, $signature:expr, $new_array:ident, $get_region:ident, $set_region:ident) => {
+        impl private::Sealed for $type {}
+
+        impl ArrayElement for $type {
+            #[inline(always)]
+            fn array_signature() -> &'static str {
+                $signature
+            }
+
+            unsafe fn new_array<'a>(
+                token: &NoException<'a>,
+                length: jni_sys::jsize,
+            ) -> JavaResult<'a, NonNull<jni_sys::_jobject>> {
+                // Safe because arguments are ensured to be the correct by construction and because
+                // the array constructor throws an exception before returning `null`.
+                call_nullable_jni_method!(token, $new_array, length)
+            }
+
+            unsafe fn get_region<'a>(
+                token: &NoException<'a>,
+                array: &Object<'a>,
+                start: jni_sys::jsize,
+                buffer: &mut [Self],
+            ) -> JavaResult<'a, ()> {
+                // Safe because the Rust type has the same layout as the JNI type.
+                token.with_owned(|token| {
+                    call_jni_object_method!(
+                        token,
+                        array,
+                        $get_region,
+                        start,
+                        to_jni_length(buffer.len()),
+                        buffer.as_mut_ptr() as *mut <Self as JavaPrimitiveType>::JniType
+                    );
+                    CallOutcome::Unknown(())
+                })
+            }
+
+            unsafe fn set_region<'a>(
+                token: &NoException<'a>,
+                array: &Object<'a>,
+                start: jni_sys::jsize,
+                values: &[Self],
+            ) -> JavaResult<'a, ()> {
+                // Safe because the Rust type has the same layout as the JNI type.
+                token.with_owned(|token| {
+                    call_jni_object_method!(
+                        token,
+                        array,
+                        $set_region,
+                        start,
+                        to_jni_length(values.len()),
+                        values.as_ptr() as *const <Self as JavaPrimitiveType>::JniType
+                    );
+                    CallOutcome::Unknown(())
+                })
+            }
+        }
+
+        impl CriticalArrayElement for $type {}
+    };
+}
+
+array_element_trait!(
+    u8,
+    "[B",
+    NewByteArray,
+    GetByteArrayRegion,
+    SetByteArrayRegion
+);
+array_element_trait!(
+    i16,
+    "[S",
+    NewShortArray,
+    GetShortArrayRegion,
+    SetShortArrayRegion
+);
+array_element_trait!(i32, "[I", NewIntArray, GetIntArrayRegion, SetIntArrayRegion);
+array_element_trait!(
+    i64,
+    "[J",
+    NewLongArray,
+    GetLongArrayRegion,
+    SetLongArrayRegion
+);
+array_element_trait!(
+    f32,
+    "[F",
+    NewFloatArray,
+    GetFloatArrayRegion,
+    SetFloatArrayRegion
+);
+array_element_trait!(
+    f64,
+    "[D",
+    NewDoubleArray,
+    GetDoubleArrayRegion,
+    SetDoubleArrayRegion
+);
+
+impl private::Sealed for bool {}
+
+impl ArrayElement for bool {
+    #[inline(always)]
+    fn array_signature() -> &'static str {
+        "[Z"
+    }
+
+    unsafe fn new_array<'a>(
+        token: &NoException<'a>,
+        length: jni_sys::jsize,
+    ) -> JavaResult<'a, NonNull<jni_sys::_jobject>> {
+        // Safe because arguments are ensured to be the correct by construction and because
+        // `NewBooleanArray` throws an exception before returning `null`.
+        call_nullable_jni_method!(token, NewBooleanArray, length)
+    }
+
+    unsafe fn get_region<'a>(
+        token: &NoException<'a>,
+        array: &Object<'a>,
+        start: jni_sys::jsize,
+        buffer: &mut [Self],
+    ) -> JavaResult<'a, ()> {
+        let mut values = vec![jni_sys::JNI_FALSE; buffer.len()];
+        token.with_owned(|token| {
+            call_jni_object_method!(
+                token,
+                array,
+                GetBooleanArrayRegion,
+                start,
+                to_jni_length(values.len()),
+                values.as_mut_ptr()
+            );
+            CallOutcome::Unknown(())
+        })?;
+        buffer.copy_from_slice(&jni_bool::to_rust_array(&values));
+        Ok(())
+    }
+
+    unsafe fn set_region<'a>(
+        token: &NoException<'a>,
+        array: &Object<'a>,
+        start: jni_sys::jsize,
+        values: &[Self],
+    ) -> JavaResult<'a, ()> {
+        let values = jni_bool::to_jni_array(values);
+        token.with_owned(|token| {
+            call_jni_object_method!(
+                token,
+                array,
+                SetBooleanArrayRegion,
+                start,
+                to_jni_length(values.len()),
+                values.as_ptr()
+            );
+            CallOutcome::Unknown(())
+        })
+    }
+}
+
+impl private::Sealed for u16 {}
+
+/// Java `char[]` elements are UTF-16 code units, which are not always valid Rust `char`-s.
+impl ArrayElement for u16 {
+    #[inline(always)]
+    fn array_signature() -> &'static str {
+        "[C"
+    }
+
+    unsafe fn new_array<'a>(
+        token: &NoException<'a>,
+        length: jni_sys::jsize,
+    ) -> JavaResult<'a, NonNull<jni_sys::_jobject>> {
+        // Safe because arguments are ensured to be the correct by construction and because
+        // `NewCharArray` throws an exception before returning `null`.
+        call_nullable_jni_method!(token, NewCharArray, length)
+    }
+
+    unsafe fn get_region<'a>(
+        token: &NoException<'a>,
+        array: &Object<'a>,
+        start: jni_sys::jsize,
+        buffer: &mut [Self],
+    ) -> JavaResult<'a, ()> {
+        // Safe because `u16` is the same type as `jchar`.
+        token.with_owned(|token| {
+            call_jni_object_method!(
+                token,
+                array,
+                GetCharArrayRegion,
+                start,
+                to_jni_length(buffer.len()),
+                buffer.as_mut_ptr() as *mut jni_sys::jchar
+            );
+            CallOutcome::Unknown(())
+        })
+    }
+
+    unsafe fn set_region<'a>(
+        token: &NoException<'a>,
+        array: &Object<'a>,
+        start: jni_sys::jsize,
+        values: &[Self],
+    ) -> JavaResult<'a, ()> {
+        // Safe because `u16` is the same type as `jchar`.
+        token.with_owned(|token| {
+            call_jni_object_method!(
+                token,
+                array,
+                SetCharArrayRegion,
+                start,
+                to_jni_length(values.len()),
+                values.as_ptr() as *const jni_sys::jchar
+            );
+            CallOutcome::Unknown(())
+        })
+    }
+}
+
+impl CriticalArrayElement for u16 {}
+
+/// A type representing a Java array of primitive values, e.g. `int[]` or `byte[]`.
+///
+/// The element type `T` is the Rust type the Java primitive type maps to, e.g.
+/// `PrimitiveArray<i32>` represents an `int[]`. A `char[]` is a `PrimitiveArray<u16>`, as Java
+/// `char`-s are UTF-16 code units which can be unpaired surrogates.
+///
+/// Example:
+/// ```
+/// # use rust_jni::*;
+/// #
+/// # fn jni_main<'a>(token: NoException<'a>) -> JavaResult<'a, NoException<'a>> {
+/// let array = PrimitiveArray::<i32>::new(&token, 3)?;
+/// array.set_region(&token, 0, &[1, 2, 3])?;
+/// let mut buffer = [0; 2];
+/// array.get_region(&token, 1, &mut buffer)?;
+/// assert_eq!(buffer, [2, 3]);
+/// assert_eq!(array.len(&token), 3);
+/// # Ok(token)
+/// # }
+/// #
+/// # #[cfg(feature = "libjvm")]
+/// # fn main() {
+/// #     let init_arguments = InitArguments::default();
+/// #     let vm = JavaVM::create(&init_arguments).unwrap();
+/// #     let _ = vm.with_attached(
+/// #        &AttachArguments::new(init_arguments.version()),
+/// #        |token: NoException| {
+/// #            ((), jni_main(token).unwrap())
+/// #        },
+/// #     );
+/// # }
+/// #
+/// # #[cfg(not(feature = "libjvm"))]
+/// # fn main() {}
+/// ```
+///
+/// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#array-operations)
+#[derive(Debug, Clone)]
+pub struct PrimitiveArray<'env, T> {
+    object: Object<'env>,
+    _type: PhantomData<fn() -> T>,
+}
+
+impl<'env, T> PrimitiveArray<'env, T>
+where
+    T: ArrayElement,
+{
+    /// Create a new Java array of the given length filled with default values.
+    ///
+    /// Panics if `length` doesn't fit into a `jsize`.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#newprimitivetypearray-routines)
+    pub fn new(token: &NoException<'env>, length: usize) -> JavaResult<'env, Self> {
+        // Safe because the returned object is an array of `T`.
+        let raw_array = unsafe { T::new_array(token, to_jni_length(length)) }?;
+        // Safe because the argument is a valid array reference.
+        Ok(unsafe { Self::from_raw(token.env(), raw_array) })
+    }
+
+    /// Create a new Java array with a copy of the values.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#setprimitivetypearrayregion-routines)
+    pub fn from_slice(token: &NoException<'env>, values: &[T]) -> JavaResult<'env, Self> {
+        let array = Self::new(token, values.len())?;
+        array.set_region(token, 0, values)?;
+        Ok(array)
+    }
+
+    /// Array length.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#getarraylength)
+    pub fn len(&self, token: &NoException) -> usize {
+        // Safe because arguments are ensured to be the correct by construction.
+        let length = unsafe { call_jni_object_method!(token, self, GetArrayLength) };
+        length as usize
+    }
+
+    /// Copy all array elements into a [`Vec`](https://doc.rust-lang.org/std/vec/struct.Vec.html).
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#getprimitivetypearrayregion-routines)
+    pub fn to_vec(&self, token: &NoException<'env>) -> JavaResult<'env, Vec<T>> {
+        let mut buffer = vec![T::default(); self.len(token)];
+        self.get_region(token, 0, &mut buffer)?;
+        Ok(buffer)
+    }
+
+    /// Check if the array is empty.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#getarraylength)
+    pub fn is_empty(&self, token: &NoException) -> bool {
+        self.len(token) == 0
+    }
+
+    /// Copy array elements starting from `start` into the buffer.
+    ///
+    /// Throws `ArrayIndexOutOfBoundsException` if the region doesn't fit into the array.
+    /// Panics if the buffer length doesn't fit into a `jsize`.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#getprimitivetypearrayregion-routines)
+    pub fn get_region(
+        &self,
+        token: &NoException<'env>,
+        start: usize,
+        buffer: &mut [T],
+    ) -> JavaResult<'env, ()> {
+        // Safe because the object is an array of `T`.
+        unsafe { T::get_region(token, &self.object, to_jni_index(start), buffer) }
+    }
+
+    /// Copy values into the array starting from `start`.
+    ///
+    /// Throws `ArrayIndexOutOfBoundsException` if the region doesn't fit into the array.
+    /// Panics if the number of values doesn't fit into a `jsize`.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#setprimitivetypearrayregion-routines)
+    pub fn set_region(
+        &self,
+        token: &NoException<'env>,
+        start: usize,
+        values: &[T],
+    ) -> JavaResult<'env, ()> {
+        // Safe because the object is an array of `T`.
+        unsafe { T::set_region(token, &self.object, to_jni_index(start), values) }
+    }
+
+    /// Unsafe because an incorrect object reference can be passed.
+    #[inline(always)]
+    unsafe fn from_raw<'a>(
+        env: &'a JniEnv<'a>,
+        raw_array: NonNull<jni_sys::_jobject>,
+    ) -> PrimitiveArray<'a, T> {
+        PrimitiveArray {
+            object: Object::from_raw(env, raw_array),
+            _type: PhantomData,
+        }
+    }
+}
+
+impl<'env, T> PrimitiveArray<'env, T>
+where
+    T: CriticalArrayElement,
+{
+    /// Access the array elements without copying them.
+    ///
+    /// The VM is likely to give direct access to the array contents, but in exchange the
+    /// `callback` runs inside a critical region: it must not make any JNI calls or block waiting
+    /// on other Java threads, as the VM may have garbage collection disabled until it returns.
+    /// The token is borrowed mutably for the whole call so that the `callback` can't make
+    /// JNI calls. The elements slice is only valid for the duration of the `callback`.
+    /// Changes made to it are committed to the Java array when the critical region is released,
+    /// which happens even if the `callback` panics.
+    ///
+    /// Throws `OutOfMemoryError` if the VM could not provide the array contents.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#getprimitivearraycritical-releaseprimitivearraycritical)
+    pub fn with_critical<R>(
+        &self,
+        token: &mut NoException<'env>,
+        callback: impl FnOnce(&mut [T]) -> R,
+    ) -> JavaResult<'env, R> {
+        let length = self.len(token);
+        let elements = token.with_owned(|token| {
+            // Safe because arguments are ensured to be the correct by construction.
+            let elements = unsafe {
+                call_jni_object_method!(token, self, GetPrimitiveArrayCritical, ptr::null_mut())
+            };
+            if elements.is_null() {
+                // Safe because `GetPrimitiveArrayCritical` throws an exception before returning `null`.
+                CallOutcome::Err(unsafe { token.exchange() })
+            } else {
+                CallOutcome::Ok((elements, token))
+            }
+        })?;
+        let guard = ArrayCriticalGuard {
+            array: &self.object,
+            elements,
+        };
+        // Safe because `GetPrimitiveArrayCritical` returns a pointer to `length` elements of `T`
+        // which stays valid until `ReleasePrimitiveArrayCritical` is called by the guard.
+        Ok(callback(unsafe {
+            std::slice::from_raw_parts_mut(guard.elements as *mut T, length)
+        }))
+    }
+}
+
+/// Releases the array elements obtained by `GetPrimitiveArrayCritical` when dropped.
+struct ArrayCriticalGuard<'a, 'env> {
+    array: &'a Object<'env>,
+    elements: *mut std::os::raw::c_void,
+}
+
+impl<'a, 'env> Drop for ArrayCriticalGuard<'a, 'env> {
+    fn drop(&mut self) {
+        // Safe because arguments are ensured to be the correct by construction.
+        // Mode `0` copies back the contents and frees the elements buffer.
+        unsafe {
+            call_jni_method!(
+                self.array.env(),
+                ReleasePrimitiveArrayCritical,
+                self.array.raw_object().as_ptr(),
+                self.elements,
+                0
+            )
+        };
+    }
+}
+
+/// Allow [`PrimitiveArray`](struct.PrimitiveArray.html) to be used in place of an [`Object`](struct.Object.html).
+impl<'env, T> ::std::ops::Deref for PrimitiveArray<'env, T> {
+    type Target = Object<'env>;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.object
+    }
+}
+
+impl<'env, T> AsRef<Object<'env>> for PrimitiveArray<'env, T> {
+    #[inline(always)]
+    fn as_ref(&self) -> &Object<'env> {
+        &self.object
+    }
+}
+
+impl<'env, T> AsRef<PrimitiveArray<'env, T>> for PrimitiveArray<'env, T> {
+    #[inline(always)]
+    fn as_ref(&self) -> &PrimitiveArray<'env, T> {
+        self
+    }
+}
+
+impl<'a, T> From<PrimitiveArray<'a, T>> for Object<'a> {
+    fn from(array: PrimitiveArray<'a, T>) -> Self {
+        array.object
+    }
+}
+
+impl<'env, T> FromObject<'env> for PrimitiveArray<'env, T> {
+    #[inline(always)]
+    unsafe fn from_object(object: Object<'env>) -> Self {
+        Self {
+            object,
+            _type: PhantomData,
+        }
+    }
+}
+
+impl<T> JavaClassSignature for PrimitiveArray<'_, T>
+where
+    T: ArrayElement,
+{
+    #[inline(always)]
+    fn signature() -> &'static str {
+        T::array_signature()
+    }
+}
+
+/// Allow comparing [`PrimitiveArray`](struct.PrimitiveArray.html)
+/// to Java objects. Java objects are compared by-reference to preserve
+/// original Java semantics. To compare objects by value, call the
+/// [`equals`](struct.Object.html#method.equals) method.
+///
+/// Will panic if there is a pending exception in the current thread.
+///
+/// This is mostly a convenience for using `assert_eq!()` in tests. Always prefer using
+/// [`is_same_as`](struct.Object.html#methods.is_same_as) to comparing with `==`, because
+/// the former checks for a pending exception in compile-time rather than the run-time.
+impl<'env, T, U> PartialEq<U> for PrimitiveArray<'env, T>
+where
+    U: AsRef<Object<'env>>,
+{
+    fn eq(&self, other: &U) -> bool {
+        Object::as_ref(self).eq(other.as_ref())
+    }
+}
+
+/// A type representing a Java array of objects, e.g. `String[]` or `Object[]`.
+///
+/// The element type `T` is the Java class wrapper type of the elements, e.g.
+/// `ObjectArray<String>` represents a `String[]`.
+///
+/// Example:
+/// ```
+/// # use rust_jni::*;
+/// # use rust_jni::java::lang::String;
+/// #
+/// # fn jni_main<'a>(token: NoException<'a>) -> JavaResult<'a, NoException<'a>> {
+/// let array = ObjectArray::<String>::new(&token, 2, None::<&String>)?;
+/// array.set(&token, 1, &String::new(&token, "test")?)?;
+/// assert!(array.get(&token, 0)?.is_none());
+/// assert_eq!(array.get(&token, 1)?.unwrap().as_string(&token), "test");
+/// assert_eq!(array.len(&token), 2);
+/// # Ok(token)
+/// # }
+/// #
+/// # #[cfg(feature = "libjvm")]
+/// # fn main() {
+/// #     let init_arguments = InitArguments::default();
+/// #     let vm = JavaVM::create(&init_arguments).unwrap();
+/// #     let _ = vm.with_attached(
+/// #        &AttachArguments::new(init_arguments.version()),
+/// #        |token: NoException| {
+/// #            ((), jni_main(token).unwrap())
+/// #        },
+/// #     );
+/// # }
+/// #
+/// # #[cfg(not(feature = "libjvm"))]
+/// # fn main() {}
+/// ```
+///
+/// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#array-operations)
+#[derive(Debug, Clone)]
+pub struct ObjectArray<'env, T> {
+    object: Object<'env>,
+    _type: PhantomData<fn() -> T>,
+}
+
+impl<'env, T> ObjectArray<'env, T>
+where
+    T: JavaClass<'env>,
+{
+    /// Create a new Java array of the given length with all elements set to `initial`.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#newobjectarray)
+    pub fn new(
+        token: &NoException<'env>,
+        length: usize,
+        initial: impl JavaObjectArgument<T>,
+    ) -> JavaResult<'env, Self> {
+        let class = find_class::<T>(token)?;
+        let initial = initial
+            .as_argument()
+            .map_or(ptr::null_mut(), |initial| unsafe {
+                initial.as_ref().raw_object().as_ptr()
+            });
+        // Safe because arguments are ensured to be the correct by construction and because
+        // `NewObjectArray` throws an exception before returning `null`.
+        let raw_array = unsafe {
+            call_nullable_jni_method!(
+                token,
+                NewObjectArray,
+                length as jni_sys::jsize,
+                class.raw_object().as_ptr(),
+                initial
+            )
+        }?;
+        // Safe because the argument is a valid array reference.
+        Ok(unsafe { Self::from_raw(token.env(), raw_array) })
+    }
+
+    /// Array length.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#getarraylength)
+    pub fn len(&self, token: &NoException) -> usize {
+        // Safe because arguments are ensured to be the correct by construction.
+        let length = unsafe { call_jni_object_method!(token, self, GetArrayLength) };
+        length as usize
+    }
+
+    /// Check if the array is empty.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#getarraylength)
+    pub fn is_empty(&self, token: &NoException) -> bool {
+        self.len(token) == 0
+    }
+
+    /// Get the array element at `index`. Returns `None` if the element is `null`.
+    ///
+    /// Throws `ArrayIndexOutOfBoundsException` if the index is out of the array bounds.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#getobjectarrayelement)
+    pub fn get(&self, token: &NoException<'env>, index: usize) -> JavaResult<'env, Option<T>> {
+        // Safe because arguments are ensured to be the correct by construction.
+        let raw_element = token.with_owned(|token| {
+            let result = unsafe {
+                call_jni_object_method!(token, self, GetObjectArrayElement, index as jni_sys::jsize)
+            };
+            match NonNull::new(result) {
+                // The element could have just been null, but also an exception could have been thrown.
+                None => CallOutcome::Unknown(None),
+                // We know that there is no exception because a non-null was returned.
+                result => CallOutcome::Ok((result, token)),
+            }
+        })?;
+        // Safe because the array only contains objects of type `T`.
+        Ok(raw_element.map(|raw_element| unsafe {
+            T::from_object(Object::from_raw(token.env(), raw_element))
+        }))
+    }
+
+    /// Set the array element at `index`.
+    ///
+    /// Throws `ArrayIndexOutOfBoundsException` if the index is out of the array bounds.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#setobjectarrayelement)
+    pub fn set(
+        &self,
+        token: &NoException<'env>,
+        index: usize,
+        value: impl JavaObjectArgument<T>,
+    ) -> JavaResult<'env, ()> {
+        let value = value.as_argument().map_or(ptr::null_mut(), |value| unsafe {
+            value.as_ref().raw_object().as_ptr()
+        });
+        // Safe because arguments are ensured to be the correct by construction.
+        token.with_owned(|token| {
+            unsafe {
+                call_jni_object_method!(
+                    token,
+                    self,
+                    SetObjectArrayElement,
+                    index as jni_sys::jsize,
+                    value
+                );
+            }
+            CallOutcome::Unknown(())
+        })
+    }
+
+    /// Unsafe because an incorrect object reference can be passed.
+    #[inline(always)]
+    unsafe fn from_raw<'a>(
+        env: &'a JniEnv<'a>,
+        raw_array: NonNull<jni_sys::_jobject>,
+    ) -> ObjectArray<'a, T> {
+        ObjectArray {
+            object: Object::from_raw(env, raw_array),
+            _type: PhantomData,
+        }
+    }
+}
+
+/// Allow [`ObjectArray`](struct.ObjectArray.html) to be used in place of an [`Object`](struct.Object.html).
+impl<'env, T> ::std::ops::Deref for ObjectArray<'env, T> {
+    type Target = Object<'env>;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.object
+    }
+}
+
+impl<'env, T> AsRef<Object<'env>> for ObjectArray<'env, T> {
+    #[inline(always)]
+    fn as_ref(&self) -> &Object<'env> {
+        &self.object
+    }
+}
+
+impl<'env, T> AsRef<ObjectArray<'env, T>> for ObjectArray<'env, T> {
+    #[inline(always)]
+    fn as_ref(&self) -> &ObjectArray<'env, T> {
+        self
+    }
+}
+
+impl<'a, T> From<ObjectArray<'a, T>> for Object<'a> {
+    fn from(array: ObjectArray<'a, T>) -> Self {
+        array.object
+    }
+}
+
+impl<'env, T> FromObject<'env> for ObjectArray<'env, T> {
+    #[inline(always)]
+    unsafe fn from_object(object: Object<'env>) -> Self {
+        Self {
+            object,
+            _type: PhantomData,
+        }
+    }
+}
+
+impl<T> JavaClassSignature for ObjectArray<'_, T>
+where
+    T: JavaClassSignature,
+{
+    fn signature() -> &'static str {
+        object_array_signature(T::signature())
+    }
+}
+
+/// Get the signature of an array with the given element signature.
+///
+/// Signatures need to be `'static`, but can't be built at compile time for generic element types,
+/// so they are built once for each element type and leaked.
+fn object_array_signature(element_signature: &'static str) -> &'static str {
+    static SIGNATURES: Mutex<BTreeMap<&'static str, &'static str>> = Mutex::new(BTreeMap::new());
+    // The map is always left in a consistent state, so a poisoned lock is still usable.
+    SIGNATURES
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .entry(element_signature)
+        .or_insert_with(|| Box::leak(format!("[{}", element_signature).into_boxed_str()))
+}
+
+/// Allow comparing [`ObjectArray`](struct.ObjectArray.html)
+/// to Java objects. Java objects are compared by-reference to preserve
+/// original Java semantics. To compare objects by value, call the
+/// [`equals`](struct.Object.html#method.equals) method.
+///
+/// Will panic if there is a pending exception in the current thread.
+///
+/// This is mostly a convenience for using `assert_eq!()` in tests. Always prefer using
+/// [`is_same_as`](struct.Object.html#methods.is_same_as) to comparing with `==`, because
+/// the former checks for a pending exception in compile-time rather than the run-time.
+impl<'env, T, U> PartialEq<U> for ObjectArray<'env, T>
+where
+    U: AsRef<Object<'env>>,
+{
+    fn eq(&self, other: &U) -> bool {
+        Object::as_ref(self).eq(other.as_ref())
+    }
+}
+
+#[cfg(test)]
+mod array_tests {
+    use super::*;
+    use crate::string::String;
+    use crate::vm::JavaVMRef;
+    use mockall::Sequence;
+    use serial_test::serial;
+    use std::ffi::CStr;
+    use std::mem::{self, ManuallyDrop};
+    use std::panic;
+
+    generate_jni_env_mock!(jni_mock);
+
+    #[test]
+    fn signature() {
+        assert_eq!(PrimitiveArray::<i32>::signature(), "[I");
+        assert_eq!(PrimitiveArray::<bool>::signature(), "[Z");
+        assert_eq!(PrimitiveArray::<u16>::signature(), "[C");
+        assert_eq!(ObjectArray::<Object>::signature(), "[Ljava/lang/Object;");
+        assert_eq!(ObjectArray::<String>::signature(), "[Ljava/lang/String;");
+        assert_eq!(
+            ObjectArray::<ObjectArray<PrimitiveArray<u8>>>::signature(),
+            "[[[B"
+        );
+    }
+
+    #[test]
+    #[serial]
+    fn object_array() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_class = 0x1234 as jni_sys::jclass;
+        let raw_array = 0x5678 as jni_sys::jobjectArray;
+        let raw_element = 0x9012 as jni_sys::jobject;
+        let mut sequence = Sequence::new();
+        let find_class_mock = jni_mock::find_class_context();
+        find_class_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, name| unsafe {
+                *env == raw_env_ptr && CStr::from_ptr(*name).to_str() == Ok("java/lang/Object")
+            })
+            .returning_st(move |_env, _name| raw_class)
+            .in_sequence(&mut sequence);
+        let new_object_array_mock = jni_mock::new_object_array_context();
+        new_object_array_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, length, class, initial| {
+                *env == raw_env_ptr && *length == 2 && *class == raw_class && initial.is_null()
+            })
+            .returning_st(move |_env, _length, _class, _initial| raw_array)
+            .in_sequence(&mut sequence);
+        let delete_local_ref_mock = jni_mock::delete_local_ref_context();
+        delete_local_ref_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object| *env == raw_env_ptr && *object == raw_class)
+            .return_const(())
+            .in_sequence(&mut sequence);
+        let get_array_length_mock = jni_mock::get_array_length_context();
+        get_array_length_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, array| *env == raw_env_ptr && *array == raw_array)
+            .return_const(2)
+            .in_sequence(&mut sequence);
+        let set_object_array_element_mock = jni_mock::set_object_array_element_context();
+        set_object_array_element_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, array, index, value| {
+                *env == raw_env_ptr && *array == raw_array && *index == 1 && *value == raw_element
+            })
+            .return_const(())
+            .in_sequence(&mut sequence);
+        let exception_occured_mock = jni_mock::exception_occured_context();
+        exception_occured_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env| *env == raw_env_ptr)
+            .returning_st(|_env| ptr::null_mut())
+            .in_sequence(&mut sequence);
+        let get_object_array_element_mock = jni_mock::get_object_array_element_context();
+        get_object_array_element_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, array, index| {
+                *env == raw_env_ptr && *array == raw_array && *index == 1
+            })
+            .returning_st(move |_env, _array, _index| raw_element)
+            .in_sequence(&mut sequence);
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let token = NoException::test(&env);
+        let array =
+            ManuallyDrop::new(ObjectArray::<Object>::new(&token, 2, None::<&Object>).unwrap());
+        assert_eq!(unsafe { array.raw_object() }.as_ptr(), raw_array);
+        assert_eq!(array.len(&token), 2);
+        let element = ManuallyDrop::new(unsafe {
+            Object::from_raw(&env, NonNull::new(raw_element).unwrap())
+        });
+        array.set(&token, 1, &*element).unwrap();
+        let element = ManuallyDrop::new(array.get(&token, 1).unwrap().unwrap());
+        assert_eq!(unsafe { element.raw_object() }.as_ptr(), raw_element);
+    }
+
+    #[test]
+    #[serial]
+    fn object_array_get_null() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_array = 0x5678 as jni_sys::jobjectArray;
+        let mut sequence = Sequence::new();
+        let get_object_array_element_mock = jni_mock::get_object_array_element_context();
+        get_object_array_element_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, array, index| {
+                *env == raw_env_ptr && *array == raw_array && *index == 0
+            })
+            .returning_st(|_env, _array, _index| ptr::null_mut())
+            .in_sequence(&mut sequence);
+        let exception_occured_mock = jni_mock::exception_occured_context();
+        exception_occured_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env| *env == raw_env_ptr)
+            .returning_st(|_env| ptr::null_mut())
+            .in_sequence(&mut sequence);
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let token = NoException::test(&env);
+        let array = ManuallyDrop::new(unsafe {
+            ObjectArray::<Object>::from_raw(&env, NonNull::new(raw_array).unwrap())
+        });
+        assert!(array.get(&token, 0).unwrap().is_none());
+    }
+
+    #[test]
+    #[serial]
+    fn object_array_get_out_of_bounds() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_array = 0x5678 as jni_sys::jobjectArray;
+        let raw_throwable = 0x9012 as jni_sys::jobject;
+        let mut sequence = Sequence::new();
+        let get_object_array_element_mock = jni_mock::get_object_array_element_context();
+        get_object_array_element_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, array, index| {
+                *env == raw_env_ptr && *array == raw_array && *index == 3
+            })
+            .returning_st(|_env, _array, _index| ptr::null_mut())
+            .in_sequence(&mut sequence);
+        let exception_occured_mock = jni_mock::exception_occured_context();
+        exception_occured_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env| *env == raw_env_ptr)
+            .returning_st(move |_env| raw_throwable)
+            .in_sequence(&mut sequence);
+        let exception_clear_mock = jni_mock::exception_clear_context();
+        exception_clear_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env| *env == raw_env_ptr)
+            .return_const(())
+            .in_sequence(&mut sequence);
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let token = NoException::test(&env);
+        let array = ManuallyDrop::new(unsafe {
+            ObjectArray::<Object>::from_raw(&env, NonNull::new(raw_array).unwrap())
+        });
+        let throwable = array.get(&token, 3).unwrap_err();
+        assert_eq!(unsafe { throwable.raw_object() }.as_ptr(), raw_throwable);
+        mem::forget(throwable);
+    }
+
+    #[test]
+    #[serial]
+    fn primitive_array_with_critical() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_array = 0x5678 as jni_sys::jarray;
+        let mut elements: Vec<i32> = vec![1, 2, 3];
+        let raw_elements = elements.as_mut_ptr() as *mut std::os::raw::c_void;
+        let mut sequence = Sequence::new();
+        let get_array_length_mock = jni_mock::get_array_length_context();
+        get_array_length_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, array| *env == raw_env_ptr && *array == raw_array)
+            .return_const(3)
+            .in_sequence(&mut sequence);
+        let get_primitive_array_critical_mock = jni_mock::get_primitive_array_critical_context();
+        get_primitive_array_critical_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, array, _is_copy| *env == raw_env_ptr && *array == raw_array)
+            .returning_st(move |_env, _array, _is_copy| raw_elements)
+            .in_sequence(&mut sequence);
+        let release_primitive_array_critical_mock =
+            jni_mock::release_primitive_array_critical_context();
+        release_primitive_array_critical_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, array, elements, mode| unsafe {
+                *env == raw_env_ptr
+                    && *array == raw_array
+                    && *elements == raw_elements
+                    && *mode == 0
+                    && std::slice::from_raw_parts(*elements as *const i32, 3) == [1, 4, 9]
+            })
+            .return_const(())
+            .in_sequence(&mut sequence);
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let mut token = NoException::test(&env);
+        let array = ManuallyDrop::new(unsafe {
+            PrimitiveArray::<i32>::from_raw(&env, NonNull::new(raw_array).unwrap())
+        });
+        let sum = array
+            .with_critical(&mut token, |elements| {
+                for element in elements.iter_mut() {
+                    *element *= *element;
+                }
+                elements.iter().sum::<i32>()
+            })
+            .unwrap();
+        assert_eq!(sum, 14);
+        assert_eq!(elements, [1, 4, 9]);
+    }
+
+    #[test]
+    #[serial]
+    fn primitive_array_with_critical_panic() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_array = 0x5678 as jni_sys::jarray;
+        let mut elements: Vec<i32> = vec![1, 2, 3];
+        let raw_elements = elements.as_mut_ptr() as *mut std::os::raw::c_void;
+        let mut sequence = Sequence::new();
+        let get_array_length_mock = jni_mock::get_array_length_context();
+        get_array_length_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, array| *env == raw_env_ptr && *array == raw_array)
+            .return_const(3)
+            .in_sequence(&mut sequence);
+        let get_primitive_array_critical_mock = jni_mock::get_primitive_array_critical_context();
+        get_primitive_array_critical_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, array, _is_copy| *env == raw_env_ptr && *array == raw_array)
+            .returning_st(move |_env, _array, _is_copy| raw_elements)
+            .in_sequence(&mut sequence);
+        let release_primitive_array_critical_mock =
+            jni_mock::release_primitive_array_critical_context();
+        release_primitive_array_critical_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, array, elements, mode| {
+                *env == raw_env_ptr
+                    && *array == raw_array
+                    && *elements == raw_elements
+                    && *mode == 0
+            })
+            .return_const(())
+            .in_sequence(&mut sequence);
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let mut token = NoException::test(&env);
+        let array = ManuallyDrop::new(unsafe {
+            PrimitiveArray::<i32>::from_raw(&env, NonNull::new(raw_array).unwrap())
+        });
+        let result = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+            array.with_critical(&mut token, |_elements| panic!("callback panic"))
+        }));
+        assert!(result.is_err());
+        assert_eq!(elements, [1, 2, 3]);
+    }
+
+    #[test]
+    #[serial]
+    fn primitive_array_from_slice() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_array = 0x5678 as jni_sys::jarray;
+        let mut sequence = Sequence::new();
+        let new_int_array_mock = jni_mock::new_int_array_context();
+        new_int_array_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, length| *env == raw_env_ptr && *length == 3)
+            .returning_st(move |_env, _length| raw_array)
+            .in_sequence(&mut sequence);
+        let set_int_array_region_mock = jni_mock::set_int_array_region_context();
+        set_int_array_region_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, array, start, length, buffer| unsafe {
+                *env == raw_env_ptr
+                    && *array == raw_array
+                    && *start == 0
+                    && *length == 3
+                    && std::slice::from_raw_parts(*buffer, 3) == [1, 2, 3]
+            })
+            .return_const(())
+            .in_sequence(&mut sequence);
+        let exception_occured_mock = jni_mock::exception_occured_context();
+        exception_occured_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env| *env == raw_env_ptr)
+            .returning_st(|_env| ptr::null_mut())
+            .in_sequence(&mut sequence);
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let token = NoException::test(&env);
+        let array =
+            ManuallyDrop::new(PrimitiveArray::<i32>::from_slice(&token, &[1, 2, 3]).unwrap());
+        assert_eq!(unsafe { array.raw_object() }.as_ptr(), raw_array);
+    }
+
+    #[test]
+    #[serial]
+    fn primitive_array_to_vec() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_array = 0x5678 as jni_sys::jarray;
+        let mut sequence = Sequence::new();
+        let get_array_length_mock = jni_mock::get_array_length_context();
+        get_array_length_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, array| *env == raw_env_ptr && *array == raw_array)
+            .return_const(3)
+            .in_sequence(&mut sequence);
+        let get_int_array_region_mock = jni_mock::get_int_array_region_context();
+        get_int_array_region_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, array, start, length, _buffer| {
+                *env == raw_env_ptr && *array == raw_array && *start == 0 && *length == 3
+            })
+            .returning_st(|_env, _array, _start, _length, buffer| unsafe {
+                ptr::copy_nonoverlapping([1, 2, 3].as_ptr(), buffer, 3);
+            })
+            .in_sequence(&mut sequence);
+        let exception_occured_mock = jni_mock::exception_occured_context();
+        exception_occured_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env| *env == raw_env_ptr)
+            .returning_st(|_env| ptr::null_mut())
+            .in_sequence(&mut sequence);
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let token = NoException::test(&env);
+        let array = ManuallyDrop::new(unsafe {
+            PrimitiveArray::<i32>::from_raw(&env, NonNull::new(raw_array).unwrap())
+        });
+        assert_eq!(array.to_vec(&token).unwrap(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    #[serial]
+    fn primitive_array_get_region_index_overflow() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_array = 0x5678 as jni_sys::jarray;
+        let raw_throwable = 0x9012 as jni_sys::jobject;
+        let mut sequence = Sequence::new();
+        let get_int_array_region_mock = jni_mock::get_int_array_region_context();
+        get_int_array_region_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, array, start, length, _buffer| {
+                *env == raw_env_ptr
+                    && *array == raw_array
+                    && *start == jni_sys::jsize::MAX
+                    && *length == 1
+            })
+            .return_const(())
+            .in_sequence(&mut sequence);
+        let exception_occured_mock = jni_mock::exception_occured_context();
+        exception_occured_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env| *env == raw_env_ptr)
+            .returning_st(move |_env| raw_throwable)
+            .in_sequence(&mut sequence);
+        let exception_clear_mock = jni_mock::exception_clear_context();
+        exception_clear_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env| *env == raw_env_ptr)
+            .return_const(())
+            .in_sequence(&mut sequence);
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let token = NoException::test(&env);
+        let array = ManuallyDrop::new(unsafe {
+            PrimitiveArray::<i32>::from_raw(&env, NonNull::new(raw_array).unwrap())
+        });
+        let mut buffer = [0; 1];
+        let throwable = array
+            .get_region(&token, usize::MAX, &mut buffer)
+            .unwrap_err();
+        assert_eq!(unsafe { throwable.raw_object() }.as_ptr(), raw_throwable);
+        mem::forget(throwable);
+    }
+
+    #[test]
+    #[serial]
+    #[should_panic(expected = "Array length doesn't fit into a `jsize`.")]
+    fn primitive_array_new_length_overflow() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let token = NoException::test(&env);
+        let _ = PrimitiveArray::<i32>::new(&token, usize::MAX);
+    }
+}
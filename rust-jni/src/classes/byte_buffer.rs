@@ -0,0 +1,320 @@
+use crate::java_class::JavaClassSignature;
+use crate::object::Object;
+use crate::result::JavaResult;
+use crate::token::{CallOutcome, NoException};
+use std::marker::PhantomData;
+use std::os::raw::c_void;
+use std::ptr::NonNull;
+use std::slice;
+
+include!("../call_jni_method.rs");
+
+/// A type representing a direct Java
+/// [`ByteBuffer`](https://docs.oracle.com/javase/10/docs/api/java/nio/ByteBuffer.html)
+/// backed by Rust-owned memory.
+///
+/// The `'data` lifetime is the lifetime of the backing memory, which makes sure the memory
+/// outlives the buffer.
+///
+/// Does not implement [`FromObject`](trait.FromObject.html) because there is no way to know
+/// the lifetime of the memory backing a buffer that was received from Java. For the same reason
+/// the buffer doesn't dereference to an [`Object`](struct.Object.html): a clone or a global
+/// reference of it could outlive the memory. Use the unsafe [`as_object`](#method.as_object)
+/// to pass the buffer to Java.
+#[derive(Debug)]
+pub struct ByteBuffer<'env, 'data> {
+    object: Object<'env>,
+    data: PhantomData<&'data mut [u8]>,
+}
+
+impl<'env, 'data> ByteBuffer<'env, 'data> {
+    /// Create a new direct [`ByteBuffer`](struct.ByteBuffer.html) backed by a byte slice.
+    ///
+    /// Returns [`None`](https://doc.rust-lang.org/std/option/enum.Option.html#variant.None)
+    /// if the Java VM does not support JNI access to direct buffers.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#newdirectbytebuffer)
+    pub fn from_slice(
+        token: &NoException<'env>,
+        data: &'data mut [u8],
+    ) -> JavaResult<'env, Option<ByteBuffer<'env, 'data>>> {
+        let raw_buffer = token.with_owned(
+            #[inline(always)]
+            |token| {
+                // Safe because the arguments are ensured to be correct by construction.
+                let result = unsafe {
+                    call_jni_method!(
+                        token.env(),
+                        NewDirectByteBuffer,
+                        data.as_mut_ptr() as *mut c_void,
+                        data.len() as jni_sys::jlong
+                    )
+                };
+                match NonNull::new(result) {
+                    // Direct buffers could be unsupported, but also an exception could have been thrown.
+                    None => CallOutcome::Unknown(None),
+                    // We know that there is no exception because a non-null was returned.
+                    result => CallOutcome::Ok((result, token)),
+                }
+            },
+        )?;
+        Ok(raw_buffer.map(|raw_buffer| ByteBuffer {
+            // Safe because the argument is a valid buffer reference.
+            object: unsafe { Object::from_raw(token.env(), raw_buffer) },
+            data: PhantomData,
+        }))
+    }
+
+    /// Get the buffer as an [`Object`](struct.Object.html), e.g. to pass it to a Java method.
+    ///
+    /// Unsafe because neither Rust nor Java code may keep a reference to the buffer, like a
+    /// cloned [`Object`](struct.Object.html) or a global reference, after the `'data` borrow ends.
+    #[inline(always)]
+    pub unsafe fn as_object(&self) -> &Object<'env> {
+        &self.object
+    }
+
+    /// Get the buffer capacity in bytes.
+    ///
+    /// Returns [`None`](https://doc.rust-lang.org/std/option/enum.Option.html#variant.None)
+    /// if the Java VM does not support JNI access to direct buffers.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#getdirectbuffercapacity)
+    pub fn capacity(&self, _token: &NoException<'env>) -> Option<usize> {
+        // Safe because the argument is ensured to be the correct by construction.
+        let capacity = unsafe {
+            call_jni_method!(
+                self.object.env(),
+                GetDirectBufferCapacity,
+                self.object.raw_object().as_ptr()
+            )
+        };
+        if capacity < 0 {
+            None
+        } else {
+            Some(capacity as usize)
+        }
+    }
+
+    /// Get the memory backing the buffer as a mutable byte slice.
+    ///
+    /// Returns [`None`](https://doc.rust-lang.org/std/option/enum.Option.html#variant.None)
+    /// if the Java VM does not support JNI access to direct buffers.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#getdirectbufferaddress)
+    pub fn as_mut_slice(&mut self, token: &NoException<'env>) -> Option<&mut [u8]> {
+        let capacity = self.capacity(token)?;
+        // Safe because the argument is ensured to be the correct by construction.
+        let address = unsafe {
+            call_jni_method!(
+                self.object.env(),
+                GetDirectBufferAddress,
+                self.object.raw_object().as_ptr()
+            )
+        };
+        if address.is_null() {
+            return None;
+        }
+        // Safe because the buffer was created from a slice of `capacity` bytes that
+        // outlives the buffer and the result is borrowed mutably from the buffer.
+        Some(unsafe { slice::from_raw_parts_mut(address as *mut u8, capacity) })
+    }
+}
+
+impl JavaClassSignature for ByteBuffer<'_, '_> {
+    #[inline(always)]
+    fn signature() -> &'static str {
+        "Ljava/nio/ByteBuffer;"
+    }
+}
+
+/// Allow comparing [`ByteBuffer`](struct.ByteBuffer.html)
+/// to Java objects. Java objects are compared by-reference to preserve
+/// original Java semantics. To compare objects by value, call the
+/// [`equals`](struct.Object.html#method.equals) method.
+///
+/// Will panic if there is a pending exception in the current thread.
+///
+/// This is mostly a convenience for using `assert_eq!()` in tests. Always prefer using
+/// [`is_same_as`](struct.Object.html#methods.is_same_as) to comparing with `==`, because
+/// the former checks for a pending exception in compile-time rather than the run-time.
+impl<'env, T> PartialEq<T> for ByteBuffer<'env, '_>
+where
+    T: AsRef<Object<'env>>,
+{
+    fn eq(&self, other: &T) -> bool {
+        self.object.eq(other.as_ref())
+    }
+}
+
+#[cfg(test)]
+mod byte_buffer_tests {
+    use super::*;
+    use crate::env::JniEnv;
+    use crate::vm::JavaVMRef;
+    use serial_test::serial;
+    use std::mem::{self, ManuallyDrop};
+
+    generate_jni_env_mock!(jni_mock);
+
+    #[test]
+    fn signature() {
+        assert_eq!(ByteBuffer::signature(), "Ljava/nio/ByteBuffer;");
+    }
+
+    #[test]
+    #[serial]
+    fn from_slice() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_buffer = 0x1234 as jni_sys::jobject;
+        let mut data = [1u8, 2, 3];
+        let raw_data = data.as_mut_ptr() as usize;
+        let new_direct_byte_buffer_mock = jni_mock::new_direct_byte_buffer_context();
+        new_direct_byte_buffer_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, address, capacity| {
+                *env == raw_env_ptr && *address as usize == raw_data && *capacity == 3
+            })
+            .returning_st(move |_env, _address, _capacity| raw_buffer);
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let token = NoException::test(&env);
+        let buffer = ByteBuffer::from_slice(&token, &mut data).unwrap().unwrap();
+        assert_eq!(
+            unsafe { buffer.as_object().raw_object() }.as_ptr(),
+            raw_buffer
+        );
+        mem::forget(buffer);
+    }
+
+    #[test]
+    #[serial]
+    fn from_slice_unsupported() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let new_direct_byte_buffer_mock = jni_mock::new_direct_byte_buffer_context();
+        new_direct_byte_buffer_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, _address, capacity| *env == raw_env_ptr && *capacity == 3)
+            .returning_st(move |_env, _address, _capacity| std::ptr::null_mut());
+        let exception_occured_mock = jni_mock::exception_occured_context();
+        exception_occured_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env| *env == raw_env_ptr)
+            .returning_st(|_env| std::ptr::null_mut());
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let token = NoException::test(&env);
+        let mut data = [1u8, 2, 3];
+        assert!(ByteBuffer::from_slice(&token, &mut data).unwrap().is_none());
+    }
+
+    #[test]
+    #[serial]
+    fn capacity() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_buffer = 0x1234 as jni_sys::jobject;
+        let get_direct_buffer_capacity_mock = jni_mock::get_direct_buffer_capacity_context();
+        get_direct_buffer_capacity_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, buffer| *env == raw_env_ptr && *buffer == raw_buffer)
+            .return_const(3);
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let token = NoException::test(&env);
+        let buffer = ManuallyDrop::new(ByteBuffer {
+            object: unsafe { Object::from_raw(&env, NonNull::new(raw_buffer).unwrap()) },
+            data: PhantomData,
+        });
+        assert_eq!(buffer.capacity(&token), Some(3));
+    }
+
+    #[test]
+    #[serial]
+    fn capacity_unsupported() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_buffer = 0x1234 as jni_sys::jobject;
+        let get_direct_buffer_capacity_mock = jni_mock::get_direct_buffer_capacity_context();
+        get_direct_buffer_capacity_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, buffer| *env == raw_env_ptr && *buffer == raw_buffer)
+            .return_const(-1);
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let token = NoException::test(&env);
+        let buffer = ManuallyDrop::new(ByteBuffer {
+            object: unsafe { Object::from_raw(&env, NonNull::new(raw_buffer).unwrap()) },
+            data: PhantomData,
+        });
+        assert_eq!(buffer.capacity(&token), None);
+    }
+
+    #[test]
+    #[serial]
+    fn as_mut_slice() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_buffer = 0x1234 as jni_sys::jobject;
+        let mut data = [1u8, 2, 3];
+        let raw_data = data.as_mut_ptr() as usize;
+        let get_direct_buffer_capacity_mock = jni_mock::get_direct_buffer_capacity_context();
+        get_direct_buffer_capacity_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, buffer| *env == raw_env_ptr && *buffer == raw_buffer)
+            .return_const(3);
+        let get_direct_buffer_address_mock = jni_mock::get_direct_buffer_address_context();
+        get_direct_buffer_address_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, buffer| *env == raw_env_ptr && *buffer == raw_buffer)
+            .returning_st(move |_env, _buffer| raw_data as *mut c_void);
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let token = NoException::test(&env);
+        let mut buffer = ManuallyDrop::new(ByteBuffer {
+            object: unsafe { Object::from_raw(&env, NonNull::new(raw_buffer).unwrap()) },
+            data: PhantomData::<&mut [u8]>,
+        });
+        let slice = buffer.as_mut_slice(&token).unwrap();
+        assert_eq!(slice, &[1, 2, 3]);
+        slice[1] = 5;
+        assert_eq!(data, [1, 5, 3]);
+    }
+
+    #[test]
+    #[serial]
+    fn as_mut_slice_unsupported() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_buffer = 0x1234 as jni_sys::jobject;
+        let get_direct_buffer_capacity_mock = jni_mock::get_direct_buffer_capacity_context();
+        get_direct_buffer_capacity_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, buffer| *env == raw_env_ptr && *buffer == raw_buffer)
+            .return_const(3);
+        let get_direct_buffer_address_mock = jni_mock::get_direct_buffer_address_context();
+        get_direct_buffer_address_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, buffer| *env == raw_env_ptr && *buffer == raw_buffer)
+            .returning_st(move |_env, _buffer| std::ptr::null_mut());
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let token = NoException::test(&env);
+        let mut buffer = ManuallyDrop::new(ByteBuffer {
+            object: unsafe { Object::from_raw(&env, NonNull::new(raw_buffer).unwrap()) },
+            data: PhantomData,
+        });
+        assert_eq!(buffer.as_mut_slice(&token), None);
+    }
+}
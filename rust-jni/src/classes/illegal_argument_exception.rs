@@ -0,0 +1,157 @@
+use crate::classes::exception::Exception;
+use crate::classes::runtime_exception::RuntimeException;
+use crate::java_class::JavaClassExt;
+use crate::java_class::{FromObject, JavaClassSignature};
+use crate::object::Object;
+use crate::result::JavaResult;
+use crate::string::String;
+use crate::throwable::Throwable;
+use crate::token::NoException;
+
+/// A type representing a Java
+/// [`IllegalArgumentException`](https://docs.oracle.com/javase/10/docs/api/java/lang/IllegalArgumentException.html).
+#[derive(Debug, Clone)]
+pub struct IllegalArgumentException<'env> {
+    pub(crate) object: RuntimeException<'env>,
+}
+
+impl<'this> IllegalArgumentException<'this> {
+    /// Create a new [`IllegalArgumentException`](struct.IllegalArgumentException.html).
+    ///
+    /// [`IllegalArgumentException()` javadoc](https://docs.oracle.com/javase/10/docs/api/java/lang/IllegalArgumentException.html#<init>())
+    pub fn new(token: &NoException<'this>) -> JavaResult<'this, IllegalArgumentException<'this>> {
+        // Safe because we ensure correct arguments and return type.
+        unsafe { Self::call_constructor::<_, fn()>(token, ()) }
+    }
+
+    /// Create a new [`IllegalArgumentException`](struct.IllegalArgumentException.html) with
+    /// the specified detail message.
+    ///
+    /// [`IllegalArgumentException(String)` javadoc](https://docs.oracle.com/javase/10/docs/api/java/lang/IllegalArgumentException.html#<init>(java.lang.String))
+    pub fn with_message(
+        token: &NoException<'this>,
+        message: &str,
+    ) -> JavaResult<'this, IllegalArgumentException<'this>> {
+        let message = String::new(token, message)?;
+        // Safe because we ensure correct arguments and return type.
+        unsafe { Self::call_constructor::<_, fn(&String)>(token, (Some(&message),)) }
+    }
+}
+
+/// Allow [`IllegalArgumentException`](struct.IllegalArgumentException.html) to be used in place
+/// of an [`Object`](struct.Object.html).
+impl<'env> ::std::ops::Deref for IllegalArgumentException<'env> {
+    type Target = Object<'env>;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.object
+    }
+}
+
+impl<'env> AsRef<Object<'env>> for IllegalArgumentException<'env> {
+    #[inline(always)]
+    fn as_ref(&self) -> &Object<'env> {
+        &self.object
+    }
+}
+
+impl<'env> AsRef<Throwable<'env>> for IllegalArgumentException<'env> {
+    #[inline(always)]
+    fn as_ref(&self) -> &Throwable<'env> {
+        self.object.as_ref()
+    }
+}
+
+impl<'env> AsRef<Exception<'env>> for IllegalArgumentException<'env> {
+    #[inline(always)]
+    fn as_ref(&self) -> &Exception<'env> {
+        self.object.as_ref()
+    }
+}
+
+impl<'env> AsRef<RuntimeException<'env>> for IllegalArgumentException<'env> {
+    #[inline(always)]
+    fn as_ref(&self) -> &RuntimeException<'env> {
+        &self.object
+    }
+}
+
+impl<'env> AsRef<IllegalArgumentException<'env>> for IllegalArgumentException<'env> {
+    #[inline(always)]
+    fn as_ref(&self) -> &IllegalArgumentException<'env> {
+        self
+    }
+}
+
+impl<'a> From<IllegalArgumentException<'a>> for RuntimeException<'a> {
+    fn from(value: IllegalArgumentException<'a>) -> Self {
+        value.object
+    }
+}
+
+impl<'a> From<IllegalArgumentException<'a>> for Exception<'a> {
+    fn from(value: IllegalArgumentException<'a>) -> Self {
+        value.object.into()
+    }
+}
+
+impl<'a> From<IllegalArgumentException<'a>> for Throwable<'a> {
+    fn from(value: IllegalArgumentException<'a>) -> Self {
+        value.object.into()
+    }
+}
+
+impl<'a> From<IllegalArgumentException<'a>> for Object<'a> {
+    fn from(value: IllegalArgumentException<'a>) -> Self {
+        value.object.into()
+    }
+}
+
+impl<'env> FromObject<'env> for IllegalArgumentException<'env> {
+    #[inline(always)]
+    unsafe fn from_object(object: Object<'env>) -> Self {
+        Self {
+            object: RuntimeException::from_object(object),
+        }
+    }
+}
+
+impl JavaClassSignature for IllegalArgumentException<'_> {
+    #[inline(always)]
+    fn signature() -> &'static str {
+        "Ljava/lang/IllegalArgumentException;"
+    }
+}
+
+/// Allow comparing [`IllegalArgumentException`](struct.IllegalArgumentException.html)
+/// to Java objects. Java objects are compared by-reference to preserve
+/// original Java semantics. To compare objects by value, call the
+/// [`equals`](struct.Object.html#method.equals) method.
+///
+/// Will panic if there is a pending exception in the current thread.
+///
+/// This is mostly a convenience for using `assert_eq!()` in tests. Always prefer using
+/// [`is_same_as`](struct.Object.html#methods.is_same_as) to comparing with `==`, because
+/// the former checks for a pending exception in compile-time rather than the run-time.
+impl<'env, T> PartialEq<T> for IllegalArgumentException<'env>
+where
+    T: AsRef<Object<'env>>,
+{
+    fn eq(&self, other: &T) -> bool {
+        Object::as_ref(self).eq(other.as_ref())
+    }
+}
+
+#[cfg(test)]
+mod illegal_argument_exception_tests {
+    use super::*;
+
+    #[test]
+    fn signature() {
+        assert_eq!(
+            IllegalArgumentException::signature(),
+            "Ljava/lang/IllegalArgumentException;"
+        );
+    }
+}
@@ -0,0 +1,98 @@
+use crate::java_class::JavaClassExt;
+use crate::java_class::{FromObject, JavaClassSignature};
+use crate::nullable::NullableJavaClassExt;
+use crate::object::Object;
+use crate::result::JavaResult;
+use crate::token::NoException;
+
+/// A type representing a Java
+/// [`Integer`](https://docs.oracle.com/javase/10/docs/api/java/lang/Integer.html)
+/// -- a boxed `int` value.
+#[derive(Debug, Clone)]
+pub struct Integer<'env> {
+    object: Object<'env>,
+}
+
+impl<'env> Integer<'env> {
+    /// Get a boxed [`Integer`](struct.Integer.html) for an `int` value.
+    ///
+    /// [`Integer::valueOf(int)` javadoc](https://docs.oracle.com/javase/10/docs/api/java/lang/Integer.html#valueOf(int))
+    pub fn value_of(token: &NoException<'env>, value: i32) -> JavaResult<'env, Integer<'env>> {
+        // Safe because we ensure correct arguments and return type.
+        unsafe {
+            Self::call_static_method::<_, fn(i32) -> Integer<'env>>(token, "valueOf\0", (value,))
+        }?
+        .or_npe(token)
+    }
+
+    /// Get the `int` value of the [`Integer`](struct.Integer.html).
+    ///
+    /// [`Integer::intValue` javadoc](https://docs.oracle.com/javase/10/docs/api/java/lang/Integer.html#intValue())
+    pub fn int_value(&self, token: &NoException<'env>) -> JavaResult<'env, i32> {
+        // Safe because we ensure correct arguments and return type.
+        unsafe { self.call_method::<_, fn() -> i32>(token, "intValue\0", ()) }
+    }
+}
+
+/// Allow [`Integer`](struct.Integer.html) to be used in place of an [`Object`](struct.Object.html).
+impl<'env> ::std::ops::Deref for Integer<'env> {
+    type Target = Object<'env>;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.object
+    }
+}
+
+impl<'env> AsRef<Object<'env>> for Integer<'env> {
+    #[inline(always)]
+    fn as_ref(&self) -> &Object<'env> {
+        &self.object
+    }
+}
+
+impl<'env> AsRef<Integer<'env>> for Integer<'env> {
+    #[inline(always)]
+    fn as_ref(&self) -> &Integer<'env> {
+        self
+    }
+}
+
+impl<'a> From<Integer<'a>> for Object<'a> {
+    fn from(integer: Integer<'a>) -> Self {
+        integer.object
+    }
+}
+
+impl<'env> FromObject<'env> for Integer<'env> {
+    #[inline(always)]
+    unsafe fn from_object(object: Object<'env>) -> Self {
+        Self { object }
+    }
+}
+
+impl JavaClassSignature for Integer<'_> {
+    #[inline(always)]
+    fn signature() -> &'static str {
+        "Ljava/lang/Integer;"
+    }
+}
+
+/// Allow comparing [`Integer`](struct.Integer.html)
+/// to Java objects. Java objects are compared by-reference to preserve
+/// original Java semantics. To compare objects by value, call the
+/// [`equals`](struct.Object.html#method.equals) method.
+///
+/// Will panic if there is a pending exception in the current thread.
+///
+/// This is mostly a convenience for using `assert_eq!()` in tests. Always prefer using
+/// [`is_same_as`](struct.Object.html#methods.is_same_as) to comparing with `==`, because
+/// the former checks for a pending exception in compile-time rather than the run-time.
+impl<'env, T> PartialEq<T> for Integer<'env>
+where
+    T: AsRef<Object<'env>>,
+{
+    fn eq(&self, other: &T) -> bool {
+        Object::as_ref(self).eq(other.as_ref())
+    }
+}
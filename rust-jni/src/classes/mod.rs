@@ -1,2 +1,8 @@
+pub mod array;
+pub mod byte_buffer;
 pub mod exception;
+pub mod illegal_argument_exception;
+pub mod integer;
 pub mod null_pointer_exception;
+pub mod runtime_exception;
+pub mod stack_trace_element;
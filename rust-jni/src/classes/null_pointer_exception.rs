@@ -3,6 +3,7 @@ use crate::java_class::JavaClassExt;
 use crate::java_class::{FromObject, JavaClassSignature};
 use crate::object::Object;
 use crate::result::JavaResult;
+use crate::string::String;
 use crate::throwable::Throwable;
 use crate::token::NoException;
 
@@ -21,6 +22,19 @@ impl<'this> NullPointerException<'this> {
         // Safe because we ensure correct arguments and return type.
         unsafe { Self::call_constructor::<_, fn()>(token, ()) }
     }
+
+    /// Create a new [`NullPointerException`](struct.NullPointerException.html) with
+    /// the specified detail message.
+    ///
+    /// [`NullPointerException(String)` javadoc](https://docs.oracle.com/javase/10/docs/api/java/lang/NullPointerException.html#<init>(java.lang.String))
+    pub fn with_message(
+        token: &NoException<'this>,
+        message: &str,
+    ) -> JavaResult<'this, NullPointerException<'this>> {
+        let message = String::new(token, message)?;
+        // Safe because we ensure correct arguments and return type.
+        unsafe { Self::call_constructor::<_, fn(&String)>(token, (Some(&message),)) }
+    }
 }
 
 /// Allow [`NullPointerException`](struct.NullPointerException.html) to be used in place of an
@@ -114,3 +128,90 @@ where
         Object::as_ref(self).eq(other.as_ref())
     }
 }
+
+#[cfg(test)]
+mod null_pointer_exception_tests {
+    use super::*;
+    use crate::env::JniEnv;
+    use crate::vm::JavaVMRef;
+    use mockall::Sequence;
+    use serial_test::serial;
+    use std::ffi::CStr;
+    use std::mem::{self, ManuallyDrop};
+
+    generate_jni_env_mock!(jni_mock);
+
+    #[test]
+    #[serial]
+    fn with_message() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_string = 0x1234 as jni_sys::jstring;
+        let raw_class = 0x5678 as jni_sys::jclass;
+        let raw_method_id = 0x9012 as jni_sys::jmethodID;
+        let raw_exception = 0x3456 as jni_sys::jobject;
+        let mut sequence = Sequence::new();
+        let new_string_utf_mock = jni_mock::new_string_utf_context();
+        new_string_utf_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, bytes| unsafe {
+                *env == raw_env_ptr && CStr::from_ptr(*bytes).to_str() == Ok("test message")
+            })
+            .returning_st(move |_env, _bytes| raw_string)
+            .in_sequence(&mut sequence);
+        let find_class_mock = jni_mock::find_class_context();
+        find_class_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, name| unsafe {
+                *env == raw_env_ptr
+                    && CStr::from_ptr(*name).to_str() == Ok("java/lang/NullPointerException")
+            })
+            .returning_st(move |_env, _name| raw_class)
+            .in_sequence(&mut sequence);
+        let get_method_id_mock = jni_mock::get_method_id_context();
+        get_method_id_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, class, name, signature| unsafe {
+                *env == raw_env_ptr
+                    && *class == raw_class
+                    && CStr::from_ptr(*name).to_str() == Ok("<init>")
+                    && CStr::from_ptr(*signature).to_str() == Ok("(Ljava/lang/String;)V")
+            })
+            .returning_st(move |_env, _class, _name, _signature| raw_method_id)
+            .in_sequence(&mut sequence);
+        let new_object_a_mock = jni_mock::new_object_a_context();
+        new_object_a_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, class, method_id, arguments| unsafe {
+                *env == raw_env_ptr
+                    && *class == raw_class
+                    && *method_id == raw_method_id
+                    && (**arguments).l == raw_string
+            })
+            .returning_st(move |_env, _class, _method_id, _arguments| raw_exception)
+            .in_sequence(&mut sequence);
+        let delete_local_ref_mock = jni_mock::delete_local_ref_context();
+        delete_local_ref_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object| *env == raw_env_ptr && *object == raw_class)
+            .return_const(())
+            .in_sequence(&mut sequence);
+        delete_local_ref_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object| *env == raw_env_ptr && *object == raw_string)
+            .return_const(())
+            .in_sequence(&mut sequence);
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let token = NoException::test(&env);
+        let exception = NullPointerException::with_message(&token, "test message").unwrap();
+        assert_eq!(unsafe { exception.raw_object() }.as_ptr(), raw_exception);
+        mem::forget(exception);
+    }
+}
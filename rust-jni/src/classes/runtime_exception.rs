@@ -0,0 +1,143 @@
+use crate::classes::exception::Exception;
+use crate::java_class::JavaClassExt;
+use crate::java_class::{FromObject, JavaClassSignature};
+use crate::object::Object;
+use crate::result::JavaResult;
+use crate::string::String;
+use crate::throwable::Throwable;
+use crate::token::NoException;
+
+/// A type representing a Java
+/// [`RuntimeException`](https://docs.oracle.com/javase/10/docs/api/java/lang/RuntimeException.html).
+#[derive(Debug, Clone)]
+pub struct RuntimeException<'env> {
+    pub(crate) object: Exception<'env>,
+}
+
+impl<'this> RuntimeException<'this> {
+    /// Create a new [`RuntimeException`](struct.RuntimeException.html).
+    ///
+    /// [`RuntimeException()` javadoc](https://docs.oracle.com/javase/10/docs/api/java/lang/RuntimeException.html#<init>())
+    pub fn new(token: &NoException<'this>) -> JavaResult<'this, RuntimeException<'this>> {
+        // Safe because we ensure correct arguments and return type.
+        unsafe { Self::call_constructor::<_, fn()>(token, ()) }
+    }
+
+    /// Create a new [`RuntimeException`](struct.RuntimeException.html) with
+    /// the specified detail message.
+    ///
+    /// [`RuntimeException(String)` javadoc](https://docs.oracle.com/javase/10/docs/api/java/lang/RuntimeException.html#<init>(java.lang.String))
+    pub fn with_message(
+        token: &NoException<'this>,
+        message: &str,
+    ) -> JavaResult<'this, RuntimeException<'this>> {
+        let message = String::new(token, message)?;
+        // Safe because we ensure correct arguments and return type.
+        unsafe { Self::call_constructor::<_, fn(&String)>(token, (Some(&message),)) }
+    }
+}
+
+/// Allow [`RuntimeException`](struct.RuntimeException.html) to be used in place of an
+/// [`Object`](struct.Object.html).
+impl<'env> ::std::ops::Deref for RuntimeException<'env> {
+    type Target = Object<'env>;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.object
+    }
+}
+
+impl<'env> AsRef<Object<'env>> for RuntimeException<'env> {
+    #[inline(always)]
+    fn as_ref(&self) -> &Object<'env> {
+        &self.object
+    }
+}
+
+impl<'env> AsRef<Throwable<'env>> for RuntimeException<'env> {
+    #[inline(always)]
+    fn as_ref(&self) -> &Throwable<'env> {
+        self.object.as_ref()
+    }
+}
+
+impl<'env> AsRef<Exception<'env>> for RuntimeException<'env> {
+    #[inline(always)]
+    fn as_ref(&self) -> &Exception<'env> {
+        &self.object
+    }
+}
+
+impl<'env> AsRef<RuntimeException<'env>> for RuntimeException<'env> {
+    #[inline(always)]
+    fn as_ref(&self) -> &RuntimeException<'env> {
+        self
+    }
+}
+
+impl<'a> From<RuntimeException<'a>> for Exception<'a> {
+    fn from(value: RuntimeException<'a>) -> Self {
+        value.object
+    }
+}
+
+impl<'a> From<RuntimeException<'a>> for Throwable<'a> {
+    fn from(value: RuntimeException<'a>) -> Self {
+        value.object.into()
+    }
+}
+
+impl<'a> From<RuntimeException<'a>> for Object<'a> {
+    fn from(value: RuntimeException<'a>) -> Self {
+        value.object.into()
+    }
+}
+
+impl<'env> FromObject<'env> for RuntimeException<'env> {
+    #[inline(always)]
+    unsafe fn from_object(object: Object<'env>) -> Self {
+        Self {
+            object: Exception::from_object(object),
+        }
+    }
+}
+
+impl JavaClassSignature for RuntimeException<'_> {
+    #[inline(always)]
+    fn signature() -> &'static str {
+        "Ljava/lang/RuntimeException;"
+    }
+}
+
+/// Allow comparing [`RuntimeException`](struct.RuntimeException.html)
+/// to Java objects. Java objects are compared by-reference to preserve
+/// original Java semantics. To compare objects by value, call the
+/// [`equals`](struct.Object.html#method.equals) method.
+///
+/// Will panic if there is a pending exception in the current thread.
+///
+/// This is mostly a convenience for using `assert_eq!()` in tests. Always prefer using
+/// [`is_same_as`](struct.Object.html#methods.is_same_as) to comparing with `==`, because
+/// the former checks for a pending exception in compile-time rather than the run-time.
+impl<'env, T> PartialEq<T> for RuntimeException<'env>
+where
+    T: AsRef<Object<'env>>,
+{
+    fn eq(&self, other: &T) -> bool {
+        Object::as_ref(self).eq(other.as_ref())
+    }
+}
+
+#[cfg(test)]
+mod runtime_exception_tests {
+    use super::*;
+
+    #[test]
+    fn signature() {
+        assert_eq!(
+            RuntimeException::signature(),
+            "Ljava/lang/RuntimeException;"
+        );
+    }
+}
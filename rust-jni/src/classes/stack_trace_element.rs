@@ -0,0 +1,74 @@
+use crate::java_class::{FromObject, JavaClassSignature};
+use crate::object::Object;
+
+/// A type representing a Java
+/// [`StackTraceElement`](https://docs.oracle.com/javase/10/docs/api/java/lang/StackTraceElement.html)
+/// -- a single stack frame of a [`Throwable`](struct.Throwable.html) stack trace.
+#[derive(Debug, Clone)]
+pub struct StackTraceElement<'env> {
+    object: Object<'env>,
+}
+
+/// Allow [`StackTraceElement`](struct.StackTraceElement.html) to be used in place of an
+/// [`Object`](struct.Object.html).
+impl<'env> ::std::ops::Deref for StackTraceElement<'env> {
+    type Target = Object<'env>;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.object
+    }
+}
+
+impl<'env> AsRef<Object<'env>> for StackTraceElement<'env> {
+    #[inline(always)]
+    fn as_ref(&self) -> &Object<'env> {
+        &self.object
+    }
+}
+
+impl<'env> AsRef<StackTraceElement<'env>> for StackTraceElement<'env> {
+    #[inline(always)]
+    fn as_ref(&self) -> &StackTraceElement<'env> {
+        self
+    }
+}
+
+impl<'a> From<StackTraceElement<'a>> for Object<'a> {
+    fn from(element: StackTraceElement<'a>) -> Self {
+        element.object
+    }
+}
+
+impl<'env> FromObject<'env> for StackTraceElement<'env> {
+    #[inline(always)]
+    unsafe fn from_object(object: Object<'env>) -> Self {
+        Self { object }
+    }
+}
+
+impl JavaClassSignature for StackTraceElement<'_> {
+    #[inline(always)]
+    fn signature() -> &'static str {
+        "Ljava/lang/StackTraceElement;"
+    }
+}
+
+/// Allow comparing [`StackTraceElement`](struct.StackTraceElement.html)
+/// to Java objects. Java objects are compared by-reference to preserve
+/// original Java semantics. To compare objects by value, call the
+/// [`equals`](struct.Object.html#method.equals) method.
+///
+/// Will panic if there is a pending exception in the current thread.
+///
+/// This is mostly a convenience for using `assert_eq!()` in tests. Always prefer using
+/// [`is_same_as`](struct.Object.html#methods.is_same_as) to comparing with `==`, because
+/// the former checks for a pending exception in compile-time rather than the run-time.
+impl<'env, T> PartialEq<T> for StackTraceElement<'env>
+where
+    T: AsRef<Object<'env>>,
+{
+    fn eq(&self, other: &T) -> bool {
+        Object::as_ref(self).eq(other.as_ref())
+    }
+}
@@ -1,5 +1,11 @@
-use crate::error::JniError;
-use crate::token::{ConsumedNoException, NoException};
+use crate::error::{JniError, TokenError};
+use crate::java_class::JavaClass;
+use crate::java_string::to_java_string;
+use crate::method_cache::MethodCache;
+use crate::object::Object;
+use crate::result::JavaResult;
+use crate::throwable::Throwable;
+use crate::token::{CallOutcome, ConsumedNoException, Exception, NoException};
 use crate::version::JniVersion;
 use crate::vm::JavaVMRef;
 use core::ptr::NonNull;
@@ -7,7 +13,9 @@ use jni_sys;
 use std;
 use std::cell::RefCell;
 use std::mem;
+use std::os::raw::c_char;
 use std::panic;
+use std::ptr;
 
 include!("call_jni_method.rs");
 
@@ -193,7 +201,10 @@ include!("call_jni_method.rs");
 pub struct JniEnv<'this> {
     vm: &'this JavaVMRef,
     jni_env: NonNull<jni_sys::JNIEnv>,
+    daemon: Option<bool>,
     pub(crate) has_token: RefCell<bool>,
+    pub(crate) batching: RefCell<bool>,
+    pub(crate) method_cache: RefCell<Option<MethodCache>>,
 }
 
 // [`JniEnv`](struct.JniEnv.html) can't be passed between threads.
@@ -218,6 +229,24 @@ impl<'this> JniEnv<'this> {
         self.jni_env
     }
 
+    /// Get the [`JavaVMRef`](struct.JavaVMRef.html) this environment belongs to.
+    #[inline(always)]
+    pub(crate) fn vm(&self) -> &'this JavaVMRef {
+        self.vm
+    }
+
+    /// Check if the current thread was attached to the Java VM as a daemon.
+    ///
+    /// Returns [`None`](https://doc.rust-lang.org/std/option/enum.Option.html#variant.None)
+    /// for a [`JniEnv`](struct.JniEnv.html) passed to a native method, as the way the thread
+    /// was attached is unknown in this case.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/invocation.html#attachcurrentthreadasdaemon)
+    #[inline(always)]
+    pub fn is_daemon(&self) -> Option<bool> {
+        self.daemon
+    }
+
     fn verify_token_not_borrowed(&self) {
         if !*self.has_token.borrow() {
             self.safe_panic(
@@ -229,13 +258,37 @@ impl<'this> JniEnv<'this> {
     /// Get a [`NoException`](struct.NoException.html) token indicating that there is no pending
     /// exception in this thread.
     ///
-    /// Panics when trying to obtain the token for the second time.
+    /// Panics when trying to obtain the token for the second time or when there is
+    /// a pending exception. Use [`try_token`](#method.try_token) to handle these cases.
     ///
     /// Read more about tokens in [`NoException`](struct.NoException.html) documentation.
     // TODO(#22): Return a token with the env if possible:
     // https://stackoverflow.com/questions/50891977/can-i-return-a-value-and-a-reference-to-it-from-a-function.
     pub fn token<'a>(&'a self) -> NoException<'a> {
-        self.verify_token_not_borrowed();
+        match self.try_token() {
+            Ok(token) => token,
+            Err(TokenError::AlreadyTaken) => self.safe_panic(
+                "Trying to obtain a second `NoException` token from the `JniEnv` value.",
+            ),
+            Err(TokenError::ExceptionPending) => self.safe_panic(
+                "Trying to obtain a `NoException` token when there is a pending exception.",
+            ),
+        }
+    }
+
+    /// Get a [`NoException`](struct.NoException.html) token indicating that there is no pending
+    /// exception in this thread.
+    ///
+    /// Returns [`TokenError::AlreadyTaken`](enum.TokenError.html#variant.AlreadyTaken)
+    /// when trying to obtain the token for the second time and
+    /// [`TokenError::ExceptionPending`](enum.TokenError.html#variant.ExceptionPending)
+    /// when there is a pending exception.
+    ///
+    /// Read more about tokens in [`NoException`](struct.NoException.html) documentation.
+    pub fn try_token<'a>(&'a self) -> Result<NoException<'a>, TokenError> {
+        if !*self.has_token.borrow() {
+            return Err(TokenError::AlreadyTaken);
+        }
 
         // Safe because:
         //  - We don't leak the [`Exception`](struct.Exception.html) token.
@@ -244,14 +297,10 @@ impl<'this> JniEnv<'this> {
         #[allow(unused_unsafe)]
         unsafe {
             match NoException::check_pending_exception(self) {
-                Err(_) => {
-                    self.safe_panic(
-                        "Trying to obtain a `NoException` token when there is a pending exception.",
-                    );
-                }
+                Err(_) => Err(TokenError::ExceptionPending),
                 Ok(token) => {
                     *self.has_token.borrow_mut() = false;
-                    token
+                    Ok(token)
                 }
             }
         }
@@ -282,11 +331,195 @@ impl<'this> JniEnv<'this> {
 
     /// Get JNI versoin.
     ///
+    /// Queries the Java VM, so the result reflects the JNI version the VM supports
+    /// rather than the version requested when attaching the thread, which may be lower.
+    ///
     /// [JNI documentation](https://docs.oracle.com/en/java/javase/11/docs/specs/jni/functions.html#getversion)
     pub fn version(&self) -> JniVersion {
         JniVersion::from_raw(unsafe { call_jni_method!(self, GetVersion) })
     }
 
+    /// Enable caching of method IDs for all Java method calls made with this
+    /// [`JniEnv`](struct.JniEnv.html).
+    ///
+    /// Every Java method call needs to look up the method ID by the method name and signature,
+    /// which is slow compared to the call itself. With the cache enabled, the lookup only happens
+    /// on the first call of a method of a class. This is useful when calling the same methods
+    /// many times.
+    ///
+    /// Cached method IDs hold global references to their classes, so the classes can't be
+    /// unloaded while their method IDs are cached. Call
+    /// [`clear_method_cache`](#method.clear_method_cache) when the cached classes are no longer
+    /// needed and can be unloaded. The cache is also cleared when the
+    /// [`JniEnv`](struct.JniEnv.html) is detached or dropped.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/design.html#accessing-fields-and-methods)
+    pub fn enable_method_cache(&self) {
+        let mut cache = self.method_cache.borrow_mut();
+        if cache.is_none() {
+            *cache = Some(MethodCache::default());
+        }
+    }
+
+    /// Remove all method IDs from the method cache, allowing their classes to be unloaded.
+    ///
+    /// The cache stays enabled if it was enabled.
+    pub fn clear_method_cache(&self) {
+        if let Some(cache) = self.method_cache.borrow_mut().as_mut() {
+            // Safe because all cached references were created with this `JniEnv`.
+            unsafe { cache.clear(self) };
+        }
+    }
+
+    /// Ensure that at least `capacity` local references can be created in the current thread.
+    ///
+    /// The VM is only guaranteed to support 16 local references by default and may warn
+    /// about creating more, so reserve the capacity before creating many local references.
+    ///
+    /// Throws `OutOfMemoryError` if the capacity could not be reserved.
+    /// Panics if `capacity` doesn't fit into a `jint`.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#ensurelocalcapacity)
+    pub fn ensure_local_capacity(
+        &self,
+        token: &NoException<'this>,
+        capacity: usize,
+    ) -> JavaResult<'this, ()> {
+        let capacity = jni_sys::jint::try_from(capacity)
+            .expect("Local reference capacity doesn't fit into a `jint`.");
+        token.with_owned(|token| {
+            // Safe because the argument is ensured to be the correct by construction.
+            let status = unsafe { call_jni_method!(token.env(), EnsureLocalCapacity, capacity) };
+            if status == jni_sys::JNI_OK {
+                CallOutcome::Ok(((), token))
+            } else {
+                // Safe because `EnsureLocalCapacity` throws an exception when it fails.
+                CallOutcome::Err(unsafe { token.exchange() })
+            }
+        })
+    }
+
+    /// Run a closure in a new local reference frame, which can hold at least `capacity`
+    /// local references.
+    ///
+    /// All local references created inside the frame are freed when the closure returns,
+    /// so this is useful for native code that creates many temporary objects. The outer token is
+    /// borrowed for the whole call and the closure receives a token scoped to the frame instead,
+    /// so local references created inside the frame can't outlive it. The result of the closure
+    /// can't hold local references, use
+    /// [`with_local_frame_returning`](#method.with_local_frame_returning) to return an object
+    /// from the frame. An exception thrown by the closure is moved out of the frame.
+    ///
+    /// Throws `OutOfMemoryError` if the frame could not be created.
+    /// Panics if `capacity` doesn't fit into a `jint`.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#pushlocalframe)
+    pub fn with_local_frame<T: 'static>(
+        &self,
+        token: &mut NoException<'this>,
+        capacity: usize,
+        callback: impl for<'frame> FnOnce(&NoException<'frame>) -> JavaResult<'frame, T>,
+    ) -> JavaResult<'this, T> {
+        let frame = LocalFrame::push(token, capacity)?;
+        match callback(token) {
+            Ok(result) => {
+                mem::drop(frame);
+                Ok(result)
+            }
+            // Safe because the exception is a `Throwable`.
+            Err(throwable) => Err(unsafe { frame.pop_with(token, throwable) }),
+        }
+    }
+
+    /// Run a closure in a new local reference frame, which can hold at least `capacity`
+    /// local references, and move the object returned by the closure out of the frame.
+    ///
+    /// All other local references created inside the frame are freed when the closure returns.
+    /// The outer token is borrowed for the whole call and the closure receives a token scoped
+    /// to the frame instead, so the returned object is the only one that can leave it.
+    /// An exception thrown by the closure is moved out of the frame.
+    ///
+    /// Throws `OutOfMemoryError` if the frame could not be created.
+    /// Panics if `capacity` doesn't fit into a `jint`.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#poplocalframe)
+    pub fn with_local_frame_returning(
+        &self,
+        token: &mut NoException<'this>,
+        capacity: usize,
+        callback: impl for<'frame> FnOnce(
+            &NoException<'frame>,
+        ) -> JavaResult<'frame, Option<Object<'frame>>>,
+    ) -> JavaResult<'this, Option<Object<'this>>> {
+        let frame = LocalFrame::push(token, capacity)?;
+        match callback(token) {
+            Ok(None) => {
+                mem::drop(frame);
+                Ok(None)
+            }
+            // Safe because the result is an `Object`.
+            Ok(Some(result)) => Ok(Some(unsafe { frame.pop_with(token, result) })),
+            // Safe because the exception is a `Throwable`.
+            Err(throwable) => Err(unsafe { frame.pop_with(token, throwable) }),
+        }
+    }
+
+    /// Get and clear the pending exception in the current thread, if there is one.
+    ///
+    /// This is a low-level escape hatch for native method implementations, which receive
+    /// a [`JniEnv`](struct.JniEnv.html) that can have an exception pending and might need
+    /// to inspect it without obtaining a token first. Prefer the
+    /// [`NoException`](struct.NoException.html) and [`Exception`](struct.Exception.html)
+    /// tokens in all other cases.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#exceptionoccurred)
+    pub fn take_exception<'a>(&'a self) -> Option<Throwable<'a>> {
+        // Safe because the argument is ensured to be the correct by construction.
+        let raw_throwable = NonNull::new(unsafe { call_jni_method!(self, ExceptionOccurred) })?;
+        // Safe because the argument is ensured to be the correct by construction.
+        unsafe { call_jni_method!(self, ExceptionClear) };
+        // Safe because `ExceptionOccurred` returns a valid `Throwable` reference.
+        Some(unsafe { Throwable::from_raw(self, raw_throwable) })
+    }
+
+    /// Throw an existing exception.
+    ///
+    /// The `throwable` wrapper is dropped after the call, which deletes its local reference.
+    /// Only the pending exception keeps referencing the object.
+    ///
+    /// Unlike [`Throwable::throw`](java/lang/struct.Throwable.html#method.throw), never panics:
+    /// the thread has to be assumed to be in an exception state after an attempt to throw,
+    /// so an [`Exception`](struct.Exception.html) token is returned even if `Throw` reports
+    /// an error.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#throw)
+    pub fn throw<'env, 'token>(
+        &self,
+        throwable: Throwable<'env>,
+        token: NoException<'token>,
+    ) -> Exception<'token>
+    where
+        'env: 'token,
+    {
+        // Safe because the argument is ensured to be the correct by construction.
+        let _status = unsafe { call_jni_method!(self, Throw, throwable.raw_object().as_ptr()) };
+        // Safe because we've just thrown an exception.
+        unsafe { token.exchange() }
+    }
+
+    /// Report an unrecoverable error and abort the Java VM.
+    ///
+    /// Native methods should call this instead of panicking when they detect a failure they
+    /// can't recover from, like corrupted state, as unwinding across the FFI boundary is
+    /// undefined behaviour. Can be called with a pending exception.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#fatalerror)
+    pub fn fatal_error(&self, message: &str) -> ! {
+        let message = to_java_string(message);
+        // Safe because the argument is ensured to be the correct by construction.
+        unsafe { call_jni_method!(self, FatalError, message.as_ptr() as *const c_char) }
+    }
+
     /// Detach current thread.
     ///
     /// Calling this method consumes [`JniEnv`](struct.JniEnv.html). Detaching the thread is not allowed
@@ -297,6 +530,7 @@ impl<'this> JniEnv<'this> {
     ///
     /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/invocation.html#detachcurrentthread)
     pub fn detach(self, _token: ConsumedNoException) -> Option<JniError> {
+        self.clear_method_cache();
         // Safe because all JNI arguments are correct by construction.
         let result = unsafe { self.vm.detach() };
         mem::forget(self);
@@ -310,15 +544,20 @@ impl<'this> JniEnv<'this> {
         JniEnv {
             vm,
             jni_env,
+            daemon: None,
             has_token: RefCell::new(true),
+            batching: RefCell::new(false),
+            method_cache: RefCell::new(None),
         }
     }
 
     pub(crate) unsafe fn attached<'vm: 'env, 'env>(
         vm: &'vm JavaVMRef,
         jni_env: NonNull<jni_sys::JNIEnv>,
+        daemon: bool,
     ) -> JniEnv<'env> {
-        let env = JniEnv::new(vm, jni_env);
+        let mut env = JniEnv::new(vm, jni_env);
+        env.daemon = Some(daemon);
         // Safe because we are not leaking the tokens anywhere.
         #[allow(unused_unsafe)]
         let exception_pending = unsafe { NoException::check_pending_exception(&env).is_err() };
@@ -341,7 +580,10 @@ impl<'this> JniEnv<'this> {
             vm: &vm,
             // It's fine if the env is null in unit tests as they don't call the actual JNI API.
             jni_env: unsafe { NonNull::new_unchecked(ptr) },
+            daemon: None,
             has_token: RefCell::new(true),
+            batching: RefCell::new(false),
+            method_cache: RefCell::new(None),
         }
     }
 
@@ -351,6 +593,58 @@ impl<'this> JniEnv<'this> {
     }
 }
 
+/// A pushed local reference frame. Pops the frame when dropped, so that the frame is popped
+/// even if the code running in it panics.
+struct LocalFrame<'env> {
+    env: &'env JniEnv<'env>,
+}
+
+impl<'env> LocalFrame<'env> {
+    fn push(token: &NoException<'env>, capacity: usize) -> JavaResult<'env, Self> {
+        let capacity = jni_sys::jint::try_from(capacity)
+            .expect("Local reference capacity doesn't fit into a `jint`.");
+        token.with_owned(|token| {
+            // Safe because the argument is ensured to be the correct by construction.
+            let status = unsafe { call_jni_method!(token.env(), PushLocalFrame, capacity) };
+            if status == jni_sys::JNI_OK {
+                CallOutcome::Ok((LocalFrame { env: token.env() }, token))
+            } else {
+                // Safe because `PushLocalFrame` throws an exception when it fails.
+                CallOutcome::Err(unsafe { token.exchange() })
+            }
+        })
+    }
+
+    /// Pop the frame and move `object` out of it into the previous frame.
+    ///
+    /// Unsafe because `object` must be an instance of `S`.
+    unsafe fn pop_with<'frame, S: JavaClass<'env>>(
+        self,
+        token: &NoException<'env>,
+        object: impl JavaClass<'frame>,
+    ) -> S {
+        let env = self.env;
+        mem::forget(self);
+        let object: Object<'frame> = object.into();
+        // Safe because the arguments are ensured to be the correct by construction.
+        let raw_object =
+            unsafe { call_jni_method!(env, PopLocalFrame, object.raw_object().as_ptr()) };
+        // The local reference in the popped frame has already been freed.
+        mem::forget(object);
+        // Should not fail: `PopLocalFrame` returns a new reference to a non-null object.
+        let raw_object = NonNull::new(raw_object).unwrap();
+        // Safe because the new reference references the same object.
+        unsafe { S::from_object(Object::from_raw(token.env(), raw_object)) }
+    }
+}
+
+impl<'env> Drop for LocalFrame<'env> {
+    fn drop(&mut self) {
+        // Safe because the argument is ensured to be the correct by construction.
+        unsafe { call_jni_method!(self.env, PopLocalFrame, ptr::null_mut()) };
+    }
+}
+
 /// [`Drop`](https://doc.rust-lang.org/std/ops/trait.Drop.html) detaches the current thread from the Java VM.
 /// It's not safe to do so with an exception pending, so it panics if this happens.
 ///
@@ -371,6 +665,7 @@ impl<'vm> Drop for JniEnv<'vm> {
                  exception by unwrapping the exception token before dropping it.",
             );
         }
+        self.clear_method_cache();
         // Safe because the current thread is guaranteed to be attached and the argument is correct.
         unsafe {
             let error = self.vm.detach();
@@ -391,7 +686,12 @@ mod jni_env_tests {
     use super::*;
     use mockall::*;
     use serial_test::serial;
+    use std::env::{current_exe, var_os};
+    use std::ffi::CStr;
     use std::mem::ManuallyDrop;
+    use std::process::Command;
+
+    const FATAL_ERROR_CHILD: &str = "RUST_JNI_FATAL_ERROR_CHILD";
 
     generate_java_vm_mock!(mock);
     generate_jni_env_mock!(jni_mock);
@@ -431,6 +731,52 @@ mod jni_env_tests {
         assert_eq!(env.version(), JniVersion::V4);
     }
 
+    #[test]
+    #[serial]
+    fn version_v10() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let get_version_mock = jni_mock::get_version_context();
+        get_version_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env| *env == raw_env_ptr)
+            .return_const(JniVersion::V10.to_raw());
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        assert_eq!(env.version(), JniVersion::V10);
+    }
+
+    #[test]
+    #[serial]
+    fn fatal_error() {
+        // `fatal_error` aborts the process, so call it in a child test process.
+        if var_os(FATAL_ERROR_CHILD).is_some() {
+            let raw_env = jni_mock::raw_jni_env();
+            let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+            let fatal_error_mock = jni_mock::fatal_error_context();
+            fatal_error_mock
+                .expect()
+                .times(1)
+                .withf_st(move |env, message| {
+                    *env == raw_env_ptr
+                        && unsafe { CStr::from_ptr(*message) }.to_str() == Ok("test message")
+                })
+                .return_const(());
+            let vm = JavaVMRef::test_default();
+            let env = JniEnv::test(&vm, raw_env_ptr);
+            env.fatal_error("test message");
+        }
+        let output = Command::new(current_exe().unwrap())
+            .args(["--exact", "env::jni_env_tests::fatal_error", "--nocapture"])
+            .env(FATAL_ERROR_CHILD, "1")
+            .output()
+            .unwrap();
+        assert!(!output.status.success());
+        assert!(std::string::String::from_utf8_lossy(&output.stderr)
+            .contains("FatalError: test message"));
+    }
+
     #[test]
     #[serial]
     fn detach() {
@@ -610,4 +956,396 @@ mod jni_env_tests {
         let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
         env.token();
     }
+
+    #[test]
+    #[serial]
+    fn try_token() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let exception_check_mock = jni_mock::exception_check_context();
+        exception_check_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env| *env == raw_env_ptr)
+            .return_const(jni_sys::JNI_FALSE);
+        let raw_java_vm_ptr = 0x1234 as *mut jni_sys::JavaVM;
+        let vm = JavaVMRef::test(raw_java_vm_ptr);
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        assert!(env.try_token().is_ok());
+        assert_eq!(env.has_token, RefCell::new(false));
+    }
+
+    #[test]
+    #[serial]
+    fn try_token_twice() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let exception_check_mock = jni_mock::exception_check_context();
+        exception_check_mock
+            .expect()
+            .times(1)
+            .return_const(jni_sys::JNI_FALSE);
+        let raw_java_vm_ptr = 0x1234 as *mut jni_sys::JavaVM;
+        let vm = JavaVMRef::test(raw_java_vm_ptr);
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        assert!(env.try_token().is_ok());
+        assert_eq!(env.try_token().map(|_| ()), Err(TokenError::AlreadyTaken));
+    }
+
+    #[test]
+    #[serial]
+    fn try_token_pending_exception() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let exception_check_mock = jni_mock::exception_check_context();
+        exception_check_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env| *env == raw_env_ptr)
+            .return_const(jni_sys::JNI_TRUE);
+        let raw_java_vm_ptr = 0x1234 as *mut jni_sys::JavaVM;
+        let vm = JavaVMRef::test(raw_java_vm_ptr);
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        assert_eq!(
+            env.try_token().map(|_| ()),
+            Err(TokenError::ExceptionPending)
+        );
+        assert_eq!(env.has_token, RefCell::new(true));
+    }
+
+    #[test]
+    #[serial]
+    fn take_exception_none() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let exception_occured_mock = jni_mock::exception_occured_context();
+        exception_occured_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env| *env == raw_env_ptr)
+            .returning_st(|_env| ptr::null_mut());
+        let exception_clear_mock = jni_mock::exception_clear_context();
+        exception_clear_mock.expect().times(0);
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        assert!(env.take_exception().is_none());
+    }
+
+    #[test]
+    #[serial]
+    fn take_exception() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_throwable = 0x1234 as jni_sys::jthrowable;
+        let mut sequence = Sequence::new();
+        let exception_occured_mock = jni_mock::exception_occured_context();
+        exception_occured_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env| *env == raw_env_ptr)
+            .returning_st(move |_env| raw_throwable)
+            .in_sequence(&mut sequence);
+        let exception_clear_mock = jni_mock::exception_clear_context();
+        exception_clear_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env| *env == raw_env_ptr)
+            .return_const(())
+            .in_sequence(&mut sequence);
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let throwable = env.take_exception().unwrap();
+        assert_eq!(unsafe { throwable.raw_object() }.as_ptr(), raw_throwable);
+        mem::forget(throwable);
+    }
+
+    fn throw_with_status(status: jni_sys::jint) {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_throwable = 0x1234 as jni_sys::jthrowable;
+        let mut sequence = Sequence::new();
+        let throw_mock = jni_mock::throw_context();
+        throw_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, throwable| *env == raw_env_ptr && *throwable == raw_throwable)
+            .return_const(status)
+            .in_sequence(&mut sequence);
+        let delete_local_ref_mock = jni_mock::delete_local_ref_context();
+        delete_local_ref_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object| *env == raw_env_ptr && *object == raw_throwable)
+            .return_const(())
+            .in_sequence(&mut sequence);
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let token = NoException::test(&env);
+        let throwable = unsafe { Throwable::from_raw(&env, NonNull::new(raw_throwable).unwrap()) };
+        let _exception = env.throw(throwable, token);
+    }
+
+    #[test]
+    #[serial]
+    fn throw() {
+        throw_with_status(jni_sys::JNI_OK);
+    }
+
+    #[test]
+    #[serial]
+    fn throw_error() {
+        throw_with_status(jni_sys::JNI_ERR);
+    }
+
+    #[test]
+    #[serial]
+    fn with_local_frame() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let mut sequence = Sequence::new();
+        let push_local_frame_mock = jni_mock::push_local_frame_context();
+        push_local_frame_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, capacity| *env == raw_env_ptr && *capacity == 16)
+            .return_const(jni_sys::JNI_OK)
+            .in_sequence(&mut sequence);
+        let pop_local_frame_mock = jni_mock::pop_local_frame_context();
+        pop_local_frame_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, result| *env == raw_env_ptr && result.is_null())
+            .returning_st(|_env, _result| ptr::null_mut())
+            .in_sequence(&mut sequence);
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let mut token = NoException::test(&env);
+        assert_eq!(
+            env.with_local_frame(&mut token, 16, |_token| Ok(17))
+                .unwrap(),
+            17
+        );
+    }
+
+    #[test]
+    #[serial]
+    fn with_local_frame_push_error() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_throwable = 0x1234 as jni_sys::jthrowable;
+        let mut sequence = Sequence::new();
+        let push_local_frame_mock = jni_mock::push_local_frame_context();
+        push_local_frame_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, capacity| *env == raw_env_ptr && *capacity == 16)
+            .return_const(jni_sys::JNI_ERR)
+            .in_sequence(&mut sequence);
+        let exception_occured_mock = jni_mock::exception_occured_context();
+        exception_occured_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env| *env == raw_env_ptr)
+            .returning_st(move |_env| raw_throwable)
+            .in_sequence(&mut sequence);
+        let exception_clear_mock = jni_mock::exception_clear_context();
+        exception_clear_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env| *env == raw_env_ptr)
+            .return_const(())
+            .in_sequence(&mut sequence);
+        // No frame was pushed, so no frame must be popped.
+        let pop_local_frame_mock = jni_mock::pop_local_frame_context();
+        pop_local_frame_mock.expect().times(0);
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let mut token = NoException::test(&env);
+        let throwable = env
+            .with_local_frame(&mut token, 16, |_token| -> JavaResult<()> {
+                panic!("Should not run the closure.")
+            })
+            .unwrap_err();
+        assert_eq!(unsafe { throwable.raw_object() }.as_ptr(), raw_throwable);
+        mem::forget(throwable);
+    }
+
+    #[test]
+    #[serial]
+    #[should_panic(expected = "Local reference capacity doesn't fit into a `jint`.")]
+    fn with_local_frame_overflow() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let mut token = NoException::test(&env);
+        let _ = env.with_local_frame(&mut token, usize::MAX, |_token| Ok(()));
+    }
+
+    #[test]
+    #[serial]
+    fn with_local_frame_exception() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_throwable = 0x1234 as jni_sys::jthrowable;
+        let raw_result = 0x5678 as jni_sys::jthrowable;
+        let mut sequence = Sequence::new();
+        let push_local_frame_mock = jni_mock::push_local_frame_context();
+        push_local_frame_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, capacity| *env == raw_env_ptr && *capacity == 16)
+            .return_const(jni_sys::JNI_OK)
+            .in_sequence(&mut sequence);
+        let pop_local_frame_mock = jni_mock::pop_local_frame_context();
+        pop_local_frame_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, result| *env == raw_env_ptr && *result == raw_throwable)
+            .returning_st(move |_env, _result| raw_result)
+            .in_sequence(&mut sequence);
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let mut token = NoException::test(&env);
+        let throwable = env
+            .with_local_frame(&mut token, 16, |token| -> JavaResult<()> {
+                Err(unsafe {
+                    Throwable::from_raw(token.env(), NonNull::new(raw_throwable).unwrap())
+                })
+            })
+            .unwrap_err();
+        assert_eq!(unsafe { throwable.raw_object() }.as_ptr(), raw_result);
+        mem::forget(throwable);
+    }
+
+    #[test]
+    #[serial]
+    fn with_local_frame_returning() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_object = 0x1234 as jni_sys::jobject;
+        let raw_result = 0x5678 as jni_sys::jobject;
+        let mut sequence = Sequence::new();
+        let push_local_frame_mock = jni_mock::push_local_frame_context();
+        push_local_frame_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, capacity| *env == raw_env_ptr && *capacity == 16)
+            .return_const(jni_sys::JNI_OK)
+            .in_sequence(&mut sequence);
+        let pop_local_frame_mock = jni_mock::pop_local_frame_context();
+        pop_local_frame_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, result| *env == raw_env_ptr && *result == raw_object)
+            .returning_st(move |_env, _result| raw_result)
+            .in_sequence(&mut sequence);
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let mut token = NoException::test(&env);
+        let object = env
+            .with_local_frame_returning(&mut token, 16, |token| {
+                Ok(Some(unsafe {
+                    Object::from_raw(token.env(), NonNull::new(raw_object).unwrap())
+                }))
+            })
+            .unwrap()
+            .unwrap();
+        assert_eq!(unsafe { object.raw_object() }.as_ptr(), raw_result);
+        mem::forget(object);
+    }
+
+    #[test]
+    #[serial]
+    fn with_local_frame_returning_none() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let mut sequence = Sequence::new();
+        let push_local_frame_mock = jni_mock::push_local_frame_context();
+        push_local_frame_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, capacity| *env == raw_env_ptr && *capacity == 16)
+            .return_const(jni_sys::JNI_OK)
+            .in_sequence(&mut sequence);
+        let pop_local_frame_mock = jni_mock::pop_local_frame_context();
+        pop_local_frame_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, result| *env == raw_env_ptr && result.is_null())
+            .returning_st(|_env, _result| ptr::null_mut())
+            .in_sequence(&mut sequence);
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let mut token = NoException::test(&env);
+        assert!(env
+            .with_local_frame_returning(&mut token, 16, |_token| Ok(None))
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    #[serial]
+    fn ensure_local_capacity() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let ensure_local_capacity_mock = jni_mock::ensure_local_capacity_context();
+        ensure_local_capacity_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, capacity| *env == raw_env_ptr && *capacity == 100)
+            .return_const(jni_sys::JNI_OK);
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let token = NoException::test(&env);
+        assert!(env.ensure_local_capacity(&token, 100).is_ok());
+    }
+
+    #[test]
+    #[serial]
+    #[should_panic(expected = "Local reference capacity doesn't fit into a `jint`.")]
+    fn ensure_local_capacity_overflow() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let token = NoException::test(&env);
+        let _ = env.ensure_local_capacity(&token, usize::MAX);
+    }
+
+    #[test]
+    #[serial]
+    fn ensure_local_capacity_error() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_throwable = 0x1234 as jni_sys::jthrowable;
+        let mut sequence = Sequence::new();
+        let ensure_local_capacity_mock = jni_mock::ensure_local_capacity_context();
+        ensure_local_capacity_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, capacity| *env == raw_env_ptr && *capacity == 100)
+            .return_const(jni_sys::JNI_ENOMEM)
+            .in_sequence(&mut sequence);
+        let exception_occured_mock = jni_mock::exception_occured_context();
+        exception_occured_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env| *env == raw_env_ptr)
+            .returning_st(move |_env| raw_throwable)
+            .in_sequence(&mut sequence);
+        let exception_clear_mock = jni_mock::exception_clear_context();
+        exception_clear_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env| *env == raw_env_ptr)
+            .return_const(())
+            .in_sequence(&mut sequence);
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let token = NoException::test(&env);
+        let throwable = env.ensure_local_capacity(&token, 100).unwrap_err();
+        assert_eq!(unsafe { throwable.raw_object() }.as_ptr(), raw_throwable);
+        mem::forget(throwable);
+    }
 }
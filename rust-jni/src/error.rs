@@ -1,3 +1,6 @@
+use std::error::Error;
+use std::fmt;
+
 /// Errors returned by JNI function.
 ///
 /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#return-codes)
@@ -20,6 +23,9 @@ pub enum JniError {
     VmExists,
     /// Returned when passing invalid arguments to JNI calls.
     InvalidArguments,
+    /// Returned by [`rust-jni`](index.html) when an operation is not allowed
+    /// because there is a pending exception in the current thread.
+    PendingException,
 }
 
 impl JniError {
@@ -37,6 +43,58 @@ impl JniError {
     }
 }
 
+impl fmt::Display for JniError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            JniError::Unknown(error) => write!(formatter, "Unknown JNI error: {}", error),
+            JniError::ThreadDetached => {
+                write!(formatter, "The current thread is not attached to a Java VM")
+            }
+            JniError::UnsupportedVersion => write!(formatter, "Unsupported JNI version"),
+            JniError::NotEnoughMemory => write!(formatter, "Not enough memory"),
+            JniError::VmExists => write!(formatter, "A Java VM already exists in this process"),
+            JniError::InvalidArguments => write!(formatter, "Invalid arguments"),
+            JniError::PendingException => {
+                write!(
+                    formatter,
+                    "There is a pending exception in the current thread"
+                )
+            }
+        }
+    }
+}
+
+impl Error for JniError {}
+
+/// Errors returned when obtaining a [`NoException`](struct.NoException.html) token
+/// with [`JniEnv::try_token`](struct.JniEnv.html#method.try_token).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenError {
+    /// Returned when the token was already taken from the [`JniEnv`](struct.JniEnv.html).
+    AlreadyTaken,
+    /// Returned when there is a pending exception in the current thread.
+    ExceptionPending,
+}
+
+impl fmt::Display for TokenError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TokenError::AlreadyTaken => write!(
+                formatter,
+                "The `NoException` token was already taken from the `JniEnv`"
+            ),
+            TokenError::ExceptionPending => {
+                write!(
+                    formatter,
+                    "There is a pending exception in the current thread"
+                )
+            }
+        }
+    }
+}
+
+impl Error for TokenError {}
+
 #[cfg(test)]
 mod from_raw_tests {
     use super::*;
@@ -73,5 +131,58 @@ mod from_raw_tests {
     #[test]
     fn from_raw_unknown_error() {
         assert_eq!(JniError::from_raw(7), Some(JniError::Unknown(7)));
+        assert_eq!(
+            JniError::from_raw(jni_sys::JNI_ERR),
+            Some(JniError::Unknown(jni_sys::JNI_ERR))
+        );
+    }
+}
+
+#[cfg(test)]
+mod display_tests {
+    use super::*;
+
+    #[test]
+    fn display() {
+        assert_eq!(JniError::Unknown(-1).to_string(), "Unknown JNI error: -1");
+        assert_eq!(
+            JniError::ThreadDetached.to_string(),
+            "The current thread is not attached to a Java VM"
+        );
+        assert_eq!(
+            JniError::UnsupportedVersion.to_string(),
+            "Unsupported JNI version"
+        );
+        assert_eq!(JniError::NotEnoughMemory.to_string(), "Not enough memory");
+        assert_eq!(
+            JniError::VmExists.to_string(),
+            "A Java VM already exists in this process"
+        );
+        assert_eq!(JniError::InvalidArguments.to_string(), "Invalid arguments");
+        assert_eq!(
+            JniError::PendingException.to_string(),
+            "There is a pending exception in the current thread"
+        );
+    }
+
+    #[test]
+    fn display_token_error() {
+        assert_eq!(
+            TokenError::AlreadyTaken.to_string(),
+            "The `NoException` token was already taken from the `JniEnv`"
+        );
+        assert_eq!(
+            TokenError::ExceptionPending.to_string(),
+            "There is a pending exception in the current thread"
+        );
+    }
+
+    #[test]
+    fn error() {
+        let error: Box<dyn Error> = Box::new(JniError::VmExists);
+        assert_eq!(
+            error.to_string(),
+            "A Java VM already exists in this process"
+        );
     }
 }
@@ -0,0 +1,391 @@
+use crate::class::Class;
+use crate::java_class::{JavaClass, JavaClassRef, JavaClassSignature};
+use crate::object::Object;
+use crate::result::JavaResult;
+use crate::token::{CallOutcome, NoException};
+use crate::version::JniVersion;
+use crate::vm::JavaVMRef;
+use core::ptr::NonNull;
+use std::fmt;
+use std::marker::PhantomData;
+
+include!("call_jni_method.rs");
+
+/// A global reference to a Java object.
+///
+/// Unlike Java class wrappers, a [`GlobalRef`](struct.GlobalRef.html) is not bound to a
+/// [`JniEnv`](struct.JniEnv.html) and can be stored for as long as needed and passed between threads.
+///
+/// The type parameter is the Java class wrapper type of the referenced object, e.g.
+/// `GlobalRef<Object<'static>>`. It is only used to check the class signature of the object.
+///
+/// Example:
+/// ```
+/// # use rust_jni::*;
+/// # use rust_jni::java::lang::String;
+/// #
+/// # fn jni_main<'a>(token: NoException<'a>) -> JavaResult<'a, NoException<'a>> {
+/// let string = String::new(&token, "test")?;
+/// let global: GlobalRef<String<'static>> = GlobalRef::new(&token, &string)?;
+/// let string = global.with::<String>(&token)?;
+/// assert_eq!(string.as_string(&token), "test");
+/// # Ok(token)
+/// # }
+/// #
+/// # #[cfg(feature = "libjvm")]
+/// # fn main() {
+/// #     let init_arguments = InitArguments::default();
+/// #     let vm = JavaVM::create(&init_arguments).unwrap();
+/// #     let _ = vm.with_attached(
+/// #        &AttachArguments::new(init_arguments.version()),
+/// #        |token: NoException| {
+/// #            ((), jni_main(token).unwrap())
+/// #        },
+/// #     );
+/// # }
+/// #
+/// # #[cfg(not(feature = "libjvm"))]
+/// # fn main() {}
+/// ```
+///
+/// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#global-and-local-references)
+pub struct GlobalRef<T> {
+    vm: JavaVMRef,
+    version: JniVersion,
+    raw_object: NonNull<jni_sys::_jobject>,
+    _type: PhantomData<fn() -> T>,
+}
+
+/// Make [`GlobalRef`](struct.GlobalRef.html) sendable between threads.
+/// Guaranteed to be safe by JNI.
+///
+/// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#global-references)
+unsafe impl<T> Send for GlobalRef<T> {}
+
+/// Make [`GlobalRef`](struct.GlobalRef.html) shareable by multiple threads.
+/// Guaranteed to be safe by JNI.
+///
+/// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#global-references)
+unsafe impl<T> Sync for GlobalRef<T> {}
+
+impl<T> GlobalRef<T>
+where
+    T: JavaClassSignature,
+{
+    /// Create a new global reference to a Java object.
+    ///
+    /// Panics if the class of the object doesn't match `T`.
+    /// Any object can be referenced as a `GlobalRef<Object<'static>>`.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#newglobalref)
+    pub fn new<'a, S>(token: &NoException<'a>, object: &S) -> JavaResult<'a, Self>
+    where
+        S: JavaClassRef<'a>,
+    {
+        if T::signature() != S::signature() && T::signature() != Object::signature() {
+            panic!(
+                "Can't create a global reference of type {} to an object of type {}.",
+                T::signature(),
+                S::signature()
+            );
+        }
+        // Safe because arguments are ensured to be the correct by construction and because
+        // `NewGlobalRef` throws an exception before returning `null`.
+        let raw_object = unsafe {
+            call_nullable_jni_method!(token, NewGlobalRef, object.as_ref().raw_object().as_ptr())?
+        };
+        #[cfg(test)]
+        crate::testing::track_references(1);
+        Ok(Self {
+            vm: *token.env().vm(),
+            version: token.env().version(),
+            raw_object,
+            _type: PhantomData,
+        })
+    }
+
+    /// Create a local reference to the referenced Java object bound to the token's
+    /// [`JniEnv`](struct.JniEnv.html).
+    ///
+    /// Panics if the class `S` doesn't match `T` or if the [`JniEnv`](struct.JniEnv.html)
+    /// belongs to a different Java VM.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#newlocalref)
+    pub fn with<'a, S>(&self, token: &NoException<'a>) -> JavaResult<'a, S>
+    where
+        S: JavaClass<'a>,
+    {
+        if T::signature() != S::signature() && S::signature() != Object::signature() {
+            panic!(
+                "Can't view a global reference of type {} as an object of type {}.",
+                T::signature(),
+                S::signature()
+            );
+        }
+        if *token.env().vm() != self.vm {
+            panic!("Trying to use a global reference with a JniEnv of a different Java VM.");
+        }
+        // Safe because arguments are ensured to be the correct by construction and because
+        // `NewLocalRef` throws an exception before returning `null`.
+        let raw_object =
+            unsafe { call_nullable_jni_method!(token, NewLocalRef, self.raw_object.as_ptr())? };
+        // Safe because the class of the object was checked above.
+        Ok(unsafe { S::from_object(Object::from_raw(token.env(), raw_object)) })
+    }
+}
+
+impl GlobalRef<Object<'static>> {
+    /// Create a global reference of a more specific type to the referenced Java object.
+    ///
+    /// Returns `None` if the object is not an instance of the class `T`.
+    ///
+    /// Panics if the [`JniEnv`](struct.JniEnv.html) belongs to a different Java VM.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#newglobalref)
+    pub fn downcast_global<'a, T>(
+        &self,
+        token: &NoException<'a>,
+    ) -> JavaResult<'a, Option<GlobalRef<T>>>
+    where
+        T: JavaClassSignature,
+    {
+        let object = self.with::<Object>(token)?;
+        let signature = T::signature();
+        let class_name = signature
+            .strip_prefix('L')
+            .and_then(|name| name.strip_suffix(';'))
+            .unwrap_or(signature);
+        if !object.is_instance_of(token, &Class::find(token, class_name)?) {
+            return Ok(None);
+        }
+        // Safe because arguments are ensured to be the correct by construction and because
+        // `NewGlobalRef` throws an exception before returning `null`.
+        let raw_object = unsafe {
+            call_nullable_jni_method!(token, NewGlobalRef, object.raw_object().as_ptr())?
+        };
+        #[cfg(test)]
+        crate::testing::track_references(1);
+        Ok(Some(GlobalRef {
+            vm: self.vm,
+            version: self.version,
+            raw_object,
+            _type: PhantomData,
+        }))
+    }
+}
+
+impl<T> GlobalRef<T> {
+    /// Get the raw global reference pointer.
+    ///
+    /// # Safety
+    ///
+    /// This function provides low-level access to the Java object and thus is unsafe.
+    #[inline(always)]
+    pub unsafe fn raw_object(&self) -> NonNull<jni_sys::_jobject> {
+        self.raw_object
+    }
+
+    /// Get the [`JavaVMRef`](struct.JavaVMRef.html) this global reference belongs to.
+    #[inline(always)]
+    pub fn vm(&self) -> JavaVMRef {
+        self.vm
+    }
+
+    /// Unsafe because an incorrect object reference can be passed.
+    #[cfg(test)]
+    pub(crate) unsafe fn from_raw(
+        vm: JavaVMRef,
+        version: JniVersion,
+        raw_object: NonNull<jni_sys::_jobject>,
+    ) -> Self {
+        crate::testing::track_references(1);
+        Self {
+            vm,
+            version,
+            raw_object,
+            _type: PhantomData,
+        }
+    }
+}
+
+/// Allow displaying [`GlobalRef`](struct.GlobalRef.html) values.
+impl<T> fmt::Debug for GlobalRef<T> {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter
+            .debug_struct("GlobalRef")
+            .field("vm", &self.vm)
+            .field("raw_object", &self.raw_object)
+            .finish()
+    }
+}
+
+/// [`Drop`](https://doc.rust-lang.org/std/ops/trait.Drop.html) deletes the global reference.
+/// If the current thread is not attached to the Java VM, it is attached for the duration of the call.
+///
+/// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#deleteglobalref)
+impl<T> Drop for GlobalRef<T> {
+    fn drop(&mut self) {
+        #[cfg(test)]
+        crate::testing::track_references(-1);
+        let raw_object = self.raw_object.as_ptr();
+        // Safe because the arguments are correct and `DeleteGlobalRef` can be called
+        // with a pending exception.
+        unsafe {
+            self.vm.with_raw_env(self.version, |raw_env| {
+                let raw_env = raw_env.as_ptr();
+                let jni_fn = ((**raw_env).DeleteGlobalRef).unwrap();
+                jni_fn(raw_env, raw_object);
+            })
+        };
+    }
+}
+
+#[cfg(test)]
+mod global_ref_tests {
+    use super::*;
+    use crate::env::JniEnv;
+    use mockall::Sequence;
+    use serial_test::serial;
+    use std::ffi::c_void;
+    use std::mem::ManuallyDrop;
+
+    generate_java_vm_mock!(mock);
+    generate_jni_env_mock!(jni_mock);
+
+    #[test]
+    #[serial]
+    fn to_global() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_java_vm = mock::raw_java_vm();
+        let raw_java_vm_ptr = &mut (&raw_java_vm as jni_sys::JavaVM) as *mut jni_sys::JavaVM;
+        let raw_object = 0x1234 as jni_sys::jobject;
+        let raw_global = 0x5678 as jni_sys::jobject;
+        let mut sequence = Sequence::new();
+        let new_global_ref_mock = jni_mock::new_global_ref_context();
+        new_global_ref_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object| *env == raw_env_ptr && *object == raw_object)
+            .returning_st(move |_env, _object| raw_global)
+            .in_sequence(&mut sequence);
+        let get_version_mock = jni_mock::get_version_context();
+        get_version_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env| *env == raw_env_ptr)
+            .return_const(jni_sys::JNI_VERSION_1_8)
+            .in_sequence(&mut sequence);
+        let get_env_mock = mock::get_env_context();
+        get_env_mock
+            .expect()
+            .times(1)
+            .withf_st(move |java_vm, jni_env, version| unsafe {
+                if *java_vm != raw_java_vm_ptr || *version != jni_sys::JNI_VERSION_1_8 {
+                    return false;
+                }
+                **jni_env = raw_env_ptr as *mut c_void;
+                true
+            })
+            .return_const(jni_sys::JNI_OK)
+            .in_sequence(&mut sequence);
+        let delete_global_ref_mock = jni_mock::delete_global_ref_context();
+        delete_global_ref_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object| *env == raw_env_ptr && *object == raw_global)
+            .return_const(())
+            .in_sequence(&mut sequence);
+        let vm = JavaVMRef::test(raw_java_vm_ptr);
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let token = NoException::test(&env);
+        let object =
+            ManuallyDrop::new(unsafe { Object::from_raw(&env, NonNull::new(raw_object).unwrap()) });
+        let global = object.to_global(&token).unwrap();
+        assert_eq!(unsafe { global.raw_object() }.as_ptr(), raw_global);
+        assert_eq!(global.vm(), vm);
+    }
+
+    #[test]
+    #[serial]
+    fn with() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_global = 0x1234 as jni_sys::jobject;
+        let raw_local = 0x5678 as jni_sys::jobject;
+        let mut sequence = Sequence::new();
+        let new_local_ref_mock = jni_mock::new_local_ref_context();
+        new_local_ref_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object| *env == raw_env_ptr && *object == raw_global)
+            .returning_st(move |_env, _object| raw_local)
+            .in_sequence(&mut sequence);
+        let delete_local_ref_mock = jni_mock::delete_local_ref_context();
+        delete_local_ref_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object| *env == raw_env_ptr && *object == raw_local)
+            .return_const(())
+            .in_sequence(&mut sequence);
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let token = NoException::test(&env);
+        let global: ManuallyDrop<GlobalRef<Object<'static>>> = ManuallyDrop::new(unsafe {
+            GlobalRef::from_raw(vm, JniVersion::V8, NonNull::new(raw_global).unwrap())
+        });
+        let object = global.with::<Object>(&token).unwrap();
+        assert_eq!(unsafe { object.raw_object() }.as_ptr(), raw_local);
+    }
+
+    #[test]
+    #[serial]
+    fn drop_detached() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_java_vm = mock::raw_java_vm();
+        let raw_java_vm_ptr = &mut (&raw_java_vm as jni_sys::JavaVM) as *mut jni_sys::JavaVM;
+        let raw_global = 0x1234 as jni_sys::jobject;
+        let mut sequence = Sequence::new();
+        let get_env_mock = mock::get_env_context();
+        get_env_mock
+            .expect()
+            .times(1)
+            .withf_st(move |java_vm, _jni_env, version| {
+                *java_vm == raw_java_vm_ptr && *version == jni_sys::JNI_VERSION_1_8
+            })
+            .return_const(jni_sys::JNI_EDETACHED)
+            .in_sequence(&mut sequence);
+        let attach_current_thread_mock = mock::attach_current_thread_context();
+        attach_current_thread_mock
+            .expect()
+            .times(1)
+            .withf_st(move |java_vm, jni_env, _argument| unsafe {
+                if *java_vm != raw_java_vm_ptr {
+                    return false;
+                }
+                **jni_env = raw_env_ptr as *mut c_void;
+                true
+            })
+            .return_const(jni_sys::JNI_OK)
+            .in_sequence(&mut sequence);
+        let delete_global_ref_mock = jni_mock::delete_global_ref_context();
+        delete_global_ref_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object| *env == raw_env_ptr && *object == raw_global)
+            .return_const(())
+            .in_sequence(&mut sequence);
+        let detach_thread_mock = mock::detach_thread_context();
+        detach_thread_mock
+            .expect()
+            .times(1)
+            .withf_st(move |java_vm| *java_vm == raw_java_vm_ptr)
+            .return_const(jni_sys::JNI_OK)
+            .in_sequence(&mut sequence);
+        let vm = JavaVMRef::test(raw_java_vm_ptr);
+        let global: GlobalRef<Object<'static>> =
+            unsafe { GlobalRef::from_raw(vm, JniVersion::V8, NonNull::new(raw_global).unwrap()) };
+        drop(global);
+    }
+}
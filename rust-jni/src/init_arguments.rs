@@ -3,12 +3,24 @@ use crate::jni_bool;
 use crate::version::JniVersion;
 use cfg_if::cfg_if;
 use jni_sys;
+use std::borrow::Cow;
 use std::ffi::{CStr, CString};
 use std::marker::PhantomData;
+use std::mem;
 use std::os::raw::c_void;
+use std::path::Path;
 use std::ptr;
 use std::slice;
 
+/// The system property setting the class path.
+const CLASS_PATH_PROPERTY: &str = "java.class.path";
+
+/// The platform separator of class path entries.
+#[cfg(windows)]
+const CLASS_PATH_SEPARATOR: &str = ";";
+#[cfg(not(windows))]
+const CLASS_PATH_SEPARATOR: &str = ":";
+
 /// Verbose options for starting a Java VM.
 ///
 /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/invocation.html#jni_createjavavm)
@@ -53,7 +65,7 @@ mod verbose_option_to_string_tests {
 /// Options for starting a Java VM.
 ///
 /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/invocation.html#jni_createjavavm)
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone)]
 pub enum JvmOption {
     /// Unknown option.
     /// Needed for forward compability and to set custom options.
@@ -67,19 +79,109 @@ pub enum JvmOption {
     ///
     /// Passed to the JVM as `-verbose:${verbose_option}`.
     Verbose(JvmVerboseOption),
+    /// A system property.
+    ///
+    /// Passed to the JVM as `-D${key}=${value}`.
+    SystemProperty { key: String, value: String },
+    /// A hook called by the JVM when it exits, e.g. on `System.exit()`, with the exit code.
+    ///
+    /// The hook runs in the JVM context and must not panic.
+    ///
+    /// Passed to the JVM as `exit` with the hook as the option extra info.
+    ExitHook(extern "system" fn(jni_sys::jint)),
+    /// A hook called by the JVM when it aborts.
+    ///
+    /// The hook runs in the JVM context and must not panic.
+    ///
+    /// Passed to the JVM as `abort` with the hook as the option extra info.
+    AbortHook(extern "system" fn()),
+}
+
+/// Hooks are compared by their addresses.
+impl PartialEq for JvmOption {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (JvmOption::Unknown(left), JvmOption::Unknown(right)) => left == right,
+            (JvmOption::CheckedJni, JvmOption::CheckedJni) => true,
+            (JvmOption::Verbose(left), JvmOption::Verbose(right)) => left == right,
+            (
+                JvmOption::SystemProperty { key, value },
+                JvmOption::SystemProperty {
+                    key: other_key,
+                    value: other_value,
+                },
+            ) => key == other_key && value == other_value,
+            (JvmOption::ExitHook(left), JvmOption::ExitHook(right)) => {
+                *left as usize == *right as usize
+            }
+            (JvmOption::AbortHook(left), JvmOption::AbortHook(right)) => {
+                *left as usize == *right as usize
+            }
+            _ => false,
+        }
+    }
 }
 
+impl Eq for JvmOption {}
+
 impl JvmOption {
     /// Unsafe because one can pass a non-UTF-8 or non-null-terminated option string.
     unsafe fn from_raw(option: &jni_sys::JavaVMOption) -> Self {
         // TODO(#14): support platform encodings other than UTF-8.
         let option_string = CStr::from_ptr((*option).optionString).to_str().unwrap();
         match option_string {
+            "exit" if !option.extraInfo.is_null() => {
+                JvmOption::ExitHook(mem::transmute::<
+                    *mut c_void,
+                    extern "system" fn(jni_sys::jint),
+                >(option.extraInfo))
+            }
+            "abort" if !option.extraInfo.is_null() => {
+                JvmOption::AbortHook(mem::transmute::<*mut c_void, extern "system" fn()>(
+                    option.extraInfo,
+                ))
+            }
+            option => JvmOption::from_option_string(option),
+        }
+    }
+
+    /// Parse an option string as passed to the JVM, e.g. `-Dkey=value`.
+    fn from_option_string(option: &str) -> Self {
+        match option {
             "-Xcheck:jni" => JvmOption::CheckedJni,
             "-verbose:gc" => JvmOption::Verbose(JvmVerboseOption::Gc),
             "-verbose:jni" => JvmOption::Verbose(JvmVerboseOption::Jni),
             "-verbose:class" => JvmOption::Verbose(JvmVerboseOption::Class),
-            option => JvmOption::Unknown(option.to_owned()),
+            option => match option
+                .strip_prefix("-D")
+                .and_then(|property| property.split_once('='))
+            {
+                Some((key, value)) if !key.is_empty() => JvmOption::SystemProperty {
+                    key: key.to_owned(),
+                    value: value.to_owned(),
+                },
+                _ => JvmOption::Unknown(option.to_owned()),
+            },
+        }
+    }
+
+    /// The option extra info. Only used to pass the hooks to the JVM.
+    fn extra_info(&self) -> *mut c_void {
+        match self {
+            JvmOption::ExitHook(hook) => *hook as *mut c_void,
+            JvmOption::AbortHook(hook) => *hook as *mut c_void,
+            _ => ptr::null_mut(),
+        }
+    }
+
+    /// The name of an option with a value, e.g. `-Dkey` for `-Dkey=value`.
+    fn key(&self) -> Option<Cow<'_, str>> {
+        match self {
+            JvmOption::Unknown(value) if value.contains('=') => {
+                value.split('=').next().map(Cow::Borrowed)
+            }
+            JvmOption::SystemProperty { key, .. } => Some(Cow::Owned(format!("-D{}", key))),
+            _ => None,
         }
     }
 
@@ -88,6 +190,11 @@ impl JvmOption {
             JvmOption::Unknown(value) => CString::new(value.as_str()),
             JvmOption::CheckedJni => CString::new("-Xcheck:jni"),
             JvmOption::Verbose(option) => CString::new(format!("-verbose:{}", option.to_string())),
+            JvmOption::SystemProperty { key, value } => {
+                CString::new(format!("-D{}={}", key, value))
+            }
+            JvmOption::ExitHook(_) => CString::new("exit"),
+            JvmOption::AbortHook(_) => CString::new("abort"),
         }
         .unwrap()
     }
@@ -114,6 +221,55 @@ mod jvm_option_tests {
         );
     }
 
+    #[test]
+    fn from_raw_system_property() {
+        let option_string = CStr::from_bytes_with_nul(b"-Dkey=value\0").unwrap();
+        let option = raw_vm_option(&option_string);
+        assert_eq!(
+            unsafe { JvmOption::from_raw(&option) },
+            JvmOption::SystemProperty {
+                key: "key".to_owned(),
+                value: "value".to_owned(),
+            }
+        );
+    }
+
+    #[test]
+    fn from_raw_system_property_empty_value() {
+        let option_string = CStr::from_bytes_with_nul(b"-Dkey=\0").unwrap();
+        let option = raw_vm_option(&option_string);
+        assert_eq!(
+            unsafe { JvmOption::from_raw(&option) },
+            JvmOption::SystemProperty {
+                key: "key".to_owned(),
+                value: "".to_owned(),
+            }
+        );
+    }
+
+    #[test]
+    fn from_raw_system_property_value_with_equals() {
+        let option_string = CStr::from_bytes_with_nul(b"-Dkey=a=b\0").unwrap();
+        let option = raw_vm_option(&option_string);
+        assert_eq!(
+            unsafe { JvmOption::from_raw(&option) },
+            JvmOption::SystemProperty {
+                key: "key".to_owned(),
+                value: "a=b".to_owned(),
+            }
+        );
+    }
+
+    #[test]
+    fn from_raw_system_property_without_value() {
+        let option_string = CStr::from_bytes_with_nul(b"-Dkey\0").unwrap();
+        let option = raw_vm_option(&option_string);
+        assert_eq!(
+            unsafe { JvmOption::from_raw(&option) },
+            JvmOption::Unknown("-Dkey".to_owned())
+        );
+    }
+
     #[test]
     fn from_raw_checked_jni() {
         let option_string = CStr::from_bytes_with_nul(b"-Xcheck:jni\0").unwrap();
@@ -169,6 +325,34 @@ mod option_to_string_tests {
         );
     }
 
+    #[test]
+    fn to_string_system_property() {
+        assert_eq!(
+            JvmOption::SystemProperty {
+                key: "key".to_owned(),
+                value: "value".to_owned(),
+            }
+            .to_string(),
+            CString::new("-Dkey=value").unwrap()
+        );
+        assert_eq!(
+            JvmOption::SystemProperty {
+                key: "key".to_owned(),
+                value: "".to_owned(),
+            }
+            .to_string(),
+            CString::new("-Dkey=").unwrap()
+        );
+        assert_eq!(
+            JvmOption::SystemProperty {
+                key: "key".to_owned(),
+                value: "a=b".to_owned(),
+            }
+            .to_string(),
+            CString::new("-Dkey=a=b").unwrap()
+        );
+    }
+
     #[test]
     fn to_string_verbose() {
         assert_eq!(
@@ -186,6 +370,68 @@ mod option_to_string_tests {
     }
 }
 
+/// Split a whitespace-separated option string into options. Quotes group whitespace into
+/// a single option and are removed.
+fn split_options(options: &str) -> Vec<String> {
+    let mut result = vec![];
+    let mut current: Option<String> = None;
+    let mut quote = None;
+    for character in options.chars() {
+        match (quote, character) {
+            (Some(quote_character), character) if character == quote_character => quote = None,
+            (Some(_), character) => current.get_or_insert_with(String::new).push(character),
+            (None, '"') | (None, '\'') => {
+                quote = Some(character);
+                current.get_or_insert_with(String::new);
+            }
+            (None, character) if character.is_whitespace() => result.extend(current.take()),
+            (None, character) => current.get_or_insert_with(String::new).push(character),
+        }
+    }
+    result.extend(current);
+    result
+}
+
+#[cfg(test)]
+mod split_options_tests {
+    use super::*;
+
+    #[test]
+    fn empty() {
+        assert!(split_options("  ").is_empty());
+    }
+
+    #[test]
+    fn whitespace() {
+        assert_eq!(split_options(" -a\t-b  -c\n"), vec!["-a", "-b", "-c"]);
+    }
+
+    #[test]
+    fn quoted() {
+        assert_eq!(
+            split_options("\"-Da=b c\" -Dd='e \"f\"' ''"),
+            vec!["-Da=b c", "-Dd=e \"f\"", ""]
+        );
+    }
+}
+
+/// A difference between two sets of Java VM init arguments.
+///
+/// Returned by [`InitArguments::diff`](struct.InitArguments.html#method.diff).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OptionDiff {
+    /// The requested JNI version is different.
+    Version { from: JniVersion, to: JniVersion },
+    /// Ignoring unrecognized options is enabled in one set and disabled in the other.
+    IgnoreUnrecognized { from: bool, to: bool },
+    /// The option is only present in the other set.
+    Added(JvmOption),
+    /// The option is only present in this set.
+    Removed(JvmOption),
+    /// The option is set to a different value, e.g. `-Dkey=value`.
+    Changed { from: JvmOption, to: JvmOption },
+}
+
 /// Arguments for creating a Java VM.
 ///
 /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/invocation.html#jni_createjavavm)
@@ -315,6 +561,77 @@ impl InitArguments {
         self.with_options(&[option])
     }
 
+    /// Add init options from a whitespace-separated option string, like the one in the
+    /// `JAVA_TOOL_OPTIONS` environment variable, to the Java VM init arguments.
+    ///
+    /// Options containing whitespace can be quoted with `"` or `'`.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/invocation.html#jni_createjavavm)
+    pub fn with_options_from_str(self, options: &str) -> Self {
+        let options = split_options(options)
+            .iter()
+            .map(|option| JvmOption::from_option_string(option))
+            .collect::<Vec<_>>();
+        self.with_options(&options)
+    }
+
+    /// Add a verbose option to the Java VM init arguments for each of the `options`,
+    /// skipping the ones that are already present.
+    ///
+    /// Passed to the JVM as `-verbose:${verbose_option}`.
+    pub fn with_verbose(mut self, options: &[JvmVerboseOption]) -> Self {
+        for option in options {
+            let option = JvmOption::Verbose(*option);
+            if !self.options.contains(&option) {
+                self.options.push(option);
+            }
+        }
+        self
+    }
+
+    /// Set the class path of the Java VM, replacing any class path set before.
+    ///
+    /// The paths are joined with the platform separator: `;` on Windows and `:` elsewhere.
+    ///
+    /// Panics if a path is not valid UTF-8.
+    ///
+    /// Passed to the JVM as `-Djava.class.path=${paths}`.
+    pub fn with_class_path(mut self, paths: &[&Path]) -> Self {
+        let paths = paths
+            .iter()
+            .map(|path| {
+                path.to_str()
+                    .unwrap_or_else(|| panic!("Class path {:?} is not valid UTF-8.", path))
+            })
+            .collect::<Vec<_>>()
+            .join(CLASS_PATH_SEPARATOR);
+        let class_path = JvmOption::SystemProperty {
+            key: CLASS_PATH_PROPERTY.to_owned(),
+            value: paths,
+        };
+        self.options
+            .retain(|option| option.key() != class_path.key());
+        self.with_option(class_path)
+    }
+
+    /// Register a hook the JVM calls with the exit code when it exits, e.g. on `System.exit()`.
+    ///
+    /// The hook runs in the JVM context and must not panic.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/invocation.html#jni_createjavavm)
+    pub fn with_exit_hook(self, hook: extern "system" fn(jni_sys::jint)) -> Self {
+        self.with_option(JvmOption::ExitHook(hook))
+    }
+
+    /// Register a hook the JVM calls when it aborts.
+    ///
+    /// The hook runs in the JVM context and must not panic.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/invocation.html#jni_createjavavm)
+    pub fn with_abort_hook(self, hook: extern "system" fn()) -> Self {
+        self.with_option(JvmOption::AbortHook(hook))
+    }
+
     /// Disable checking JNI calls for correctness.
     pub fn unchecked(self) -> Self {
         InitArguments {
@@ -336,20 +653,29 @@ impl InitArguments {
         self.with_option(JvmOption::CheckedJni)
     }
 
-    /// Request for JVM to ignore unrecognized options on startup.
+    /// Request for JVM to ignore unrecognized options on startup if `ignore` is `true` and to fail
+    /// in presence of them otherwise.
+    ///
+    /// Failing is a default.
     ///
     /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/invocation.html#jni_createjavavm)
-    pub fn ignore_unrecognized_options(mut self) -> Self {
-        self.ignore_unrecognized = true;
+    pub fn ignore_unrecognized(mut self, ignore: bool) -> Self {
+        self.ignore_unrecognized = ignore;
         self
     }
 
+    /// Request for JVM to ignore unrecognized options on startup.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/invocation.html#jni_createjavavm)
+    pub fn ignore_unrecognized_options(self) -> Self {
+        self.ignore_unrecognized(true)
+    }
+
     /// Request for JVM to fail in presence of unrecognized options on startup.
     ///
     /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/invocation.html#jni_createjavavm)
-    pub fn fail_on_unrecognized_options(mut self) -> Self {
-        self.ignore_unrecognized = false;
-        self
+    pub fn fail_on_unrecognized_options(self) -> Self {
+        self.ignore_unrecognized(false)
     }
 
     /// Return the JNI version these arguments will request when creating a Java VM.
@@ -358,6 +684,79 @@ impl InitArguments {
     pub fn version(&self) -> JniVersion {
         self.version
     }
+
+    /// Get the differences between these and the other Java VM init arguments.
+    ///
+    /// Options of the form `key=value` which are present in both sets with different values
+    /// are reported as changed rather than removed and added.
+    ///
+    /// # Example
+    /// ```
+    /// use rust_jni::{InitArguments, JvmOption, OptionDiff};
+    ///
+    /// let arguments = InitArguments::default().with_option(JvmOption::SystemProperty {
+    ///     key: "key".to_owned(),
+    ///     value: "value".to_owned(),
+    /// });
+    /// let other = InitArguments::default().with_option(JvmOption::SystemProperty {
+    ///     key: "key".to_owned(),
+    ///     value: "other".to_owned(),
+    /// });
+    ///
+    /// assert_eq!(
+    ///     arguments.diff(&other),
+    ///     vec![OptionDiff::Changed {
+    ///         from: JvmOption::SystemProperty {
+    ///             key: "key".to_owned(),
+    ///             value: "value".to_owned(),
+    ///         },
+    ///         to: JvmOption::SystemProperty {
+    ///             key: "key".to_owned(),
+    ///             value: "other".to_owned(),
+    ///         },
+    ///     }]
+    /// );
+    /// ```
+    pub fn diff(&self, other: &InitArguments) -> Vec<OptionDiff> {
+        let mut diffs = vec![];
+        if self.version != other.version {
+            diffs.push(OptionDiff::Version {
+                from: self.version,
+                to: other.version,
+            });
+        }
+        if self.ignore_unrecognized != other.ignore_unrecognized {
+            diffs.push(OptionDiff::IgnoreUnrecognized {
+                from: self.ignore_unrecognized,
+                to: other.ignore_unrecognized,
+            });
+        }
+        let mut added = other
+            .options
+            .iter()
+            .filter(|&option| !self.options.contains(option))
+            .collect::<Vec<_>>();
+        for option in self
+            .options
+            .iter()
+            .filter(|&option| !other.options.contains(option))
+        {
+            let changed = option.key().and_then(|key| {
+                added
+                    .iter()
+                    .position(|added_option| added_option.key().as_ref() == Some(&key))
+            });
+            match changed {
+                Some(index) => diffs.push(OptionDiff::Changed {
+                    from: option.clone(),
+                    to: added.remove(index).clone(),
+                }),
+                None => diffs.push(OptionDiff::Removed(option.clone())),
+            }
+        }
+        diffs.extend(added.into_iter().cloned().map(OptionDiff::Added));
+        diffs
+    }
 }
 
 #[cfg(test)]
@@ -399,6 +798,32 @@ pub mod init_arguments_manipulation_tests {
         );
     }
 
+    #[test]
+    fn with_options_from_str() {
+        let arguments = InitArguments {
+            options: vec![JvmOption::CheckedJni],
+            ..default_args()
+        };
+        assert_eq!(
+            arguments.with_options_from_str("-Xmx512m -Dfoo=bar \"-Dpath=a b\""),
+            InitArguments {
+                options: vec![
+                    JvmOption::CheckedJni,
+                    JvmOption::Unknown("-Xmx512m".to_owned()),
+                    JvmOption::SystemProperty {
+                        key: "foo".to_owned(),
+                        value: "bar".to_owned(),
+                    },
+                    JvmOption::SystemProperty {
+                        key: "path".to_owned(),
+                        value: "a b".to_owned(),
+                    },
+                ],
+                ..default_args()
+            }
+        );
+    }
+
     #[test]
     fn with_options() {
         let arguments = InitArguments {
@@ -439,6 +864,116 @@ pub mod init_arguments_manipulation_tests {
         );
     }
 
+    #[test]
+    fn with_verbose() {
+        let arguments = default_args();
+        assert_eq!(
+            arguments.with_verbose(&[JvmVerboseOption::Class, JvmVerboseOption::Gc]),
+            InitArguments {
+                options: vec![
+                    JvmOption::Verbose(JvmVerboseOption::Class),
+                    JvmOption::Verbose(JvmVerboseOption::Gc),
+                ],
+                ..default_args()
+            }
+        );
+    }
+
+    #[test]
+    fn with_verbose_deduplicates() {
+        let arguments = InitArguments {
+            options: vec![JvmOption::Verbose(JvmVerboseOption::Gc)],
+            ..default_args()
+        };
+        assert_eq!(
+            arguments.with_verbose(&[
+                JvmVerboseOption::Class,
+                JvmVerboseOption::Gc,
+                JvmVerboseOption::Class,
+            ]),
+            InitArguments {
+                options: vec![
+                    JvmOption::Verbose(JvmVerboseOption::Gc),
+                    JvmOption::Verbose(JvmVerboseOption::Class),
+                ],
+                ..default_args()
+            }
+        );
+    }
+
+    #[test]
+    fn with_class_path() {
+        let arguments = InitArguments {
+            options: vec![JvmOption::CheckedJni],
+            ..default_args()
+        };
+        assert_eq!(
+            arguments.with_class_path(&[Path::new("classes"), Path::new("lib.jar")]),
+            InitArguments {
+                options: vec![
+                    JvmOption::CheckedJni,
+                    JvmOption::SystemProperty {
+                        key: "java.class.path".to_owned(),
+                        value: format!("classes{}lib.jar", CLASS_PATH_SEPARATOR),
+                    },
+                ],
+                ..default_args()
+            }
+        );
+    }
+
+    #[test]
+    fn with_class_path_replaces_class_path() {
+        let arguments = InitArguments {
+            options: vec![
+                JvmOption::Unknown("-Djava.class.path=old.jar".to_owned()),
+                JvmOption::Unknown("-Dkey=value".to_owned()),
+            ],
+            ..default_args()
+        };
+        assert_eq!(
+            arguments
+                .with_class_path(&[Path::new("first.jar")])
+                .with_class_path(&[Path::new("second.jar")]),
+            InitArguments {
+                options: vec![
+                    JvmOption::Unknown("-Dkey=value".to_owned()),
+                    JvmOption::SystemProperty {
+                        key: "java.class.path".to_owned(),
+                        value: "second.jar".to_owned(),
+                    },
+                ],
+                ..default_args()
+            }
+        );
+    }
+
+    #[test]
+    fn with_class_path_empty() {
+        assert_eq!(
+            default_args().with_class_path(&[]),
+            InitArguments {
+                options: vec![JvmOption::SystemProperty {
+                    key: "java.class.path".to_owned(),
+                    value: "".to_owned(),
+                }],
+                ..default_args()
+            }
+        );
+    }
+
+    #[test]
+    #[cfg(windows)]
+    fn class_path_separator() {
+        assert_eq!(CLASS_PATH_SEPARATOR, ";");
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn class_path_separator() {
+        assert_eq!(CLASS_PATH_SEPARATOR, ":");
+    }
+
     #[test]
     fn unchecked() {
         let arguments = InitArguments {
@@ -475,6 +1010,19 @@ pub mod init_arguments_manipulation_tests {
         );
     }
 
+    #[test]
+    fn ignore_unrecognized() {
+        let arguments = default_args();
+        assert_eq!(
+            arguments.clone().ignore_unrecognized(true),
+            InitArguments {
+                ignore_unrecognized: true,
+                ..default_args()
+            }
+        );
+        assert_eq!(arguments.ignore_unrecognized(false), default_args());
+    }
+
     #[test]
     fn ignore_unrecognized_options() {
         let arguments = InitArguments {
@@ -515,6 +1063,100 @@ pub mod init_arguments_manipulation_tests {
     }
 }
 
+#[cfg(test)]
+mod init_arguments_diff_tests {
+    use super::*;
+
+    fn default_args() -> InitArguments {
+        InitArguments {
+            version: JniVersion::V4,
+            options: vec![],
+            ignore_unrecognized: false,
+        }
+    }
+
+    #[test]
+    fn same() {
+        let arguments = InitArguments {
+            options: vec![JvmOption::CheckedJni],
+            ..default_args()
+        };
+        assert_eq!(arguments.diff(&arguments.clone()), vec![]);
+    }
+
+    #[test]
+    fn version_and_ignore_unrecognized() {
+        let other = InitArguments {
+            version: JniVersion::V8,
+            ignore_unrecognized: true,
+            ..default_args()
+        };
+        assert_eq!(
+            default_args().diff(&other),
+            vec![
+                OptionDiff::Version {
+                    from: JniVersion::V4,
+                    to: JniVersion::V8,
+                },
+                OptionDiff::IgnoreUnrecognized {
+                    from: false,
+                    to: true,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn added_and_removed() {
+        let arguments = InitArguments {
+            options: vec![
+                JvmOption::CheckedJni,
+                JvmOption::Verbose(JvmVerboseOption::Gc),
+            ],
+            ..default_args()
+        };
+        let other = InitArguments {
+            options: vec![
+                JvmOption::Verbose(JvmVerboseOption::Gc),
+                JvmOption::Unknown("-Xmx1g".to_owned()),
+            ],
+            ..default_args()
+        };
+        assert_eq!(
+            arguments.diff(&other),
+            vec![
+                OptionDiff::Removed(JvmOption::CheckedJni),
+                OptionDiff::Added(JvmOption::Unknown("-Xmx1g".to_owned())),
+            ]
+        );
+    }
+
+    #[test]
+    fn changed() {
+        let arguments = InitArguments {
+            options: vec![
+                JvmOption::Unknown("-Dkey=value".to_owned()),
+                JvmOption::Unknown("-Dother=value".to_owned()),
+            ],
+            ..default_args()
+        };
+        let other = InitArguments {
+            options: vec![
+                JvmOption::Unknown("-Dother=value".to_owned()),
+                JvmOption::Unknown("-Dkey=new-value".to_owned()),
+            ],
+            ..default_args()
+        };
+        assert_eq!(
+            arguments.diff(&other),
+            vec![OptionDiff::Changed {
+                from: JvmOption::Unknown("-Dkey=value".to_owned()),
+                to: JvmOption::Unknown("-Dkey=new-value".to_owned()),
+            }]
+        );
+    }
+}
+
 #[cfg(test)]
 pub(crate) mod init_arguments_creation_tests {
     use super::*;
@@ -629,6 +1271,84 @@ pub(crate) mod init_arguments_creation_tests {
         );
     }
 
+    #[test]
+    #[serial]
+    fn get_default_v21() {
+        let resulting_arguments = InitArguments {
+            version: JniVersion::V21,
+            ..default_args()
+        };
+        let mut strings_buffer = vec![];
+        let mut options_buffer = vec![];
+        let raw_resulting_arguments =
+            resulting_arguments.to_raw(&mut strings_buffer, &mut options_buffer);
+
+        let mock = jni_mock::JNI_GetDefaultJavaVMInitArgs_context();
+        mock.expect()
+            .times(1)
+            .withf_st(move |arguments: &*mut ::std::os::raw::c_void| {
+                let arguments = *arguments as *mut jni_sys::JavaVMInitArgs;
+                // We know that this pointer points to a valid value.
+                match unsafe { arguments.as_mut() } {
+                    None => false,
+                    Some(arguments) => {
+                        if arguments.version != JniVersion::V21.to_raw() {
+                            false
+                        } else {
+                            *arguments = raw_resulting_arguments.raw_arguments;
+                            true
+                        }
+                    }
+                }
+            })
+            .return_const(jni_sys::JNI_OK);
+        assert_eq!(
+            InitArguments::get_default(JniVersion::V21),
+            Ok(InitArguments {
+                version: JniVersion::V21,
+                options: vec![JvmOption::CheckedJni],
+                ..default_args()
+            })
+        );
+    }
+
+    #[test]
+    #[serial]
+    fn get_default_unknown_version() {
+        let resulting_arguments = InitArguments {
+            version: JniVersion::Unknown(0x00160000),
+            ..default_args()
+        };
+        let mut strings_buffer = vec![];
+        let mut options_buffer = vec![];
+        let raw_resulting_arguments =
+            resulting_arguments.to_raw(&mut strings_buffer, &mut options_buffer);
+
+        let mock = jni_mock::JNI_GetDefaultJavaVMInitArgs_context();
+        mock.expect()
+            .times(1)
+            .withf_st(move |arguments: &*mut ::std::os::raw::c_void| {
+                let arguments = *arguments as *mut jni_sys::JavaVMInitArgs;
+                // We know that this pointer points to a valid value.
+                match unsafe { arguments.as_mut() } {
+                    None => false,
+                    Some(arguments) => {
+                        if arguments.version != JniVersion::V21.to_raw() {
+                            false
+                        } else {
+                            *arguments = raw_resulting_arguments.raw_arguments;
+                            true
+                        }
+                    }
+                }
+            })
+            .return_const(jni_sys::JNI_OK);
+        assert_eq!(
+            InitArguments::get_default(JniVersion::V21),
+            Err(JniError::UnsupportedVersion)
+        );
+    }
+
     #[test]
     #[serial]
     fn get_latest_default() {
@@ -701,7 +1421,7 @@ impl InitArguments {
                 *buffer = option.to_string();
                 jni_sys::JavaVMOption {
                     optionString: buffer.as_ptr() as *mut i8,
-                    extraInfo: ptr::null_mut(),
+                    extraInfo: option.extra_info(),
                 }
             })
             .collect();
@@ -728,6 +1448,14 @@ mod init_arguments_to_raw_tests {
             options: vec![
                 JvmOption::Unknown("qwer".to_owned()),
                 JvmOption::Verbose(JvmVerboseOption::Gc),
+                JvmOption::SystemProperty {
+                    key: "key".to_owned(),
+                    value: "".to_owned(),
+                },
+                JvmOption::SystemProperty {
+                    key: "other".to_owned(),
+                    value: "a=b".to_owned(),
+                },
             ],
             ignore_unrecognized: false,
         };
@@ -735,7 +1463,7 @@ mod init_arguments_to_raw_tests {
         let mut options_buffer = vec![];
         let raw_arguments = arguments.to_raw(&mut strings_buffer, &mut options_buffer);
         assert_eq!(raw_arguments.raw_arguments.version, JniVersion::V4.to_raw());
-        assert_eq!(raw_arguments.raw_arguments.nOptions, 2);
+        assert_eq!(raw_arguments.raw_arguments.nOptions, 4);
         assert_eq!(
             raw_arguments.raw_arguments.ignoreUnrecognized,
             jni_sys::JNI_FALSE
@@ -746,7 +1474,72 @@ mod init_arguments_to_raw_tests {
                 raw_arguments.raw_arguments.nOptions as usize,
             )
         };
+        assert_eq!(raw_options.len(), 4);
+        for (raw_option, option) in raw_options.iter().zip(arguments.options.into_iter()) {
+            assert_eq!(option, unsafe { JvmOption::from_raw(raw_option) });
+        }
+    }
+
+    #[test]
+    fn to_raw_ignore_unrecognized() {
+        let arguments = InitArguments {
+            version: JniVersion::V8,
+            options: vec![],
+            ignore_unrecognized: false,
+        }
+        .ignore_unrecognized(true);
+        let mut strings_buffer = vec![];
+        let mut options_buffer = vec![];
+        let raw_arguments = arguments.to_raw(&mut strings_buffer, &mut options_buffer);
+        assert_eq!(
+            raw_arguments.raw_arguments.ignoreUnrecognized,
+            jni_sys::JNI_TRUE
+        );
+        assert_eq!(
+            unsafe { InitArguments::from_raw(&raw_arguments.raw_arguments) },
+            arguments
+        );
+    }
+
+    extern "system" fn test_exit_hook(_code: jni_sys::jint) {}
+
+    extern "system" fn test_abort_hook() {}
+
+    #[test]
+    fn to_raw_hooks() {
+        let arguments = InitArguments {
+            version: JniVersion::V4,
+            options: vec![],
+            ignore_unrecognized: false,
+        }
+        .with_exit_hook(test_exit_hook)
+        .with_abort_hook(test_abort_hook);
+        let mut strings_buffer = vec![];
+        let mut options_buffer = vec![];
+        let raw_arguments = arguments.to_raw(&mut strings_buffer, &mut options_buffer);
+        let raw_options = unsafe {
+            slice::from_raw_parts(
+                raw_arguments.raw_arguments.options,
+                raw_arguments.raw_arguments.nOptions as usize,
+            )
+        };
         assert_eq!(raw_options.len(), 2);
+        assert_eq!(
+            unsafe { CStr::from_ptr(raw_options[0].optionString) },
+            CStr::from_bytes_with_nul(b"exit\0").unwrap()
+        );
+        assert_eq!(
+            raw_options[0].extraInfo,
+            test_exit_hook as extern "system" fn(jni_sys::jint) as *mut c_void
+        );
+        assert_eq!(
+            unsafe { CStr::from_ptr(raw_options[1].optionString) },
+            CStr::from_bytes_with_nul(b"abort\0").unwrap()
+        );
+        assert_eq!(
+            raw_options[1].extraInfo,
+            test_abort_hook as extern "system" fn() as *mut c_void
+        );
         for (raw_option, option) in raw_options.iter().zip(arguments.options.into_iter()) {
             assert_eq!(option, unsafe { JvmOption::from_raw(raw_option) });
         }
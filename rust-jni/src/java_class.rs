@@ -134,6 +134,23 @@ pub trait JavaClassExt<'a> {
     /// type signature.
     fn class(token: &NoException<'a>) -> JavaResult<'a, Class<'a>>;
 
+    /// Cast the object to a Java class wrapper type `T` if the object is an instance of it.
+    ///
+    /// This is a checked runtime cast, like Java's `instanceof` followed by a cast. It is
+    /// distinct from casts to parent classes through
+    /// [`AsRef`](https://doc.rust-lang.org/std/convert/trait.AsRef.html), which are checked
+    /// at compile time. Returns [`None`](https://doc.rust-lang.org/std/option/enum.Option.html#variant.None)
+    /// if the object is not an instance of `T`.
+    ///
+    /// The returned value is a new local reference to the same object, so the original can
+    /// still be used, e.g. `object.try_cast::<Throwable>(&token)?` gives a checked
+    /// [`Throwable`](java/lang/struct.Throwable.html) view of an object of an unknown class.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#isinstanceof)
+    fn try_cast<T>(&self, token: &NoException<'a>) -> JavaResult<'a, Option<T>>
+    where
+        T: JavaClass<'a>;
+
     /// Get the raw object pointer with ownership transfer.
     ///
     /// The caller is responsible for managing the Java object's lifecycle ofter calling this.
@@ -362,6 +379,19 @@ where
         find_class::<Self>(token)
     }
 
+    fn try_cast<S>(&self, token: &NoException<'a>) -> JavaResult<'a, Option<S>>
+    where
+        S: JavaClass<'a>,
+    {
+        let class = find_class::<S>(token)?;
+        if !self.as_ref().is_instance_of(token, &class) {
+            return Ok(None);
+        }
+        let object = self.as_ref().clone_object(token)?;
+        // Safe because we just checked that the object is an instance of `S`.
+        Ok(Some(unsafe { S::from_object(object) }))
+    }
+
     #[inline(always)]
     unsafe fn take_raw_object(self) -> NonNull<jni_sys::_jobject> {
         Object::take_raw_object(self)
@@ -471,7 +501,234 @@ where
 #[inline(always)]
 pub fn find_class<'a, T: JavaClass<'a>>(token: &NoException<'a>) -> JavaResult<'a, Class<'a>> {
     let signature = T::signature();
+    // Array classes are looked up by their signature.
+    if signature.starts_with('[') {
+        return Class::find(token, signature);
+    }
     // Class signatures are of the form "L${CLASS_NAME};", so to get the class name
     // we remove the first and the last character.
     Class::find(token, &signature[1..signature.len() - 1])
 }
+
+#[cfg(test)]
+mod java_class_tests {
+    use super::*;
+    use crate::env::JniEnv;
+    use crate::string::String;
+    use crate::vm::JavaVMRef;
+    use mockall::Sequence;
+    use serial_test::serial;
+    use std::ffi::CStr;
+    use std::mem::{self, ManuallyDrop};
+
+    generate_jni_env_mock!(jni_mock);
+
+    #[test]
+    #[serial]
+    fn try_cast() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_object = 0x1234 as jni_sys::jobject;
+        let raw_class = 0x5678 as jni_sys::jclass;
+        let raw_cast_object = 0x9012 as jni_sys::jobject;
+        let mut sequence = Sequence::new();
+        let find_class_mock = jni_mock::find_class_context();
+        find_class_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, name| unsafe {
+                *env == raw_env_ptr && CStr::from_ptr(*name).to_str() == Ok("java/lang/String")
+            })
+            .returning_st(move |_env, _name| raw_class)
+            .in_sequence(&mut sequence);
+        let is_instance_of_mock = jni_mock::is_instance_of_context();
+        is_instance_of_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object, class| {
+                *env == raw_env_ptr && *object == raw_object && *class == raw_class
+            })
+            .return_const(jni_sys::JNI_TRUE)
+            .in_sequence(&mut sequence);
+        let new_local_ref_mock = jni_mock::new_local_ref_context();
+        new_local_ref_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object| *env == raw_env_ptr && *object == raw_object)
+            .returning_st(move |_env, _object| raw_cast_object)
+            .in_sequence(&mut sequence);
+        let delete_local_ref_mock = jni_mock::delete_local_ref_context();
+        delete_local_ref_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object| *env == raw_env_ptr && *object == raw_class)
+            .return_const(())
+            .in_sequence(&mut sequence);
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let token = NoException::test(&env);
+        let object =
+            ManuallyDrop::new(unsafe { Object::from_raw(&env, NonNull::new(raw_object).unwrap()) });
+        let string = object.try_cast::<String>(&token).unwrap().unwrap();
+        assert_eq!(unsafe { string.raw_object() }.as_ptr(), raw_cast_object);
+        mem::forget(string);
+    }
+
+    #[test]
+    #[serial]
+    fn try_cast_not_instance() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_object = 0x1234 as jni_sys::jobject;
+        let raw_class = 0x5678 as jni_sys::jclass;
+        let mut sequence = Sequence::new();
+        let find_class_mock = jni_mock::find_class_context();
+        find_class_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, name| unsafe {
+                *env == raw_env_ptr && CStr::from_ptr(*name).to_str() == Ok("java/lang/String")
+            })
+            .returning_st(move |_env, _name| raw_class)
+            .in_sequence(&mut sequence);
+        let is_instance_of_mock = jni_mock::is_instance_of_context();
+        is_instance_of_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object, class| {
+                *env == raw_env_ptr && *object == raw_object && *class == raw_class
+            })
+            .return_const(jni_sys::JNI_FALSE)
+            .in_sequence(&mut sequence);
+        let delete_local_ref_mock = jni_mock::delete_local_ref_context();
+        delete_local_ref_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object| *env == raw_env_ptr && *object == raw_class)
+            .return_const(())
+            .in_sequence(&mut sequence);
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let token = NoException::test(&env);
+        let object =
+            ManuallyDrop::new(unsafe { Object::from_raw(&env, NonNull::new(raw_object).unwrap()) });
+        assert!(object.try_cast::<String>(&token).unwrap().is_none());
+    }
+
+    /// A class wrapper with the `from_object` downcast the generator emits for every class.
+    struct TestClass<'env> {
+        object: Object<'env>,
+    }
+
+    impl<'env> TestClass<'env> {
+        fn get_class(token: &NoException<'env>) -> JavaResult<'env, Class<'env>> {
+            Class::find(token, "c/d/TestClass")
+        }
+
+        fn from_object(
+            object: &Object<'env>,
+            token: &NoException<'env>,
+        ) -> JavaResult<'env, Option<Self>> {
+            if !object.is_instance_of(token, &Self::get_class(token)?) {
+                return Ok(None);
+            }
+            let object = object.clone_object(token)?;
+            Ok(Some(TestClass { object }))
+        }
+    }
+
+    #[test]
+    #[serial]
+    fn generated_from_object() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_object = 0x1234 as jni_sys::jobject;
+        let raw_class = 0x5678 as jni_sys::jclass;
+        let raw_test_object = 0x9012 as jni_sys::jobject;
+        let mut sequence = Sequence::new();
+        let find_class_mock = jni_mock::find_class_context();
+        find_class_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, name| unsafe {
+                *env == raw_env_ptr && CStr::from_ptr(*name).to_str() == Ok("c/d/TestClass")
+            })
+            .returning_st(move |_env, _name| raw_class)
+            .in_sequence(&mut sequence);
+        let is_instance_of_mock = jni_mock::is_instance_of_context();
+        is_instance_of_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object, class| {
+                *env == raw_env_ptr && *object == raw_object && *class == raw_class
+            })
+            .return_const(jni_sys::JNI_TRUE)
+            .in_sequence(&mut sequence);
+        let delete_local_ref_mock = jni_mock::delete_local_ref_context();
+        delete_local_ref_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object| *env == raw_env_ptr && *object == raw_class)
+            .return_const(())
+            .in_sequence(&mut sequence);
+        let new_local_ref_mock = jni_mock::new_local_ref_context();
+        new_local_ref_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object| *env == raw_env_ptr && *object == raw_object)
+            .returning_st(move |_env, _object| raw_test_object)
+            .in_sequence(&mut sequence);
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let token = NoException::test(&env);
+        let object =
+            ManuallyDrop::new(unsafe { Object::from_raw(&env, NonNull::new(raw_object).unwrap()) });
+        let test_object = TestClass::from_object(&object, &token).unwrap().unwrap();
+        assert_eq!(
+            unsafe { test_object.object.raw_object() }.as_ptr(),
+            raw_test_object
+        );
+        mem::forget(test_object.object);
+    }
+
+    #[test]
+    #[serial]
+    fn generated_from_object_not_instance() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_object = 0x1234 as jni_sys::jobject;
+        let raw_class = 0x5678 as jni_sys::jclass;
+        let mut sequence = Sequence::new();
+        let find_class_mock = jni_mock::find_class_context();
+        find_class_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, name| unsafe {
+                *env == raw_env_ptr && CStr::from_ptr(*name).to_str() == Ok("c/d/TestClass")
+            })
+            .returning_st(move |_env, _name| raw_class)
+            .in_sequence(&mut sequence);
+        let is_instance_of_mock = jni_mock::is_instance_of_context();
+        is_instance_of_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object, class| {
+                *env == raw_env_ptr && *object == raw_object && *class == raw_class
+            })
+            .return_const(jni_sys::JNI_FALSE)
+            .in_sequence(&mut sequence);
+        let delete_local_ref_mock = jni_mock::delete_local_ref_context();
+        delete_local_ref_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object| *env == raw_env_ptr && *object == raw_class)
+            .return_const(())
+            .in_sequence(&mut sequence);
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let token = NoException::test(&env);
+        let object =
+            ManuallyDrop::new(unsafe { Object::from_raw(&env, NonNull::new(raw_object).unwrap()) });
+        assert!(TestClass::from_object(&object, &token).unwrap().is_none());
+    }
+}
@@ -1,3 +1,5 @@
+use crate::class::Class;
+use crate::classes::array::ObjectArray;
 use crate::java_class::find_class;
 use crate::java_class::JavaClass;
 use crate::java_class::JavaClassRef;
@@ -9,6 +11,7 @@ use crate::object::Object;
 use crate::result::JavaResult;
 use crate::token::NoException;
 use core::ptr;
+use core::ptr::NonNull;
 
 /// A helper trait to allow accepting as many types
 /// as possible as method arguments in place of Java objects.
@@ -66,9 +69,14 @@ where
 
 pub trait ToJniTypeTuple {
     type JniType: JniArgumentTypeTuple;
+    type JniTypeWithObject: JniArgumentTypeTuple;
 
     // Unsafe because it returns raw pointers to Java objects.
     unsafe fn to_jni(&self) -> Self::JniType;
+
+    // Same as `to_jni`, but appends an extra object argument at the end.
+    // Unsafe because it returns raw pointers to Java objects.
+    unsafe fn to_jni_with_object(&self, object: jni_sys::jobject) -> Self::JniTypeWithObject;
 }
 
 /// A trait that needs to be implemented for a type that needs to be passed to Java.
@@ -126,6 +134,7 @@ macro_rules! java_argument_type_impls {
             $($type: ToJniType,)*
         {
             type JniType = ($($type::JniType,)*);
+            type JniTypeWithObject = ($($type::JniType,)* jni_sys::jobject,);
 
             #[inline(always)]
             unsafe fn to_jni(&self) -> Self::JniType {
@@ -133,6 +142,13 @@ macro_rules! java_argument_type_impls {
                 let ($($type,)*) = self;
                 ($($type.to_jni(),)*)
             }
+
+            #[inline(always)]
+            unsafe fn to_jni_with_object(&self, object: jni_sys::jobject) -> Self::JniTypeWithObject {
+                #[allow(non_snake_case)]
+                let ($($type,)*) = self;
+                ($($type.to_jni(),)* object,)
+            }
         }
 
         impl<'a, 'this: 'a, $($type),*> JavaArgumentTuple<'a, 'this> for ($($type,)*)
@@ -202,6 +218,18 @@ pub trait JavaMethodResult<'a>: JniSignature {
     where
         T: JavaClass<'a>,
         A: JniArgumentTypeTuple;
+
+    unsafe fn call_nonvirtual_method<T, A>(
+        object: &T,
+        class: &Class<'a>,
+        token: &NoException<'a>,
+        name: &str,
+        signature: &str,
+        arguments: A,
+    ) -> JavaResult<'a, Self::ResultType>
+    where
+        T: JavaClass<'a>,
+        A: JniArgumentTypeTuple;
 }
 
 impl<'a, S> JavaMethodResult<'a> for S
@@ -249,4 +277,1095 @@ where
             |result| Self::from_object(Object::from_raw(token.env(), result)),
         ))
     }
+
+    #[inline(always)]
+    unsafe fn call_nonvirtual_method<T, A>(
+        object: &T,
+        class: &Class<'a>,
+        token: &NoException<'a>,
+        name: &str,
+        signature: &str,
+        arguments: A,
+    ) -> JavaResult<'a, Self::ResultType>
+    where
+        T: JavaClass<'a>,
+        A: JniArgumentTypeTuple,
+    {
+        let result = jni_methods::call_nonvirtual_object_method(
+            object.as_ref(),
+            class,
+            token,
+            name,
+            signature,
+            arguments,
+        )?;
+        Ok(result.map(
+            #[inline(always)]
+            |result| Self::from_object(Object::from_raw(object.as_ref().env(), result)),
+        ))
+    }
+}
+
+/// A trait that needs to be implemented for a type that can be stored in a Java field.
+///
+/// See more detailed info for passing values betweed Java and rust in
+/// [`JavaClassSignature`](trait.JavaClassSignature.html) documentation.
+pub trait JavaFieldType<'a>: JniSignature {
+    /// The type of values read from the field.
+    type ResultType;
+    /// The type of values written to the field.
+    type ArgumentType<'b>
+    where
+        Self: 'b;
+
+    unsafe fn get_field<T>(
+        object: &T,
+        token: &NoException<'a>,
+        name: &str,
+        signature: &str,
+    ) -> JavaResult<'a, Self::ResultType>
+    where
+        T: JavaClass<'a>;
+
+    unsafe fn set_field<T>(
+        object: &T,
+        token: &NoException<'a>,
+        name: &str,
+        signature: &str,
+        value: Self::ArgumentType<'_>,
+    ) -> JavaResult<'a, ()>
+    where
+        T: JavaClass<'a>;
+
+    unsafe fn get_static_field<T>(
+        token: &NoException<'a>,
+        name: &str,
+        signature: &str,
+    ) -> JavaResult<'a, Self::ResultType>
+    where
+        T: JavaClass<'a>;
+
+    unsafe fn set_static_field<T>(
+        token: &NoException<'a>,
+        name: &str,
+        signature: &str,
+        value: Self::ArgumentType<'_>,
+    ) -> JavaResult<'a, ()>
+    where
+        T: JavaClass<'a>;
+}
+
+impl<'a, S> JavaFieldType<'a> for S
+where
+    S: JavaClass<'a>,
+{
+    type ResultType = Option<Self>;
+    type ArgumentType<'b>
+        = Option<&'b Self>
+    where
+        Self: 'b;
+
+    #[inline(always)]
+    unsafe fn get_field<T>(
+        object: &T,
+        token: &NoException<'a>,
+        name: &str,
+        signature: &str,
+    ) -> JavaResult<'a, Self::ResultType>
+    where
+        T: JavaClass<'a>,
+    {
+        let result: jni_sys::jobject =
+            jni_methods::get_field(object.as_ref(), token, name, signature)?;
+        Ok(NonNull::new(result).map(
+            #[inline(always)]
+            |result| Self::from_object(Object::from_raw(object.as_ref().env(), result)),
+        ))
+    }
+
+    #[inline(always)]
+    unsafe fn set_field<T>(
+        object: &T,
+        token: &NoException<'a>,
+        name: &str,
+        signature: &str,
+        value: Self::ArgumentType<'_>,
+    ) -> JavaResult<'a, ()>
+    where
+        T: JavaClass<'a>,
+    {
+        jni_methods::set_field(object.as_ref(), token, name, signature, value.to_jni())
+    }
+
+    #[inline(always)]
+    unsafe fn get_static_field<T>(
+        token: &NoException<'a>,
+        name: &str,
+        signature: &str,
+    ) -> JavaResult<'a, Self::ResultType>
+    where
+        T: JavaClass<'a>,
+    {
+        let class = find_class::<T>(token)?;
+        let result: jni_sys::jobject =
+            jni_methods::get_static_field(&class, token, name, signature)?;
+        Ok(NonNull::new(result).map(
+            #[inline(always)]
+            |result| Self::from_object(Object::from_raw(token.env(), result)),
+        ))
+    }
+
+    #[inline(always)]
+    unsafe fn set_static_field<T>(
+        token: &NoException<'a>,
+        name: &str,
+        signature: &str,
+        value: Self::ArgumentType<'_>,
+    ) -> JavaResult<'a, ()>
+    where
+        T: JavaClass<'a>,
+    {
+        let class = find_class::<T>(token)?;
+        jni_methods::set_static_field(&class, token, name, signature, value.to_jni())
+    }
+}
+
+/// Get a value of a Java object's field.
+///
+/// The function has two generic parameters:
+///  - The first one is the type of the field. Must be specified
+///  - The second one is the class of the object. Can be inferred
+///
+/// Object fields are returned as [`Option`](https://doc.rust-lang.org/std/option/enum.Option.html)-s
+/// as they can be `null`.
+///
+/// Example:
+/// ```
+/// # use rust_jni::*;
+/// # use rust_jni::java::lang::Integer;
+/// #
+/// # fn jni_main<'a>(token: NoException<'a>) -> JavaResult<'a, NoException<'a>> {
+/// let integer = Integer::value_of(&token, 17)?;
+/// // Safe because the correct field type is specified.
+/// let value = unsafe { get_field::<i32, _>(&integer, &token, "value\0") }?;
+/// assert_eq!(value, 17);
+/// # Ok(token)
+/// # }
+/// #
+/// # #[cfg(feature = "libjvm")]
+/// # fn main() {
+/// #     let init_arguments = InitArguments::default();
+/// #     let vm = JavaVM::create(&init_arguments).unwrap();
+/// #     let _ = vm.with_attached(
+/// #        &AttachArguments::new(init_arguments.version()),
+/// #        |token: NoException| {
+/// #            ((), jni_main(token).unwrap())
+/// #        },
+/// #     );
+/// # }
+/// #
+/// # #[cfg(not(feature = "libjvm"))]
+/// # fn main() {}
+/// ```
+///
+/// Note that field name string *must* be null-terminating.
+///
+/// # Safety
+///
+/// Unsafe because incorrect field type can be specified.
+///
+/// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#gettypefield-routines)
+#[inline(always)]
+pub unsafe fn get_field<'a, F, T>(
+    object: &T,
+    token: &NoException<'a>,
+    name: &str,
+) -> JavaResult<'a, F::ResultType>
+where
+    F: JavaFieldType<'a>,
+    T: JavaClass<'a>,
+{
+    F::get_field(object, token, name, &format!("{}\0", F::signature()))
+}
+
+/// Call a method implementation of a specific Java class on a Java object,
+/// bypassing virtual dispatch. This is how `super.method()` calls are made in Java.
+///
+/// The function has three generic parameters:
+///  - The first one is the class of the object. Can be inferred
+///  - The second one is the type of the arguments tuple. Can be inferred
+///  - The third one is the signature of the Java method. Must be specified
+///
+/// The method is looked up in the `class` which must be the class of the object or one
+/// of it's superclasses.
+///
+/// Example:
+/// ```
+/// # use rust_jni::*;
+/// # use rust_jni::java::lang::{Class, Object, String};
+/// #
+/// # fn jni_main<'a>(token: NoException<'a>) -> JavaResult<'a, NoException<'a>> {
+/// let string = String::new(&token, "test")?;
+/// let object_class = Class::find(&token, "java/lang/Object")?;
+/// // Safe because correct arguments are passed and correct return type specified.
+/// // See `Object::toString()` javadoc:
+/// // https://docs.oracle.com/javase/10/docs/api/java/lang/Object.html#toString()
+/// let value = unsafe {
+///     call_nonvirtual_method::<_, _, fn() -> String<'a>>(
+///         &string,
+///         &token,
+///         &object_class,
+///         "toString\0",
+///         (),
+///     )
+/// }
+/// .or_npe(&token)?
+/// .as_string(&token);
+/// assert!(value.starts_with("java.lang.String@"));
+/// # Ok(token)
+/// # }
+/// #
+/// # #[cfg(feature = "libjvm")]
+/// # fn main() {
+/// #     let init_arguments = InitArguments::default();
+/// #     let vm = JavaVM::create(&init_arguments).unwrap();
+/// #     let _ = vm.with_attached(
+/// #        &AttachArguments::new(init_arguments.version()),
+/// #        |token: NoException| {
+/// #            ((), jni_main(token).unwrap())
+/// #        },
+/// #     );
+/// # }
+/// #
+/// # #[cfg(not(feature = "libjvm"))]
+/// # fn main() {}
+/// ```
+///
+/// Note that method name string *must* be null-terminating.
+///
+/// # Safety
+///
+/// Unsafe because incorrect parameters can be passed to a method or incorrect return type specified.
+///
+/// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#callnonvirtualtypemethod-routines)
+#[inline(always)]
+pub unsafe fn call_nonvirtual_method<'a, 'b, T, A, F>(
+    object: &T,
+    token: &NoException<'a>,
+    class: &Class<'a>,
+    name: &str,
+    arguments: A::ActualType,
+) -> JavaResult<'a, <<F as JavaMethodSignature<'b, 'a, A>>::Out as JavaMethodResult<'a>>::ResultType>
+where
+    T: JavaClass<'a>,
+    A: JavaArgumentTuple<'b, 'a>,
+    F: JavaMethodSignature<'b, 'a, A>,
+    'a: 'b,
+{
+    <<F as JavaMethodSignature<'b, 'a, A>>::Out as JavaMethodResult<'a>>::call_nonvirtual_method::<
+        T,
+        <A::ActualType as ToJniTypeTuple>::JniType,
+    >(
+        object,
+        class,
+        token,
+        name,
+        &F::method_signature(),
+        ToJniTypeTuple::to_jni(&arguments),
+    )
+}
+
+/// Call a Java method with a variable number of `Object` arguments.
+///
+/// Java compiles `Object...` parameters into a trailing `Object[]` parameter. This function
+/// takes the fixed leading arguments as a tuple and packs `varargs` into a temporary
+/// [`ObjectArray`](struct.ObjectArray.html) which is appended as the last argument.
+/// The array only lives for the duration of the call.
+///
+/// The function has three generic parameters:
+///  - The first one is the class of the object. Can be inferred
+///  - The second one is the type of the fixed arguments tuple. Can be inferred
+///  - The third one is the signature of the Java method without the varargs parameter.
+///    Must be specified
+///
+/// Example:
+/// ```
+/// # use rust_jni::*;
+/// # use rust_jni::java::lang::{Object, String};
+/// #
+/// # fn jni_main<'a>(token: NoException<'a>) -> JavaResult<'a, NoException<'a>> {
+/// let format = String::new(&token, "%s and %s")?;
+/// let first = String::new(&token, "this")?;
+/// let second = String::new(&token, "that")?;
+/// // Safe because correct arguments are passed and correct return type specified.
+/// // See `String::formatted(Object...)` javadoc:
+/// // https://docs.oracle.com/en/java/javase/15/docs/api/java.base/java/lang/String.html#formatted(java.lang.Object...)
+/// let value = unsafe {
+///     call_method_varargs::<_, _, fn() -> String<'a>>(
+///         &format,
+///         &token,
+///         "formatted\0",
+///         (),
+///         &[&first, &second],
+///     )
+/// }
+/// .or_npe(&token)?
+/// .as_string(&token);
+/// assert_eq!(value, "this and that");
+/// # Ok(token)
+/// # }
+/// #
+/// # #[cfg(feature = "libjvm")]
+/// # fn main() {
+/// #     let init_arguments = InitArguments::default();
+/// #     let vm = JavaVM::create(&init_arguments).unwrap();
+/// #     let _ = vm.with_attached(
+/// #        &AttachArguments::new(init_arguments.version()),
+/// #        |token: NoException| {
+/// #            ((), jni_main(token).unwrap())
+/// #        },
+/// #     );
+/// # }
+/// #
+/// # #[cfg(not(feature = "libjvm"))]
+/// # fn main() {}
+/// ```
+///
+/// Note that method name string *must* be null-terminating.
+///
+/// # Safety
+///
+/// Unsafe because incorrect parameters can be passed to a method or incorrect return type specified.
+///
+/// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#calltypemethod-routines)
+#[inline(always)]
+pub unsafe fn call_method_varargs<'a, 'b, T, A, F>(
+    object: &T,
+    token: &NoException<'a>,
+    name: &str,
+    arguments: A::ActualType,
+    varargs: &[&Object<'a>],
+) -> JavaResult<'a, <<F as JavaMethodSignature<'b, 'a, A>>::Out as JavaMethodResult<'a>>::ResultType>
+where
+    T: JavaClass<'a>,
+    A: JavaArgumentTuple<'b, 'a>,
+    F: JavaMethodSignature<'b, 'a, A>,
+    'a: 'b,
+{
+    let array = ObjectArray::<Object>::new(token, varargs.len(), None::<&Object>)?;
+    for (index, value) in varargs.iter().enumerate() {
+        array.set(token, index, *value)?;
+    }
+    <<F as JavaMethodSignature<'b, 'a, A>>::Out as JavaMethodResult<'a>>::call_method::<
+        T,
+        <A::ActualType as ToJniTypeTuple>::JniTypeWithObject,
+    >(
+        object,
+        token,
+        name,
+        &varargs_method_signature(&F::method_signature()),
+        ToJniTypeTuple::to_jni_with_object(&arguments, array.raw_object().as_ptr()),
+    )
+}
+
+/// Append an `Object[]` parameter to a method signature.
+fn varargs_method_signature(signature: &str) -> std::string::String {
+    // Argument signatures never contain parentheses, so the first one closes the argument list.
+    let end = signature.find(')').unwrap();
+    format!(
+        "{}{}{}",
+        &signature[..end],
+        <ObjectArray<Object> as JniSignature>::signature(),
+        &signature[end..]
+    )
+}
+
+/// Set a value of a Java object's field.
+///
+/// The function has two generic parameters:
+///  - The first one is the type of the field. Must be specified
+///  - The second one is the class of the object. Can be inferred
+///
+/// Object field values are passed as [`Option`](https://doc.rust-lang.org/std/option/enum.Option.html)-s
+/// as they can be `null`.
+///
+/// Note that field name string *must* be null-terminating.
+///
+/// # Safety
+///
+/// Unsafe because incorrect field type can be specified.
+///
+/// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#settypefield-routines)
+#[inline(always)]
+pub unsafe fn set_field<'a, F, T>(
+    object: &T,
+    token: &NoException<'a>,
+    name: &str,
+    value: F::ArgumentType<'_>,
+) -> JavaResult<'a, ()>
+where
+    F: JavaFieldType<'a>,
+    T: JavaClass<'a>,
+{
+    F::set_field(object, token, name, &format!("{}\0", F::signature()), value)
+}
+
+/// Get a value of a Java class's static field.
+///
+/// The function has two generic parameters:
+///  - The first one is the type of the field. Must be specified
+///  - The second one is the class owning the field. Must be specified
+///
+/// Object fields are returned as [`Option`](https://doc.rust-lang.org/std/option/enum.Option.html)-s
+/// as they can be `null`.
+///
+/// Example:
+/// ```
+/// # use rust_jni::*;
+/// # use rust_jni::java::lang::Integer;
+/// #
+/// # fn jni_main<'a>(token: NoException<'a>) -> JavaResult<'a, NoException<'a>> {
+/// // Safe because the correct field type is specified.
+/// let max_value = unsafe { get_static_field::<i32, Integer>(&token, "MAX_VALUE\0") }?;
+/// assert_eq!(max_value, i32::MAX);
+/// # Ok(token)
+/// # }
+/// #
+/// # #[cfg(feature = "libjvm")]
+/// # fn main() {
+/// #     let init_arguments = InitArguments::default();
+/// #     let vm = JavaVM::create(&init_arguments).unwrap();
+/// #     let _ = vm.with_attached(
+/// #        &AttachArguments::new(init_arguments.version()),
+/// #        |token: NoException| {
+/// #            ((), jni_main(token).unwrap())
+/// #        },
+/// #     );
+/// # }
+/// #
+/// # #[cfg(not(feature = "libjvm"))]
+/// # fn main() {}
+/// ```
+///
+/// Note that field name string *must* be null-terminating.
+///
+/// # Safety
+///
+/// Unsafe because incorrect field type can be specified.
+///
+/// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#getstatictypefield-routines)
+#[inline(always)]
+pub unsafe fn get_static_field<'a, F, T>(
+    token: &NoException<'a>,
+    name: &str,
+) -> JavaResult<'a, F::ResultType>
+where
+    F: JavaFieldType<'a>,
+    T: JavaClass<'a>,
+{
+    F::get_static_field::<T>(token, name, &format!("{}\0", F::signature()))
+}
+
+/// Set a value of a Java class's static field.
+///
+/// The function has two generic parameters:
+///  - The first one is the type of the field. Must be specified
+///  - The second one is the class owning the field. Must be specified
+///
+/// Object field values are passed as [`Option`](https://doc.rust-lang.org/std/option/enum.Option.html)-s
+/// as they can be `null`.
+///
+/// Note that field name string *must* be null-terminating.
+///
+/// # Safety
+///
+/// Unsafe because incorrect field type can be specified.
+///
+/// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#setstatictypefield-routines)
+#[inline(always)]
+pub unsafe fn set_static_field<'a, F, T>(
+    token: &NoException<'a>,
+    name: &str,
+    value: F::ArgumentType<'_>,
+) -> JavaResult<'a, ()>
+where
+    F: JavaFieldType<'a>,
+    T: JavaClass<'a>,
+{
+    F::set_static_field::<T>(token, name, &format!("{}\0", F::signature()), value)
+}
+
+#[cfg(test)]
+mod java_methods_tests {
+    use super::*;
+    use crate::env::JniEnv;
+    use crate::string::String;
+    use crate::vm::JavaVMRef;
+    use mockall::Sequence;
+    use serial_test::serial;
+    use std::ffi::CStr;
+    use std::mem::{self, ManuallyDrop};
+
+    generate_jni_env_mock!(jni_mock);
+
+    #[test]
+    #[serial]
+    fn nonvirtual_int_method() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_object = 0x1234 as jni_sys::jobject;
+        let raw_class = 0x5678 as jni_sys::jclass;
+        let raw_method_id = 0x9012 as jni_sys::jmethodID;
+        let mut sequence = Sequence::new();
+        let get_method_id_mock = jni_mock::get_method_id_context();
+        get_method_id_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, class, name, signature| unsafe {
+                *env == raw_env_ptr
+                    && *class == raw_class
+                    && CStr::from_ptr(*name).to_str() == Ok("hashCode")
+                    && CStr::from_ptr(*signature).to_str() == Ok("()I")
+            })
+            .returning_st(move |_env, _class, _name, _signature| raw_method_id)
+            .in_sequence(&mut sequence);
+        let call_nonvirtual_int_method_a_mock = jni_mock::call_nonvirtual_int_method_a_context();
+        call_nonvirtual_int_method_a_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object, class, method_id, _arguments| {
+                *env == raw_env_ptr
+                    && *object == raw_object
+                    && *class == raw_class
+                    && *method_id == raw_method_id
+            })
+            .return_const(17)
+            .in_sequence(&mut sequence);
+        let exception_occured_mock = jni_mock::exception_occured_context();
+        exception_occured_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env| *env == raw_env_ptr)
+            .returning_st(|_env| ptr::null_mut())
+            .in_sequence(&mut sequence);
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let token = NoException::test(&env);
+        let object =
+            ManuallyDrop::new(unsafe { Object::from_raw(&env, NonNull::new(raw_object).unwrap()) });
+        let class =
+            ManuallyDrop::new(unsafe { Class::from_raw(&env, NonNull::new(raw_class).unwrap()) });
+        assert_eq!(
+            unsafe {
+                call_nonvirtual_method::<_, _, fn() -> i32>(
+                    &*object,
+                    &token,
+                    &class,
+                    "hashCode\0",
+                    (),
+                )
+            }
+            .unwrap(),
+            17
+        );
+    }
+
+    #[test]
+    #[serial]
+    fn nonvirtual_object_method() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_object = 0x1234 as jni_sys::jobject;
+        let raw_class = 0x5678 as jni_sys::jclass;
+        let raw_method_id = 0x9012 as jni_sys::jmethodID;
+        let raw_result = 0x3456 as jni_sys::jobject;
+        let mut sequence = Sequence::new();
+        let get_method_id_mock = jni_mock::get_method_id_context();
+        get_method_id_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, class, name, signature| unsafe {
+                *env == raw_env_ptr
+                    && *class == raw_class
+                    && CStr::from_ptr(*name).to_str() == Ok("toString")
+                    && CStr::from_ptr(*signature).to_str() == Ok("()Ljava/lang/String;")
+            })
+            .returning_st(move |_env, _class, _name, _signature| raw_method_id)
+            .in_sequence(&mut sequence);
+        let call_nonvirtual_object_method_a_mock =
+            jni_mock::call_nonvirtual_object_method_a_context();
+        call_nonvirtual_object_method_a_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object, class, method_id, _arguments| {
+                *env == raw_env_ptr
+                    && *object == raw_object
+                    && *class == raw_class
+                    && *method_id == raw_method_id
+            })
+            .returning_st(move |_env, _object, _class, _method_id, _arguments| raw_result)
+            .in_sequence(&mut sequence);
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let token = NoException::test(&env);
+        let object =
+            ManuallyDrop::new(unsafe { Object::from_raw(&env, NonNull::new(raw_object).unwrap()) });
+        let class =
+            ManuallyDrop::new(unsafe { Class::from_raw(&env, NonNull::new(raw_class).unwrap()) });
+        fn call_to_string<'a>(
+            object: &Object<'a>,
+            token: &NoException<'a>,
+            class: &Class<'a>,
+        ) -> JavaResult<'a, Option<String<'a>>> {
+            unsafe {
+                call_nonvirtual_method::<_, _, fn() -> String<'a>>(
+                    object,
+                    token,
+                    class,
+                    "toString\0",
+                    (),
+                )
+            }
+        }
+        let result = call_to_string(&object, &token, &class).unwrap().unwrap();
+        assert_eq!(unsafe { result.raw_object() }.as_ptr(), raw_result);
+        mem::forget(result);
+    }
+
+    #[test]
+    #[serial]
+    fn varargs_method() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_object = 0x1234 as jni_sys::jobject;
+        let raw_class = 0x5678 as jni_sys::jclass;
+        let raw_object_class = 0x7890 as jni_sys::jclass;
+        let raw_method_id = 0x9012 as jni_sys::jmethodID;
+        let raw_array = 0x3456 as jni_sys::jobjectArray;
+        let raw_elements = [
+            0x1111 as jni_sys::jobject,
+            0x2222 as jni_sys::jobject,
+            0x3333 as jni_sys::jobject,
+        ];
+        let mut sequence = Sequence::new();
+        let find_class_mock = jni_mock::find_class_context();
+        find_class_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, name| unsafe {
+                *env == raw_env_ptr && CStr::from_ptr(*name).to_str() == Ok("java/lang/Object")
+            })
+            .returning_st(move |_env, _name| raw_object_class)
+            .in_sequence(&mut sequence);
+        let new_object_array_mock = jni_mock::new_object_array_context();
+        new_object_array_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, length, class, initial| {
+                *env == raw_env_ptr
+                    && *length == 3
+                    && *class == raw_object_class
+                    && initial.is_null()
+            })
+            .returning_st(move |_env, _length, _class, _initial| raw_array)
+            .in_sequence(&mut sequence);
+        let delete_local_ref_mock = jni_mock::delete_local_ref_context();
+        delete_local_ref_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object| *env == raw_env_ptr && *object == raw_object_class)
+            .return_const(())
+            .in_sequence(&mut sequence);
+        let set_object_array_element_mock = jni_mock::set_object_array_element_context();
+        let exception_occured_mock = jni_mock::exception_occured_context();
+        for (index, raw_element) in raw_elements.iter().cloned().enumerate() {
+            set_object_array_element_mock
+                .expect()
+                .times(1)
+                .withf_st(move |env, array, element_index, value| {
+                    *env == raw_env_ptr
+                        && *array == raw_array
+                        && *element_index == index as jni_sys::jsize
+                        && *value == raw_element
+                })
+                .return_const(())
+                .in_sequence(&mut sequence);
+            exception_occured_mock
+                .expect()
+                .times(1)
+                .withf_st(move |env| *env == raw_env_ptr)
+                .returning_st(|_env| ptr::null_mut())
+                .in_sequence(&mut sequence);
+        }
+        let get_object_class_mock = jni_mock::get_object_class_context();
+        get_object_class_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object| *env == raw_env_ptr && *object == raw_object)
+            .returning_st(move |_env, _object| raw_class)
+            .in_sequence(&mut sequence);
+        let get_method_id_mock = jni_mock::get_method_id_context();
+        get_method_id_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, class, name, signature| unsafe {
+                *env == raw_env_ptr
+                    && *class == raw_class
+                    && CStr::from_ptr(*name).to_str() == Ok("count")
+                    && CStr::from_ptr(*signature).to_str() == Ok("(I[Ljava/lang/Object;)I")
+            })
+            .returning_st(move |_env, _class, _name, _signature| raw_method_id)
+            .in_sequence(&mut sequence);
+        let call_int_method_a_mock = jni_mock::call_int_method_a_context();
+        call_int_method_a_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object, method_id, arguments| unsafe {
+                *env == raw_env_ptr
+                    && *object == raw_object
+                    && *method_id == raw_method_id
+                    && (**arguments).i == 17
+                    && (*arguments.offset(1)).l == raw_array
+            })
+            .return_const(3)
+            .in_sequence(&mut sequence);
+        exception_occured_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env| *env == raw_env_ptr)
+            .returning_st(|_env| ptr::null_mut())
+            .in_sequence(&mut sequence);
+        delete_local_ref_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object| *env == raw_env_ptr && *object == raw_class)
+            .return_const(())
+            .in_sequence(&mut sequence);
+        delete_local_ref_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object| *env == raw_env_ptr && *object == raw_array)
+            .return_const(())
+            .in_sequence(&mut sequence);
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let token = NoException::test(&env);
+        let object =
+            ManuallyDrop::new(unsafe { Object::from_raw(&env, NonNull::new(raw_object).unwrap()) });
+        let elements = raw_elements
+            .iter()
+            .map(|raw_element| {
+                ManuallyDrop::new(unsafe {
+                    Object::from_raw(&env, NonNull::new(*raw_element).unwrap())
+                })
+            })
+            .collect::<Vec<_>>();
+        let varargs = elements
+            .iter()
+            .map(|element| &**element)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            unsafe {
+                call_method_varargs::<_, _, fn(i32) -> i32>(
+                    &*object,
+                    &token,
+                    "count\0",
+                    (17,),
+                    &varargs,
+                )
+            }
+            .unwrap(),
+            3
+        );
+    }
+
+    #[test]
+    fn varargs_signature() {
+        assert_eq!(
+            varargs_method_signature("()V\0"),
+            "([Ljava/lang/Object;)V\0"
+        );
+        assert_eq!(
+            varargs_method_signature("(ILjava/lang/String;)[I\0"),
+            "(ILjava/lang/String;[Ljava/lang/Object;)[I\0"
+        );
+    }
+
+    #[test]
+    #[serial]
+    fn int_field() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_object = 0x1234 as jni_sys::jobject;
+        let raw_class = 0x5678 as jni_sys::jclass;
+        let raw_field_id = 0x9012 as jni_sys::jfieldID;
+        let mut sequence = Sequence::new();
+        let get_object_class_mock = jni_mock::get_object_class_context();
+        let get_field_id_mock = jni_mock::get_field_id_context();
+        let get_int_field_mock = jni_mock::get_int_field_context();
+        let set_int_field_mock = jni_mock::set_int_field_context();
+        let delete_local_ref_mock = jni_mock::delete_local_ref_context();
+        get_object_class_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object| *env == raw_env_ptr && *object == raw_object)
+            .returning_st(move |_env, _object| raw_class)
+            .in_sequence(&mut sequence);
+        get_field_id_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, class, name, signature| unsafe {
+                *env == raw_env_ptr
+                    && *class == raw_class
+                    && CStr::from_ptr(*name).to_str() == Ok("value")
+                    && CStr::from_ptr(*signature).to_str() == Ok("I")
+            })
+            .returning_st(move |_env, _class, _name, _signature| raw_field_id)
+            .in_sequence(&mut sequence);
+        get_int_field_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object, field_id| {
+                *env == raw_env_ptr && *object == raw_object && *field_id == raw_field_id
+            })
+            .return_const(17)
+            .in_sequence(&mut sequence);
+        delete_local_ref_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object| *env == raw_env_ptr && *object == raw_class)
+            .return_const(())
+            .in_sequence(&mut sequence);
+        get_object_class_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object| *env == raw_env_ptr && *object == raw_object)
+            .returning_st(move |_env, _object| raw_class)
+            .in_sequence(&mut sequence);
+        get_field_id_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, class, name, signature| unsafe {
+                *env == raw_env_ptr
+                    && *class == raw_class
+                    && CStr::from_ptr(*name).to_str() == Ok("value")
+                    && CStr::from_ptr(*signature).to_str() == Ok("I")
+            })
+            .returning_st(move |_env, _class, _name, _signature| raw_field_id)
+            .in_sequence(&mut sequence);
+        set_int_field_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object, field_id, value| {
+                *env == raw_env_ptr
+                    && *object == raw_object
+                    && *field_id == raw_field_id
+                    && *value == 18
+            })
+            .return_const(())
+            .in_sequence(&mut sequence);
+        delete_local_ref_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object| *env == raw_env_ptr && *object == raw_class)
+            .return_const(())
+            .in_sequence(&mut sequence);
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let token = NoException::test(&env);
+        let object =
+            ManuallyDrop::new(unsafe { Object::from_raw(&env, NonNull::new(raw_object).unwrap()) });
+        assert_eq!(
+            unsafe { get_field::<i32, _>(&*object, &token, "value\0") }.unwrap(),
+            17
+        );
+        unsafe { set_field::<i32, _>(&*object, &token, "value\0", 18) }.unwrap();
+    }
+
+    #[test]
+    #[serial]
+    fn object_field() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_object = 0x1234 as jni_sys::jobject;
+        let raw_class = 0x5678 as jni_sys::jclass;
+        let raw_field_id = 0x9012 as jni_sys::jfieldID;
+        let raw_value = 0x3456 as jni_sys::jobject;
+        let mut sequence = Sequence::new();
+        let get_object_class_mock = jni_mock::get_object_class_context();
+        let get_field_id_mock = jni_mock::get_field_id_context();
+        let get_object_field_mock = jni_mock::get_object_field_context();
+        let set_object_field_mock = jni_mock::set_object_field_context();
+        let delete_local_ref_mock = jni_mock::delete_local_ref_context();
+        get_object_class_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object| *env == raw_env_ptr && *object == raw_object)
+            .returning_st(move |_env, _object| raw_class)
+            .in_sequence(&mut sequence);
+        get_field_id_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, class, name, signature| unsafe {
+                *env == raw_env_ptr
+                    && *class == raw_class
+                    && CStr::from_ptr(*name).to_str() == Ok("field")
+                    && CStr::from_ptr(*signature).to_str() == Ok("Ljava/lang/Object;")
+            })
+            .returning_st(move |_env, _class, _name, _signature| raw_field_id)
+            .in_sequence(&mut sequence);
+        get_object_field_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object, field_id| {
+                *env == raw_env_ptr && *object == raw_object && *field_id == raw_field_id
+            })
+            .returning_st(move |_env, _object, _field_id| raw_value)
+            .in_sequence(&mut sequence);
+        delete_local_ref_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object| *env == raw_env_ptr && *object == raw_class)
+            .return_const(())
+            .in_sequence(&mut sequence);
+        get_object_class_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object| *env == raw_env_ptr && *object == raw_object)
+            .returning_st(move |_env, _object| raw_class)
+            .in_sequence(&mut sequence);
+        get_field_id_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, class, name, signature| unsafe {
+                *env == raw_env_ptr
+                    && *class == raw_class
+                    && CStr::from_ptr(*name).to_str() == Ok("field")
+                    && CStr::from_ptr(*signature).to_str() == Ok("Ljava/lang/Object;")
+            })
+            .returning_st(move |_env, _class, _name, _signature| raw_field_id)
+            .in_sequence(&mut sequence);
+        set_object_field_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object, field_id, value| {
+                *env == raw_env_ptr
+                    && *object == raw_object
+                    && *field_id == raw_field_id
+                    && value.is_null()
+            })
+            .return_const(())
+            .in_sequence(&mut sequence);
+        delete_local_ref_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object| *env == raw_env_ptr && *object == raw_class)
+            .return_const(())
+            .in_sequence(&mut sequence);
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let token = NoException::test(&env);
+        let object =
+            ManuallyDrop::new(unsafe { Object::from_raw(&env, NonNull::new(raw_object).unwrap()) });
+        let value = unsafe { get_field::<Object, _>(&*object, &token, "field\0") }
+            .unwrap()
+            .unwrap();
+        assert_eq!(unsafe { value.raw_object() }.as_ptr(), raw_value);
+        mem::forget(value);
+        unsafe { set_field::<Object, _>(&*object, &token, "field\0", None) }.unwrap();
+    }
+
+    #[test]
+    #[serial]
+    fn static_object_field() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_class = 0x5678 as jni_sys::jclass;
+        let raw_field_id = 0x9012 as jni_sys::jfieldID;
+        let raw_value = 0x3456 as jni_sys::jobject;
+        let mut sequence = Sequence::new();
+        let find_class_mock = jni_mock::find_class_context();
+        let get_static_field_id_mock = jni_mock::get_static_field_id_context();
+        let get_static_object_field_mock = jni_mock::get_static_object_field_context();
+        let set_static_object_field_mock = jni_mock::set_static_object_field_context();
+        let delete_local_ref_mock = jni_mock::delete_local_ref_context();
+        find_class_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, name| unsafe {
+                *env == raw_env_ptr && CStr::from_ptr(*name).to_str() == Ok("java/lang/String")
+            })
+            .returning_st(move |_env, _name| raw_class)
+            .in_sequence(&mut sequence);
+        get_static_field_id_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, class, name, signature| unsafe {
+                *env == raw_env_ptr
+                    && *class == raw_class
+                    && CStr::from_ptr(*name).to_str() == Ok("CONSTANT")
+                    && CStr::from_ptr(*signature).to_str() == Ok("Ljava/lang/String;")
+            })
+            .returning_st(move |_env, _class, _name, _signature| raw_field_id)
+            .in_sequence(&mut sequence);
+        get_static_object_field_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, class, field_id| {
+                *env == raw_env_ptr && *class == raw_class && *field_id == raw_field_id
+            })
+            .returning_st(move |_env, _class, _field_id| raw_value)
+            .in_sequence(&mut sequence);
+        delete_local_ref_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object| *env == raw_env_ptr && *object == raw_class)
+            .return_const(())
+            .in_sequence(&mut sequence);
+        find_class_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, name| unsafe {
+                *env == raw_env_ptr && CStr::from_ptr(*name).to_str() == Ok("java/lang/String")
+            })
+            .returning_st(move |_env, _name| raw_class)
+            .in_sequence(&mut sequence);
+        get_static_field_id_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, class, name, signature| unsafe {
+                *env == raw_env_ptr
+                    && *class == raw_class
+                    && CStr::from_ptr(*name).to_str() == Ok("CONSTANT")
+                    && CStr::from_ptr(*signature).to_str() == Ok("Ljava/lang/String;")
+            })
+            .returning_st(move |_env, _class, _name, _signature| raw_field_id)
+            .in_sequence(&mut sequence);
+        set_static_object_field_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, class, field_id, value| {
+                *env == raw_env_ptr
+                    && *class == raw_class
+                    && *field_id == raw_field_id
+                    && *value == raw_value
+            })
+            .return_const(())
+            .in_sequence(&mut sequence);
+        delete_local_ref_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object| *env == raw_env_ptr && *object == raw_class)
+            .return_const(())
+            .in_sequence(&mut sequence);
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let token = NoException::test(&env);
+        let value = unsafe { get_static_field::<String, String>(&token, "CONSTANT\0") }
+            .unwrap()
+            .unwrap();
+        assert_eq!(unsafe { value.raw_object() }.as_ptr(), raw_value);
+        unsafe { set_static_field::<String, String>(&token, "CONSTANT\0", Some(&value)) }.unwrap();
+        mem::forget(value);
+    }
 }
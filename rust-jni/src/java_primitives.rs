@@ -1,8 +1,10 @@
+use crate::class::Class;
 use crate::env::JniEnv;
 use crate::java_class::find_class;
 use crate::java_class::JavaClass;
 use crate::java_class::JniSignature;
 use crate::java_methods::JavaArgumentType;
+use crate::java_methods::JavaFieldType;
 use crate::java_methods::JavaMethodResult;
 use crate::java_methods::ToJniType;
 use crate::jni_bool;
@@ -103,6 +105,31 @@ macro_rules! java_method_result_trait {
                     )?;
                 Ok(JavaPrimitiveType::from_jni(result))
             }
+
+            #[inline(always)]
+            unsafe fn call_nonvirtual_method<T, A>(
+                object: &T,
+                class: &Class<'a>,
+                token: &NoException<'a>,
+                name: &str,
+                signature: &str,
+                arguments: A,
+            ) -> JavaResult<'a, Self::ResultType>
+            where
+                T: JavaClass<'a>,
+                A: JniArgumentTypeTuple,
+            {
+                let result: <Self as JavaPrimitiveType>::JniType =
+                    jni_methods::call_nonvirtual_primitive_method(
+                        object.as_ref(),
+                        class,
+                        token,
+                        name,
+                        signature,
+                        arguments,
+                    )?;
+                Ok(JavaPrimitiveType::from_jni(result))
+            }
         }
 
         impl ToJavaNativeResult for $type {
@@ -147,12 +174,92 @@ macro_rules! java_primitive_native_argument_trait {
     };
 }
 
+macro_rules! java_field_type_trait {
+    ($type:ty) => {
+        impl<'a> JavaFieldType<'a> for $type {
+            type ResultType = Self;
+            type ArgumentType<'b> = Self;
+
+            #[inline(always)]
+            unsafe fn get_field<T>(
+                object: &T,
+                token: &NoException<'a>,
+                name: &str,
+                signature: &str,
+            ) -> JavaResult<'a, Self::ResultType>
+            where
+                T: JavaClass<'a>,
+            {
+                let result: <Self as JavaPrimitiveType>::JniType =
+                    jni_methods::get_field(object.as_ref(), token, name, signature)?;
+                Ok(JavaPrimitiveType::from_jni(result))
+            }
+
+            #[inline(always)]
+            unsafe fn set_field<T>(
+                object: &T,
+                token: &NoException<'a>,
+                name: &str,
+                signature: &str,
+                value: Self::ArgumentType<'_>,
+            ) -> JavaResult<'a, ()>
+            where
+                T: JavaClass<'a>,
+            {
+                jni_methods::set_field(
+                    object.as_ref(),
+                    token,
+                    name,
+                    signature,
+                    JavaPrimitiveType::to_jni(value),
+                )
+            }
+
+            #[inline(always)]
+            unsafe fn get_static_field<T>(
+                token: &NoException<'a>,
+                name: &str,
+                signature: &str,
+            ) -> JavaResult<'a, Self::ResultType>
+            where
+                T: JavaClass<'a>,
+            {
+                let class = find_class::<T>(token)?;
+                let result: <Self as JavaPrimitiveType>::JniType =
+                    jni_methods::get_static_field(&class, token, name, signature)?;
+                Ok(JavaPrimitiveType::from_jni(result))
+            }
+
+            #[inline(always)]
+            unsafe fn set_static_field<T>(
+                token: &NoException<'a>,
+                name: &str,
+                signature: &str,
+                value: Self::ArgumentType<'_>,
+            ) -> JavaResult<'a, ()>
+            where
+                T: JavaClass<'a>,
+            {
+                let class = find_class::<T>(token)?;
+                jni_methods::set_static_field(
+                    &class,
+                    token,
+                    name,
+                    signature,
+                    JavaPrimitiveType::to_jni(value),
+                )
+            }
+        }
+    };
+}
+
 macro_rules! java_primitive_traits {
     ($type:ty, $jni_type:ty, $typedoc:expr) => {
         java_primitive_type_trait!($type, $jni_type, $typedoc);
         java_primitive_argument_trait!($type);
         java_primitive_native_argument_trait!($type);
         java_method_result_trait!($type);
+        java_field_type_trait!($type);
     };
 }
 
@@ -183,6 +290,7 @@ java_signature_trait!(
 java_primitive_argument_trait!(bool);
 java_primitive_native_argument_trait!(bool);
 java_method_result_trait!(bool);
+java_field_type_trait!(bool);
 
 impl JavaPrimitiveType for char {
     type JniType = jni_sys::jchar;
@@ -218,6 +326,7 @@ java_signature_trait!(
 java_primitive_argument_trait!(char);
 java_primitive_native_argument_trait!(char);
 java_method_result_trait!(char);
+java_field_type_trait!(char);
 
 java_primitive_traits!(
     u8,
@@ -240,18 +349,72 @@ java_primitive_traits!(
     "[`i64`](https://doc.rust-lang.org/std/primitive.i64.html)"
 );
 
-java_primitive_type_trait!(
+java_primitive_traits!(
     f32,
     jni_sys::jfloat,
     "[`f32`](https://doc.rust-lang.org/std/primitive.f32.html)"
 );
-// TODO(#25): floating point numbers don't work properly.
-// java_primitive_argument_trait!(f32);
-java_primitive_native_argument_trait!(f32);
-java_method_result_trait!(f32);
 
 java_primitive_traits!(
     f64,
     jni_sys::jdouble,
     "[`f64`](https://doc.rust-lang.org/std/primitive.f64.html)"
 );
+
+#[cfg(test)]
+mod java_primitives_tests {
+    use super::*;
+    use crate::jni_types::private::JniType;
+    use crate::object::Object;
+    use crate::vm::JavaVMRef;
+    use core::ptr::NonNull;
+    use serial_test::serial;
+    use std::f32;
+    use std::mem::ManuallyDrop;
+
+    generate_jni_env_mock!(jni_mock);
+
+    /// Pass the value to a mocked `CallFloatMethodA` that returns its argument.
+    fn float_round_trip(value: f32) -> f32 {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_object = 0x1234 as jni_sys::jobject;
+        let raw_method_id = 0x5678 as jni_sys::jmethodID;
+        let call_float_method_a_mock = jni_mock::call_float_method_a_context();
+        call_float_method_a_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object, method_id, _arguments| {
+                *env == raw_env_ptr && *object == raw_object && *method_id == raw_method_id
+            })
+            .returning_st(|_env, _object, _method_id, arguments| unsafe { (*arguments).f });
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let token = NoException::test(&env);
+        let object =
+            ManuallyDrop::new(unsafe { Object::from_raw(&env, NonNull::new(raw_object).unwrap()) });
+        let argument = unsafe { <f32 as ToJniType>::to_jni(&value) };
+        let result = unsafe {
+            <jni_sys::jfloat as JniType>::call_method(&token, &object, raw_method_id, (argument,))
+        };
+        <f32 as JavaPrimitiveType>::from_jni(result)
+    }
+
+    #[test]
+    #[serial]
+    fn float() {
+        assert_eq!(float_round_trip(1.5), 1.5);
+    }
+
+    #[test]
+    #[serial]
+    fn float_nan() {
+        assert!(float_round_trip(f32::NAN).is_nan());
+    }
+
+    #[test]
+    #[serial]
+    fn float_infinity() {
+        assert_eq!(float_round_trip(f32::INFINITY), f32::INFINITY);
+    }
+}
@@ -15,6 +15,22 @@ pub(crate) fn to_jni(value: bool) -> jni_sys::jboolean {
     }
 }
 
+/// Convert elements of a Java `boolean[]` region.
+///
+/// Unlike [`to_rust`](fn.to_rust.html), any non-zero element is mapped to `true`, as the region
+/// is written by Java code and JNI doesn't guarantee the values to be `JNI_TRUE`.
+pub(crate) fn to_rust_array(values: &[jni_sys::jboolean]) -> Vec<bool> {
+    values
+        .iter()
+        .map(|value| *value != jni_sys::JNI_FALSE)
+        .collect()
+}
+
+/// Convert a Rust `bool` slice for a Java `boolean[]` region.
+pub(crate) fn to_jni_array(values: &[bool]) -> Vec<jni_sys::jboolean> {
+    values.iter().map(|value| to_jni(*value)).collect()
+}
+
 #[cfg(test)]
 mod jni_bool_tests {
     use super::*;
@@ -36,4 +52,26 @@ mod jni_bool_tests {
     fn test_to_rust_unknown() {
         to_rust(10);
     }
+
+    #[test]
+    fn test_to_jni_array() {
+        assert_eq!(
+            to_jni_array(&[true, false, true]),
+            vec![jni_sys::JNI_TRUE, jni_sys::JNI_FALSE, jni_sys::JNI_TRUE]
+        );
+    }
+
+    #[test]
+    fn test_to_rust_array() {
+        assert_eq!(
+            to_rust_array(&[jni_sys::JNI_TRUE, jni_sys::JNI_FALSE, 10]),
+            vec![true, false, true]
+        );
+    }
+
+    #[test]
+    fn test_array_round_trip() {
+        let values = [true, false, false, true, true, false];
+        assert_eq!(to_rust_array(&to_jni_array(&values)), values);
+    }
 }
@@ -2,7 +2,8 @@ use crate::class::Class;
 use crate::java_string::{
     to_java_string_null_terminated, to_java_string_null_terminated_unchecked,
 };
-use crate::jni_types::private::{JniArgumentTypeTuple, JniPrimitiveType, JniType};
+use crate::jni_types::private::{JniArgumentTypeTuple, JniFieldType, JniPrimitiveType, JniType};
+use crate::method_cache::cached_method_id;
 use crate::object::Object;
 use crate::result::JavaResult;
 use crate::token::{CallOutcome, NoException};
@@ -18,43 +19,87 @@ unsafe fn get_method_id<'a>(
     name: &str,
     signature: &str,
 ) -> JavaResult<'a, NonNull<jni_sys::_jmethodID>> {
+    cached_method_id(class, token, false, name, signature, || {
+        let name = to_java_string_null_terminated(name);
+        let signature = to_java_string_null_terminated_unchecked(signature);
+        // Safe because arguments are ensured to be the correct by construction and because
+        // `GetMethodID` throws an exception before returning `null`.
+        #[allow(unused_unsafe)]
+        unsafe {
+            call_nullable_jni_method!(
+                token,
+                GetMethodID,
+                class.raw_object().as_ptr(),
+                name.as_ptr() as *const c_char,
+                signature.as_ptr() as *const c_char
+            )
+        }
+    })
+}
+
+/// Unsafe because signature must be null-terminated.
+unsafe fn get_static_method_id<'a>(
+    class: &Class<'a>,
+    token: &NoException<'a>,
+    name: &str,
+    signature: &str,
+) -> JavaResult<'a, NonNull<jni_sys::_jmethodID>> {
+    cached_method_id(class, token, true, name, signature, || {
+        let name = to_java_string_null_terminated(name);
+        let signature = to_java_string_null_terminated_unchecked(signature);
+        // Safe because arguments are ensured to be the correct by construction and because
+        // `GetMethodID` throws an exception before returning `null`.
+        #[allow(unused_unsafe)]
+        unsafe {
+            call_nullable_jni_method!(
+                token,
+                GetStaticMethodID,
+                class.raw_object().as_ptr(),
+                name.as_ptr() as *const c_char,
+                signature.as_ptr() as *const c_char
+            )
+        }
+    })
+}
+
+/// Unsafe because signature must be null-terminated.
+unsafe fn get_field_id<'a>(
+    class: &Class<'a>,
+    token: &NoException<'a>,
+    name: &str,
+    signature: &str,
+) -> JavaResult<'a, NonNull<jni_sys::_jfieldID>> {
     let name = to_java_string_null_terminated(name);
     let signature = to_java_string_null_terminated_unchecked(signature);
     // Safe because arguments are ensured to be the correct by construction and because
-    // `GetMethodID` throws an exception before returning `null`.
-    #[allow(unused_unsafe)]
-    unsafe {
-        call_nullable_jni_method!(
-            token,
-            GetMethodID,
-            class.raw_object().as_ptr(),
-            name.as_ptr() as *const c_char,
-            signature.as_ptr() as *const c_char
-        )
-    }
+    // `GetFieldID` throws an exception before returning `null`.
+    call_nullable_jni_method!(
+        token,
+        GetFieldID,
+        class.raw_object().as_ptr(),
+        name.as_ptr() as *const c_char,
+        signature.as_ptr() as *const c_char
+    )
 }
 
 /// Unsafe because signature must be null-terminated.
-unsafe fn get_static_method_id<'a>(
+unsafe fn get_static_field_id<'a>(
     class: &Class<'a>,
     token: &NoException<'a>,
     name: &str,
     signature: &str,
-) -> JavaResult<'a, NonNull<jni_sys::_jmethodID>> {
+) -> JavaResult<'a, NonNull<jni_sys::_jfieldID>> {
     let name = to_java_string_null_terminated(name);
     let signature = to_java_string_null_terminated_unchecked(signature);
     // Safe because arguments are ensured to be the correct by construction and because
-    // `GetMethodID` throws an exception before returning `null`.
-    #[allow(unused_unsafe)]
-    unsafe {
-        call_nullable_jni_method!(
-            token,
-            GetStaticMethodID,
-            class.raw_object().as_ptr(),
-            name.as_ptr() as *const c_char,
-            signature.as_ptr() as *const c_char
-        )
-    }
+    // `GetStaticFieldID` throws an exception before returning `null`.
+    call_nullable_jni_method!(
+        token,
+        GetStaticFieldID,
+        class.raw_object().as_ptr(),
+        name.as_ptr() as *const c_char,
+        signature.as_ptr() as *const c_char
+    )
 }
 
 /// Call a method on a Java object that returns a primitive value.
@@ -109,6 +154,66 @@ pub(crate) unsafe fn call_object_method<'a>(
     )
 }
 
+/// Call a method implementation of a specific Java class on a Java object that returns
+/// a primitive value.
+///
+/// Unsafe because it is possible to pass incorrect arguments or return type.
+pub(crate) unsafe fn call_nonvirtual_primitive_method<'a, R: JniPrimitiveType>(
+    object: &Object<'a>,
+    class: &Class<'a>,
+    token: &NoException<'a>,
+    name: &str,
+    signature: &str,
+    arguments: impl JniArgumentTypeTuple,
+) -> JavaResult<'a, R> {
+    let method_id = get_method_id(class, token, name, signature)?;
+    token.with_owned(
+        #[inline(always)]
+        |token| {
+            CallOutcome::Unknown(R::call_nonvirtual_method(
+                &token,
+                object,
+                class,
+                method_id.as_ptr(),
+                arguments,
+            ))
+        },
+    )
+}
+
+/// Call a method implementation of a specific Java class on a Java object that returns
+/// another object.
+///
+/// Unsafe because it is possible to pass incorrect arguments or return type.
+pub(crate) unsafe fn call_nonvirtual_object_method<'a>(
+    object: &Object<'a>,
+    class: &Class<'a>,
+    token: &NoException<'a>,
+    name: &str,
+    signature: &str,
+    arguments: impl JniArgumentTypeTuple,
+) -> JavaResult<'a, Option<NonNull<jni_sys::_jobject>>> {
+    let method_id = get_method_id(class, token, name, signature)?;
+    token.with_owned(
+        #[inline(always)]
+        |token| {
+            let result = jni_sys::jobject::call_nonvirtual_method(
+                &token,
+                object,
+                class,
+                method_id.as_ptr(),
+                arguments,
+            );
+            match NonNull::new(result) {
+                // The method could have just returned null, but also could have thrown an Exception.
+                None => CallOutcome::Unknown(None),
+                // We know that there is no exception because a non-null was returned.
+                result => CallOutcome::Ok((result, token)),
+            }
+        },
+    )
+}
+
 /// Call a static method on a Java class that returns a primitive value.
 ///
 /// Unsafe because it is possible to pass incorrect arguments or return type.
@@ -159,6 +264,9 @@ pub(crate) unsafe fn call_static_object_method<'a>(
 
 /// Call a constructor of a Java class.
 ///
+/// The constructor is always resolved as `<init>`, so non-public constructors can be called
+/// too as JNI doesn't enforce Java access control.
+///
 /// Unsafe because it is possible to pass incorrect arguments.
 pub(crate) unsafe fn call_constructor<'a, A: JniArgumentTypeTuple>(
     class: &Class<'a>,
@@ -179,3 +287,65 @@ pub(crate) unsafe fn call_constructor<'a, A: JniArgumentTypeTuple>(
         },
     )
 }
+
+/// Get a value of a Java object's field.
+///
+/// Unsafe because it is possible to pass an incorrect field type.
+pub(crate) unsafe fn get_field<'a, R: JniFieldType>(
+    object: &Object<'a>,
+    token: &NoException<'a>,
+    name: &str,
+    signature: &str,
+) -> JavaResult<'a, R> {
+    let class = object.class(token);
+    let field_id = get_field_id(&class, token, name, signature)?;
+    // `Get<Type>Field` functions don't throw exceptions.
+    Ok(R::get_field(token, object, field_id.as_ptr()))
+}
+
+/// Set a value of a Java object's field.
+///
+/// Unsafe because it is possible to pass an incorrect field type.
+pub(crate) unsafe fn set_field<'a, R: JniFieldType>(
+    object: &Object<'a>,
+    token: &NoException<'a>,
+    name: &str,
+    signature: &str,
+    value: R,
+) -> JavaResult<'a, ()> {
+    let class = object.class(token);
+    let field_id = get_field_id(&class, token, name, signature)?;
+    // `Set<Type>Field` functions don't throw exceptions.
+    R::set_field(token, object, field_id.as_ptr(), value);
+    Ok(())
+}
+
+/// Get a value of a Java class's static field.
+///
+/// Unsafe because it is possible to pass an incorrect field type.
+pub(crate) unsafe fn get_static_field<'a, R: JniFieldType>(
+    class: &Class<'a>,
+    token: &NoException<'a>,
+    name: &str,
+    signature: &str,
+) -> JavaResult<'a, R> {
+    let field_id = get_static_field_id(class, token, name, signature)?;
+    // `GetStatic<Type>Field` functions don't throw exceptions.
+    Ok(R::get_static_field(token, class, field_id.as_ptr()))
+}
+
+/// Set a value of a Java class's static field.
+///
+/// Unsafe because it is possible to pass an incorrect field type.
+pub(crate) unsafe fn set_static_field<'a, R: JniFieldType>(
+    class: &Class<'a>,
+    token: &NoException<'a>,
+    name: &str,
+    signature: &str,
+    value: R,
+) -> JavaResult<'a, ()> {
+    let field_id = get_static_field_id(class, token, name, signature)?;
+    // `SetStatic<Type>Field` functions don't throw exceptions.
+    R::set_static_field(token, class, field_id.as_ptr(), value);
+    Ok(())
+}
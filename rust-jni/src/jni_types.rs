@@ -28,6 +28,14 @@ pub(crate) mod private {
             method_id: jni_sys::jmethodID,
             arguments: In,
         ) -> Self;
+
+        unsafe fn call_nonvirtual_method<In: JniArgumentTypeTuple>(
+            token: &NoException,
+            object: &Object,
+            class: &Class,
+            method_id: jni_sys::jmethodID,
+            arguments: In,
+        ) -> Self;
     }
 
     /// A trait that represents primitive JNI types. It's implemented for all JNI primitive types.
@@ -37,18 +45,49 @@ pub(crate) mod private {
 
     /// A trait that represents JNI types that can be passed as arguments to JNI functions.
     /// Implemented for all JNI types except for [`()`](https://doc.rust-lang.org/stable/std/primitive.unit.html).
-    ///
-    /// Temporarily [not implemented](https://github.com/Monnoroch/rust-jni/issues/25) for
-    /// [`jfloat`](https://docs.rs/jni-sys/0.3.0/jni_sys/type.jfloat.html).
-    pub trait JniArgumentType: JniType {}
+    pub trait JniArgumentType: JniType {
+        /// Convert the value into a [`jvalue`](https://docs.rs/jni-sys/0.3.0/jni_sys/union.jvalue.html)
+        /// to be passed to a JNI function in an array of arguments.
+        fn to_jni_value(self) -> jni_sys::jvalue;
+    }
 
-    /// A trait that represents JNI types that can be passed as arguments to native Java functions.
+    /// A trait that represents JNI types that can be stored in Java fields.
     /// Implemented for all JNI types except for [`()`](https://doc.rust-lang.org/stable/std/primitive.unit.html).
-    // TODO(#25): remove this trait and replace with JniArgumentType when the float issue is fixed.
-    pub trait JniNativeArgumentType: JniType {}
+    /// Implements reading and writing Java fields.
+    pub trait JniFieldType: JniType {
+        unsafe fn get_field(
+            token: &NoException,
+            object: &Object,
+            field_id: jni_sys::jfieldID,
+        ) -> Self;
+
+        unsafe fn set_field(
+            token: &NoException,
+            object: &Object,
+            field_id: jni_sys::jfieldID,
+            value: Self,
+        );
+
+        unsafe fn get_static_field(
+            token: &NoException,
+            class: &Class,
+            field_id: jni_sys::jfieldID,
+        ) -> Self;
+
+        unsafe fn set_static_field(
+            token: &NoException,
+            class: &Class,
+            field_id: jni_sys::jfieldID,
+            value: Self,
+        );
+    }
 
-    /// A trait that implements calling JNI variadic functions using a macro to generate
-    /// it's instances for tuples of different sizes.
+    /// A trait that implements calling JNI functions taking an array of arguments using a macro
+    /// to generate it's instances for tuples of different sizes.
+    ///
+    /// The `...A` JNI functions are used instead of the variadic ones, because C variadic
+    /// arguments promote [`jfloat`](https://docs.rs/jni-sys/0.3.0/jni_sys/type.jfloat.html)-s
+    /// to doubles, which Rust doesn't do.
     /// This is essentially the "[`JniType`](trait.JniType.html) for packed argument tuples".
     // TODO: reimplement once Rust has variadic functions or variadic templates.
     pub trait JniArgumentTypeTuple {
@@ -73,6 +112,14 @@ pub(crate) mod private {
             arguments: Self,
         ) -> jni_sys::jobject;
 
+        unsafe fn call_nonvirtual_object_method(
+            token: &NoException,
+            object: &Object,
+            class: &Class,
+            method_id: jni_sys::jmethodID,
+            arguments: Self,
+        ) -> jni_sys::jobject;
+
         unsafe fn call_void_method(
             token: &NoException,
             object: &Object,
@@ -87,6 +134,14 @@ pub(crate) mod private {
             arguments: Self,
         ) -> ();
 
+        unsafe fn call_nonvirtual_void_method(
+            token: &NoException,
+            object: &Object,
+            class: &Class,
+            method_id: jni_sys::jmethodID,
+            arguments: Self,
+        ) -> ();
+
         unsafe fn call_boolean_method(
             token: &NoException,
             object: &Object,
@@ -101,6 +156,14 @@ pub(crate) mod private {
             arguments: Self,
         ) -> jni_sys::jboolean;
 
+        unsafe fn call_nonvirtual_boolean_method(
+            token: &NoException,
+            object: &Object,
+            class: &Class,
+            method_id: jni_sys::jmethodID,
+            arguments: Self,
+        ) -> jni_sys::jboolean;
+
         unsafe fn call_char_method(
             token: &NoException,
             object: &Object,
@@ -115,6 +178,14 @@ pub(crate) mod private {
             arguments: Self,
         ) -> jni_sys::jchar;
 
+        unsafe fn call_nonvirtual_char_method(
+            token: &NoException,
+            object: &Object,
+            class: &Class,
+            method_id: jni_sys::jmethodID,
+            arguments: Self,
+        ) -> jni_sys::jchar;
+
         unsafe fn call_byte_method(
             token: &NoException,
             object: &Object,
@@ -129,6 +200,14 @@ pub(crate) mod private {
             arguments: Self,
         ) -> jni_sys::jbyte;
 
+        unsafe fn call_nonvirtual_byte_method(
+            token: &NoException,
+            object: &Object,
+            class: &Class,
+            method_id: jni_sys::jmethodID,
+            arguments: Self,
+        ) -> jni_sys::jbyte;
+
         unsafe fn call_short_method(
             token: &NoException,
             object: &Object,
@@ -143,6 +222,14 @@ pub(crate) mod private {
             arguments: Self,
         ) -> jni_sys::jshort;
 
+        unsafe fn call_nonvirtual_short_method(
+            token: &NoException,
+            object: &Object,
+            class: &Class,
+            method_id: jni_sys::jmethodID,
+            arguments: Self,
+        ) -> jni_sys::jshort;
+
         unsafe fn call_int_method(
             token: &NoException,
             object: &Object,
@@ -157,6 +244,14 @@ pub(crate) mod private {
             arguments: Self,
         ) -> jni_sys::jint;
 
+        unsafe fn call_nonvirtual_int_method(
+            token: &NoException,
+            object: &Object,
+            class: &Class,
+            method_id: jni_sys::jmethodID,
+            arguments: Self,
+        ) -> jni_sys::jint;
+
         unsafe fn call_long_method(
             token: &NoException,
             object: &Object,
@@ -171,6 +266,14 @@ pub(crate) mod private {
             arguments: Self,
         ) -> jni_sys::jlong;
 
+        unsafe fn call_nonvirtual_long_method(
+            token: &NoException,
+            object: &Object,
+            class: &Class,
+            method_id: jni_sys::jmethodID,
+            arguments: Self,
+        ) -> jni_sys::jlong;
+
         unsafe fn call_float_method(
             token: &NoException,
             object: &Object,
@@ -185,6 +288,14 @@ pub(crate) mod private {
             arguments: Self,
         ) -> jni_sys::jfloat;
 
+        unsafe fn call_nonvirtual_float_method(
+            token: &NoException,
+            object: &Object,
+            class: &Class,
+            method_id: jni_sys::jmethodID,
+            arguments: Self,
+        ) -> jni_sys::jfloat;
+
         unsafe fn call_double_method(
             token: &NoException,
             object: &Object,
@@ -198,6 +309,14 @@ pub(crate) mod private {
             method_id: jni_sys::jmethodID,
             arguments: Self,
         ) -> jni_sys::jdouble;
+
+        unsafe fn call_nonvirtual_double_method(
+            token: &NoException,
+            object: &Object,
+            class: &Class,
+            method_id: jni_sys::jmethodID,
+            arguments: Self,
+        ) -> jni_sys::jdouble;
     }
 }
 
@@ -205,7 +324,7 @@ use private::*;
 
 /// A macro for generating [`JniType`](trait.JniType.html) implementation for primitive types.
 macro_rules! jni_type_trait {
-    ($type:ty, $default:expr, $method:ident, $static_method:ident) => {
+    ($type:ty, $default:expr, $method:ident, $static_method:ident, $nonvirtual_method:ident) => {
         impl JniType for $type {
             #[inline(always)]
             fn default() -> Self {
@@ -231,6 +350,17 @@ macro_rules! jni_type_trait {
             ) -> Self {
                 In::$static_method(token, class, method_id, arguments)
             }
+
+            #[inline(always)]
+            unsafe fn call_nonvirtual_method<In: JniArgumentTypeTuple>(
+                token: &NoException,
+                object: &Object,
+                class: &Class,
+                method_id: jni_sys::jmethodID,
+                arguments: In,
+            ) -> Self {
+                In::$nonvirtual_method(token, object, class, method_id, arguments)
+            }
         }
     };
 }
@@ -239,13 +369,21 @@ jni_type_trait!(
     jni_sys::jobject,
     ptr::null_mut(),
     call_object_method,
-    call_static_object_method
+    call_static_object_method,
+    call_nonvirtual_object_method
 );
 
 /// A macro for generating [`JniPrimitiveType`](trait.JniPrimitiveType.html) implementation for primitive types.
 macro_rules! jni_primitive_type_trait {
-    ($type:ty, $default:expr, $signature:expr, $method:ident, $static_method:ident) => {
-        jni_type_trait!($type, $default, $method, $static_method);
+    (
+        $type:ty,
+        $default:expr,
+        $signature:expr,
+        $method:ident,
+        $static_method:ident,
+        $nonvirtual_method:ident
+    ) => {
+        jni_type_trait!($type, $default, $method, $static_method, $nonvirtual_method);
 
         impl JniPrimitiveType for $type {
             #[inline(always)]
@@ -256,62 +394,77 @@ macro_rules! jni_primitive_type_trait {
     };
 }
 
-jni_primitive_type_trait!((), (), "V", call_void_method, call_static_void_method);
+jni_primitive_type_trait!(
+    (),
+    (),
+    "V",
+    call_void_method,
+    call_static_void_method,
+    call_nonvirtual_void_method
+);
 jni_primitive_type_trait!(
     jni_sys::jboolean,
     jni_sys::JNI_FALSE,
     "Z",
     call_boolean_method,
-    call_static_boolean_method
+    call_static_boolean_method,
+    call_nonvirtual_boolean_method
 );
 jni_primitive_type_trait!(
     jni_sys::jchar,
     0,
     "C",
     call_char_method,
-    call_static_char_method
+    call_static_char_method,
+    call_nonvirtual_char_method
 );
 jni_primitive_type_trait!(
     jni_sys::jbyte,
     0,
     "B",
     call_byte_method,
-    call_static_byte_method
+    call_static_byte_method,
+    call_nonvirtual_byte_method
 );
 jni_primitive_type_trait!(
     jni_sys::jshort,
     0,
     "S",
     call_short_method,
-    call_static_short_method
+    call_static_short_method,
+    call_nonvirtual_short_method
 );
 jni_primitive_type_trait!(
     jni_sys::jint,
     0,
     "I",
     call_int_method,
-    call_static_int_method
+    call_static_int_method,
+    call_nonvirtual_int_method
 );
 jni_primitive_type_trait!(
     jni_sys::jlong,
     0,
     "J",
     call_long_method,
-    call_static_long_method
+    call_static_long_method,
+    call_nonvirtual_long_method
 );
 jni_primitive_type_trait!(
     jni_sys::jfloat,
     0.,
     "F",
     call_float_method,
-    call_static_float_method
+    call_static_float_method,
+    call_nonvirtual_float_method
 );
 jni_primitive_type_trait!(
     jni_sys::jdouble,
     0.,
     "D",
     call_double_method,
-    call_static_double_method
+    call_static_double_method,
+    call_nonvirtual_double_method
 );
 
 macro_rules! jni_method_call {
@@ -325,12 +478,38 @@ macro_rules! jni_method_call {
         ) -> $return_type {
             #[allow(non_snake_case)]
             let ($($argument,)*) = arguments;
+            let arguments: &[jni_sys::jvalue] = &[$($argument.to_jni_value(),)*];
             call_jni_object_method!(
                 token,
                 object,
                 $method,
-                method_id
-                $(,$argument)*
+                method_id,
+                arguments.as_ptr()
+            )
+        }
+    }
+}
+
+macro_rules! jni_nonvirtual_method_call {
+    ($name:ident, $method:ident, $return_type:ty, $($argument:ident,)*) => {
+        #[inline(always)]
+        unsafe fn $name(
+            token: &NoException,
+            object: &Object,
+            class: &Class,
+            method_id: jni_sys::jmethodID,
+            arguments: Self
+        ) -> $return_type {
+            #[allow(non_snake_case)]
+            let ($($argument,)*) = arguments;
+            let arguments: &[jni_sys::jvalue] = &[$($argument.to_jni_value(),)*];
+            call_jni_object_method!(
+                token,
+                object,
+                $method,
+                class.raw_object().as_ptr(),
+                method_id,
+                arguments.as_ptr()
             )
         }
     }
@@ -347,32 +526,43 @@ macro_rules! input_tuple_impls {
         where
             $($type: JniArgumentType,)*
         {
-            jni_method_call!(call_constructor, Class, NewObject, jni_sys::jobject, $($type,)*);
-            jni_method_call!(call_object_method, Object, CallObjectMethod, jni_sys::jobject, $($type,)*);
-            jni_method_call!(call_static_object_method, Class, CallStaticObjectMethod, jni_sys::jobject, $($type,)*);
-            jni_method_call!(call_void_method, Object, CallVoidMethod, (), $($type,)*);
-            jni_method_call!(call_static_void_method, Class, CallStaticVoidMethod, (), $($type,)*);
-            jni_method_call!(call_boolean_method, Object, CallBooleanMethod, jni_sys::jboolean, $($type,)*);
-            jni_method_call!(call_static_boolean_method, Class, CallStaticBooleanMethod, jni_sys::jboolean, $($type,)*);
-            jni_method_call!(call_char_method, Object, CallCharMethod, jni_sys::jchar, $($type,)*);
-            jni_method_call!(call_static_char_method, Class, CallStaticCharMethod, jni_sys::jchar, $($type,)*);
-            jni_method_call!(call_byte_method, Object, CallByteMethod, jni_sys::jbyte, $($type,)*);
-            jni_method_call!(call_static_byte_method, Class, CallStaticByteMethod, jni_sys::jbyte, $($type,)*);
-            jni_method_call!(call_short_method, Object, CallShortMethod, jni_sys::jshort, $($type,)*);
-            jni_method_call!(call_static_short_method, Class, CallStaticShortMethod, jni_sys::jshort, $($type,)*);
-            jni_method_call!(call_int_method, Object, CallIntMethod, jni_sys::jint, $($type,)*);
-            jni_method_call!(call_static_int_method, Class, CallStaticIntMethod, jni_sys::jint, $($type,)*);
-            jni_method_call!(call_long_method, Object, CallLongMethod, jni_sys::jlong, $($type,)*);
-            jni_method_call!(call_static_long_method, Class, CallStaticLongMethod, jni_sys::jlong, $($type,)*);
-            jni_method_call!(call_float_method, Object, CallFloatMethod, jni_sys::jfloat, $($type,)*);
-            jni_method_call!(call_static_float_method, Class, CallStaticFloatMethod, jni_sys::jfloat, $($type,)*);
-            jni_method_call!(call_double_method, Object, CallDoubleMethod, jni_sys::jdouble, $($type,)*);
-            jni_method_call!(call_static_double_method, Class, CallStaticDoubleMethod, jni_sys::jdouble, $($type,)*);
+            jni_method_call!(call_constructor, Class, NewObjectA, jni_sys::jobject, $($type,)*);
+            jni_method_call!(call_object_method, Object, CallObjectMethodA, jni_sys::jobject, $($type,)*);
+            jni_method_call!(call_static_object_method, Class, CallStaticObjectMethodA, jni_sys::jobject, $($type,)*);
+            jni_nonvirtual_method_call!(call_nonvirtual_object_method, CallNonvirtualObjectMethodA, jni_sys::jobject, $($type,)*);
+            jni_method_call!(call_void_method, Object, CallVoidMethodA, (), $($type,)*);
+            jni_method_call!(call_static_void_method, Class, CallStaticVoidMethodA, (), $($type,)*);
+            jni_nonvirtual_method_call!(call_nonvirtual_void_method, CallNonvirtualVoidMethodA, (), $($type,)*);
+            jni_method_call!(call_boolean_method, Object, CallBooleanMethodA, jni_sys::jboolean, $($type,)*);
+            jni_method_call!(call_static_boolean_method, Class, CallStaticBooleanMethodA, jni_sys::jboolean, $($type,)*);
+            jni_nonvirtual_method_call!(call_nonvirtual_boolean_method, CallNonvirtualBooleanMethodA, jni_sys::jboolean, $($type,)*);
+            jni_method_call!(call_char_method, Object, CallCharMethodA, jni_sys::jchar, $($type,)*);
+            jni_method_call!(call_static_char_method, Class, CallStaticCharMethodA, jni_sys::jchar, $($type,)*);
+            jni_nonvirtual_method_call!(call_nonvirtual_char_method, CallNonvirtualCharMethodA, jni_sys::jchar, $($type,)*);
+            jni_method_call!(call_byte_method, Object, CallByteMethodA, jni_sys::jbyte, $($type,)*);
+            jni_method_call!(call_static_byte_method, Class, CallStaticByteMethodA, jni_sys::jbyte, $($type,)*);
+            jni_nonvirtual_method_call!(call_nonvirtual_byte_method, CallNonvirtualByteMethodA, jni_sys::jbyte, $($type,)*);
+            jni_method_call!(call_short_method, Object, CallShortMethodA, jni_sys::jshort, $($type,)*);
+            jni_method_call!(call_static_short_method, Class, CallStaticShortMethodA, jni_sys::jshort, $($type,)*);
+            jni_nonvirtual_method_call!(call_nonvirtual_short_method, CallNonvirtualShortMethodA, jni_sys::jshort, $($type,)*);
+            jni_method_call!(call_int_method, Object, CallIntMethodA, jni_sys::jint, $($type,)*);
+            jni_method_call!(call_static_int_method, Class, CallStaticIntMethodA, jni_sys::jint, $($type,)*);
+            jni_nonvirtual_method_call!(call_nonvirtual_int_method, CallNonvirtualIntMethodA, jni_sys::jint, $($type,)*);
+            jni_method_call!(call_long_method, Object, CallLongMethodA, jni_sys::jlong, $($type,)*);
+            jni_method_call!(call_static_long_method, Class, CallStaticLongMethodA, jni_sys::jlong, $($type,)*);
+            jni_nonvirtual_method_call!(call_nonvirtual_long_method, CallNonvirtualLongMethodA, jni_sys::jlong, $($type,)*);
+            jni_method_call!(call_float_method, Object, CallFloatMethodA, jni_sys::jfloat, $($type,)*);
+            jni_method_call!(call_static_float_method, Class, CallStaticFloatMethodA, jni_sys::jfloat, $($type,)*);
+            jni_nonvirtual_method_call!(call_nonvirtual_float_method, CallNonvirtualFloatMethodA, jni_sys::jfloat, $($type,)*);
+            jni_method_call!(call_double_method, Object, CallDoubleMethodA, jni_sys::jdouble, $($type,)*);
+            jni_method_call!(call_static_double_method, Class, CallStaticDoubleMethodA, jni_sys::jdouble, $($type,)*);
+            jni_nonvirtual_method_call!(call_nonvirtual_double_method, CallNonvirtualDoubleMethodA, jni_sys::jdouble, $($type,)*);
         }
         peel_input_tuple_impls! { $($type,)* }
     );
 }
 
+// One more than the Java argument tuples support to allow appending a varargs array.
 input_tuple_impls! {
     T0,
     T1,
@@ -386,22 +576,140 @@ input_tuple_impls! {
     T9,
     T10,
     T11,
+    T12,
 }
 
-impl JniArgumentType for jni_sys::jboolean {}
-impl JniArgumentType for jni_sys::jchar {}
-impl JniArgumentType for jni_sys::jbyte {}
-impl JniArgumentType for jni_sys::jshort {}
-impl JniArgumentType for jni_sys::jint {}
-impl JniArgumentType for jni_sys::jlong {}
-// TODO(#25): floating point numbers don't work properly.
-// impl JniArgumentType for jni_sys::jfloat {}
-impl JniArgumentType for jni_sys::jdouble {}
-impl JniArgumentType for jni_sys::jobject {}
+/// A macro for generating [`JniArgumentType`](trait.JniArgumentType.html) implementation for JNI types.
+macro_rules! jni_argument_type_trait {
+    ($type:ty, $field:ident) => {
+        impl JniArgumentType for $type {
+            #[inline(always)]
+            fn to_jni_value(self) -> jni_sys::jvalue {
+                jni_sys::jvalue { $field: self }
+            }
+        }
+    };
+}
 
-impl<T> JniNativeArgumentType for T where T: JniArgumentType {}
-impl JniNativeArgumentType for jni_sys::jfloat {}
+jni_argument_type_trait!(jni_sys::jboolean, z);
+jni_argument_type_trait!(jni_sys::jchar, c);
+jni_argument_type_trait!(jni_sys::jbyte, b);
+jni_argument_type_trait!(jni_sys::jshort, s);
+jni_argument_type_trait!(jni_sys::jint, i);
+jni_argument_type_trait!(jni_sys::jlong, j);
+jni_argument_type_trait!(jni_sys::jfloat, f);
+jni_argument_type_trait!(jni_sys::jdouble, d);
+jni_argument_type_trait!(jni_sys::jobject, l);
 
 // [`()`](https://doc.rust-lang.org/stable/std/primitive.unit.html)
 // can't be passed as an argument to a function.
 // impl !JniArgumentType for () {}
+
+/// A macro for generating [`JniFieldType`](trait.JniFieldType.html) implementation for JNI types.
+macro_rules! jni_field_type_trait {
+    ($type:ty, $get_method:ident, $set_method:ident, $get_static_method:ident, $set_static_method:ident) => {
+        impl JniFieldType for $type {
+            #[inline(always)]
+            unsafe fn get_field(
+                token: &NoException,
+                object: &Object,
+                field_id: jni_sys::jfieldID,
+            ) -> Self {
+                call_jni_object_method!(token, object, $get_method, field_id)
+            }
+
+            #[inline(always)]
+            unsafe fn set_field(
+                token: &NoException,
+                object: &Object,
+                field_id: jni_sys::jfieldID,
+                value: Self,
+            ) {
+                call_jni_object_method!(token, object, $set_method, field_id, value)
+            }
+
+            #[inline(always)]
+            unsafe fn get_static_field(
+                token: &NoException,
+                class: &Class,
+                field_id: jni_sys::jfieldID,
+            ) -> Self {
+                call_jni_object_method!(token, class, $get_static_method, field_id)
+            }
+
+            #[inline(always)]
+            unsafe fn set_static_field(
+                token: &NoException,
+                class: &Class,
+                field_id: jni_sys::jfieldID,
+                value: Self,
+            ) {
+                call_jni_object_method!(token, class, $set_static_method, field_id, value)
+            }
+        }
+    };
+}
+
+jni_field_type_trait!(
+    jni_sys::jboolean,
+    GetBooleanField,
+    SetBooleanField,
+    GetStaticBooleanField,
+    SetStaticBooleanField
+);
+jni_field_type_trait!(
+    jni_sys::jchar,
+    GetCharField,
+    SetCharField,
+    GetStaticCharField,
+    SetStaticCharField
+);
+jni_field_type_trait!(
+    jni_sys::jbyte,
+    GetByteField,
+    SetByteField,
+    GetStaticByteField,
+    SetStaticByteField
+);
+jni_field_type_trait!(
+    jni_sys::jshort,
+    GetShortField,
+    SetShortField,
+    GetStaticShortField,
+    SetStaticShortField
+);
+jni_field_type_trait!(
+    jni_sys::jint,
+    GetIntField,
+    SetIntField,
+    GetStaticIntField,
+    SetStaticIntField
+);
+jni_field_type_trait!(
+    jni_sys::jlong,
+    GetLongField,
+    SetLongField,
+    GetStaticLongField,
+    SetStaticLongField
+);
+jni_field_type_trait!(
+    jni_sys::jfloat,
+    GetFloatField,
+    SetFloatField,
+    GetStaticFloatField,
+    SetStaticFloatField
+);
+jni_field_type_trait!(
+    jni_sys::jdouble,
+    GetDoubleField,
+    SetDoubleField,
+    GetStaticDoubleField,
+    SetStaticDoubleField
+);
+jni_field_type_trait!(
+    jni_sys::jobject,
+    GetObjectField,
+    SetObjectField,
+    GetStaticObjectField,
+    SetStaticObjectField
+);
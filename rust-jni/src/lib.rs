@@ -15,10 +15,12 @@
 pub mod testing;
 
 mod attach_arguments;
+mod captured_error;
 mod class;
 mod classes;
 mod env;
 mod error;
+mod global_ref;
 mod init_arguments;
 mod java_class;
 mod java_methods;
@@ -27,31 +29,43 @@ mod java_string;
 mod jni_bool;
 mod jni_methods;
 mod jni_types;
+mod method_cache;
 mod native_method;
 mod nullable;
 mod object;
+mod registry;
 mod result;
 mod string;
 mod throwable;
 mod token;
 mod version;
 mod vm;
+mod weak_global_ref;
 
 pub use attach_arguments::AttachArguments;
+pub use captured_error::CapturedJavaError;
+pub use classes::array::{ArrayElement, CriticalArrayElement, ObjectArray, PrimitiveArray};
 pub use env::JniEnv;
-pub use error::JniError;
-pub use init_arguments::{InitArguments, JvmOption, JvmVerboseOption};
+pub use error::{JniError, TokenError};
+pub use global_ref::GlobalRef;
+pub use init_arguments::{InitArguments, JvmOption, JvmVerboseOption, OptionDiff};
 pub use java_class::{FromObject, JavaClassExt, JavaClassSignature};
-pub use java_methods::JavaObjectArgument;
+pub use java_methods::{
+    call_method_varargs, call_nonvirtual_method, get_field, get_static_field, set_field,
+    set_static_field, JavaObjectArgument,
+};
 pub use native_method::{
     native_method_implementation, native_method_implementation_new,
-    static_native_method_implementation,
+    static_native_method_implementation, NativeHandle,
 };
 pub use nullable::NullableJavaClassExt;
-pub use result::JavaResult;
+pub use object::{ByIdentity, ByValue, Monitor, RefType};
+pub use registry::GlobalRegistry;
+pub use result::{JavaResult, JavaResultExt, ResultExt};
 pub use token::{ConsumedNoException, Exception, NoException};
 pub use version::JniVersion;
-pub use vm::{JavaVM, JavaVMRef};
+pub use vm::{AttachGuard, JavaVM, JavaVMRef};
+pub use weak_global_ref::WeakGlobalRef;
 
 pub mod java {
     pub mod lang {
@@ -63,9 +77,23 @@ pub mod java {
 
         pub use crate::class::Class;
         pub use crate::classes::exception::Exception;
+        pub use crate::classes::illegal_argument_exception::IllegalArgumentException;
+        pub use crate::classes::integer::Integer;
         pub use crate::classes::null_pointer_exception::NullPointerException;
+        pub use crate::classes::runtime_exception::RuntimeException;
+        pub use crate::classes::stack_trace_element::StackTraceElement;
         pub use crate::object::Object;
         pub use crate::string::String;
         pub use crate::throwable::Throwable;
     }
+
+    pub mod nio {
+        //! Package java.nio.
+        //!
+        //! Defines buffers, which are containers for data.
+        //!
+        //! [`java.nio` javadoc](https://docs.oracle.com/en/java/javase/11/docs/api/java.base/java/nio/package-summary.html)
+
+        pub use crate::classes::byte_buffer::ByteBuffer;
+    }
 }
@@ -0,0 +1,289 @@
+use crate::class::Class;
+use crate::env::JniEnv;
+use crate::jni_bool;
+use crate::result::JavaResult;
+use crate::token::{CallOutcome, NoException};
+use core::ptr::NonNull;
+use std::collections::HashMap;
+
+include!("call_jni_method.rs");
+
+/// A cache of method IDs of the methods called through a [`JniEnv`](struct.JniEnv.html).
+///
+/// Method IDs are looked up by the method name and signature. As different classes can have
+/// methods with the same name and signature, each cached method ID stores a global reference to
+/// its class, which is compared to the class of the call. The global reference also prevents
+/// the class from being unloaded, which would invalidate the method ID.
+#[derive(Debug, Default)]
+pub(crate) struct MethodCache {
+    methods: HashMap<String, HashMap<String, Vec<CachedMethod>>>,
+    static_methods: HashMap<String, HashMap<String, Vec<CachedMethod>>>,
+}
+
+#[derive(Debug)]
+struct CachedMethod {
+    class: NonNull<jni_sys::_jobject>,
+    method_id: NonNull<jni_sys::_jmethodID>,
+}
+
+impl MethodCache {
+    /// Delete global class references of all cached method IDs and empty the cache.
+    ///
+    /// Unsafe because the cache must only contain references created with this
+    /// [`JniEnv`](struct.JniEnv.html)'s Java VM.
+    pub(crate) unsafe fn clear(&mut self, env: &JniEnv) {
+        let methods = self.methods.drain().chain(self.static_methods.drain());
+        for (_, signatures) in methods {
+            for method in signatures.into_values().flatten() {
+                // `DeleteGlobalRef` can be called with a pending exception.
+                call_jni_method!(env, DeleteGlobalRef, method.class.as_ptr());
+            }
+        }
+    }
+
+    fn methods(
+        &mut self,
+        is_static: bool,
+    ) -> &mut HashMap<String, HashMap<String, Vec<CachedMethod>>> {
+        if is_static {
+            &mut self.static_methods
+        } else {
+            &mut self.methods
+        }
+    }
+}
+
+/// Get a method ID from the [`JniEnv`](struct.JniEnv.html)'s method cache or look it up and
+/// add it to the cache if the cache is enabled.
+///
+/// Unsafe because the `lookup` function must return a method ID of the `class` with the
+/// given name and signature.
+pub(crate) unsafe fn cached_method_id<'a>(
+    class: &Class<'a>,
+    token: &NoException<'a>,
+    is_static: bool,
+    name: &str,
+    signature: &str,
+    lookup: impl FnOnce() -> JavaResult<'a, NonNull<jni_sys::_jmethodID>>,
+) -> JavaResult<'a, NonNull<jni_sys::_jmethodID>> {
+    let env = token.env();
+    let mut cache = env.method_cache.borrow_mut();
+    let cache = match cache.as_mut() {
+        None => return lookup(),
+        Some(cache) => cache,
+    };
+    let cached = cache
+        .methods(is_static)
+        .get(name)
+        .and_then(|signatures| signatures.get(signature))
+        .and_then(|methods| {
+            methods.iter().find(|method| {
+                jni_bool::to_rust(call_jni_method!(
+                    env,
+                    IsSameObject,
+                    method.class.as_ptr(),
+                    class.raw_object().as_ptr()
+                ))
+            })
+        });
+    if let Some(method) = cached {
+        return Ok(method.method_id);
+    }
+    let method_id = lookup()?;
+    // Safe because the arguments are correct and because `NewGlobalRef` throws an exception
+    // before returning `null`.
+    let class = call_nullable_jni_method!(token, NewGlobalRef, class.raw_object().as_ptr())?;
+    cache
+        .methods(is_static)
+        .entry(name.to_owned())
+        .or_default()
+        .entry(signature.to_owned())
+        .or_default()
+        .push(CachedMethod { class, method_id });
+    Ok(method_id)
+}
+
+#[cfg(test)]
+mod method_cache_tests {
+    use crate::object::Object;
+    use crate::token::NoException;
+    use crate::vm::JavaVMRef;
+    use crate::JniEnv;
+    use core::ptr::{self, NonNull};
+    use mockall::Sequence;
+    use serial_test::serial;
+    use std::ffi::CStr;
+    use std::mem::ManuallyDrop;
+
+    generate_jni_env_mock!(jni_mock);
+
+    #[test]
+    #[serial]
+    fn method_id_cached() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_object = 0x1234 as jni_sys::jobject;
+        let raw_class = 0x5678 as jni_sys::jclass;
+        let raw_global_class = 0x9012 as jni_sys::jclass;
+        let raw_method_id = 0x3456 as jni_sys::jmethodID;
+        let mut sequence = Sequence::new();
+        let get_object_class_mock = jni_mock::get_object_class_context();
+        let get_method_id_mock = jni_mock::get_method_id_context();
+        let new_global_ref_mock = jni_mock::new_global_ref_context();
+        let is_same_object_mock = jni_mock::is_same_object_context();
+        let call_int_method_a_mock = jni_mock::call_int_method_a_context();
+        let exception_occured_mock = jni_mock::exception_occured_context();
+        let delete_local_ref_mock = jni_mock::delete_local_ref_context();
+        let delete_global_ref_mock = jni_mock::delete_global_ref_context();
+        get_object_class_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object| *env == raw_env_ptr && *object == raw_object)
+            .returning_st(move |_env, _object| raw_class)
+            .in_sequence(&mut sequence);
+        get_method_id_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, class, name, signature| unsafe {
+                *env == raw_env_ptr
+                    && *class == raw_class
+                    && CStr::from_ptr(*name).to_str() == Ok("hashCode")
+                    && CStr::from_ptr(*signature).to_str() == Ok("()I")
+            })
+            .returning_st(move |_env, _class, _name, _signature| raw_method_id)
+            .in_sequence(&mut sequence);
+        new_global_ref_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object| *env == raw_env_ptr && *object == raw_class)
+            .returning_st(move |_env, _object| raw_global_class)
+            .in_sequence(&mut sequence);
+        call_int_method_a_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object, method_id, _arguments| {
+                *env == raw_env_ptr && *object == raw_object && *method_id == raw_method_id
+            })
+            .return_const(17)
+            .in_sequence(&mut sequence);
+        exception_occured_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env| *env == raw_env_ptr)
+            .returning_st(|_env| ptr::null_mut())
+            .in_sequence(&mut sequence);
+        delete_local_ref_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object| *env == raw_env_ptr && *object == raw_class)
+            .return_const(())
+            .in_sequence(&mut sequence);
+        get_object_class_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object| *env == raw_env_ptr && *object == raw_object)
+            .returning_st(move |_env, _object| raw_class)
+            .in_sequence(&mut sequence);
+        is_same_object_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object1, object2| {
+                *env == raw_env_ptr && *object1 == raw_global_class && *object2 == raw_class
+            })
+            .return_const(jni_sys::JNI_TRUE)
+            .in_sequence(&mut sequence);
+        call_int_method_a_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object, method_id, _arguments| {
+                *env == raw_env_ptr && *object == raw_object && *method_id == raw_method_id
+            })
+            .return_const(17)
+            .in_sequence(&mut sequence);
+        exception_occured_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env| *env == raw_env_ptr)
+            .returning_st(|_env| ptr::null_mut())
+            .in_sequence(&mut sequence);
+        delete_local_ref_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object| *env == raw_env_ptr && *object == raw_class)
+            .return_const(())
+            .in_sequence(&mut sequence);
+        delete_global_ref_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object| *env == raw_env_ptr && *object == raw_global_class)
+            .return_const(())
+            .in_sequence(&mut sequence);
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        env.enable_method_cache();
+        let token = NoException::test(&env);
+        let object =
+            ManuallyDrop::new(unsafe { Object::from_raw(&env, NonNull::new(raw_object).unwrap()) });
+        assert_eq!(object.hash_code(&token).unwrap(), 17);
+        assert_eq!(object.hash_code(&token).unwrap(), 17);
+        env.clear_method_cache();
+    }
+
+    #[test]
+    #[serial]
+    fn method_id_different_class() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_object = 0x1234 as jni_sys::jobject;
+        let raw_class = 0x5678 as jni_sys::jclass;
+        let raw_global_class = 0x9012 as jni_sys::jclass;
+        let raw_method_id = 0x3456 as jni_sys::jmethodID;
+        let get_object_class_mock = jni_mock::get_object_class_context();
+        get_object_class_mock
+            .expect()
+            .times(2)
+            .returning_st(move |_env, _object| raw_class);
+        let get_method_id_mock = jni_mock::get_method_id_context();
+        get_method_id_mock
+            .expect()
+            .times(2)
+            .returning_st(move |_env, _class, _name, _signature| raw_method_id);
+        let new_global_ref_mock = jni_mock::new_global_ref_context();
+        new_global_ref_mock
+            .expect()
+            .times(2)
+            .returning_st(move |_env, _object| raw_global_class);
+        let is_same_object_mock = jni_mock::is_same_object_context();
+        is_same_object_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object1, object2| {
+                *env == raw_env_ptr && *object1 == raw_global_class && *object2 == raw_class
+            })
+            .return_const(jni_sys::JNI_FALSE);
+        let call_int_method_a_mock = jni_mock::call_int_method_a_context();
+        call_int_method_a_mock.expect().times(2).return_const(17);
+        let exception_occured_mock = jni_mock::exception_occured_context();
+        exception_occured_mock
+            .expect()
+            .times(2)
+            .returning_st(|_env| ptr::null_mut());
+        let delete_local_ref_mock = jni_mock::delete_local_ref_context();
+        delete_local_ref_mock.expect().times(2).return_const(());
+        let delete_global_ref_mock = jni_mock::delete_global_ref_context();
+        delete_global_ref_mock
+            .expect()
+            .times(2)
+            .withf_st(move |env, object| *env == raw_env_ptr && *object == raw_global_class)
+            .return_const(());
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        env.enable_method_cache();
+        let token = NoException::test(&env);
+        let object =
+            ManuallyDrop::new(unsafe { Object::from_raw(&env, NonNull::new(raw_object).unwrap()) });
+        assert_eq!(object.hash_code(&token).unwrap(), 17);
+        assert_eq!(object.hash_code(&token).unwrap(), 17);
+        env.clear_method_cache();
+    }
+}
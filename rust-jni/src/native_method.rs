@@ -4,13 +4,14 @@ use crate::error::JniError;
 use crate::java_class::FromObject;
 use crate::java_class::JavaClass;
 use crate::java_string::to_java_string_null_terminated;
-use crate::jni_types::private::JniNativeArgumentType;
+use crate::jni_types::private::JniArgumentType;
 use crate::jni_types::private::JniType;
 use crate::object::Object;
 use crate::result::JavaResult;
 use crate::token::NoException;
 use crate::vm::JavaVMRef;
 use jni_sys;
+use std::marker::PhantomData;
 use std::mem;
 use std::mem::ManuallyDrop;
 use std::panic;
@@ -60,7 +61,7 @@ where
 /// These are either primitive types convertible to JNI types or
 /// [`Option`](https://doc.rust-lang.org/std/option/enum.Option.html)-s of Java class wrappers.
 pub trait ToJavaNativeArgument<'this> {
-    type JniType: JniNativeArgumentType;
+    type JniType: JniArgumentType;
     type ArgumentType;
 
     unsafe fn from_raw(env: &'this JniEnv<'this>, value: Self::JniType) -> Self::ArgumentType;
@@ -657,7 +658,10 @@ where
         #[allow(unused_unsafe)]
         let env = ManuallyDrop::new(unsafe { JniEnv::new(&vm, NonNull::new(raw_env).unwrap()) });
         let token = env.token();
-        callback(token, arguments)
+        let result = callback(token, arguments);
+        // The `JniEnv` is not dropped, so the method cache needs to be cleared explicitly.
+        env.clear_method_cache();
+        result
     });
     match result {
         Ok(result) => result,
@@ -704,3 +708,103 @@ unsafe fn throw_new_runtime_exception(raw_env: *mut jni_sys::JNIEnv, message: im
         }
     }
 }
+
+/// A helper for storing a pointer to a Rust value in a Java `long` field.
+///
+/// Java classes with native state usually keep a pointer to the Rust value in a `long` field,
+/// create it in a native constructor and free it in a native `close` or `finalize` method.
+/// [`jlong`](../jni_sys/type.jlong.html) is always 64-bit, so it can hold a pointer
+/// on any platform. A `0` value is used as a `null` sentinel.
+///
+/// Example:
+/// ```
+/// # use rust_jni::*;
+/// #
+/// let handle = NativeHandle::into_jlong(Box::new(17));
+/// assert_ne!(handle, NativeHandle::<i32>::NULL);
+/// unsafe {
+///     *NativeHandle::<i32>::from_jlong(handle).unwrap() += 1;
+///     assert_eq!(NativeHandle::<i32>::take_jlong(handle), Some(Box::new(18)));
+///     assert_eq!(NativeHandle::<i32>::from_jlong(NativeHandle::<i32>::NULL), None);
+/// }
+/// ```
+#[derive(Debug)]
+pub struct NativeHandle<T> {
+    _type: PhantomData<T>,
+}
+
+impl<T> NativeHandle<T> {
+    /// A `null` handle value.
+    pub const NULL: jni_sys::jlong = 0;
+
+    /// Transfer ownership of the value to a handle.
+    ///
+    /// The value is leaked until it is reclaimed with
+    /// [`take_jlong`](struct.NativeHandle.html#method.take_jlong).
+    pub fn into_jlong(value: Box<T>) -> jni_sys::jlong {
+        Box::into_raw(value) as usize as jni_sys::jlong
+    }
+
+    /// Get a reference to the value owned by the handle. Returns
+    /// [`None`](https://doc.rust-lang.org/std/option/enum.Option.html#variant.None)
+    /// for a `null` handle.
+    ///
+    /// # Safety
+    ///
+    /// Unsafe because the handle must be either `null` or created by
+    /// [`into_jlong`](struct.NativeHandle.html#method.into_jlong) and not yet taken and
+    /// because the caller chooses the reference lifetime.
+    pub unsafe fn from_jlong<'a>(handle: jni_sys::jlong) -> Option<&'a mut T> {
+        (handle as usize as *mut T).as_mut()
+    }
+
+    /// Take back ownership of the value owned by the handle. Returns
+    /// [`None`](https://doc.rust-lang.org/std/option/enum.Option.html#variant.None)
+    /// for a `null` handle.
+    ///
+    /// # Safety
+    ///
+    /// Unsafe because the handle must be either `null` or created by
+    /// [`into_jlong`](struct.NativeHandle.html#method.into_jlong) and not yet taken.
+    pub unsafe fn take_jlong(handle: jni_sys::jlong) -> Option<Box<T>> {
+        NonNull::new(handle as usize as *mut T).map(|value| Box::from_raw(value.as_ptr()))
+    }
+}
+
+#[cfg(test)]
+mod native_handle_tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let handle = NativeHandle::into_jlong(Box::new(vec![1, 2, 3]));
+        assert_ne!(handle, NativeHandle::<Vec<i32>>::NULL);
+        unsafe {
+            NativeHandle::<Vec<i32>>::from_jlong(handle)
+                .unwrap()
+                .push(4);
+            assert_eq!(
+                NativeHandle::<Vec<i32>>::from_jlong(handle),
+                Some(&mut vec![1, 2, 3, 4])
+            );
+            assert_eq!(
+                NativeHandle::<Vec<i32>>::take_jlong(handle),
+                Some(Box::new(vec![1, 2, 3, 4]))
+            );
+        }
+    }
+
+    #[test]
+    fn null() {
+        unsafe {
+            assert_eq!(
+                NativeHandle::<i32>::from_jlong(NativeHandle::<i32>::NULL),
+                None
+            );
+            assert_eq!(
+                NativeHandle::<i32>::take_jlong(NativeHandle::<i32>::NULL),
+                None
+            );
+        }
+    }
+}
@@ -1,16 +1,21 @@
 use crate::class::Class;
 use crate::env::JniEnv;
+use crate::global_ref::GlobalRef;
 use crate::java_class::JavaClass;
 use crate::java_class::JavaClassExt;
 use crate::java_class::{FromObject, JavaClassSignature};
 use crate::java_methods::JavaObjectArgument;
 use crate::jni_bool;
+use crate::jni_methods;
+use crate::nullable::NullableJavaClassExt;
 use crate::result::JavaResult;
 use crate::string::String;
 use crate::token::{CallOutcome, NoException};
+use crate::weak_global_ref::WeakGlobalRef;
 use core::ptr::{self, NonNull};
 use jni_sys;
 use std::fmt;
+use std::hash::{Hash, Hasher};
 use std::mem;
 
 include!("call_jni_method.rs");
@@ -19,6 +24,12 @@ include!("call_jni_method.rs");
 /// [`java.lang.Object`](https://docs.oracle.com/javase/10/docs/api/java/lang/Object.html) class
 /// -- the root class of Java's class hierarchy.
 ///
+/// An [`Object`](struct.Object.html) never wraps a `null` reference. Java methods that
+/// return objects produce an `Option`, with Java `null` represented as
+/// [`None`](https://doc.rust-lang.org/std/option/enum.Option.html#variant.None).
+/// Use [`or_npe`](../../trait.NullableJavaClassExt.html#tymethod.or_npe) to turn a `null`
+/// into a [`NullPointerException`](struct.NullPointerException.html).
+///
 /// [`Object` javadoc](https://docs.oracle.com/javase/10/docs/api/java/lang/Object.html)
 // TODO: examples.
 pub struct Object<'env> {
@@ -75,6 +86,9 @@ impl<'env> Object<'env> {
 
     /// Compare with another Java object by reference.
     ///
+    /// Accepts a reference to any Java class, so objects of different classes can be compared
+    /// without casting them to [`Object`](struct.Object.html) first.
+    ///
     /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#issameobject)
     pub fn is_same_as<'a>(
         &self,
@@ -111,6 +125,17 @@ impl<'env> Object<'env> {
         jni_bool::to_rust(is_instance)
     }
 
+    /// Get the kind of the reference this object holds.
+    ///
+    /// Useful for debugging reference leaks. Requires JNI 1.6 or newer.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#getobjectreftype)
+    pub fn ref_type(&self, token: &NoException) -> RefType {
+        // Safe because arguments are ensured to be correct references by construction.
+        let ref_type = unsafe { call_jni_object_method!(token, self, GetObjectRefType) };
+        RefType::from_raw(ref_type)
+    }
+
     /// Clone the [`Object`](struct.Object.html). This is not a deep clone of the Java object,
     /// but a Rust-like clone of the value. Since Java objects are reference counted, this will
     /// increment the reference count.
@@ -130,6 +155,21 @@ impl<'env> Object<'env> {
         Ok(unsafe { Self::from_raw(self.env, raw_object) })
     }
 
+    /// Get the fully-qualified name of the object's class, as returned by
+    /// [`Class::get_name`](struct.Class.html#method.get_name).
+    ///
+    /// Mostly useful for debugging and error messages.
+    pub fn get_class_name(
+        &self,
+        token: &NoException<'env>,
+    ) -> JavaResult<'env, std::string::String> {
+        Ok(self
+            .class(token)
+            .get_name(token)
+            .or_npe(token)?
+            .as_string(token))
+    }
+
     /// Convert the object to a string.
     ///
     /// [`Object::toString` javadoc](https://docs.oracle.com/javase/10/docs/api/java/lang/Object.html#toString())
@@ -140,16 +180,23 @@ impl<'env> Object<'env> {
 
     /// Compare to another Java object.
     ///
+    /// Objects that are the same by reference are always equal according to the
+    /// [`Object::equals`](https://docs.oracle.com/javase/10/docs/api/java/lang/Object.html#equals(java.lang.Object))
+    /// contract, so the Java method is only called if [`is_same_as`](#method.is_same_as)
+    /// returns `false`.
+    ///
     /// [`Object::equals` javadoc](https://docs.oracle.com/javase/10/docs/api/java/lang/Object.html#equals(java.lang.Object))
     pub fn equals(
         &self,
         token: &NoException<'env>,
         other: impl JavaObjectArgument<Object<'env>>,
     ) -> JavaResult<'env, bool> {
-        // Safe because we ensure correct arguments and return type.
-        unsafe {
-            self.call_method::<_, fn(&Object) -> bool>(token, "equals\0", (other.as_argument(),))
+        let other = other.as_argument();
+        if self.is_same_as(token, other) {
+            return Ok(true);
         }
+        // Safe because we ensure correct arguments and return type.
+        unsafe { self.call_method::<_, fn(&Object) -> bool>(token, "equals\0", (other,)) }
     }
 
     /// Get the hash code of the [`Object`](struct.Object.html).
@@ -160,6 +207,150 @@ impl<'env> Object<'env> {
         unsafe { self.call_method::<_, fn() -> i32>(token, "hashCode\0", ()) }
     }
 
+    /// Get the identity hash code of the [`Object`](struct.Object.html).
+    ///
+    /// Unlike [`hash_code`](#method.hash_code), the identity hash code is consistent with
+    /// [`is_same_as`](#method.is_same_as): all references to the same object have the same
+    /// identity hash code.
+    ///
+    /// [`System::identityHashCode` javadoc](https://docs.oracle.com/javase/10/docs/api/java/lang/System.html#identityHashCode(java.lang.Object))
+    pub fn identity_hash_code(&self, token: &NoException<'env>) -> JavaResult<'env, i32> {
+        let system = Class::find(token, "java/lang/System")?;
+        // Safe because we ensure correct arguments and return type.
+        unsafe {
+            jni_methods::call_static_primitive_method(
+                &system,
+                token,
+                "identityHashCode\0",
+                "(Ljava/lang/Object;)I\0",
+                (self.raw_object().as_ptr(),),
+            )
+        }
+    }
+
+    /// Create a type-erased global reference to the object, which can outlive the
+    /// [`JniEnv`](../../struct.JniEnv.html) and be stored long-term.
+    ///
+    /// Use [`GlobalRef::with`](../../struct.GlobalRef.html#method.with) to temporarily view
+    /// the referenced object as a local [`Object`](struct.Object.html) again.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#newglobalref)
+    pub fn to_global(
+        &self,
+        token: &NoException<'env>,
+    ) -> JavaResult<'env, GlobalRef<Object<'static>>> {
+        GlobalRef::new(token, self)
+    }
+
+    /// Create a type-erased weak global reference to the object, which doesn't prevent
+    /// the object from being garbage collected.
+    ///
+    /// Use [`WeakGlobalRef::upgrade`](../../struct.WeakGlobalRef.html#method.upgrade) to get
+    /// the referenced object back as a local [`Object`](struct.Object.html) if it is still alive.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#newweakglobalref)
+    pub fn to_weak_global(
+        &self,
+        token: &NoException<'env>,
+    ) -> JavaResult<'env, WeakGlobalRef<Object<'static>>> {
+        WeakGlobalRef::new(token, self)
+    }
+
+    /// Convert into a type-erased global reference, which can be stored long-term
+    /// alongside references to objects of other classes.
+    ///
+    /// Use [`GlobalRef::downcast_global`](../../struct.GlobalRef.html#method.downcast_global)
+    /// to get a reference of a more specific type back.
+    pub fn into_global_object(
+        self,
+        token: &NoException<'env>,
+    ) -> JavaResult<'env, GlobalRef<Object<'static>>> {
+        self.to_global(token)
+    }
+
+    /// Enter the object's monitor.
+    ///
+    /// The monitor is exited when the returned [`Monitor`](../../struct.Monitor.html) guard is
+    /// dropped or explicitly [`exit`](../../struct.Monitor.html#method.exit)-ed.
+    /// Prefer [`synchronized`](#method.synchronized) when the guarded code fits in a closure.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#monitorenter)
+    pub fn monitor_enter<'a>(
+        &'a self,
+        token: &NoException<'env>,
+    ) -> JavaResult<'env, Monitor<'a, 'env>> {
+        // Safe because arguments are ensured to be correct references by construction.
+        // A failing `MonitorEnter` throws an exception, which is checked at runtime.
+        let status = token.with_owned(|token| {
+            CallOutcome::Unknown(unsafe { call_jni_object_method!(token, self, MonitorEnter) })
+        })?;
+        if status != jni_sys::JNI_OK {
+            panic!(
+                "Failed to enter the monitor of object {:?}, status: {}.",
+                self.raw_object, status
+            );
+        }
+        Ok(Monitor { object: self })
+    }
+
+    /// Run a closure while holding the object's monitor, like a Java `synchronized` block.
+    ///
+    /// The monitor is exited after the closure returns, including when it returns an error.
+    /// If the closure returns an error, it is returned after the monitor is exited.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#monitor-operations)
+    pub fn synchronized<T>(
+        &self,
+        token: &NoException<'env>,
+        function: impl FnOnce(&NoException<'env>) -> JavaResult<'env, T>,
+    ) -> JavaResult<'env, T> {
+        let monitor = self.monitor_enter(token)?;
+        let result = function(token);
+        let exited = monitor.exit(token);
+        let result = result?;
+        exited?;
+        Ok(result)
+    }
+
+    /// Wake up a single thread waiting on the object's monitor.
+    ///
+    /// The current thread must own the object's monitor, for example by being inside
+    /// [`synchronized`](#method.synchronized). This can't be checked statically, so calling
+    /// this method without owning the monitor throws `IllegalMonitorStateException`.
+    ///
+    /// [`Object::notify` javadoc](https://docs.oracle.com/javase/10/docs/api/java/lang/Object.html#notify())
+    pub fn notify(&self, token: &NoException<'env>) -> JavaResult<'env, ()> {
+        // Safe because we ensure correct arguments and return type.
+        unsafe { self.call_method::<_, fn()>(token, "notify\0", ()) }
+    }
+
+    /// Wake up all threads waiting on the object's monitor.
+    ///
+    /// The current thread must own the object's monitor, for example by being inside
+    /// [`synchronized`](#method.synchronized). This can't be checked statically, so calling
+    /// this method without owning the monitor throws `IllegalMonitorStateException`.
+    ///
+    /// [`Object::notifyAll` javadoc](https://docs.oracle.com/javase/10/docs/api/java/lang/Object.html#notifyAll())
+    pub fn notify_all(&self, token: &NoException<'env>) -> JavaResult<'env, ()> {
+        // Safe because we ensure correct arguments and return type.
+        unsafe { self.call_method::<_, fn()>(token, "notifyAll\0", ()) }
+    }
+
+    /// Release the object's monitor and wait until another thread notifies it or `timeout_millis`
+    /// milliseconds pass. A timeout of `0` means waiting without a timeout.
+    ///
+    /// The current thread must own the object's monitor, for example by being inside
+    /// [`synchronized`](#method.synchronized). This can't be checked statically, so calling
+    /// this method without owning the monitor throws `IllegalMonitorStateException`.
+    /// Also throws `IllegalArgumentException` if the timeout is negative and
+    /// `InterruptedException` if the thread is interrupted while waiting.
+    ///
+    /// [`Object::wait` javadoc](https://docs.oracle.com/javase/10/docs/api/java/lang/Object.html#wait(long))
+    pub fn wait(&self, token: &NoException<'env>, timeout_millis: i64) -> JavaResult<'env, ()> {
+        // Safe because we ensure correct arguments and return type.
+        unsafe { self.call_method::<_, fn(i64)>(token, "wait\0", (timeout_millis,)) }
+    }
+
     /// Create a new [`Object`](struct.Object.html) with a message.
     ///
     /// [`Object()` javadoc](https://docs.oracle.com/javase/10/docs/api/java/lang/Object.html#<init>())
@@ -177,6 +368,8 @@ impl<'env> Object<'env> {
         env: &'a JniEnv<'a>,
         raw_object: NonNull<jni_sys::_jobject>,
     ) -> Object<'a> {
+        #[cfg(test)]
+        crate::testing::track_references(1);
         Object { env, raw_object }
     }
 }
@@ -187,6 +380,8 @@ impl<'env> Object<'env> {
 /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#deletelocalref)
 impl<'env> Drop for Object<'env> {
     fn drop(&mut self) {
+        #[cfg(test)]
+        crate::testing::track_references(-1);
         // Safe because the argument is ensured to be correct references by construction.
         // DeleteLocalRef can handle nulls without any issues.
         unsafe {
@@ -245,6 +440,77 @@ where
     }
 }
 
+/// A wrapper for comparing Java objects by value with `==`.
+///
+/// Comparing [`Object`](java/lang/struct.Object.html)s directly preserves Java by-reference
+/// semantics. Wrapping them into `ByValue` compares them with the
+/// [`equals`](java/lang/struct.Object.html#method.equals) method instead:
+/// `ByValue(&a) == ByValue(&b)`.
+///
+/// Will panic if there is a pending exception in the current thread or if `equals` throws.
+///
+/// This is mostly a convenience for using `assert_eq!()` in tests. Always prefer calling
+/// [`equals`](java/lang/struct.Object.html#method.equals) directly, because it checks for
+/// a pending exception in compile-time rather than the run-time.
+#[derive(Debug)]
+pub struct ByValue<'a, T>(pub &'a T);
+
+impl<'a, 'b, 'env, T, U> PartialEq<ByValue<'b, U>> for ByValue<'a, T>
+where
+    T: AsRef<Object<'env>>,
+    U: AsRef<Object<'env>>,
+{
+    fn eq(&self, other: &ByValue<'b, U>) -> bool {
+        let object = self.0.as_ref();
+        // Safe because we are not leaking the tokens anywhere.
+        unsafe {
+            match NoException::check_pending_exception(object.env()) {
+                Err(_) => {
+                    panic!("Comparing Java objects with a pending exception in the current thread")
+                }
+                Ok(token) => object
+                    .equals(&token, other.0.as_ref())
+                    .unwrap_or_else(|_| panic!("Object::equals threw an exception")),
+            }
+        }
+    }
+}
+
+/// A wrapper for using Java objects as keys in hash maps.
+///
+/// Java objects are hashed by their
+/// [`identity_hash_code`](java/lang/struct.Object.html#method.identity_hash_code) and compared
+/// by-reference with [`is_same_as`](java/lang/struct.Object.html#method.is_same_as), so that
+/// equal keys always have equal hashes.
+///
+/// The token for calling Java is obtained internally, so hashing and comparing will panic if
+/// there is a pending exception in the current thread.
+#[derive(Debug)]
+pub struct ByIdentity<'a, 'env>(pub &'a Object<'env>);
+
+impl<'a, 'env> Hash for ByIdentity<'a, 'env> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // Safe because we are not leaking the tokens anywhere.
+        unsafe {
+            match NoException::check_pending_exception(self.0.env()) {
+                Err(_) => {
+                    panic!("Hashing a Java object with a pending exception in the current thread")
+                }
+                Ok(token) => self.0.identity_hash_code(&token).unwrap().hash(state),
+            }
+        }
+    }
+}
+
+impl<'a, 'b, 'env> PartialEq<ByIdentity<'b, 'env>> for ByIdentity<'a, 'env> {
+    fn eq(&self, other: &ByIdentity<'b, 'env>) -> bool {
+        self.0 == other.0
+    }
+}
+
+/// Objects are compared by-reference, which is an equivalence relation.
+impl<'a, 'env> Eq for ByIdentity<'a, 'env> {}
+
 fn string_or_null<'a>(string: &'a Option<std::string::String>) -> &'a str {
     string
         .as_ref()
@@ -325,3 +591,892 @@ impl<'a> Clone for Object<'a> {
         }
     }
 }
+
+/// A guard holding a Java object's monitor, returned by
+/// [`Object::monitor_enter`](java/lang/struct.Object.html#method.monitor_enter).
+///
+/// [`Drop`](https://doc.rust-lang.org/std/ops/trait.Drop.html) exits the monitor.
+/// [`exit`](#method.exit) does the same, but also reports exceptions.
+///
+/// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#monitor-operations)
+#[derive(Debug)]
+pub struct Monitor<'a, 'env> {
+    object: &'a Object<'env>,
+}
+
+impl<'a, 'env> Monitor<'a, 'env> {
+    /// Exit the monitor.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#monitorexit)
+    pub fn exit(self, token: &NoException<'env>) -> JavaResult<'env, ()> {
+        let object = self.object;
+        mem::forget(self);
+        // Safe because arguments are ensured to be correct references by construction.
+        // The monitor was entered, so `MonitorExit` can only fail by throwing an exception.
+        token.with_owned(|token| {
+            unsafe { call_jni_object_method!(token, object, MonitorExit) };
+            CallOutcome::Unknown(())
+        })
+    }
+}
+
+/// [`Drop`](https://doc.rust-lang.org/std/ops/trait.Drop.html) exits the monitor.
+///
+/// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#monitorexit)
+impl<'a, 'env> Drop for Monitor<'a, 'env> {
+    fn drop(&mut self) {
+        // Safe because the argument is ensured to be a correct reference by construction and
+        // `MonitorExit` can be called with a pending exception.
+        let status = unsafe {
+            call_jni_method!(
+                self.object.env,
+                MonitorExit,
+                self.object.raw_object.as_ptr()
+            )
+        };
+        if status != jni_sys::JNI_OK {
+            // No meaningful way to handle the error except for logging it.
+            println!(
+                "Error {} when calling `MonitorExit` on {:?}",
+                status, self.object.raw_object
+            );
+        }
+    }
+}
+
+/// Kind of a Java object reference, returned by
+/// [`Object::ref_type`](java/lang/struct.Object.html#method.ref_type).
+///
+/// Maps to the `jni_sys::jobjectRefType` enum.
+///
+/// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#getobjectreftype)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RefType {
+    /// A local reference.
+    Local,
+    /// A global reference.
+    Global,
+    /// A weak global reference.
+    Weak,
+    /// Not a valid reference, e.g. a deleted one.
+    Invalid,
+}
+
+impl RefType {
+    /// Convert from a raw `jobjectRefType` value.
+    fn from_raw(ref_type: jni_sys::jobjectRefType) -> RefType {
+        match ref_type {
+            jni_sys::jobjectRefType::JNILocalRefType => RefType::Local,
+            jni_sys::jobjectRefType::JNIGlobalRefType => RefType::Global,
+            jni_sys::jobjectRefType::JNIWeakGlobalRefType => RefType::Weak,
+            jni_sys::jobjectRefType::JNIInvalidRefType => RefType::Invalid,
+        }
+    }
+}
+
+#[cfg(test)]
+mod object_tests {
+    use super::*;
+    use crate::throwable::Throwable;
+    use crate::vm::JavaVMRef;
+    use mockall::Sequence;
+    use serial_test::serial;
+    use std::collections::hash_map::DefaultHasher;
+    use std::ffi::CStr;
+    use std::mem::ManuallyDrop;
+
+    generate_jni_env_mock!(jni_mock);
+
+    fn compare_by_value(equals: jni_sys::jboolean) -> bool {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_object1 = 0x1234 as jni_sys::jobject;
+        let raw_object2 = 0x5678 as jni_sys::jobject;
+        let raw_class = 0x9012 as jni_sys::jclass;
+        let raw_method_id = 0x3456 as jni_sys::jmethodID;
+        let mut sequence = Sequence::new();
+        let exception_check_mock = jni_mock::exception_check_context();
+        exception_check_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env| *env == raw_env_ptr)
+            .return_const(jni_sys::JNI_FALSE)
+            .in_sequence(&mut sequence);
+        let is_same_object_mock = jni_mock::is_same_object_context();
+        is_same_object_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object1, object2| {
+                *env == raw_env_ptr && *object1 == raw_object1 && *object2 == raw_object2
+            })
+            .return_const(jni_sys::JNI_FALSE)
+            .in_sequence(&mut sequence);
+        let get_object_class_mock = jni_mock::get_object_class_context();
+        get_object_class_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object| *env == raw_env_ptr && *object == raw_object1)
+            .returning_st(move |_env, _object| raw_class)
+            .in_sequence(&mut sequence);
+        let get_method_id_mock = jni_mock::get_method_id_context();
+        get_method_id_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, class, name, signature| unsafe {
+                *env == raw_env_ptr
+                    && *class == raw_class
+                    && CStr::from_ptr(*name).to_str() == Ok("equals")
+                    && CStr::from_ptr(*signature).to_str() == Ok("(Ljava/lang/Object;)Z")
+            })
+            .returning_st(move |_env, _class, _name, _signature| raw_method_id)
+            .in_sequence(&mut sequence);
+        let call_boolean_method_a_mock = jni_mock::call_boolean_method_a_context();
+        call_boolean_method_a_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object, method_id, arguments| unsafe {
+                *env == raw_env_ptr
+                    && *object == raw_object1
+                    && *method_id == raw_method_id
+                    && (**arguments).l == raw_object2
+            })
+            .return_const(equals)
+            .in_sequence(&mut sequence);
+        let exception_occured_mock = jni_mock::exception_occured_context();
+        exception_occured_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env| *env == raw_env_ptr)
+            .returning_st(|_env| ptr::null_mut())
+            .in_sequence(&mut sequence);
+        let delete_local_ref_mock = jni_mock::delete_local_ref_context();
+        delete_local_ref_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object| *env == raw_env_ptr && *object == raw_class)
+            .return_const(())
+            .in_sequence(&mut sequence);
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let object1 = ManuallyDrop::new(unsafe {
+            Object::from_raw(&env, NonNull::new(raw_object1).unwrap())
+        });
+        let object2 = ManuallyDrop::new(unsafe {
+            Object::from_raw(&env, NonNull::new(raw_object2).unwrap())
+        });
+        ByValue(&*object1) == ByValue(&*object2)
+    }
+
+    #[test]
+    #[serial]
+    fn by_value_equal() {
+        assert!(compare_by_value(jni_sys::JNI_TRUE));
+    }
+
+    #[test]
+    #[serial]
+    fn by_value_not_equal() {
+        assert!(!compare_by_value(jni_sys::JNI_FALSE));
+    }
+
+    #[test]
+    #[serial]
+    fn by_identity_hash() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_object = 0x1234 as jni_sys::jobject;
+        let raw_system_class = 0x5678 as jni_sys::jclass;
+        let raw_method_id = 0x9012 as jni_sys::jmethodID;
+        let mut sequence = Sequence::new();
+        let exception_check_mock = jni_mock::exception_check_context();
+        exception_check_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env| *env == raw_env_ptr)
+            .return_const(jni_sys::JNI_FALSE)
+            .in_sequence(&mut sequence);
+        let find_class_mock = jni_mock::find_class_context();
+        find_class_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, name| unsafe {
+                *env == raw_env_ptr && CStr::from_ptr(*name).to_str() == Ok("java/lang/System")
+            })
+            .returning_st(move |_env, _name| raw_system_class)
+            .in_sequence(&mut sequence);
+        let get_static_method_id_mock = jni_mock::get_static_method_id_context();
+        get_static_method_id_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, class, name, signature| unsafe {
+                *env == raw_env_ptr
+                    && *class == raw_system_class
+                    && CStr::from_ptr(*name).to_str() == Ok("identityHashCode")
+                    && CStr::from_ptr(*signature).to_str() == Ok("(Ljava/lang/Object;)I")
+            })
+            .returning_st(move |_env, _class, _name, _signature| raw_method_id)
+            .in_sequence(&mut sequence);
+        let call_static_int_method_a_mock = jni_mock::call_static_int_method_a_context();
+        call_static_int_method_a_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, class, method_id, arguments| unsafe {
+                *env == raw_env_ptr
+                    && *class == raw_system_class
+                    && *method_id == raw_method_id
+                    && (**arguments).l == raw_object
+            })
+            .return_const(17)
+            .in_sequence(&mut sequence);
+        let exception_occured_mock = jni_mock::exception_occured_context();
+        exception_occured_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env| *env == raw_env_ptr)
+            .returning_st(|_env| ptr::null_mut())
+            .in_sequence(&mut sequence);
+        let delete_local_ref_mock = jni_mock::delete_local_ref_context();
+        delete_local_ref_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object| *env == raw_env_ptr && *object == raw_system_class)
+            .return_const(())
+            .in_sequence(&mut sequence);
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let object =
+            ManuallyDrop::new(unsafe { Object::from_raw(&env, NonNull::new(raw_object).unwrap()) });
+        let mut hasher = DefaultHasher::new();
+        ByIdentity(&object).hash(&mut hasher);
+        let mut expected_hasher = DefaultHasher::new();
+        17i32.hash(&mut expected_hasher);
+        assert_eq!(hasher.finish(), expected_hasher.finish());
+    }
+
+    #[cfg(feature = "trace")]
+    #[test]
+    #[serial]
+    fn clone_traced() {
+        use std::sync::Mutex;
+
+        struct CapturingLogger {
+            messages: Mutex<Vec<std::string::String>>,
+        }
+
+        impl log::Log for CapturingLogger {
+            fn enabled(&self, _metadata: &log::Metadata) -> bool {
+                true
+            }
+
+            fn log(&self, record: &log::Record) {
+                self.messages
+                    .lock()
+                    .unwrap()
+                    .push(format!("{}", record.args()));
+            }
+
+            fn flush(&self) {}
+        }
+
+        static LOGGER: CapturingLogger = CapturingLogger {
+            messages: Mutex::new(vec![]),
+        };
+        log::set_logger(&LOGGER).unwrap();
+        log::set_max_level(log::LevelFilter::Trace);
+
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_object = 0x1234 as jni_sys::jobject;
+        let raw_cloned_object = 0x5678 as jni_sys::jobject;
+        let exception_check_mock = jni_mock::exception_check_context();
+        exception_check_mock
+            .expect()
+            .times(1)
+            .return_const(jni_sys::JNI_FALSE);
+        let new_local_ref_mock = jni_mock::new_local_ref_context();
+        new_local_ref_mock
+            .expect()
+            .times(1)
+            .returning_st(move |_env, _object| raw_cloned_object);
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let object =
+            ManuallyDrop::new(unsafe { Object::from_raw(&env, NonNull::new(raw_object).unwrap()) });
+        let cloned = ManuallyDrop::new((*object).clone());
+        assert_eq!(unsafe { cloned.raw_object() }.as_ptr(), raw_cloned_object);
+        assert_eq!(
+            *LOGGER.messages.lock().unwrap(),
+            vec![
+                "Calling JNI method ExceptionCheck",
+                "Calling JNI method NewLocalRef"
+            ]
+        );
+    }
+
+    #[test]
+    #[serial]
+    fn count_leaked_references() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_object = 0x1234 as jni_sys::jobject;
+        let raw_leaked_object = 0x5678 as jni_sys::jobject;
+        let delete_local_ref_mock = jni_mock::delete_local_ref_context();
+        delete_local_ref_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object| *env == raw_env_ptr && *object == raw_object)
+            .return_const(());
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let deleted = crate::testing::count_leaked_references(|| {
+            let object = unsafe { Object::from_raw(&env, NonNull::new(raw_object).unwrap()) };
+            drop(object);
+        });
+        assert_eq!(deleted, 0);
+        let leaked = crate::testing::count_leaked_references(|| {
+            let object =
+                unsafe { Object::from_raw(&env, NonNull::new(raw_leaked_object).unwrap()) };
+            mem::forget(object);
+        });
+        assert_eq!(leaked, 1);
+    }
+
+    #[test]
+    #[serial]
+    fn is_same_as_different_types() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_throwable = 0x1234 as jni_sys::jobject;
+        let raw_class = 0x5678 as jni_sys::jobject;
+        let is_same_object_mock = jni_mock::is_same_object_context();
+        is_same_object_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object1, object2| {
+                *env == raw_env_ptr && *object1 == raw_throwable && *object2 == raw_class
+            })
+            .return_const(jni_sys::JNI_FALSE);
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let token = NoException::test(&env);
+        let throwable = ManuallyDrop::new(unsafe {
+            Throwable::from_raw(&env, NonNull::new(raw_throwable).unwrap())
+        });
+        let class =
+            ManuallyDrop::new(unsafe { Class::from_raw(&env, NonNull::new(raw_class).unwrap()) });
+        assert!(!throwable.is_same_as(&token, &*class));
+    }
+
+    #[test]
+    #[serial]
+    fn equals_same_object() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_object = 0x1234 as jni_sys::jobject;
+        let is_same_object_mock = jni_mock::is_same_object_context();
+        is_same_object_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object1, object2| {
+                *env == raw_env_ptr && *object1 == raw_object && *object2 == raw_object
+            })
+            .return_const(jni_sys::JNI_TRUE);
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let token = NoException::test(&env);
+        let object =
+            ManuallyDrop::new(unsafe { Object::from_raw(&env, NonNull::new(raw_object).unwrap()) });
+        // `Object::equals` is not dispatched: calling any other JNI method would panic.
+        assert!(object.equals(&token, &*object).unwrap());
+    }
+
+    #[test]
+    #[serial]
+    fn hash_code() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_object = 0x1234 as jni_sys::jobject;
+        let raw_class = 0x5678 as jni_sys::jclass;
+        let raw_method_id = 0x9012 as jni_sys::jmethodID;
+        let mut sequence = Sequence::new();
+        let get_object_class_mock = jni_mock::get_object_class_context();
+        get_object_class_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object| *env == raw_env_ptr && *object == raw_object)
+            .returning_st(move |_env, _object| raw_class)
+            .in_sequence(&mut sequence);
+        let get_method_id_mock = jni_mock::get_method_id_context();
+        get_method_id_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, class, name, signature| unsafe {
+                *env == raw_env_ptr
+                    && *class == raw_class
+                    && CStr::from_ptr(*name).to_str() == Ok("hashCode")
+                    && CStr::from_ptr(*signature).to_str() == Ok("()I")
+            })
+            .returning_st(move |_env, _class, _name, _signature| raw_method_id)
+            .in_sequence(&mut sequence);
+        let call_int_method_a_mock = jni_mock::call_int_method_a_context();
+        call_int_method_a_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object, method_id, _arguments| {
+                *env == raw_env_ptr && *object == raw_object && *method_id == raw_method_id
+            })
+            .return_const(17)
+            .in_sequence(&mut sequence);
+        let exception_occured_mock = jni_mock::exception_occured_context();
+        exception_occured_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env| *env == raw_env_ptr)
+            .returning_st(|_env| ptr::null_mut())
+            .in_sequence(&mut sequence);
+        let delete_local_ref_mock = jni_mock::delete_local_ref_context();
+        delete_local_ref_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object| *env == raw_env_ptr && *object == raw_class)
+            .return_const(())
+            .in_sequence(&mut sequence);
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let token = NoException::test(&env);
+        let object =
+            ManuallyDrop::new(unsafe { Object::from_raw(&env, NonNull::new(raw_object).unwrap()) });
+        assert_eq!(object.hash_code(&token).unwrap(), 17);
+    }
+
+    fn call_notify(raw_throwable: jni_sys::jobject) -> bool {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_object = 0x1234 as jni_sys::jobject;
+        let raw_class = 0x5678 as jni_sys::jclass;
+        let raw_method_id = 0x9012 as jni_sys::jmethodID;
+        let mut sequence = Sequence::new();
+        let get_object_class_mock = jni_mock::get_object_class_context();
+        get_object_class_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object| *env == raw_env_ptr && *object == raw_object)
+            .returning_st(move |_env, _object| raw_class)
+            .in_sequence(&mut sequence);
+        let get_method_id_mock = jni_mock::get_method_id_context();
+        get_method_id_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, class, name, signature| unsafe {
+                *env == raw_env_ptr
+                    && *class == raw_class
+                    && CStr::from_ptr(*name).to_str() == Ok("notify")
+                    && CStr::from_ptr(*signature).to_str() == Ok("()V")
+            })
+            .returning_st(move |_env, _class, _name, _signature| raw_method_id)
+            .in_sequence(&mut sequence);
+        let call_void_method_a_mock = jni_mock::call_void_method_a_context();
+        call_void_method_a_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object, method_id, _arguments| {
+                *env == raw_env_ptr && *object == raw_object && *method_id == raw_method_id
+            })
+            .return_const(())
+            .in_sequence(&mut sequence);
+        let exception_occured_mock = jni_mock::exception_occured_context();
+        exception_occured_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env| *env == raw_env_ptr)
+            .returning_st(move |_env| raw_throwable)
+            .in_sequence(&mut sequence);
+        let exception_clear_mock = jni_mock::exception_clear_context();
+        if !raw_throwable.is_null() {
+            exception_clear_mock
+                .expect()
+                .times(1)
+                .withf_st(move |env| *env == raw_env_ptr)
+                .return_const(())
+                .in_sequence(&mut sequence);
+        }
+        let delete_local_ref_mock = jni_mock::delete_local_ref_context();
+        delete_local_ref_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object| *env == raw_env_ptr && *object == raw_class)
+            .return_const(())
+            .in_sequence(&mut sequence);
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let token = NoException::test(&env);
+        let object =
+            ManuallyDrop::new(unsafe { Object::from_raw(&env, NonNull::new(raw_object).unwrap()) });
+        let result = object.notify(&token);
+        match result {
+            Ok(()) => true,
+            Err(throwable) => {
+                assert_eq!(unsafe { throwable.raw_object() }.as_ptr(), raw_throwable);
+                mem::forget(throwable);
+                false
+            }
+        }
+    }
+
+    #[test]
+    #[serial]
+    fn notify() {
+        assert!(call_notify(ptr::null_mut()));
+    }
+
+    #[test]
+    #[serial]
+    fn notify_illegal_monitor_state() {
+        assert!(!call_notify(0x3456 as jni_sys::jobject));
+    }
+
+    #[test]
+    #[serial]
+    fn synchronized() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_object = 0x1234 as jni_sys::jobject;
+        let mut sequence = Sequence::new();
+        let monitor_enter_mock = jni_mock::monitor_enter_context();
+        let monitor_exit_mock = jni_mock::monitor_exit_context();
+        let exception_occured_mock = jni_mock::exception_occured_context();
+        monitor_enter_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object| *env == raw_env_ptr && *object == raw_object)
+            .return_const(jni_sys::JNI_OK)
+            .in_sequence(&mut sequence);
+        exception_occured_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env| *env == raw_env_ptr)
+            .returning_st(|_| ptr::null_mut())
+            .in_sequence(&mut sequence);
+        monitor_exit_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object| *env == raw_env_ptr && *object == raw_object)
+            .return_const(jni_sys::JNI_OK)
+            .in_sequence(&mut sequence);
+        exception_occured_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env| *env == raw_env_ptr)
+            .returning_st(|_| ptr::null_mut())
+            .in_sequence(&mut sequence);
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let token = NoException::test(&env);
+        let object =
+            ManuallyDrop::new(unsafe { Object::from_raw(&env, NonNull::new(raw_object).unwrap()) });
+        assert_eq!(object.synchronized(&token, |_| Ok(17)).unwrap(), 17);
+    }
+
+    #[test]
+    #[serial]
+    fn synchronized_error() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_object = 0x1234 as jni_sys::jobject;
+        let raw_throwable = 0x5678 as jni_sys::jobject;
+        let mut sequence = Sequence::new();
+        let monitor_enter_mock = jni_mock::monitor_enter_context();
+        let monitor_exit_mock = jni_mock::monitor_exit_context();
+        let exception_occured_mock = jni_mock::exception_occured_context();
+        monitor_enter_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object| *env == raw_env_ptr && *object == raw_object)
+            .return_const(jni_sys::JNI_OK)
+            .in_sequence(&mut sequence);
+        exception_occured_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env| *env == raw_env_ptr)
+            .returning_st(|_| ptr::null_mut())
+            .in_sequence(&mut sequence);
+        monitor_exit_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object| *env == raw_env_ptr && *object == raw_object)
+            .return_const(jni_sys::JNI_OK)
+            .in_sequence(&mut sequence);
+        exception_occured_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env| *env == raw_env_ptr)
+            .returning_st(|_| ptr::null_mut())
+            .in_sequence(&mut sequence);
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let token = NoException::test(&env);
+        let object =
+            ManuallyDrop::new(unsafe { Object::from_raw(&env, NonNull::new(raw_object).unwrap()) });
+        let throwable = object
+            .synchronized::<()>(&token, |_| {
+                Err(unsafe { Throwable::from_raw(&env, NonNull::new(raw_throwable).unwrap()) })
+            })
+            .unwrap_err();
+        assert_eq!(unsafe { throwable.raw_object() }.as_ptr(), raw_throwable);
+        mem::forget(throwable);
+    }
+
+    #[test]
+    #[serial]
+    fn monitor_enter() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_object = 0x1234 as jni_sys::jobject;
+        let mut sequence = Sequence::new();
+        let monitor_enter_mock = jni_mock::monitor_enter_context();
+        let monitor_exit_mock = jni_mock::monitor_exit_context();
+        let exception_occured_mock = jni_mock::exception_occured_context();
+        monitor_enter_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object| *env == raw_env_ptr && *object == raw_object)
+            .return_const(jni_sys::JNI_OK)
+            .in_sequence(&mut sequence);
+        exception_occured_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env| *env == raw_env_ptr)
+            .returning_st(|_| ptr::null_mut())
+            .in_sequence(&mut sequence);
+        monitor_exit_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object| *env == raw_env_ptr && *object == raw_object)
+            .return_const(jni_sys::JNI_OK)
+            .in_sequence(&mut sequence);
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let token = NoException::test(&env);
+        let object =
+            ManuallyDrop::new(unsafe { Object::from_raw(&env, NonNull::new(raw_object).unwrap()) });
+        let monitor = object.monitor_enter(&token).unwrap();
+        drop(monitor);
+    }
+
+    #[test]
+    #[serial]
+    fn monitor_enter_error() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_object = 0x1234 as jni_sys::jobject;
+        let raw_throwable = 0x5678 as jni_sys::jobject;
+        let mut sequence = Sequence::new();
+        let monitor_enter_mock = jni_mock::monitor_enter_context();
+        let monitor_exit_mock = jni_mock::monitor_exit_context();
+        let exception_occured_mock = jni_mock::exception_occured_context();
+        let exception_clear_mock = jni_mock::exception_clear_context();
+        monitor_enter_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object| *env == raw_env_ptr && *object == raw_object)
+            .return_const(jni_sys::JNI_ERR)
+            .in_sequence(&mut sequence);
+        exception_occured_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env| *env == raw_env_ptr)
+            .returning_st(move |_| raw_throwable)
+            .in_sequence(&mut sequence);
+        exception_clear_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env| *env == raw_env_ptr)
+            .return_const(())
+            .in_sequence(&mut sequence);
+        monitor_exit_mock.expect().times(0);
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let token = NoException::test(&env);
+        let object =
+            ManuallyDrop::new(unsafe { Object::from_raw(&env, NonNull::new(raw_object).unwrap()) });
+        let throwable = object.monitor_enter(&token).unwrap_err();
+        assert_eq!(unsafe { throwable.raw_object() }.as_ptr(), raw_throwable);
+        mem::forget(throwable);
+    }
+
+    #[test]
+    #[serial]
+    fn monitor_exit() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_object = 0x1234 as jni_sys::jobject;
+        let mut sequence = Sequence::new();
+        let monitor_enter_mock = jni_mock::monitor_enter_context();
+        let monitor_exit_mock = jni_mock::monitor_exit_context();
+        let exception_occured_mock = jni_mock::exception_occured_context();
+        monitor_enter_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object| *env == raw_env_ptr && *object == raw_object)
+            .return_const(jni_sys::JNI_OK)
+            .in_sequence(&mut sequence);
+        exception_occured_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env| *env == raw_env_ptr)
+            .returning_st(|_| ptr::null_mut())
+            .in_sequence(&mut sequence);
+        monitor_exit_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object| *env == raw_env_ptr && *object == raw_object)
+            .return_const(jni_sys::JNI_OK)
+            .in_sequence(&mut sequence);
+        exception_occured_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env| *env == raw_env_ptr)
+            .returning_st(|_| ptr::null_mut())
+            .in_sequence(&mut sequence);
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let token = NoException::test(&env);
+        let object =
+            ManuallyDrop::new(unsafe { Object::from_raw(&env, NonNull::new(raw_object).unwrap()) });
+        let monitor = object.monitor_enter(&token).unwrap();
+        monitor.exit(&token).unwrap();
+    }
+
+    #[test]
+    #[serial]
+    fn get_class_name() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_object = 0x1234 as jni_sys::jobject;
+        let raw_class = 0x5678 as jni_sys::jclass;
+        let raw_class_class = 0x9012 as jni_sys::jclass;
+        let raw_method_id = 0x3456 as jni_sys::jmethodID;
+        let raw_name = 0x7890 as jni_sys::jstring;
+        const NAME: &str = "java.lang.Object";
+        let mut sequence = Sequence::new();
+        let get_object_class_mock = jni_mock::get_object_class_context();
+        get_object_class_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object| *env == raw_env_ptr && *object == raw_object)
+            .returning_st(move |_env, _object| raw_class)
+            .in_sequence(&mut sequence);
+        get_object_class_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object| *env == raw_env_ptr && *object == raw_class)
+            .returning_st(move |_env, _object| raw_class_class)
+            .in_sequence(&mut sequence);
+        let get_method_id_mock = jni_mock::get_method_id_context();
+        get_method_id_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, class, name, signature| unsafe {
+                *env == raw_env_ptr
+                    && *class == raw_class_class
+                    && CStr::from_ptr(*name).to_str() == Ok("getName")
+                    && CStr::from_ptr(*signature).to_str() == Ok("()Ljava/lang/String;")
+            })
+            .returning_st(move |_env, _class, _name, _signature| raw_method_id)
+            .in_sequence(&mut sequence);
+        let call_object_method_a_mock = jni_mock::call_object_method_a_context();
+        call_object_method_a_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object, method_id, _arguments| {
+                *env == raw_env_ptr && *object == raw_class && *method_id == raw_method_id
+            })
+            .returning_st(move |_env, _object, _method_id, _arguments| raw_name)
+            .in_sequence(&mut sequence);
+        let delete_local_ref_mock = jni_mock::delete_local_ref_context();
+        delete_local_ref_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object| *env == raw_env_ptr && *object == raw_class_class)
+            .return_const(())
+            .in_sequence(&mut sequence);
+        let get_string_length_mock = jni_mock::get_string_length_context();
+        get_string_length_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, string| *env == raw_env_ptr && *string == raw_name)
+            .return_const(NAME.len() as jni_sys::jsize)
+            .in_sequence(&mut sequence);
+        let get_string_utf_length_mock = jni_mock::get_string_utf_length_context();
+        get_string_utf_length_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, string| *env == raw_env_ptr && *string == raw_name)
+            .return_const(NAME.len() as jni_sys::jsize)
+            .in_sequence(&mut sequence);
+        let get_string_utf_region_mock = jni_mock::get_string_utf_region_context();
+        get_string_utf_region_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, string, start, length, _buffer| {
+                *env == raw_env_ptr
+                    && *string == raw_name
+                    && *start == 0
+                    && *length == NAME.len() as jni_sys::jsize
+            })
+            .returning_st(|_env, _string, _start, _length, buffer| unsafe {
+                ptr::copy_nonoverlapping(
+                    "java.lang.Object\0".as_ptr(),
+                    buffer as *mut u8,
+                    NAME.len() + 1,
+                )
+            })
+            .in_sequence(&mut sequence);
+        delete_local_ref_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object| *env == raw_env_ptr && *object == raw_name)
+            .return_const(())
+            .in_sequence(&mut sequence);
+        delete_local_ref_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object| *env == raw_env_ptr && *object == raw_class)
+            .return_const(())
+            .in_sequence(&mut sequence);
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let token = NoException::test(&env);
+        let object =
+            ManuallyDrop::new(unsafe { Object::from_raw(&env, NonNull::new(raw_object).unwrap()) });
+        assert_eq!(object.get_class_name(&token).unwrap(), NAME);
+    }
+
+    #[test]
+    #[serial]
+    fn ref_type() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_object = 0x1234 as jni_sys::jobject;
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let token = NoException::test(&env);
+        let object =
+            ManuallyDrop::new(unsafe { Object::from_raw(&env, NonNull::new(raw_object).unwrap()) });
+        let get_object_ref_type_mock = jni_mock::get_object_ref_type_context();
+        for (raw_ref_type, ref_type) in [
+            (jni_sys::jobjectRefType::JNILocalRefType, RefType::Local),
+            (jni_sys::jobjectRefType::JNIGlobalRefType, RefType::Global),
+            (jni_sys::jobjectRefType::JNIWeakGlobalRefType, RefType::Weak),
+            (jni_sys::jobjectRefType::JNIInvalidRefType, RefType::Invalid),
+        ] {
+            get_object_ref_type_mock.checkpoint();
+            get_object_ref_type_mock
+                .expect()
+                .times(1)
+                .withf_st(move |env, object| *env == raw_env_ptr && *object == raw_object)
+                .returning_st(move |_env, _object| raw_ref_type);
+            assert_eq!(object.ref_type(&token), ref_type);
+        }
+    }
+}
@@ -0,0 +1,270 @@
+use crate::global_ref::GlobalRef;
+use crate::java_class::JavaClassSignature;
+use crate::result::JavaResult;
+use crate::token::NoException;
+use crate::vm::JavaVMRef;
+use std::any::Any;
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::Hash;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+type References<K> = HashMap<JavaVMRef, HashMap<K, Arc<dyn Any + Send + Sync>>>;
+
+/// A thread-safe registry of [`GlobalRef`](struct.GlobalRef.html)-s keyed by the Java VM
+/// and a user-provided key.
+///
+/// Useful for managing Java singletons: all threads looking up the same key share
+/// a single global reference instead of each creating their own.
+///
+/// Example:
+/// ```
+/// # use rust_jni::*;
+/// # use rust_jni::java::lang::String;
+/// #
+/// # fn jni_main<'a>(token: NoException<'a>) -> JavaResult<'a, NoException<'a>> {
+/// let registry = GlobalRegistry::new();
+/// let first = registry.get_or_create(&token, "key", |token| {
+///     GlobalRef::<String<'static>>::new(token, &String::new(token, "first")?)
+/// })?;
+/// let second = registry.get_or_create(&token, "key", |token| {
+///     GlobalRef::<String<'static>>::new(token, &String::new(token, "second")?)
+/// })?;
+/// assert_eq!(second.with::<String>(&token)?.as_string(&token), "first");
+/// # Ok(token)
+/// # }
+/// #
+/// # #[cfg(feature = "libjvm")]
+/// # fn main() {
+/// #     let init_arguments = InitArguments::default();
+/// #     let vm = JavaVM::create(&init_arguments).unwrap();
+/// #     let _ = vm.with_attached(
+/// #        &AttachArguments::new(init_arguments.version()),
+/// #        |token: NoException| {
+/// #            ((), jni_main(token).unwrap())
+/// #        },
+/// #     );
+/// # }
+/// #
+/// # #[cfg(not(feature = "libjvm"))]
+/// # fn main() {}
+/// ```
+pub struct GlobalRegistry<K> {
+    references: Mutex<References<K>>,
+}
+
+impl<K> GlobalRegistry<K>
+where
+    K: Hash + Eq,
+{
+    /// Create an empty registry.
+    pub fn new() -> Self {
+        Self {
+            references: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Get the global reference registered for the key and the token's Java VM or create and
+    /// register a new one if there is none.
+    ///
+    /// The `create` closure is called without holding the registry lock. If several threads
+    /// race to create a reference for the same key, all of them get the one registered first.
+    ///
+    /// Panics if the registered reference has a different type.
+    pub fn get_or_create<'a, T>(
+        &self,
+        token: &NoException<'a>,
+        key: K,
+        create: impl FnOnce(&NoException<'a>) -> JavaResult<'a, GlobalRef<T>>,
+    ) -> JavaResult<'a, Arc<GlobalRef<T>>>
+    where
+        T: JavaClassSignature + 'static,
+    {
+        let vm = *token.env().vm();
+        if let Some(reference) = self.get(vm, &key) {
+            return Ok(reference);
+        }
+
+        let created: Arc<dyn Any + Send + Sync> = Arc::new(create(token)?);
+        let reference = self
+            .lock()
+            .entry(vm)
+            .or_default()
+            .entry(key)
+            .or_insert(created)
+            .clone();
+        Ok(Self::downcast(reference))
+    }
+
+    /// Get the global reference registered for the key and the Java VM.
+    ///
+    /// Panics if the registered reference has a different type.
+    pub fn get<T>(&self, vm: JavaVMRef, key: &K) -> Option<Arc<GlobalRef<T>>>
+    where
+        T: 'static,
+    {
+        self.lock()
+            .get(&vm)
+            .and_then(|references| references.get(key))
+            .cloned()
+            .map(Self::downcast)
+    }
+
+    /// Remove the global reference registered for the key and the Java VM.
+    ///
+    /// The global reference is deleted once all [`Arc`](https://doc.rust-lang.org/std/sync/struct.Arc.html)-s
+    /// to it obtained from the registry are dropped.
+    ///
+    /// Returns `true` if there was a reference registered.
+    pub fn remove(&self, vm: JavaVMRef, key: &K) -> bool {
+        let removed = self
+            .lock()
+            .get_mut(&vm)
+            .and_then(|references| references.remove(key));
+        removed.is_some()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, References<K>> {
+        // The map is always left in a consistent state, so a poisoned lock is still usable.
+        self.references
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn downcast<T>(reference: Arc<dyn Any + Send + Sync>) -> Arc<GlobalRef<T>>
+    where
+        T: 'static,
+    {
+        reference
+            .downcast::<GlobalRef<T>>()
+            .unwrap_or_else(|_| panic!("A global reference was registered with a different type."))
+    }
+}
+
+impl<K> Default for GlobalRegistry<K>
+where
+    K: Hash + Eq,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Allow displaying [`GlobalRegistry`](struct.GlobalRegistry.html) values.
+impl<K> fmt::Debug for GlobalRegistry<K> {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.debug_struct("GlobalRegistry").finish()
+    }
+}
+
+#[cfg(test)]
+mod global_registry_tests {
+    use super::*;
+    use crate::env::JniEnv;
+    use crate::object::Object;
+    use crate::version::JniVersion;
+    use core::ptr::NonNull;
+    use std::mem::ManuallyDrop;
+
+    fn test_reference(vm: JavaVMRef, raw_object: usize) -> GlobalRef<Object<'static>> {
+        unsafe {
+            GlobalRef::from_raw(
+                vm,
+                JniVersion::V8,
+                NonNull::new(raw_object as jni_sys::jobject).unwrap(),
+            )
+        }
+    }
+
+    #[test]
+    fn same_key() {
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test_default(&vm));
+        let token = NoException::test(&env);
+        let registry = ManuallyDrop::new(GlobalRegistry::new());
+        let first = ManuallyDrop::new(
+            registry
+                .get_or_create(&token, "key", |_| Ok(test_reference(vm, 0x1234)))
+                .unwrap(),
+        );
+        let second = ManuallyDrop::new(
+            registry
+                .get_or_create::<Object<'static>>(&token, "key", |_| {
+                    panic!("Should not create a second reference.")
+                })
+                .unwrap(),
+        );
+        assert!(Arc::ptr_eq(&first, &second));
+        assert_eq!(unsafe { second.raw_object() }, unsafe {
+            first.raw_object()
+        });
+    }
+
+    #[test]
+    fn different_keys() {
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test_default(&vm));
+        let token = NoException::test(&env);
+        let registry = ManuallyDrop::new(GlobalRegistry::new());
+        let first = ManuallyDrop::new(
+            registry
+                .get_or_create(&token, "key1", |_| Ok(test_reference(vm, 0x1234)))
+                .unwrap(),
+        );
+        let second = ManuallyDrop::new(
+            registry
+                .get_or_create(&token, "key2", |_| Ok(test_reference(vm, 0x5678)))
+                .unwrap(),
+        );
+        assert!(!Arc::ptr_eq(&first, &second));
+    }
+
+    #[test]
+    fn get() {
+        let vm = JavaVMRef::test_default();
+        let other_vm = JavaVMRef::test(0x1234 as *mut jni_sys::JavaVM);
+        let env = ManuallyDrop::new(JniEnv::test_default(&vm));
+        let token = NoException::test(&env);
+        let registry = ManuallyDrop::new(GlobalRegistry::new());
+        assert!(registry.get::<Object<'static>>(vm, &"key").is_none());
+        let created = ManuallyDrop::new(
+            registry
+                .get_or_create(&token, "key", |_| Ok(test_reference(vm, 0x1234)))
+                .unwrap(),
+        );
+        let found = ManuallyDrop::new(registry.get::<Object<'static>>(vm, &"key").unwrap());
+        assert!(Arc::ptr_eq(&created, &found));
+        assert!(registry.get::<Object<'static>>(other_vm, &"key").is_none());
+    }
+
+    #[test]
+    fn remove() {
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test_default(&vm));
+        let token = NoException::test(&env);
+        let registry = GlobalRegistry::new();
+        let _created = ManuallyDrop::new(
+            registry
+                .get_or_create(&token, "key", |_| Ok(test_reference(vm, 0x1234)))
+                .unwrap(),
+        );
+        assert!(registry.remove(vm, &"key"));
+        assert!(!registry.remove(vm, &"key"));
+        assert!(registry.get::<Object<'static>>(vm, &"key").is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "A global reference was registered with a different type")]
+    fn wrong_type() {
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test_default(&vm));
+        let token = NoException::test(&env);
+        let registry = ManuallyDrop::new(GlobalRegistry::new());
+        let _created = ManuallyDrop::new(
+            registry
+                .get_or_create(&token, "key", |_| Ok(test_reference(vm, 0x1234)))
+                .unwrap(),
+        );
+        registry.get::<crate::string::String<'static>>(vm, &"key");
+    }
+}
@@ -1,7 +1,487 @@
+use crate::class::Class;
+use crate::error::JniError;
+use crate::java_class::{JavaClass, JavaClassExt};
+use crate::java_string::to_java_string;
 use crate::throwable::Throwable;
+use crate::token::{Exception, NoException};
+use std::fmt::Display;
+use std::os::raw::c_char;
+
+include!("call_jni_method.rs");
 
 /// A type that represents a result of a Java method call. A Java method can either return
 /// a result or throw a
 /// [`Throwable`](https://docs.oracle.com/javase/10/docs/api/java/lang/Throwable.html)
 /// in which case it will be captured in a [`Throwable`](java/lang/struct.Throwable.html) value.
 pub type JavaResult<'env, T> = Result<T, Throwable<'env>>;
+
+/// Extension trait that adds helper methods for converting Rust errors into Java exceptions
+/// to [`Result`](https://doc.rust-lang.org/std/result/enum.Result.html).
+pub trait ResultExt<T> {
+    /// Convert a Rust [`Result`](https://doc.rust-lang.org/std/result/enum.Result.html) into
+    /// a thrown Java exception.
+    ///
+    /// On [`Ok`](https://doc.rust-lang.org/std/result/enum.Result.html#variant.Ok) returns
+    /// the value together with the [`NoException`](struct.NoException.html) token.
+    /// On [`Err`](https://doc.rust-lang.org/std/result/enum.Result.html#variant.Err) throws
+    /// a new exception of the class `class_name` with the error's
+    /// [`Display`](https://doc.rust-lang.org/std/fmt/trait.Display.html) as a message and
+    /// returns the [`Exception`](struct.Exception.html) token. If the exception class can't be
+    /// found, the exception thrown by looking it up is thrown instead.
+    ///
+    /// This is useful in native methods to report Rust errors to the Java caller.
+    ///
+    /// Example:
+    /// ```
+    /// # use rust_jni::*;
+    /// #
+    /// # fn jni_main<'a>(token: NoException<'a>) -> JavaResult<'a, NoException<'a>> {
+    /// let result: Result<i32, std::string::String> = Err("Invalid state".to_owned());
+    /// let exception = result
+    ///     .or_throw(token, "java/lang/IllegalStateException")
+    ///     .unwrap_err();
+    /// let (exception, token) = exception.unwrap();
+    /// assert_eq!(
+    ///     exception.get_message(&token).or_npe(&token)?.as_string(&token),
+    ///     "Invalid state"
+    /// );
+    /// # Ok(token)
+    /// # }
+    /// #
+    /// # #[cfg(feature = "libjvm")]
+    /// # fn main() {
+    /// #     let init_arguments = InitArguments::default();
+    /// #     let vm = JavaVM::create(&init_arguments).unwrap();
+    /// #     let _ = vm.with_attached(
+    /// #        &AttachArguments::new(init_arguments.version()),
+    /// #        |token: NoException| {
+    /// #            ((), jni_main(token).unwrap())
+    /// #        },
+    /// #     );
+    /// # }
+    /// #
+    /// # #[cfg(not(feature = "libjvm"))]
+    /// # fn main() {}
+    /// ```
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#thrownew)
+    fn or_throw<'a>(
+        self,
+        token: NoException<'a>,
+        class_name: &str,
+    ) -> Result<(T, NoException<'a>), Exception<'a>>;
+}
+
+/// Add Java exception helper methods from [`ResultExt`](trait.ResultExt.html)
+/// to `Result<T, E: Display>`.
+impl<T, E> ResultExt<T> for Result<T, E>
+where
+    E: Display,
+{
+    fn or_throw<'a>(
+        self,
+        token: NoException<'a>,
+        class_name: &str,
+    ) -> Result<(T, NoException<'a>), Exception<'a>> {
+        let error = match self {
+            Ok(value) => return Ok((value, token)),
+            Err(error) => error,
+        };
+        let class = match Class::find(&token, class_name) {
+            Ok(class) => class,
+            Err(exception) => return Err(exception.throw(token)),
+        };
+        let message = to_java_string(&error.to_string());
+        // Safe because the arguments are correct.
+        let error = JniError::from_raw(unsafe {
+            call_jni_method!(
+                token.env(),
+                ThrowNew,
+                class.raw_object().as_ptr() as jni_sys::jclass,
+                message.as_ptr() as *const c_char
+            )
+        });
+        // Can't really handle failing throwing an exception.
+        if let Some(error) = error {
+            panic!(
+                "Throwing a new exception has failed with status {:?}.",
+                error
+            );
+        }
+        // Safe because we just threw the exception.
+        Err(unsafe { token.exchange() })
+    }
+}
+
+/// Extension trait that adds helper methods for handling Java exceptions
+/// to [`JavaResult`](type.JavaResult.html).
+pub trait JavaResultExt<'env, T> {
+    /// Handle a Java exception of a specific class, like a Java `catch` block.
+    ///
+    /// On [`Err`](https://doc.rust-lang.org/std/result/enum.Result.html#variant.Err) checks
+    /// whether the exception is an instance of the class `E` and if so returns the result of
+    /// calling `handler` on it. Other exceptions are propagated as is. If looking up the class
+    /// of `E` throws, the new exception is propagated instead.
+    ///
+    /// Example:
+    /// ```
+    /// # use rust_jni::*;
+    /// # use rust_jni::java::lang::RuntimeException;
+    /// #
+    /// # fn jni_main<'a>(token: NoException<'a>) -> JavaResult<'a, NoException<'a>> {
+    /// let result: JavaResult<i32> = Err(RuntimeException::new(&token)?.into());
+    /// let value = result.catch(&token, |_exception: RuntimeException| Ok(0))?;
+    /// assert_eq!(value, 0);
+    /// # Ok(token)
+    /// # }
+    /// #
+    /// # #[cfg(feature = "libjvm")]
+    /// # fn main() {
+    /// #     let init_arguments = InitArguments::default();
+    /// #     let vm = JavaVM::create(&init_arguments).unwrap();
+    /// #     let _ = vm.with_attached(
+    /// #        &AttachArguments::new(init_arguments.version()),
+    /// #        |token: NoException| {
+    /// #            ((), jni_main(token).unwrap())
+    /// #        },
+    /// #     );
+    /// # }
+    /// #
+    /// # #[cfg(not(feature = "libjvm"))]
+    /// # fn main() {}
+    /// ```
+    fn catch<E>(
+        self,
+        token: &NoException<'env>,
+        handler: impl FnOnce(E) -> JavaResult<'env, T>,
+    ) -> JavaResult<'env, T>
+    where
+        E: JavaClass<'env>;
+
+    /// Convert the exception into its string representation, for example to report it.
+    ///
+    /// On [`Err`](https://doc.rust-lang.org/std/result/enum.Result.html#variant.Err) calls
+    /// [`to_string`](java/lang/struct.Object.html#method.to_string) on the exception and
+    /// returns it as a Rust string which doesn't borrow the [`JniEnv`](struct.JniEnv.html).
+    /// If `toString` throws itself, a placeholder message is returned instead.
+    fn into_message_result(self, token: &NoException<'env>) -> Result<T, std::string::String>;
+}
+
+/// Add Java exception handling methods from [`JavaResultExt`](trait.JavaResultExt.html)
+/// to [`JavaResult`](type.JavaResult.html).
+impl<'env, T> JavaResultExt<'env, T> for JavaResult<'env, T> {
+    fn catch<E>(
+        self,
+        token: &NoException<'env>,
+        handler: impl FnOnce(E) -> JavaResult<'env, T>,
+    ) -> JavaResult<'env, T>
+    where
+        E: JavaClass<'env>,
+    {
+        let throwable = match self {
+            Ok(value) => return Ok(value),
+            Err(throwable) => throwable,
+        };
+        match throwable.try_cast::<E>(token)? {
+            Some(exception) => handler(exception),
+            None => Err(throwable),
+        }
+    }
+
+    fn into_message_result(self, token: &NoException<'env>) -> Result<T, std::string::String> {
+        let throwable = match self {
+            Ok(value) => return Ok(value),
+            Err(throwable) => throwable,
+        };
+        let message = match throwable.to_string(token) {
+            Ok(Some(message)) => message.as_string(token),
+            Ok(None) => "null".to_owned(),
+            Err(_) => "<exception thrown from Throwable::toString>".to_owned(),
+        };
+        Err(message)
+    }
+}
+
+#[cfg(test)]
+mod result_tests {
+    use super::*;
+    use crate::classes::runtime_exception::RuntimeException;
+    use crate::env::JniEnv;
+    use crate::vm::JavaVMRef;
+    use core::ptr::NonNull;
+    use mockall::Sequence;
+    use serial_test::serial;
+    use std::ffi::CStr;
+    use std::mem::{self, ManuallyDrop};
+    use std::ptr;
+
+    generate_jni_env_mock!(jni_mock);
+
+    #[test]
+    #[serial]
+    fn catch_matching() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_throwable = 0x1234 as jni_sys::jobject;
+        let raw_class = 0x5678 as jni_sys::jclass;
+        let raw_exception = 0x9012 as jni_sys::jobject;
+        let mut sequence = Sequence::new();
+        let find_class_mock = jni_mock::find_class_context();
+        find_class_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, name| unsafe {
+                *env == raw_env_ptr
+                    && CStr::from_ptr(*name).to_str() == Ok("java/lang/RuntimeException")
+            })
+            .returning_st(move |_env, _name| raw_class)
+            .in_sequence(&mut sequence);
+        let is_instance_of_mock = jni_mock::is_instance_of_context();
+        is_instance_of_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object, class| {
+                *env == raw_env_ptr && *object == raw_throwable && *class == raw_class
+            })
+            .return_const(jni_sys::JNI_TRUE)
+            .in_sequence(&mut sequence);
+        let new_local_ref_mock = jni_mock::new_local_ref_context();
+        new_local_ref_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object| *env == raw_env_ptr && *object == raw_throwable)
+            .returning_st(move |_env, _object| raw_exception)
+            .in_sequence(&mut sequence);
+        let delete_local_ref_mock = jni_mock::delete_local_ref_context();
+        delete_local_ref_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object| *env == raw_env_ptr && *object == raw_class)
+            .return_const(())
+            .in_sequence(&mut sequence);
+        delete_local_ref_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object| *env == raw_env_ptr && *object == raw_throwable)
+            .return_const(())
+            .in_sequence(&mut sequence);
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let token = NoException::test(&env);
+        let result: JavaResult<i32> =
+            Err(unsafe { Throwable::from_raw(&env, NonNull::new(raw_throwable).unwrap()) });
+        let value = result
+            .catch(&token, |exception: RuntimeException| {
+                assert_eq!(unsafe { exception.raw_object() }.as_ptr(), raw_exception);
+                mem::forget(exception);
+                Ok(17)
+            })
+            .unwrap();
+        assert_eq!(value, 17);
+    }
+
+    #[test]
+    #[serial]
+    fn catch_not_matching() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_throwable = 0x1234 as jni_sys::jobject;
+        let raw_class = 0x5678 as jni_sys::jclass;
+        let mut sequence = Sequence::new();
+        let find_class_mock = jni_mock::find_class_context();
+        find_class_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, name| unsafe {
+                *env == raw_env_ptr
+                    && CStr::from_ptr(*name).to_str() == Ok("java/lang/RuntimeException")
+            })
+            .returning_st(move |_env, _name| raw_class)
+            .in_sequence(&mut sequence);
+        let is_instance_of_mock = jni_mock::is_instance_of_context();
+        is_instance_of_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object, class| {
+                *env == raw_env_ptr && *object == raw_throwable && *class == raw_class
+            })
+            .return_const(jni_sys::JNI_FALSE)
+            .in_sequence(&mut sequence);
+        let delete_local_ref_mock = jni_mock::delete_local_ref_context();
+        delete_local_ref_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object| *env == raw_env_ptr && *object == raw_class)
+            .return_const(())
+            .in_sequence(&mut sequence);
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let token = NoException::test(&env);
+        let result: JavaResult<i32> =
+            Err(unsafe { Throwable::from_raw(&env, NonNull::new(raw_throwable).unwrap()) });
+        let throwable = result
+            .catch(&token, |_exception: RuntimeException| {
+                panic!("The handler must not be called for other exceptions")
+            })
+            .unwrap_err();
+        assert_eq!(unsafe { throwable.raw_object() }.as_ptr(), raw_throwable);
+        mem::forget(throwable);
+    }
+
+    #[test]
+    #[serial]
+    fn into_message_result_ok() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let token = NoException::test(&env);
+        let result: JavaResult<i32> = Ok(17);
+        assert_eq!(result.into_message_result(&token), Ok(17));
+    }
+
+    fn into_message_result_error(
+        raw_to_string_exception: jni_sys::jobject,
+    ) -> Result<i32, std::string::String> {
+        const MESSAGE: &str = "java.lang.RuntimeException: test";
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_throwable = 0x1234 as jni_sys::jobject;
+        let raw_class = 0x5678 as jni_sys::jclass;
+        let raw_method_id = 0x9012 as jni_sys::jmethodID;
+        let raw_string = if raw_to_string_exception.is_null() {
+            0x3456 as jni_sys::jobject
+        } else {
+            ptr::null_mut()
+        };
+        let mut sequence = Sequence::new();
+        let get_object_class_mock = jni_mock::get_object_class_context();
+        get_object_class_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object| *env == raw_env_ptr && *object == raw_throwable)
+            .returning_st(move |_env, _object| raw_class)
+            .in_sequence(&mut sequence);
+        let get_method_id_mock = jni_mock::get_method_id_context();
+        get_method_id_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, class, name, signature| unsafe {
+                *env == raw_env_ptr
+                    && *class == raw_class
+                    && CStr::from_ptr(*name).to_str() == Ok("toString")
+                    && CStr::from_ptr(*signature).to_str() == Ok("()Ljava/lang/String;")
+            })
+            .returning_st(move |_env, _class, _name, _signature| raw_method_id)
+            .in_sequence(&mut sequence);
+        let call_object_method_a_mock = jni_mock::call_object_method_a_context();
+        call_object_method_a_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object, method_id, _arguments| {
+                *env == raw_env_ptr && *object == raw_throwable && *method_id == raw_method_id
+            })
+            .returning_st(move |_env, _object, _method_id, _arguments| raw_string)
+            .in_sequence(&mut sequence);
+        let exception_occured_mock = jni_mock::exception_occured_context();
+        let exception_clear_mock = jni_mock::exception_clear_context();
+        if !raw_to_string_exception.is_null() {
+            exception_occured_mock
+                .expect()
+                .times(1)
+                .withf_st(move |env| *env == raw_env_ptr)
+                .returning_st(move |_env| raw_to_string_exception)
+                .in_sequence(&mut sequence);
+            exception_clear_mock
+                .expect()
+                .times(1)
+                .withf_st(move |env| *env == raw_env_ptr)
+                .return_const(())
+                .in_sequence(&mut sequence);
+        }
+        let delete_local_ref_mock = jni_mock::delete_local_ref_context();
+        delete_local_ref_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object| *env == raw_env_ptr && *object == raw_class)
+            .return_const(())
+            .in_sequence(&mut sequence);
+        let get_string_length_mock = jni_mock::get_string_length_context();
+        let get_string_utf_length_mock = jni_mock::get_string_utf_length_context();
+        let get_string_utf_region_mock = jni_mock::get_string_utf_region_context();
+        if raw_to_string_exception.is_null() {
+            get_string_length_mock
+                .expect()
+                .times(1)
+                .withf_st(move |env, string| *env == raw_env_ptr && *string == raw_string)
+                .return_const(MESSAGE.len() as jni_sys::jsize)
+                .in_sequence(&mut sequence);
+            get_string_utf_length_mock
+                .expect()
+                .times(1)
+                .withf_st(move |env, string| *env == raw_env_ptr && *string == raw_string)
+                .return_const(MESSAGE.len() as jni_sys::jsize)
+                .in_sequence(&mut sequence);
+            get_string_utf_region_mock
+                .expect()
+                .times(1)
+                .withf_st(move |env, string, start, length, _buffer| {
+                    *env == raw_env_ptr
+                        && *string == raw_string
+                        && *start == 0
+                        && *length == MESSAGE.len() as jni_sys::jsize
+                })
+                .returning_st(|_env, _string, _start, _length, buffer| unsafe {
+                    ptr::copy_nonoverlapping(MESSAGE.as_ptr(), buffer as *mut u8, MESSAGE.len());
+                    *buffer.add(MESSAGE.len()) = 0;
+                })
+                .in_sequence(&mut sequence);
+            delete_local_ref_mock
+                .expect()
+                .times(1)
+                .withf_st(move |env, object| *env == raw_env_ptr && *object == raw_string)
+                .return_const(())
+                .in_sequence(&mut sequence);
+        } else {
+            delete_local_ref_mock
+                .expect()
+                .times(1)
+                .withf_st(move |env, object| {
+                    *env == raw_env_ptr && *object == raw_to_string_exception
+                })
+                .return_const(())
+                .in_sequence(&mut sequence);
+        }
+        delete_local_ref_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object| *env == raw_env_ptr && *object == raw_throwable)
+            .return_const(())
+            .in_sequence(&mut sequence);
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let token = NoException::test(&env);
+        let result: JavaResult<i32> =
+            Err(unsafe { Throwable::from_raw(&env, NonNull::new(raw_throwable).unwrap()) });
+        let result = result.into_message_result(&token);
+        result
+    }
+
+    #[test]
+    #[serial]
+    fn into_message_result_error_message() {
+        assert_eq!(
+            into_message_result_error(ptr::null_mut()),
+            Err("java.lang.RuntimeException: test".to_owned())
+        );
+    }
+
+    #[test]
+    #[serial]
+    fn into_message_result_to_string_throws() {
+        assert_eq!(
+            into_message_result_error(0x7890 as jni_sys::jobject),
+            Err("<exception thrown from Throwable::toString>".to_owned())
+        );
+    }
+}
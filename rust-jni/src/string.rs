@@ -8,6 +8,7 @@ use crate::token::{CallOutcome, NoException};
 use core::ptr::NonNull;
 use jni_sys;
 use std;
+use std::ffi::CStr;
 use std::os::raw::c_char;
 use std::ptr;
 
@@ -37,6 +38,12 @@ impl<'env> String<'env> {
 
     /// Create a new Java string from a Rust string.
     ///
+    /// The string is encoded in
+    /// [modified UTF-8](https://docs.oracle.com/javase/10/docs/specs/jni/types.html#modified-utf-8-strings),
+    /// so strings with embedded `NUL` characters are preserved.
+    ///
+    /// Throws `OutOfMemoryError` if the VM could not allocate the string.
+    ///
     /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#newstringutf)
     pub fn new<'a>(token: &NoException<'a>, string: &str) -> JavaResult<'a, String<'a>> {
         if string.is_empty() {
@@ -53,15 +60,91 @@ impl<'env> String<'env> {
         Ok(unsafe { Self::from_raw(token.env(), raw_string) })
     }
 
-    /// String length (the number of unicode characters).
+    /// Create a new Java string from UTF-16 code units.
+    ///
+    /// Unlike [`new`](#method.new), passes the text to Java as is, which is useful for
+    /// text with supplementary characters that is already encoded as UTF-16.
+    ///
+    /// Throws `OutOfMemoryError` if the VM could not allocate the string.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#newstring)
+    pub fn from_utf16<'a>(token: &NoException<'a>, chars: &[u16]) -> JavaResult<'a, String<'a>> {
+        // Safe because arguments are ensured to be the correct by construction and because
+        // `NewString` throws an exception before returning `null`.
+        let raw_string = unsafe {
+            call_nullable_jni_method!(
+                token,
+                NewString,
+                chars.as_ptr() as *const jni_sys::jchar,
+                chars.len() as jni_sys::jsize
+            )
+        }?;
+        // Safe because the argument is a valid string reference.
+        Ok(unsafe { Self::from_raw(token.env(), raw_string) })
+    }
+
+    /// String length (the number of UTF-16 code units).
+    ///
+    /// Same as [`len_utf16`](#method.len_utf16).
     ///
     /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#getstringlength)
     pub fn len(&self, token: &NoException) -> usize {
+        self.len_utf16(token)
+    }
+
+    /// String length in UTF-16 code units, as returned by Java's
+    /// [`String::length`](https://docs.oracle.com/javase/10/docs/api/java/lang/String.html#length()).
+    ///
+    /// Supplementary characters count as two code units, so this can be larger than the number
+    /// of Rust `char`-s in the string.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#getstringlength)
+    pub fn len_utf16(&self, token: &NoException) -> usize {
         // Safe because arguments are ensured to be the correct by construction.
         let length = unsafe { call_jni_object_method!(token, self, GetStringLength) };
         length as usize
     }
 
+    /// Get the UTF-16 code unit at `index`, like Java's
+    /// [`String::charAt`](https://docs.oracle.com/javase/10/docs/api/java/lang/String.html#charAt(int)).
+    ///
+    /// The code unit is not always a valid `char` on its own, as supplementary characters
+    /// are encoded as a surrogate pair.
+    ///
+    /// Throws `StringIndexOutOfBoundsException` if the index is out of the string bounds.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#getstringregion)
+    pub fn char_at(&self, token: &NoException<'env>, index: usize) -> JavaResult<'env, u16> {
+        // An index that doesn't fit into a `jsize` is out of bounds of any Java string, so
+        // replace it with the largest `jsize` to make JNI throw.
+        let index = jni_sys::jsize::try_from(index).unwrap_or(jni_sys::jsize::MAX);
+        let mut value: jni_sys::jchar = 0;
+        token.with_owned(|token| {
+            // Safe because arguments are ensured to be the correct by construction.
+            unsafe {
+                call_jni_object_method!(
+                    token,
+                    self,
+                    GetStringRegion,
+                    index,
+                    1 as jni_sys::jsize,
+                    &mut value as *mut jni_sys::jchar
+                );
+            }
+            CallOutcome::Unknown(())
+        })?;
+        Ok(value)
+    }
+
+    /// Check if the string is empty.
+    ///
+    /// Only checks the string length and doesn't extract the string contents.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#getstringlength)
+    pub fn is_empty(&self, token: &NoException) -> bool {
+        self.len(token) == 0
+    }
+
     /// String size (the number of bytes in modified UTF-8).
     ///
     /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#getstringutflength)
@@ -101,6 +184,76 @@ impl<'env> String<'env> {
         from_java_string(buffer.as_slice()).unwrap().into_owned()
     }
 
+    /// Get the raw bytes of the Java `String` in JNI's
+    /// [modified UTF-8](https://docs.oracle.com/javase/10/docs/specs/jni/types.html#modified-utf-8-strings)
+    /// encoding, without converting them to standard UTF-8.
+    ///
+    /// Modified UTF-8 differs from standard UTF-8 in two ways: the `NUL` character is encoded
+    /// with two bytes `0xC0 0x80`, so the bytes never contain a zero byte, and supplementary
+    /// characters are encoded as a surrogate pair of three bytes each instead of four bytes.
+    ///
+    /// Throws `OutOfMemoryError` if the VM could not allocate the bytes.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#getstringutfchars)
+    pub fn as_modified_utf8(&self, token: &NoException<'env>) -> JavaResult<'env, Vec<u8>> {
+        let chars = token.with_owned(|token| {
+            // Safe because arguments are ensured to be the correct by construction.
+            let chars =
+                unsafe { call_jni_object_method!(token, self, GetStringUTFChars, ptr::null_mut()) };
+            if chars.is_null() {
+                // Safe because `GetStringUTFChars` throws an exception before returning `null`.
+                CallOutcome::Err(unsafe { token.exchange() })
+            } else {
+                CallOutcome::Ok((chars, token))
+            }
+        })?;
+        // Safe because `GetStringUTFChars` returns a valid null-terminated string.
+        let bytes = unsafe { CStr::from_ptr(chars) }.to_bytes().to_vec();
+        // Safe because arguments are ensured to be the correct by construction.
+        unsafe { call_jni_object_method!(token, self, ReleaseStringUTFChars, chars) };
+        Ok(bytes)
+    }
+
+    /// Access the UTF-16 code units of the Java `String` without copying them.
+    ///
+    /// The VM is likely to give direct access to the string contents, but in exchange the
+    /// `callback` runs inside a critical region: it must not make any JNI calls or block waiting
+    /// on other Java threads, as the VM may have garbage collection disabled until it returns.
+    /// The token is borrowed mutably for the whole call so that the `callback` can't make
+    /// JNI calls. The code units slice is only valid for the duration of the `callback`.
+    /// The string contents are released even if the `callback` panics.
+    ///
+    /// Throws `OutOfMemoryError` if the VM could not provide the string contents.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#getstringcritical-releasestringcritical)
+    pub fn with_critical<R>(
+        &self,
+        token: &mut NoException<'env>,
+        callback: impl FnOnce(&[u16]) -> R,
+    ) -> JavaResult<'env, R> {
+        let length = self.len_utf16(token);
+        let chars = token.with_owned(|token| {
+            // Safe because arguments are ensured to be the correct by construction.
+            let chars =
+                unsafe { call_jni_object_method!(token, self, GetStringCritical, ptr::null_mut()) };
+            if chars.is_null() {
+                // Safe because `GetStringCritical` throws an exception before returning `null`.
+                CallOutcome::Err(unsafe { token.exchange() })
+            } else {
+                CallOutcome::Ok((chars, token))
+            }
+        })?;
+        let guard = StringCriticalGuard {
+            string: self,
+            chars,
+        };
+        // Safe because `GetStringCritical` returns a pointer to `length` UTF-16 code units
+        // which stays valid until `ReleaseStringCritical` is called by the guard.
+        Ok(callback(unsafe {
+            std::slice::from_raw_parts(guard.chars, length)
+        }))
+    }
+
     /// Get the string value of an integer.
     ///
     /// [`String::valueOf(int)` javadoc](https://docs.oracle.com/javase/10/docs/api/java/lang/String.html#valueOf(int)).
@@ -188,3 +341,528 @@ where
         Object::as_ref(self).eq(other.as_ref())
     }
 }
+
+/// Releases the string contents obtained by `GetStringCritical` when dropped.
+struct StringCriticalGuard<'a, 'env> {
+    string: &'a String<'env>,
+    chars: *const jni_sys::jchar,
+}
+
+impl<'a, 'env> Drop for StringCriticalGuard<'a, 'env> {
+    fn drop(&mut self) {
+        let string = self.string;
+        // Safe because arguments are ensured to be the correct by construction.
+        unsafe {
+            call_jni_method!(
+                string.env(),
+                ReleaseStringCritical,
+                string.raw_object().as_ptr(),
+                self.chars
+            )
+        };
+    }
+}
+
+#[cfg(test)]
+mod string_tests {
+    use super::*;
+    use crate::vm::JavaVMRef;
+    use mockall::Sequence;
+    use serial_test::serial;
+    use std::cell::RefCell;
+    use std::mem::{self, ManuallyDrop};
+    use std::panic;
+    use std::rc::Rc;
+
+    generate_jni_env_mock!(jni_mock);
+
+    #[test]
+    #[serial]
+    fn from_utf16() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_string = 0x1234 as jni_sys::jobject;
+        // "a😀" with the emoji encoded as a surrogate pair.
+        let chars: &[u16] = &[0x61, 0xD83D, 0xDE00];
+        let raw_chars = chars.as_ptr();
+        let new_string_mock = jni_mock::new_string_context();
+        new_string_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, chars, len| {
+                *env == raw_env_ptr && *chars == raw_chars && *len == 3
+            })
+            .returning_st(move |_env, _chars, _len| raw_string);
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let token = NoException::test(&env);
+        let string = String::from_utf16(&token, chars).unwrap();
+        unsafe {
+            assert_eq!(string.raw_object().as_ptr(), raw_string);
+        }
+        mem::forget(string);
+    }
+
+    #[test]
+    #[serial]
+    fn is_empty() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_string = 0x1234 as jni_sys::jobject;
+        let get_string_length_mock = jni_mock::get_string_length_context();
+        get_string_length_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, string| *env == raw_env_ptr && *string == raw_string)
+            .return_const(0);
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let token = NoException::test(&env);
+        let string =
+            ManuallyDrop::new(unsafe { String::from_raw(&env, NonNull::new(raw_string).unwrap()) });
+        assert!(string.is_empty(&token));
+    }
+
+    #[test]
+    #[serial]
+    fn is_not_empty() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_string = 0x1234 as jni_sys::jobject;
+        let get_string_length_mock = jni_mock::get_string_length_context();
+        get_string_length_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, string| *env == raw_env_ptr && *string == raw_string)
+            .return_const(5);
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let token = NoException::test(&env);
+        let string =
+            ManuallyDrop::new(unsafe { String::from_raw(&env, NonNull::new(raw_string).unwrap()) });
+        assert!(!string.is_empty(&token));
+    }
+
+    #[test]
+    #[serial]
+    fn as_modified_utf8() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_string = 0x1234 as jni_sys::jobject;
+        static CHARS: &[u8] = b"a\xC0\x80b\0";
+        let raw_chars = CHARS.as_ptr() as *const c_char;
+        let mut sequence = Sequence::new();
+        let get_string_utf_chars_mock = jni_mock::get_string_utf_chars_context();
+        get_string_utf_chars_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, string, _is_copy| *env == raw_env_ptr && *string == raw_string)
+            .returning_st(move |_env, _string, _is_copy| raw_chars)
+            .in_sequence(&mut sequence);
+        let release_string_utf_chars_mock = jni_mock::release_string_utf_chars_context();
+        release_string_utf_chars_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, string, chars| {
+                *env == raw_env_ptr && *string == raw_string && *chars == raw_chars
+            })
+            .return_const(())
+            .in_sequence(&mut sequence);
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let token = NoException::test(&env);
+        let string =
+            ManuallyDrop::new(unsafe { String::from_raw(&env, NonNull::new(raw_string).unwrap()) });
+        assert_eq!(
+            string.as_modified_utf8(&token).unwrap(),
+            vec![b'a', 0xC0, 0x80, b'b']
+        );
+    }
+
+    #[test]
+    #[serial]
+    fn as_modified_utf8_error() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_string = 0x1234 as jni_sys::jobject;
+        let raw_throwable = 0x5678 as jni_sys::jthrowable;
+        let mut sequence = Sequence::new();
+        let get_string_utf_chars_mock = jni_mock::get_string_utf_chars_context();
+        get_string_utf_chars_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, string, _is_copy| *env == raw_env_ptr && *string == raw_string)
+            .returning_st(|_env, _string, _is_copy| ptr::null())
+            .in_sequence(&mut sequence);
+        let exception_occured_mock = jni_mock::exception_occured_context();
+        exception_occured_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env| *env == raw_env_ptr)
+            .returning_st(move |_env| raw_throwable)
+            .in_sequence(&mut sequence);
+        let exception_clear_mock = jni_mock::exception_clear_context();
+        exception_clear_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env| *env == raw_env_ptr)
+            .return_const(())
+            .in_sequence(&mut sequence);
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let token = NoException::test(&env);
+        let string =
+            ManuallyDrop::new(unsafe { String::from_raw(&env, NonNull::new(raw_string).unwrap()) });
+        let throwable = string.as_modified_utf8(&token).unwrap_err();
+        assert_eq!(unsafe { throwable.raw_object() }.as_ptr(), raw_throwable);
+        mem::forget(throwable);
+    }
+
+    #[test]
+    #[serial]
+    fn with_critical() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_string = 0x1234 as jni_sys::jobject;
+        static CHARS: &[u16] = &[0x61, 0x44b, 0xd83d, 0xde00];
+        let raw_chars = CHARS.as_ptr();
+        let mut sequence = Sequence::new();
+        let get_string_length_mock = jni_mock::get_string_length_context();
+        get_string_length_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, string| *env == raw_env_ptr && *string == raw_string)
+            .return_const(CHARS.len() as jni_sys::jsize)
+            .in_sequence(&mut sequence);
+        let get_string_critical_mock = jni_mock::get_string_critical_context();
+        get_string_critical_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, string, _is_copy| *env == raw_env_ptr && *string == raw_string)
+            .returning_st(move |_env, _string, _is_copy| raw_chars)
+            .in_sequence(&mut sequence);
+        let release_string_critical_mock = jni_mock::release_string_critical_context();
+        release_string_critical_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, string, chars| {
+                *env == raw_env_ptr && *string == raw_string && *chars == raw_chars
+            })
+            .return_const(())
+            .in_sequence(&mut sequence);
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let mut token = NoException::test(&env);
+        let string =
+            ManuallyDrop::new(unsafe { String::from_raw(&env, NonNull::new(raw_string).unwrap()) });
+        assert_eq!(
+            string
+                .with_critical(&mut token, |chars| {
+                    std::string::String::from_utf16(chars).unwrap()
+                })
+                .unwrap(),
+            "aы\u{1F600}"
+        );
+    }
+
+    #[test]
+    #[serial]
+    fn with_critical_panic() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_string = 0x1234 as jni_sys::jobject;
+        static CHARS: &[u16] = &[0x61, 0x44b, 0xd83d, 0xde00];
+        let raw_chars = CHARS.as_ptr();
+        let mut sequence = Sequence::new();
+        let get_string_length_mock = jni_mock::get_string_length_context();
+        get_string_length_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, string| *env == raw_env_ptr && *string == raw_string)
+            .return_const(CHARS.len() as jni_sys::jsize)
+            .in_sequence(&mut sequence);
+        let get_string_critical_mock = jni_mock::get_string_critical_context();
+        get_string_critical_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, string, _is_copy| *env == raw_env_ptr && *string == raw_string)
+            .returning_st(move |_env, _string, _is_copy| raw_chars)
+            .in_sequence(&mut sequence);
+        let release_string_critical_mock = jni_mock::release_string_critical_context();
+        release_string_critical_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, string, chars| {
+                *env == raw_env_ptr && *string == raw_string && *chars == raw_chars
+            })
+            .return_const(())
+            .in_sequence(&mut sequence);
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let mut token = NoException::test(&env);
+        let string =
+            ManuallyDrop::new(unsafe { String::from_raw(&env, NonNull::new(raw_string).unwrap()) });
+        let result = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+            string.with_critical(&mut token, |_chars| panic!("callback panic"))
+        }));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    #[serial]
+    fn len_utf16() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_string = 0x1234 as jni_sys::jobject;
+        let get_string_length_mock = jni_mock::get_string_length_context();
+        get_string_length_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, string| *env == raw_env_ptr && *string == raw_string)
+            .return_const(5);
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let token = NoException::test(&env);
+        let string =
+            ManuallyDrop::new(unsafe { String::from_raw(&env, NonNull::new(raw_string).unwrap()) });
+        assert_eq!(string.len_utf16(&token), 5);
+    }
+
+    #[test]
+    #[serial]
+    fn char_at() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_string = 0x1234 as jni_sys::jobject;
+        let mut sequence = Sequence::new();
+        let get_string_region_mock = jni_mock::get_string_region_context();
+        get_string_region_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, string, start, length, _buffer| {
+                *env == raw_env_ptr && *string == raw_string && *start == 3 && *length == 1
+            })
+            .returning_st(|_env, _string, _start, _length, buffer| unsafe {
+                *buffer = 0xd83d;
+            })
+            .in_sequence(&mut sequence);
+        let exception_occured_mock = jni_mock::exception_occured_context();
+        exception_occured_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env| *env == raw_env_ptr)
+            .returning_st(|_env| ptr::null_mut())
+            .in_sequence(&mut sequence);
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let token = NoException::test(&env);
+        let string =
+            ManuallyDrop::new(unsafe { String::from_raw(&env, NonNull::new(raw_string).unwrap()) });
+        assert_eq!(string.char_at(&token, 3).unwrap(), 0xd83d);
+    }
+
+    #[test]
+    #[serial]
+    fn char_at_out_of_bounds() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_string = 0x1234 as jni_sys::jobject;
+        let raw_throwable = 0x5678 as jni_sys::jthrowable;
+        let mut sequence = Sequence::new();
+        let get_string_region_mock = jni_mock::get_string_region_context();
+        get_string_region_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, string, start, length, _buffer| {
+                *env == raw_env_ptr && *string == raw_string && *start == 3 && *length == 1
+            })
+            .return_const(())
+            .in_sequence(&mut sequence);
+        let exception_occured_mock = jni_mock::exception_occured_context();
+        exception_occured_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env| *env == raw_env_ptr)
+            .returning_st(move |_env| raw_throwable)
+            .in_sequence(&mut sequence);
+        let exception_clear_mock = jni_mock::exception_clear_context();
+        exception_clear_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env| *env == raw_env_ptr)
+            .return_const(())
+            .in_sequence(&mut sequence);
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let token = NoException::test(&env);
+        let string =
+            ManuallyDrop::new(unsafe { String::from_raw(&env, NonNull::new(raw_string).unwrap()) });
+        let throwable = string.char_at(&token, 3).unwrap_err();
+        assert_eq!(unsafe { throwable.raw_object() }.as_ptr(), raw_throwable);
+        mem::forget(throwable);
+    }
+
+    #[test]
+    #[serial]
+    fn char_at_index_overflow() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_string = 0x1234 as jni_sys::jobject;
+        let raw_throwable = 0x5678 as jni_sys::jthrowable;
+        let mut sequence = Sequence::new();
+        let get_string_region_mock = jni_mock::get_string_region_context();
+        get_string_region_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, string, start, length, _buffer| {
+                *env == raw_env_ptr
+                    && *string == raw_string
+                    && *start == jni_sys::jsize::MAX
+                    && *length == 1
+            })
+            .return_const(())
+            .in_sequence(&mut sequence);
+        let exception_occured_mock = jni_mock::exception_occured_context();
+        exception_occured_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env| *env == raw_env_ptr)
+            .returning_st(move |_env| raw_throwable)
+            .in_sequence(&mut sequence);
+        let exception_clear_mock = jni_mock::exception_clear_context();
+        exception_clear_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env| *env == raw_env_ptr)
+            .return_const(())
+            .in_sequence(&mut sequence);
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let token = NoException::test(&env);
+        let string =
+            ManuallyDrop::new(unsafe { String::from_raw(&env, NonNull::new(raw_string).unwrap()) });
+        let throwable = string.char_at(&token, usize::MAX).unwrap_err();
+        assert_eq!(unsafe { throwable.raw_object() }.as_ptr(), raw_throwable);
+        mem::forget(throwable);
+    }
+
+    #[test]
+    #[serial]
+    fn new() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_string = 0x1234 as jni_sys::jobject;
+        let new_string_utf_mock = jni_mock::new_string_utf_context();
+        new_string_utf_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, bytes| unsafe {
+                *env == raw_env_ptr && CStr::from_ptr(*bytes).to_bytes() == [b'a', 0xC0, 0x80, b'b']
+            })
+            .returning_st(move |_env, _bytes| raw_string);
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let token = NoException::test(&env);
+        let string = String::new(&token, "a\0b").unwrap();
+        assert_eq!(unsafe { string.raw_object() }.as_ptr(), raw_string);
+        mem::forget(string);
+    }
+
+    #[test]
+    #[serial]
+    fn new_error() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_throwable = 0x5678 as jni_sys::jthrowable;
+        let mut sequence = Sequence::new();
+        let new_string_utf_mock = jni_mock::new_string_utf_context();
+        new_string_utf_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, _bytes| *env == raw_env_ptr)
+            .returning_st(|_env, _bytes| ptr::null_mut())
+            .in_sequence(&mut sequence);
+        let exception_occured_mock = jni_mock::exception_occured_context();
+        exception_occured_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env| *env == raw_env_ptr)
+            .returning_st(move |_env| raw_throwable)
+            .in_sequence(&mut sequence);
+        let exception_clear_mock = jni_mock::exception_clear_context();
+        exception_clear_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env| *env == raw_env_ptr)
+            .return_const(())
+            .in_sequence(&mut sequence);
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let token = NoException::test(&env);
+        let throwable = String::new(&token, "test").unwrap_err();
+        assert_eq!(unsafe { throwable.raw_object() }.as_ptr(), raw_throwable);
+        mem::forget(throwable);
+    }
+
+    #[test]
+    #[serial]
+    fn new_round_trip() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_string = 0x1234 as jni_sys::jobject;
+        const VALUE: &str = "a\0b \u{1F600}";
+        let bytes = Rc::new(RefCell::new(Vec::new()));
+        let mut sequence = Sequence::new();
+        let new_string_utf_mock = jni_mock::new_string_utf_context();
+        let new_string_bytes = bytes.clone();
+        new_string_utf_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, _bytes| *env == raw_env_ptr)
+            .returning_st(move |_env, bytes| {
+                *new_string_bytes.borrow_mut() = unsafe { CStr::from_ptr(bytes) }
+                    .to_bytes_with_nul()
+                    .to_vec();
+                raw_string
+            })
+            .in_sequence(&mut sequence);
+        let get_string_length_mock = jni_mock::get_string_length_context();
+        get_string_length_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, string| *env == raw_env_ptr && *string == raw_string)
+            .return_const(VALUE.encode_utf16().count() as jni_sys::jsize)
+            .in_sequence(&mut sequence);
+        let get_string_utf_length_mock = jni_mock::get_string_utf_length_context();
+        let utf_length_bytes = bytes.clone();
+        get_string_utf_length_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, string| *env == raw_env_ptr && *string == raw_string)
+            .returning_st(move |_env, _string| {
+                (utf_length_bytes.borrow().len() - 1) as jni_sys::jsize
+            })
+            .in_sequence(&mut sequence);
+        let get_string_utf_region_mock = jni_mock::get_string_utf_region_context();
+        let region_bytes = bytes.clone();
+        get_string_utf_region_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, string, start, length, _buffer| {
+                *env == raw_env_ptr
+                    && *string == raw_string
+                    && *start == 0
+                    && *length == VALUE.encode_utf16().count() as jni_sys::jsize
+            })
+            .returning_st(move |_env, _string, _start, _length, buffer| unsafe {
+                let bytes = region_bytes.borrow();
+                ptr::copy_nonoverlapping(bytes.as_ptr(), buffer as *mut u8, bytes.len());
+            })
+            .in_sequence(&mut sequence);
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let token = NoException::test(&env);
+        let string = ManuallyDrop::new(String::new(&token, VALUE).unwrap());
+        assert_eq!(string.as_string(&token), VALUE);
+    }
+}
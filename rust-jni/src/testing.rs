@@ -1,6 +1,28 @@
 use jni_sys;
+use std::cell::Cell;
 use std::ptr;
 
+thread_local! {
+    static LIVE_REFERENCES: Cell<isize> = Cell::new(0);
+}
+
+/// Record that a local or global reference was created (`delta == 1`) or deleted (`delta == -1`)
+/// on the current thread.
+pub(crate) fn track_references(delta: isize) {
+    LIVE_REFERENCES.with(|count| count.set(count.get() + delta));
+}
+
+/// Count local and global references created by `function` on the current thread
+/// which it didn't delete.
+///
+/// Helps to find reference leaks: returns `0` if all references created inside the scope
+/// were deleted.
+pub fn count_leaked_references(function: impl FnOnce()) -> isize {
+    let before = LIVE_REFERENCES.with(Cell::get);
+    function();
+    LIVE_REFERENCES.with(Cell::get) - before
+}
+
 /// Create an empty Java VM interface control structure for testing purposes.
 pub fn empty_raw_java_vm() -> jni_sys::JNIInvokeInterface_ {
     jni_sys::JNIInvokeInterface_ {
@@ -419,6 +441,390 @@ macro_rules! generate_jni_env_mock {
                     pub fn exception_occured(env: *mut jni_sys::JNIEnv) -> jni_sys::jobject;
 
                     pub fn exception_clear(env: *mut jni_sys::JNIEnv);
+
+                    pub fn fatal_error(
+                        env: *mut jni_sys::JNIEnv,
+                        message: *const ::std::os::raw::c_char,
+                    );
+
+                    pub fn is_same_object(
+                        env: *mut jni_sys::JNIEnv,
+                        object1: jni_sys::jobject,
+                        object2: jni_sys::jobject,
+                    ) -> jni_sys::jboolean;
+
+                    pub fn new_string(
+                        env: *mut jni_sys::JNIEnv,
+                        chars: *const jni_sys::jchar,
+                        len: jni_sys::jsize,
+                    ) -> jni_sys::jstring;
+
+                    pub fn new_string_utf(
+                        env: *mut jni_sys::JNIEnv,
+                        bytes: *const ::std::os::raw::c_char,
+                    ) -> jni_sys::jstring;
+
+                    pub fn get_string_length(
+                        env: *mut jni_sys::JNIEnv,
+                        string: jni_sys::jstring,
+                    ) -> jni_sys::jsize;
+
+                    pub fn get_string_region(
+                        env: *mut jni_sys::JNIEnv,
+                        string: jni_sys::jstring,
+                        start: jni_sys::jsize,
+                        length: jni_sys::jsize,
+                        buffer: *mut jni_sys::jchar,
+                    );
+
+                    pub fn get_string_utf_length(
+                        env: *mut jni_sys::JNIEnv,
+                        string: jni_sys::jstring,
+                    ) -> jni_sys::jsize;
+
+                    pub fn get_string_utf_region(
+                        env: *mut jni_sys::JNIEnv,
+                        string: jni_sys::jstring,
+                        start: jni_sys::jsize,
+                        length: jni_sys::jsize,
+                        buffer: *mut ::std::os::raw::c_char,
+                    );
+
+                    pub fn get_string_utf_chars(
+                        env: *mut jni_sys::JNIEnv,
+                        string: jni_sys::jstring,
+                        is_copy: *mut jni_sys::jboolean,
+                    ) -> *const ::std::os::raw::c_char;
+
+                    pub fn release_string_utf_chars(
+                        env: *mut jni_sys::JNIEnv,
+                        string: jni_sys::jstring,
+                        chars: *const ::std::os::raw::c_char,
+                    );
+
+                    pub fn get_string_critical(
+                        env: *mut jni_sys::JNIEnv,
+                        string: jni_sys::jstring,
+                        is_copy: *mut jni_sys::jboolean,
+                    ) -> *const jni_sys::jchar;
+
+                    pub fn release_string_critical(
+                        env: *mut jni_sys::JNIEnv,
+                        string: jni_sys::jstring,
+                        chars: *const jni_sys::jchar,
+                    );
+
+                    pub fn is_instance_of(
+                        env: *mut jni_sys::JNIEnv,
+                        object: jni_sys::jobject,
+                        class: jni_sys::jclass,
+                    ) -> jni_sys::jboolean;
+
+                    pub fn find_class(
+                        env: *mut jni_sys::JNIEnv,
+                        name: *const ::std::os::raw::c_char,
+                    ) -> jni_sys::jclass;
+
+                    pub fn define_class(
+                        env: *mut jni_sys::JNIEnv,
+                        name: *const ::std::os::raw::c_char,
+                        loader: jni_sys::jobject,
+                        buffer: *const jni_sys::jbyte,
+                        length: jni_sys::jsize,
+                    ) -> jni_sys::jclass;
+
+                    pub fn is_assignable_from(
+                        env: *mut jni_sys::JNIEnv,
+                        class1: jni_sys::jclass,
+                        class2: jni_sys::jclass,
+                    ) -> jni_sys::jboolean;
+
+                    pub fn throw(
+                        env: *mut jni_sys::JNIEnv,
+                        throwable: jni_sys::jthrowable,
+                    ) -> jni_sys::jint;
+
+                    pub fn throw_new(
+                        env: *mut jni_sys::JNIEnv,
+                        class: jni_sys::jclass,
+                        message: *const ::std::os::raw::c_char,
+                    ) -> jni_sys::jint;
+
+                    pub fn get_static_method_id(
+                        env: *mut jni_sys::JNIEnv,
+                        class: jni_sys::jclass,
+                        name: *const ::std::os::raw::c_char,
+                        signature: *const ::std::os::raw::c_char,
+                    ) -> jni_sys::jmethodID;
+
+                    pub fn new_global_ref(
+                        env: *mut jni_sys::JNIEnv,
+                        object: jni_sys::jobject,
+                    ) -> jni_sys::jobject;
+
+                    pub fn new_weak_global_ref(
+                        env: *mut jni_sys::JNIEnv,
+                        object: jni_sys::jobject,
+                    ) -> jni_sys::jweak;
+
+                    pub fn delete_weak_global_ref(
+                        env: *mut jni_sys::JNIEnv,
+                        object: jni_sys::jweak,
+                    );
+
+                    pub fn ensure_local_capacity(
+                        env: *mut jni_sys::JNIEnv,
+                        capacity: jni_sys::jint,
+                    ) -> jni_sys::jint;
+
+                    pub fn push_local_frame(
+                        env: *mut jni_sys::JNIEnv,
+                        capacity: jni_sys::jint,
+                    ) -> jni_sys::jint;
+
+                    pub fn pop_local_frame(
+                        env: *mut jni_sys::JNIEnv,
+                        result: jni_sys::jobject,
+                    ) -> jni_sys::jobject;
+
+                    pub fn new_local_ref(
+                        env: *mut jni_sys::JNIEnv,
+                        object: jni_sys::jobject,
+                    ) -> jni_sys::jobject;
+
+                    pub fn delete_global_ref(env: *mut jni_sys::JNIEnv, object: jni_sys::jobject);
+
+                    pub fn get_object_class(
+                        env: *mut jni_sys::JNIEnv,
+                        object: jni_sys::jobject,
+                    ) -> jni_sys::jclass;
+
+                    pub fn get_method_id(
+                        env: *mut jni_sys::JNIEnv,
+                        class: jni_sys::jclass,
+                        name: *const ::std::os::raw::c_char,
+                        signature: *const ::std::os::raw::c_char,
+                    ) -> jni_sys::jmethodID;
+
+                    pub fn call_void_method_a(
+                        env: *mut jni_sys::JNIEnv,
+                        object: jni_sys::jobject,
+                        method_id: jni_sys::jmethodID,
+                        arguments: *const jni_sys::jvalue,
+                    );
+
+                    pub fn call_boolean_method_a(
+                        env: *mut jni_sys::JNIEnv,
+                        object: jni_sys::jobject,
+                        method_id: jni_sys::jmethodID,
+                        arguments: *const jni_sys::jvalue,
+                    ) -> jni_sys::jboolean;
+
+                    pub fn call_int_method_a(
+                        env: *mut jni_sys::JNIEnv,
+                        object: jni_sys::jobject,
+                        method_id: jni_sys::jmethodID,
+                        arguments: *const jni_sys::jvalue,
+                    ) -> jni_sys::jint;
+
+                    pub fn call_nonvirtual_int_method_a(
+                        env: *mut jni_sys::JNIEnv,
+                        object: jni_sys::jobject,
+                        class: jni_sys::jclass,
+                        method_id: jni_sys::jmethodID,
+                        arguments: *const jni_sys::jvalue,
+                    ) -> jni_sys::jint;
+
+                    pub fn call_nonvirtual_object_method_a(
+                        env: *mut jni_sys::JNIEnv,
+                        object: jni_sys::jobject,
+                        class: jni_sys::jclass,
+                        method_id: jni_sys::jmethodID,
+                        arguments: *const jni_sys::jvalue,
+                    ) -> jni_sys::jobject;
+
+                    pub fn get_array_length(
+                        env: *mut jni_sys::JNIEnv,
+                        array: jni_sys::jarray,
+                    ) -> jni_sys::jsize;
+
+                    pub fn new_int_array(
+                        env: *mut jni_sys::JNIEnv,
+                        length: jni_sys::jsize,
+                    ) -> jni_sys::jintArray;
+
+                    pub fn get_int_array_region(
+                        env: *mut jni_sys::JNIEnv,
+                        array: jni_sys::jintArray,
+                        start: jni_sys::jsize,
+                        length: jni_sys::jsize,
+                        buffer: *mut jni_sys::jint,
+                    );
+
+                    pub fn set_int_array_region(
+                        env: *mut jni_sys::JNIEnv,
+                        array: jni_sys::jintArray,
+                        start: jni_sys::jsize,
+                        length: jni_sys::jsize,
+                        buffer: *const jni_sys::jint,
+                    );
+
+                    pub fn new_object_a(
+                        env: *mut jni_sys::JNIEnv,
+                        class: jni_sys::jclass,
+                        method_id: jni_sys::jmethodID,
+                        arguments: *const jni_sys::jvalue,
+                    ) -> jni_sys::jobject;
+
+                    pub fn new_object_array(
+                        env: *mut jni_sys::JNIEnv,
+                        length: jni_sys::jsize,
+                        class: jni_sys::jclass,
+                        initial: jni_sys::jobject,
+                    ) -> jni_sys::jobjectArray;
+
+                    pub fn get_object_array_element(
+                        env: *mut jni_sys::JNIEnv,
+                        array: jni_sys::jobjectArray,
+                        index: jni_sys::jsize,
+                    ) -> jni_sys::jobject;
+
+                    pub fn set_object_array_element(
+                        env: *mut jni_sys::JNIEnv,
+                        array: jni_sys::jobjectArray,
+                        index: jni_sys::jsize,
+                        value: jni_sys::jobject,
+                    );
+
+                    pub fn get_primitive_array_critical(
+                        env: *mut jni_sys::JNIEnv,
+                        array: jni_sys::jarray,
+                        is_copy: *mut jni_sys::jboolean,
+                    ) -> *mut ::std::os::raw::c_void;
+
+                    pub fn release_primitive_array_critical(
+                        env: *mut jni_sys::JNIEnv,
+                        array: jni_sys::jarray,
+                        elements: *mut ::std::os::raw::c_void,
+                        mode: jni_sys::jint,
+                    );
+
+                    pub fn call_object_method_a(
+                        env: *mut jni_sys::JNIEnv,
+                        object: jni_sys::jobject,
+                        method_id: jni_sys::jmethodID,
+                        arguments: *const jni_sys::jvalue,
+                    ) -> jni_sys::jobject;
+
+                    pub fn call_float_method_a(
+                        env: *mut jni_sys::JNIEnv,
+                        object: jni_sys::jobject,
+                        method_id: jni_sys::jmethodID,
+                        arguments: *const jni_sys::jvalue,
+                    ) -> jni_sys::jfloat;
+
+                    pub fn call_static_int_method_a(
+                        env: *mut jni_sys::JNIEnv,
+                        class: jni_sys::jclass,
+                        method_id: jni_sys::jmethodID,
+                        arguments: *const jni_sys::jvalue,
+                    ) -> jni_sys::jint;
+
+                    pub fn monitor_enter(
+                        env: *mut jni_sys::JNIEnv,
+                        object: jni_sys::jobject,
+                    ) -> jni_sys::jint;
+
+                    pub fn monitor_exit(
+                        env: *mut jni_sys::JNIEnv,
+                        object: jni_sys::jobject,
+                    ) -> jni_sys::jint;
+
+                    pub fn get_object_ref_type(
+                        env: *mut jni_sys::JNIEnv,
+                        object: jni_sys::jobject,
+                    ) -> jni_sys::jobjectRefType;
+
+                    pub fn get_field_id(
+                        env: *mut jni_sys::JNIEnv,
+                        class: jni_sys::jclass,
+                        name: *const ::std::os::raw::c_char,
+                        signature: *const ::std::os::raw::c_char,
+                    ) -> jni_sys::jfieldID;
+
+                    pub fn get_int_field(
+                        env: *mut jni_sys::JNIEnv,
+                        object: jni_sys::jobject,
+                        field_id: jni_sys::jfieldID,
+                    ) -> jni_sys::jint;
+
+                    pub fn set_int_field(
+                        env: *mut jni_sys::JNIEnv,
+                        object: jni_sys::jobject,
+                        field_id: jni_sys::jfieldID,
+                        value: jni_sys::jint,
+                    );
+
+                    pub fn get_object_field(
+                        env: *mut jni_sys::JNIEnv,
+                        object: jni_sys::jobject,
+                        field_id: jni_sys::jfieldID,
+                    ) -> jni_sys::jobject;
+
+                    pub fn set_object_field(
+                        env: *mut jni_sys::JNIEnv,
+                        object: jni_sys::jobject,
+                        field_id: jni_sys::jfieldID,
+                        value: jni_sys::jobject,
+                    );
+
+                    pub fn get_static_field_id(
+                        env: *mut jni_sys::JNIEnv,
+                        class: jni_sys::jclass,
+                        name: *const ::std::os::raw::c_char,
+                        signature: *const ::std::os::raw::c_char,
+                    ) -> jni_sys::jfieldID;
+
+                    pub fn get_static_object_field(
+                        env: *mut jni_sys::JNIEnv,
+                        class: jni_sys::jclass,
+                        field_id: jni_sys::jfieldID,
+                    ) -> jni_sys::jobject;
+
+                    pub fn set_static_object_field(
+                        env: *mut jni_sys::JNIEnv,
+                        class: jni_sys::jclass,
+                        field_id: jni_sys::jfieldID,
+                        value: jni_sys::jobject,
+                    );
+
+                    pub fn new_direct_byte_buffer(
+                        env: *mut jni_sys::JNIEnv,
+                        address: *mut ::std::os::raw::c_void,
+                        capacity: jni_sys::jlong,
+                    ) -> jni_sys::jobject;
+
+                    pub fn get_direct_buffer_address(
+                        env: *mut jni_sys::JNIEnv,
+                        buffer: jni_sys::jobject,
+                    ) -> *mut ::std::os::raw::c_void;
+
+                    pub fn get_direct_buffer_capacity(
+                        env: *mut jni_sys::JNIEnv,
+                        buffer: jni_sys::jobject,
+                    ) -> jni_sys::jlong;
+
+                    pub fn register_natives(
+                        env: *mut jni_sys::JNIEnv,
+                        class: jni_sys::jclass,
+                        methods: *const jni_sys::JNINativeMethod,
+                        methods_count: jni_sys::jint,
+                    ) -> jni_sys::jint;
+
+                    pub fn unregister_natives(
+                        env: *mut jni_sys::JNIEnv,
+                        class: jni_sys::jclass,
+                    ) -> jni_sys::jint;
                 }
             }
 
@@ -457,6 +863,527 @@ macro_rules! generate_jni_env_mock {
                     mock_ffi::exception_clear(env)
                 }
 
+                unsafe extern "system" fn fatal_error_impl(
+                    env: *mut jni_sys::JNIEnv,
+                    message: *const ::std::os::raw::c_char,
+                ) -> ! {
+                    mock_ffi::fatal_error(env, message);
+                    // `FatalError` never returns. Unwinding out of it aborts the process, which
+                    // matches what a real Java VM does.
+                    panic!(
+                        "FatalError: {}",
+                        ::std::ffi::CStr::from_ptr(message).to_string_lossy()
+                    );
+                }
+
+                unsafe extern "system" fn is_same_object_impl(
+                    env: *mut jni_sys::JNIEnv,
+                    object1: jni_sys::jobject,
+                    object2: jni_sys::jobject,
+                ) -> jni_sys::jboolean {
+                    mock_ffi::is_same_object(env, object1, object2)
+                }
+
+                unsafe extern "system" fn new_string_impl(
+                    env: *mut jni_sys::JNIEnv,
+                    chars: *const jni_sys::jchar,
+                    len: jni_sys::jsize,
+                ) -> jni_sys::jstring {
+                    mock_ffi::new_string(env, chars, len)
+                }
+
+                unsafe extern "system" fn new_string_utf_impl(
+                    env: *mut jni_sys::JNIEnv,
+                    bytes: *const ::std::os::raw::c_char,
+                ) -> jni_sys::jstring {
+                    mock_ffi::new_string_utf(env, bytes)
+                }
+
+                unsafe extern "system" fn get_string_length_impl(
+                    env: *mut jni_sys::JNIEnv,
+                    string: jni_sys::jstring,
+                ) -> jni_sys::jsize {
+                    mock_ffi::get_string_length(env, string)
+                }
+
+                unsafe extern "system" fn get_string_region_impl(
+                    env: *mut jni_sys::JNIEnv,
+                    string: jni_sys::jstring,
+                    start: jni_sys::jsize,
+                    length: jni_sys::jsize,
+                    buffer: *mut jni_sys::jchar,
+                ) {
+                    mock_ffi::get_string_region(env, string, start, length, buffer)
+                }
+
+                unsafe extern "system" fn get_string_utf_length_impl(
+                    env: *mut jni_sys::JNIEnv,
+                    string: jni_sys::jstring,
+                ) -> jni_sys::jsize {
+                    mock_ffi::get_string_utf_length(env, string)
+                }
+
+                unsafe extern "system" fn get_string_utf_region_impl(
+                    env: *mut jni_sys::JNIEnv,
+                    string: jni_sys::jstring,
+                    start: jni_sys::jsize,
+                    length: jni_sys::jsize,
+                    buffer: *mut ::std::os::raw::c_char,
+                ) {
+                    mock_ffi::get_string_utf_region(env, string, start, length, buffer)
+                }
+
+                unsafe extern "system" fn get_string_utf_chars_impl(
+                    env: *mut jni_sys::JNIEnv,
+                    string: jni_sys::jstring,
+                    is_copy: *mut jni_sys::jboolean,
+                ) -> *const ::std::os::raw::c_char {
+                    mock_ffi::get_string_utf_chars(env, string, is_copy)
+                }
+
+                unsafe extern "system" fn release_string_utf_chars_impl(
+                    env: *mut jni_sys::JNIEnv,
+                    string: jni_sys::jstring,
+                    chars: *const ::std::os::raw::c_char,
+                ) {
+                    mock_ffi::release_string_utf_chars(env, string, chars)
+                }
+
+                unsafe extern "system" fn get_string_critical_impl(
+                    env: *mut jni_sys::JNIEnv,
+                    string: jni_sys::jstring,
+                    is_copy: *mut jni_sys::jboolean,
+                ) -> *const jni_sys::jchar {
+                    mock_ffi::get_string_critical(env, string, is_copy)
+                }
+
+                unsafe extern "system" fn release_string_critical_impl(
+                    env: *mut jni_sys::JNIEnv,
+                    string: jni_sys::jstring,
+                    chars: *const jni_sys::jchar,
+                ) {
+                    mock_ffi::release_string_critical(env, string, chars)
+                }
+
+                unsafe extern "system" fn is_instance_of_impl(
+                    env: *mut jni_sys::JNIEnv,
+                    object: jni_sys::jobject,
+                    class: jni_sys::jclass,
+                ) -> jni_sys::jboolean {
+                    mock_ffi::is_instance_of(env, object, class)
+                }
+
+                unsafe extern "system" fn find_class_impl(
+                    env: *mut jni_sys::JNIEnv,
+                    name: *const ::std::os::raw::c_char,
+                ) -> jni_sys::jclass {
+                    mock_ffi::find_class(env, name)
+                }
+
+                unsafe extern "system" fn define_class_impl(
+                    env: *mut jni_sys::JNIEnv,
+                    name: *const ::std::os::raw::c_char,
+                    loader: jni_sys::jobject,
+                    buffer: *const jni_sys::jbyte,
+                    length: jni_sys::jsize,
+                ) -> jni_sys::jclass {
+                    mock_ffi::define_class(env, name, loader, buffer, length)
+                }
+
+                unsafe extern "system" fn is_assignable_from_impl(
+                    env: *mut jni_sys::JNIEnv,
+                    class1: jni_sys::jclass,
+                    class2: jni_sys::jclass,
+                ) -> jni_sys::jboolean {
+                    mock_ffi::is_assignable_from(env, class1, class2)
+                }
+
+                unsafe extern "system" fn throw_impl(
+                    env: *mut jni_sys::JNIEnv,
+                    throwable: jni_sys::jthrowable,
+                ) -> jni_sys::jint {
+                    mock_ffi::throw(env, throwable)
+                }
+
+                unsafe extern "system" fn throw_new_impl(
+                    env: *mut jni_sys::JNIEnv,
+                    class: jni_sys::jclass,
+                    message: *const ::std::os::raw::c_char,
+                ) -> jni_sys::jint {
+                    mock_ffi::throw_new(env, class, message)
+                }
+
+                unsafe extern "system" fn get_static_method_id_impl(
+                    env: *mut jni_sys::JNIEnv,
+                    class: jni_sys::jclass,
+                    name: *const ::std::os::raw::c_char,
+                    signature: *const ::std::os::raw::c_char,
+                ) -> jni_sys::jmethodID {
+                    mock_ffi::get_static_method_id(env, class, name, signature)
+                }
+
+                unsafe extern "system" fn new_global_ref_impl(
+                    env: *mut jni_sys::JNIEnv,
+                    object: jni_sys::jobject,
+                ) -> jni_sys::jobject {
+                    mock_ffi::new_global_ref(env, object)
+                }
+
+                unsafe extern "system" fn new_weak_global_ref_impl(
+                    env: *mut jni_sys::JNIEnv,
+                    object: jni_sys::jobject,
+                ) -> jni_sys::jweak {
+                    mock_ffi::new_weak_global_ref(env, object)
+                }
+
+                unsafe extern "system" fn delete_weak_global_ref_impl(
+                    env: *mut jni_sys::JNIEnv,
+                    object: jni_sys::jweak,
+                ) {
+                    mock_ffi::delete_weak_global_ref(env, object)
+                }
+
+                unsafe extern "system" fn ensure_local_capacity_impl(
+                    env: *mut jni_sys::JNIEnv,
+                    capacity: jni_sys::jint,
+                ) -> jni_sys::jint {
+                    mock_ffi::ensure_local_capacity(env, capacity)
+                }
+
+                unsafe extern "system" fn push_local_frame_impl(
+                    env: *mut jni_sys::JNIEnv,
+                    capacity: jni_sys::jint,
+                ) -> jni_sys::jint {
+                    mock_ffi::push_local_frame(env, capacity)
+                }
+
+                unsafe extern "system" fn pop_local_frame_impl(
+                    env: *mut jni_sys::JNIEnv,
+                    result: jni_sys::jobject,
+                ) -> jni_sys::jobject {
+                    mock_ffi::pop_local_frame(env, result)
+                }
+
+                unsafe extern "system" fn new_local_ref_impl(
+                    env: *mut jni_sys::JNIEnv,
+                    object: jni_sys::jobject,
+                ) -> jni_sys::jobject {
+                    mock_ffi::new_local_ref(env, object)
+                }
+
+                unsafe extern "system" fn delete_global_ref_impl(
+                    env: *mut jni_sys::JNIEnv,
+                    object: jni_sys::jobject,
+                ) {
+                    mock_ffi::delete_global_ref(env, object)
+                }
+
+                unsafe extern "system" fn get_object_class_impl(
+                    env: *mut jni_sys::JNIEnv,
+                    object: jni_sys::jobject,
+                ) -> jni_sys::jclass {
+                    mock_ffi::get_object_class(env, object)
+                }
+
+                unsafe extern "system" fn get_method_id_impl(
+                    env: *mut jni_sys::JNIEnv,
+                    class: jni_sys::jclass,
+                    name: *const ::std::os::raw::c_char,
+                    signature: *const ::std::os::raw::c_char,
+                ) -> jni_sys::jmethodID {
+                    mock_ffi::get_method_id(env, class, name, signature)
+                }
+
+                unsafe extern "system" fn call_void_method_a_impl(
+                    env: *mut jni_sys::JNIEnv,
+                    object: jni_sys::jobject,
+                    method_id: jni_sys::jmethodID,
+                    arguments: *const jni_sys::jvalue,
+                ) {
+                    mock_ffi::call_void_method_a(env, object, method_id, arguments)
+                }
+
+                unsafe extern "system" fn call_boolean_method_a_impl(
+                    env: *mut jni_sys::JNIEnv,
+                    object: jni_sys::jobject,
+                    method_id: jni_sys::jmethodID,
+                    arguments: *const jni_sys::jvalue,
+                ) -> jni_sys::jboolean {
+                    mock_ffi::call_boolean_method_a(env, object, method_id, arguments)
+                }
+
+                unsafe extern "system" fn call_int_method_a_impl(
+                    env: *mut jni_sys::JNIEnv,
+                    object: jni_sys::jobject,
+                    method_id: jni_sys::jmethodID,
+                    arguments: *const jni_sys::jvalue,
+                ) -> jni_sys::jint {
+                    mock_ffi::call_int_method_a(env, object, method_id, arguments)
+                }
+
+                unsafe extern "system" fn call_nonvirtual_int_method_a_impl(
+                    env: *mut jni_sys::JNIEnv,
+                    object: jni_sys::jobject,
+                    class: jni_sys::jclass,
+                    method_id: jni_sys::jmethodID,
+                    arguments: *const jni_sys::jvalue,
+                ) -> jni_sys::jint {
+                    mock_ffi::call_nonvirtual_int_method_a(env, object, class, method_id, arguments)
+                }
+
+                unsafe extern "system" fn call_nonvirtual_object_method_a_impl(
+                    env: *mut jni_sys::JNIEnv,
+                    object: jni_sys::jobject,
+                    class: jni_sys::jclass,
+                    method_id: jni_sys::jmethodID,
+                    arguments: *const jni_sys::jvalue,
+                ) -> jni_sys::jobject {
+                    mock_ffi::call_nonvirtual_object_method_a(
+                        env, object, class, method_id, arguments,
+                    )
+                }
+
+                unsafe extern "system" fn get_array_length_impl(
+                    env: *mut jni_sys::JNIEnv,
+                    array: jni_sys::jarray,
+                ) -> jni_sys::jsize {
+                    mock_ffi::get_array_length(env, array)
+                }
+
+                unsafe extern "system" fn new_int_array_impl(
+                    env: *mut jni_sys::JNIEnv,
+                    length: jni_sys::jsize,
+                ) -> jni_sys::jintArray {
+                    mock_ffi::new_int_array(env, length)
+                }
+
+                unsafe extern "system" fn get_int_array_region_impl(
+                    env: *mut jni_sys::JNIEnv,
+                    array: jni_sys::jintArray,
+                    start: jni_sys::jsize,
+                    length: jni_sys::jsize,
+                    buffer: *mut jni_sys::jint,
+                ) {
+                    mock_ffi::get_int_array_region(env, array, start, length, buffer)
+                }
+
+                unsafe extern "system" fn set_int_array_region_impl(
+                    env: *mut jni_sys::JNIEnv,
+                    array: jni_sys::jintArray,
+                    start: jni_sys::jsize,
+                    length: jni_sys::jsize,
+                    buffer: *const jni_sys::jint,
+                ) {
+                    mock_ffi::set_int_array_region(env, array, start, length, buffer)
+                }
+
+                unsafe extern "system" fn new_object_a_impl(
+                    env: *mut jni_sys::JNIEnv,
+                    class: jni_sys::jclass,
+                    method_id: jni_sys::jmethodID,
+                    arguments: *const jni_sys::jvalue,
+                ) -> jni_sys::jobject {
+                    mock_ffi::new_object_a(env, class, method_id, arguments)
+                }
+
+                unsafe extern "system" fn new_object_array_impl(
+                    env: *mut jni_sys::JNIEnv,
+                    length: jni_sys::jsize,
+                    class: jni_sys::jclass,
+                    initial: jni_sys::jobject,
+                ) -> jni_sys::jobjectArray {
+                    mock_ffi::new_object_array(env, length, class, initial)
+                }
+
+                unsafe extern "system" fn get_object_array_element_impl(
+                    env: *mut jni_sys::JNIEnv,
+                    array: jni_sys::jobjectArray,
+                    index: jni_sys::jsize,
+                ) -> jni_sys::jobject {
+                    mock_ffi::get_object_array_element(env, array, index)
+                }
+
+                unsafe extern "system" fn set_object_array_element_impl(
+                    env: *mut jni_sys::JNIEnv,
+                    array: jni_sys::jobjectArray,
+                    index: jni_sys::jsize,
+                    value: jni_sys::jobject,
+                ) {
+                    mock_ffi::set_object_array_element(env, array, index, value)
+                }
+
+                unsafe extern "system" fn get_primitive_array_critical_impl(
+                    env: *mut jni_sys::JNIEnv,
+                    array: jni_sys::jarray,
+                    is_copy: *mut jni_sys::jboolean,
+                ) -> *mut ::std::os::raw::c_void {
+                    mock_ffi::get_primitive_array_critical(env, array, is_copy)
+                }
+
+                unsafe extern "system" fn release_primitive_array_critical_impl(
+                    env: *mut jni_sys::JNIEnv,
+                    array: jni_sys::jarray,
+                    elements: *mut ::std::os::raw::c_void,
+                    mode: jni_sys::jint,
+                ) {
+                    mock_ffi::release_primitive_array_critical(env, array, elements, mode)
+                }
+
+                unsafe extern "system" fn call_object_method_a_impl(
+                    env: *mut jni_sys::JNIEnv,
+                    object: jni_sys::jobject,
+                    method_id: jni_sys::jmethodID,
+                    arguments: *const jni_sys::jvalue,
+                ) -> jni_sys::jobject {
+                    mock_ffi::call_object_method_a(env, object, method_id, arguments)
+                }
+
+                unsafe extern "system" fn call_float_method_a_impl(
+                    env: *mut jni_sys::JNIEnv,
+                    object: jni_sys::jobject,
+                    method_id: jni_sys::jmethodID,
+                    arguments: *const jni_sys::jvalue,
+                ) -> jni_sys::jfloat {
+                    mock_ffi::call_float_method_a(env, object, method_id, arguments)
+                }
+
+                unsafe extern "system" fn call_static_int_method_a_impl(
+                    env: *mut jni_sys::JNIEnv,
+                    class: jni_sys::jclass,
+                    method_id: jni_sys::jmethodID,
+                    arguments: *const jni_sys::jvalue,
+                ) -> jni_sys::jint {
+                    mock_ffi::call_static_int_method_a(env, class, method_id, arguments)
+                }
+
+                unsafe extern "system" fn monitor_enter_impl(
+                    env: *mut jni_sys::JNIEnv,
+                    object: jni_sys::jobject,
+                ) -> jni_sys::jint {
+                    mock_ffi::monitor_enter(env, object)
+                }
+
+                unsafe extern "system" fn monitor_exit_impl(
+                    env: *mut jni_sys::JNIEnv,
+                    object: jni_sys::jobject,
+                ) -> jni_sys::jint {
+                    mock_ffi::monitor_exit(env, object)
+                }
+
+                unsafe extern "system" fn get_object_ref_type_impl(
+                    env: *mut jni_sys::JNIEnv,
+                    object: jni_sys::jobject,
+                ) -> jni_sys::jobjectRefType {
+                    mock_ffi::get_object_ref_type(env, object)
+                }
+
+                unsafe extern "system" fn get_field_id_impl(
+                    env: *mut jni_sys::JNIEnv,
+                    class: jni_sys::jclass,
+                    name: *const ::std::os::raw::c_char,
+                    signature: *const ::std::os::raw::c_char,
+                ) -> jni_sys::jfieldID {
+                    mock_ffi::get_field_id(env, class, name, signature)
+                }
+
+                unsafe extern "system" fn get_int_field_impl(
+                    env: *mut jni_sys::JNIEnv,
+                    object: jni_sys::jobject,
+                    field_id: jni_sys::jfieldID,
+                ) -> jni_sys::jint {
+                    mock_ffi::get_int_field(env, object, field_id)
+                }
+
+                unsafe extern "system" fn set_int_field_impl(
+                    env: *mut jni_sys::JNIEnv,
+                    object: jni_sys::jobject,
+                    field_id: jni_sys::jfieldID,
+                    value: jni_sys::jint,
+                ) {
+                    mock_ffi::set_int_field(env, object, field_id, value)
+                }
+
+                unsafe extern "system" fn get_object_field_impl(
+                    env: *mut jni_sys::JNIEnv,
+                    object: jni_sys::jobject,
+                    field_id: jni_sys::jfieldID,
+                ) -> jni_sys::jobject {
+                    mock_ffi::get_object_field(env, object, field_id)
+                }
+
+                unsafe extern "system" fn set_object_field_impl(
+                    env: *mut jni_sys::JNIEnv,
+                    object: jni_sys::jobject,
+                    field_id: jni_sys::jfieldID,
+                    value: jni_sys::jobject,
+                ) {
+                    mock_ffi::set_object_field(env, object, field_id, value)
+                }
+
+                unsafe extern "system" fn get_static_field_id_impl(
+                    env: *mut jni_sys::JNIEnv,
+                    class: jni_sys::jclass,
+                    name: *const ::std::os::raw::c_char,
+                    signature: *const ::std::os::raw::c_char,
+                ) -> jni_sys::jfieldID {
+                    mock_ffi::get_static_field_id(env, class, name, signature)
+                }
+
+                unsafe extern "system" fn get_static_object_field_impl(
+                    env: *mut jni_sys::JNIEnv,
+                    class: jni_sys::jclass,
+                    field_id: jni_sys::jfieldID,
+                ) -> jni_sys::jobject {
+                    mock_ffi::get_static_object_field(env, class, field_id)
+                }
+
+                unsafe extern "system" fn set_static_object_field_impl(
+                    env: *mut jni_sys::JNIEnv,
+                    class: jni_sys::jclass,
+                    field_id: jni_sys::jfieldID,
+                    value: jni_sys::jobject,
+                ) {
+                    mock_ffi::set_static_object_field(env, class, field_id, value)
+                }
+
+                unsafe extern "system" fn new_direct_byte_buffer_impl(
+                    env: *mut jni_sys::JNIEnv,
+                    address: *mut ::std::os::raw::c_void,
+                    capacity: jni_sys::jlong,
+                ) -> jni_sys::jobject {
+                    mock_ffi::new_direct_byte_buffer(env, address, capacity)
+                }
+
+                unsafe extern "system" fn get_direct_buffer_address_impl(
+                    env: *mut jni_sys::JNIEnv,
+                    buffer: jni_sys::jobject,
+                ) -> *mut ::std::os::raw::c_void {
+                    mock_ffi::get_direct_buffer_address(env, buffer)
+                }
+
+                unsafe extern "system" fn get_direct_buffer_capacity_impl(
+                    env: *mut jni_sys::JNIEnv,
+                    buffer: jni_sys::jobject,
+                ) -> jni_sys::jlong {
+                    mock_ffi::get_direct_buffer_capacity(env, buffer)
+                }
+
+                unsafe extern "system" fn register_natives_impl(
+                    env: *mut jni_sys::JNIEnv,
+                    class: jni_sys::jclass,
+                    methods: *const jni_sys::JNINativeMethod,
+                    methods_count: jni_sys::jint,
+                ) -> jni_sys::jint {
+                    mock_ffi::register_natives(env, class, methods, methods_count)
+                }
+
+                unsafe extern "system" fn unregister_natives_impl(
+                    env: *mut jni_sys::JNIEnv,
+                    class: jni_sys::jclass,
+                ) -> jni_sys::jint {
+                    mock_ffi::unregister_natives(env, class)
+                }
+
                 jni_sys::JNINativeInterface_ {
                     DeleteLocalRef: Some(delete_local_ref_impl),
                     GetVersion: Some(get_version_impl),
@@ -464,6 +1391,69 @@ macro_rules! generate_jni_env_mock {
                     ExceptionDescribe: Some(exception_describe_impl),
                     ExceptionOccurred: Some(exception_occured_impl),
                     ExceptionClear: Some(exception_clear_impl),
+                    FatalError: Some(fatal_error_impl),
+                    IsSameObject: Some(is_same_object_impl),
+                    NewString: Some(new_string_impl),
+                    NewStringUTF: Some(new_string_utf_impl),
+                    GetStringLength: Some(get_string_length_impl),
+                    GetStringRegion: Some(get_string_region_impl),
+                    GetStringUTFLength: Some(get_string_utf_length_impl),
+                    GetStringUTFRegion: Some(get_string_utf_region_impl),
+                    GetStringUTFChars: Some(get_string_utf_chars_impl),
+                    ReleaseStringUTFChars: Some(release_string_utf_chars_impl),
+                    GetStringCritical: Some(get_string_critical_impl),
+                    ReleaseStringCritical: Some(release_string_critical_impl),
+                    IsInstanceOf: Some(is_instance_of_impl),
+                    FindClass: Some(find_class_impl),
+                    DefineClass: Some(define_class_impl),
+                    IsAssignableFrom: Some(is_assignable_from_impl),
+                    Throw: Some(throw_impl),
+                    ThrowNew: Some(throw_new_impl),
+                    GetStaticMethodID: Some(get_static_method_id_impl),
+                    NewGlobalRef: Some(new_global_ref_impl),
+                    DeleteGlobalRef: Some(delete_global_ref_impl),
+                    EnsureLocalCapacity: Some(ensure_local_capacity_impl),
+                    PushLocalFrame: Some(push_local_frame_impl),
+                    PopLocalFrame: Some(pop_local_frame_impl),
+                    NewLocalRef: Some(new_local_ref_impl),
+                    NewWeakGlobalRef: Some(new_weak_global_ref_impl),
+                    DeleteWeakGlobalRef: Some(delete_weak_global_ref_impl),
+                    GetObjectClass: Some(get_object_class_impl),
+                    GetMethodID: Some(get_method_id_impl),
+                    CallVoidMethodA: Some(call_void_method_a_impl),
+                    CallBooleanMethodA: Some(call_boolean_method_a_impl),
+                    CallIntMethodA: Some(call_int_method_a_impl),
+                    CallNonvirtualIntMethodA: Some(call_nonvirtual_int_method_a_impl),
+                    CallNonvirtualObjectMethodA: Some(call_nonvirtual_object_method_a_impl),
+                    CallObjectMethodA: Some(call_object_method_a_impl),
+                    CallFloatMethodA: Some(call_float_method_a_impl),
+                    CallStaticIntMethodA: Some(call_static_int_method_a_impl),
+                    MonitorEnter: Some(monitor_enter_impl),
+                    MonitorExit: Some(monitor_exit_impl),
+                    GetArrayLength: Some(get_array_length_impl),
+                    NewIntArray: Some(new_int_array_impl),
+                    GetIntArrayRegion: Some(get_int_array_region_impl),
+                    SetIntArrayRegion: Some(set_int_array_region_impl),
+                    NewObjectA: Some(new_object_a_impl),
+                    NewObjectArray: Some(new_object_array_impl),
+                    GetObjectArrayElement: Some(get_object_array_element_impl),
+                    SetObjectArrayElement: Some(set_object_array_element_impl),
+                    GetPrimitiveArrayCritical: Some(get_primitive_array_critical_impl),
+                    ReleasePrimitiveArrayCritical: Some(release_primitive_array_critical_impl),
+                    GetFieldID: Some(get_field_id_impl),
+                    GetIntField: Some(get_int_field_impl),
+                    SetIntField: Some(set_int_field_impl),
+                    GetObjectField: Some(get_object_field_impl),
+                    SetObjectField: Some(set_object_field_impl),
+                    GetStaticFieldID: Some(get_static_field_id_impl),
+                    GetStaticObjectField: Some(get_static_object_field_impl),
+                    SetStaticObjectField: Some(set_static_object_field_impl),
+                    NewDirectByteBuffer: Some(new_direct_byte_buffer_impl),
+                    GetDirectBufferAddress: Some(get_direct_buffer_address_impl),
+                    GetDirectBufferCapacity: Some(get_direct_buffer_capacity_impl),
+                    RegisterNatives: Some(register_natives_impl),
+                    UnregisterNatives: Some(unregister_natives_impl),
+                    GetObjectRefType: Some(get_object_ref_type_impl),
                     ..$crate::testing::empty_raw_jni_env()
                 }
             }
@@ -1,3 +1,6 @@
+use crate::class::Class;
+use crate::classes::array::ObjectArray;
+use crate::classes::stack_trace_element::StackTraceElement;
 use crate::env::JniEnv;
 use crate::error::JniError;
 use crate::java_class::JavaClassExt;
@@ -42,7 +45,8 @@ impl<'env> Throwable<'env> {
         unsafe { token.exchange() }
     }
 
-    /// Returns a short description of this [`Throwable`](struct.Throwable.html).
+    /// Returns the detail message of this [`Throwable`](struct.Throwable.html) or `None`
+    /// if it doesn't have one.
     ///
     /// [`Throwable::getMessage` javadoc](https://docs.oracle.com/javase/10/docs/api/java/lang/Throwable.html#getMessage())
     pub fn get_message(&self, token: &NoException<'env>) -> JavaResult<'env, Option<String<'env>>> {
@@ -50,7 +54,8 @@ impl<'env> Throwable<'env> {
         unsafe { self.call_method::<_, fn() -> String<'env>>(token, "getMessage\0", ()) }
     }
 
-    /// Returns a short description of this [`Throwable`](struct.Throwable.html).
+    /// Returns the cause of this [`Throwable`](struct.Throwable.html) or `None` if the cause
+    /// is nonexistent or unknown.
     ///
     /// [`Throwable::getCause` javadoc](https://docs.oracle.com/javase/10/docs/api/java/lang/Throwable.html#getCause())
     pub fn get_cause(
@@ -61,6 +66,67 @@ impl<'env> Throwable<'env> {
         unsafe { self.call_method::<_, fn() -> Throwable<'env>>(token, "getCause\0", ()) }
     }
 
+    /// Returns the stack trace of this [`Throwable`](struct.Throwable.html) with each frame
+    /// formatted by
+    /// [`StackTraceElement::toString`](https://docs.oracle.com/javase/10/docs/api/java/lang/StackTraceElement.html#toString()).
+    ///
+    /// [`Throwable::getStackTrace` javadoc](https://docs.oracle.com/javase/10/docs/api/java/lang/Throwable.html#getStackTrace())
+    pub fn get_stack_trace(
+        &self,
+        token: &NoException<'env>,
+    ) -> JavaResult<'env, Vec<std::string::String>> {
+        // Safe because we ensure correct arguments and return type.
+        let stack_trace = unsafe {
+            self.call_method::<_, fn() -> ObjectArray<'env, StackTraceElement<'env>>>(
+                token,
+                "getStackTrace\0",
+                (),
+            )
+        }?;
+        let stack_trace = match stack_trace {
+            None => return Ok(Vec::new()),
+            Some(stack_trace) => stack_trace,
+        };
+        let length = stack_trace.len(token);
+        let mut frames = Vec::with_capacity(length);
+        for index in 0..length {
+            let frame = match stack_trace.get(token, index)? {
+                None => "null".to_owned(),
+                Some(element) => match element.to_string(token)? {
+                    None => "null".to_owned(),
+                    Some(frame) => frame.as_string(token),
+                },
+            };
+            frames.push(frame);
+        }
+        Ok(frames)
+    }
+
+    /// Check if this [`Throwable`](struct.Throwable.html) is an unchecked exception, that is
+    /// a [`RuntimeException`](https://docs.oracle.com/javase/10/docs/api/java/lang/RuntimeException.html)
+    /// or an [`Error`](https://docs.oracle.com/javase/10/docs/api/java/lang/Error.html).
+    ///
+    /// Unchecked exceptions usually signal programming errors rather than recoverable conditions.
+    ///
+    /// [JLS documentation](https://docs.oracle.com/javase/specs/jls/se10/html/jls-11.html#jls-11.1.1)
+    pub fn is_unchecked(&self, token: &NoException<'env>) -> JavaResult<'env, bool> {
+        let runtime_exception = Class::find(token, "java/lang/RuntimeException")?;
+        if self.is_instance_of(token, &runtime_exception) {
+            return Ok(true);
+        }
+        let error = Class::find(token, "java/lang/Error")?;
+        Ok(self.is_instance_of(token, &error))
+    }
+
+    /// Check if this [`Throwable`](struct.Throwable.html) is a checked exception, that is
+    /// neither a [`RuntimeException`](https://docs.oracle.com/javase/10/docs/api/java/lang/RuntimeException.html)
+    /// nor an [`Error`](https://docs.oracle.com/javase/10/docs/api/java/lang/Error.html).
+    ///
+    /// [JLS documentation](https://docs.oracle.com/javase/specs/jls/se10/html/jls-11.html#jls-11.1.1)
+    pub fn is_checked(&self, token: &NoException<'env>) -> JavaResult<'env, bool> {
+        self.is_unchecked(token).map(|unchecked| !unchecked)
+    }
+
     /// Create a new [`Throwable`](struct.Throwable.html).
     ///
     /// [`Throwable(String)` javadoc](https://docs.oracle.com/javase/10/docs/api/java/lang/Throwable.html#<init>())
@@ -178,3 +244,432 @@ where
         Object::as_ref(self).eq(other.as_ref())
     }
 }
+
+#[cfg(test)]
+mod throwable_tests {
+    use super::*;
+    use crate::vm::JavaVMRef;
+    use mockall::*;
+    use serial_test::serial;
+    use std::ffi::CStr;
+    use std::mem::{self, ManuallyDrop};
+    use std::ptr;
+
+    generate_jni_env_mock!(jni_mock);
+
+    #[test]
+    #[serial]
+    fn is_unchecked() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_throwable = 0x1234 as jni_sys::jobject;
+        let raw_runtime_exception_class = 0x5678 as jni_sys::jclass;
+        let mut sequence = Sequence::new();
+        let find_class_mock = jni_mock::find_class_context();
+        find_class_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, name| unsafe {
+                *env == raw_env_ptr
+                    && CStr::from_ptr(*name).to_str() == Ok("java/lang/RuntimeException")
+            })
+            .returning_st(move |_env, _name| raw_runtime_exception_class)
+            .in_sequence(&mut sequence);
+        let is_instance_of_mock = jni_mock::is_instance_of_context();
+        is_instance_of_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object, class| {
+                *env == raw_env_ptr
+                    && *object == raw_throwable
+                    && *class == raw_runtime_exception_class
+            })
+            .return_const(jni_sys::JNI_TRUE)
+            .in_sequence(&mut sequence);
+        let delete_local_ref_mock = jni_mock::delete_local_ref_context();
+        delete_local_ref_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object| {
+                *env == raw_env_ptr && *object == raw_runtime_exception_class
+            })
+            .return_const(())
+            .in_sequence(&mut sequence);
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let token = NoException::test(&env);
+        let throwable = ManuallyDrop::new(unsafe {
+            Throwable::from_raw(&env, NonNull::new(raw_throwable).unwrap())
+        });
+        assert!(throwable.is_unchecked(&token).unwrap());
+    }
+
+    #[test]
+    #[serial]
+    fn is_checked() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_throwable = 0x1234 as jni_sys::jobject;
+        let raw_runtime_exception_class = 0x5678 as jni_sys::jclass;
+        let raw_error_class = 0x9012 as jni_sys::jclass;
+        let mut sequence = Sequence::new();
+        let find_class_mock = jni_mock::find_class_context();
+        find_class_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, name| unsafe {
+                *env == raw_env_ptr
+                    && CStr::from_ptr(*name).to_str() == Ok("java/lang/RuntimeException")
+            })
+            .returning_st(move |_env, _name| raw_runtime_exception_class)
+            .in_sequence(&mut sequence);
+        let is_instance_of_mock = jni_mock::is_instance_of_context();
+        is_instance_of_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object, class| {
+                *env == raw_env_ptr
+                    && *object == raw_throwable
+                    && *class == raw_runtime_exception_class
+            })
+            .return_const(jni_sys::JNI_FALSE)
+            .in_sequence(&mut sequence);
+        find_class_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, name| unsafe {
+                *env == raw_env_ptr && CStr::from_ptr(*name).to_str() == Ok("java/lang/Error")
+            })
+            .returning_st(move |_env, _name| raw_error_class)
+            .in_sequence(&mut sequence);
+        is_instance_of_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object, class| {
+                *env == raw_env_ptr && *object == raw_throwable && *class == raw_error_class
+            })
+            .return_const(jni_sys::JNI_FALSE)
+            .in_sequence(&mut sequence);
+        let delete_local_ref_mock = jni_mock::delete_local_ref_context();
+        delete_local_ref_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object| *env == raw_env_ptr && *object == raw_error_class)
+            .return_const(())
+            .in_sequence(&mut sequence);
+        delete_local_ref_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object| {
+                *env == raw_env_ptr && *object == raw_runtime_exception_class
+            })
+            .return_const(())
+            .in_sequence(&mut sequence);
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let token = NoException::test(&env);
+        let throwable = ManuallyDrop::new(unsafe {
+            Throwable::from_raw(&env, NonNull::new(raw_throwable).unwrap())
+        });
+        assert!(throwable.is_checked(&token).unwrap());
+    }
+
+    #[test]
+    #[serial]
+    fn get_message() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_throwable = 0x1234 as jni_sys::jobject;
+        let raw_class = 0x5678 as jni_sys::jclass;
+        let raw_method_id = 0x9012 as jni_sys::jmethodID;
+        let raw_result = 0x3456 as jni_sys::jobject;
+        let mut sequence = Sequence::new();
+        let get_object_class_mock = jni_mock::get_object_class_context();
+        get_object_class_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object| *env == raw_env_ptr && *object == raw_throwable)
+            .returning_st(move |_env, _object| raw_class)
+            .in_sequence(&mut sequence);
+        let get_method_id_mock = jni_mock::get_method_id_context();
+        get_method_id_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, class, name, signature| unsafe {
+                *env == raw_env_ptr
+                    && *class == raw_class
+                    && CStr::from_ptr(*name).to_str() == Ok("getMessage")
+                    && CStr::from_ptr(*signature).to_str() == Ok("()Ljava/lang/String;")
+            })
+            .returning_st(move |_env, _class, _name, _signature| raw_method_id)
+            .in_sequence(&mut sequence);
+        let call_object_method_a_mock = jni_mock::call_object_method_a_context();
+        call_object_method_a_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object, method_id, _arguments| {
+                *env == raw_env_ptr && *object == raw_throwable && *method_id == raw_method_id
+            })
+            .returning_st(move |_env, _object, _method_id, _arguments| raw_result)
+            .in_sequence(&mut sequence);
+        let delete_local_ref_mock = jni_mock::delete_local_ref_context();
+        delete_local_ref_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object| *env == raw_env_ptr && *object == raw_class)
+            .return_const(())
+            .in_sequence(&mut sequence);
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let token = NoException::test(&env);
+        let throwable = ManuallyDrop::new(unsafe {
+            Throwable::from_raw(&env, NonNull::new(raw_throwable).unwrap())
+        });
+        let message = throwable.get_message(&token).unwrap().unwrap();
+        assert_eq!(unsafe { message.raw_object() }.as_ptr(), raw_result);
+        mem::forget(message);
+    }
+
+    #[test]
+    #[serial]
+    fn get_message_null() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_throwable = 0x1234 as jni_sys::jobject;
+        let raw_class = 0x5678 as jni_sys::jclass;
+        let raw_method_id = 0x9012 as jni_sys::jmethodID;
+        let mut sequence = Sequence::new();
+        let get_object_class_mock = jni_mock::get_object_class_context();
+        get_object_class_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object| *env == raw_env_ptr && *object == raw_throwable)
+            .returning_st(move |_env, _object| raw_class)
+            .in_sequence(&mut sequence);
+        let get_method_id_mock = jni_mock::get_method_id_context();
+        get_method_id_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, class, name, signature| unsafe {
+                *env == raw_env_ptr
+                    && *class == raw_class
+                    && CStr::from_ptr(*name).to_str() == Ok("getMessage")
+                    && CStr::from_ptr(*signature).to_str() == Ok("()Ljava/lang/String;")
+            })
+            .returning_st(move |_env, _class, _name, _signature| raw_method_id)
+            .in_sequence(&mut sequence);
+        let call_object_method_a_mock = jni_mock::call_object_method_a_context();
+        call_object_method_a_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object, method_id, _arguments| {
+                *env == raw_env_ptr && *object == raw_throwable && *method_id == raw_method_id
+            })
+            .returning_st(move |_env, _object, _method_id, _arguments| ptr::null_mut())
+            .in_sequence(&mut sequence);
+        let exception_occured_mock = jni_mock::exception_occured_context();
+        exception_occured_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env| *env == raw_env_ptr)
+            .returning_st(|_env| ptr::null_mut())
+            .in_sequence(&mut sequence);
+        let delete_local_ref_mock = jni_mock::delete_local_ref_context();
+        delete_local_ref_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object| *env == raw_env_ptr && *object == raw_class)
+            .return_const(())
+            .in_sequence(&mut sequence);
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let token = NoException::test(&env);
+        let throwable = ManuallyDrop::new(unsafe {
+            Throwable::from_raw(&env, NonNull::new(raw_throwable).unwrap())
+        });
+        assert!(throwable.get_message(&token).unwrap().is_none());
+    }
+
+    #[test]
+    #[serial]
+    fn get_cause() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_throwable = 0x1234 as jni_sys::jobject;
+        let raw_class = 0x5678 as jni_sys::jclass;
+        let raw_method_id = 0x9012 as jni_sys::jmethodID;
+        let raw_result = 0x3456 as jni_sys::jobject;
+        let mut sequence = Sequence::new();
+        let get_object_class_mock = jni_mock::get_object_class_context();
+        get_object_class_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object| *env == raw_env_ptr && *object == raw_throwable)
+            .returning_st(move |_env, _object| raw_class)
+            .in_sequence(&mut sequence);
+        let get_method_id_mock = jni_mock::get_method_id_context();
+        get_method_id_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, class, name, signature| unsafe {
+                *env == raw_env_ptr
+                    && *class == raw_class
+                    && CStr::from_ptr(*name).to_str() == Ok("getCause")
+                    && CStr::from_ptr(*signature).to_str() == Ok("()Ljava/lang/Throwable;")
+            })
+            .returning_st(move |_env, _class, _name, _signature| raw_method_id)
+            .in_sequence(&mut sequence);
+        let call_object_method_a_mock = jni_mock::call_object_method_a_context();
+        call_object_method_a_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object, method_id, _arguments| {
+                *env == raw_env_ptr && *object == raw_throwable && *method_id == raw_method_id
+            })
+            .returning_st(move |_env, _object, _method_id, _arguments| raw_result)
+            .in_sequence(&mut sequence);
+        let delete_local_ref_mock = jni_mock::delete_local_ref_context();
+        delete_local_ref_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object| *env == raw_env_ptr && *object == raw_class)
+            .return_const(())
+            .in_sequence(&mut sequence);
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let token = NoException::test(&env);
+        let throwable = ManuallyDrop::new(unsafe {
+            Throwable::from_raw(&env, NonNull::new(raw_throwable).unwrap())
+        });
+        let cause = throwable.get_cause(&token).unwrap().unwrap();
+        assert_eq!(unsafe { cause.raw_object() }.as_ptr(), raw_result);
+        mem::forget(cause);
+    }
+
+    #[test]
+    #[serial]
+    fn get_cause_null() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_throwable = 0x1234 as jni_sys::jobject;
+        let raw_class = 0x5678 as jni_sys::jclass;
+        let raw_method_id = 0x9012 as jni_sys::jmethodID;
+        let mut sequence = Sequence::new();
+        let get_object_class_mock = jni_mock::get_object_class_context();
+        get_object_class_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object| *env == raw_env_ptr && *object == raw_throwable)
+            .returning_st(move |_env, _object| raw_class)
+            .in_sequence(&mut sequence);
+        let get_method_id_mock = jni_mock::get_method_id_context();
+        get_method_id_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, class, name, signature| unsafe {
+                *env == raw_env_ptr
+                    && *class == raw_class
+                    && CStr::from_ptr(*name).to_str() == Ok("getCause")
+                    && CStr::from_ptr(*signature).to_str() == Ok("()Ljava/lang/Throwable;")
+            })
+            .returning_st(move |_env, _class, _name, _signature| raw_method_id)
+            .in_sequence(&mut sequence);
+        let call_object_method_a_mock = jni_mock::call_object_method_a_context();
+        call_object_method_a_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object, method_id, _arguments| {
+                *env == raw_env_ptr && *object == raw_throwable && *method_id == raw_method_id
+            })
+            .returning_st(move |_env, _object, _method_id, _arguments| ptr::null_mut())
+            .in_sequence(&mut sequence);
+        let exception_occured_mock = jni_mock::exception_occured_context();
+        exception_occured_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env| *env == raw_env_ptr)
+            .returning_st(|_env| ptr::null_mut())
+            .in_sequence(&mut sequence);
+        let delete_local_ref_mock = jni_mock::delete_local_ref_context();
+        delete_local_ref_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object| *env == raw_env_ptr && *object == raw_class)
+            .return_const(())
+            .in_sequence(&mut sequence);
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let token = NoException::test(&env);
+        let throwable = ManuallyDrop::new(unsafe {
+            Throwable::from_raw(&env, NonNull::new(raw_throwable).unwrap())
+        });
+        assert!(throwable.get_cause(&token).unwrap().is_none());
+    }
+
+    #[test]
+    #[serial]
+    fn get_stack_trace_empty() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_throwable = 0x1234 as jni_sys::jobject;
+        let raw_class = 0x5678 as jni_sys::jclass;
+        let raw_method_id = 0x9012 as jni_sys::jmethodID;
+        let raw_array = 0x3456 as jni_sys::jobject;
+        let mut sequence = Sequence::new();
+        let get_object_class_mock = jni_mock::get_object_class_context();
+        get_object_class_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object| *env == raw_env_ptr && *object == raw_throwable)
+            .returning_st(move |_env, _object| raw_class)
+            .in_sequence(&mut sequence);
+        let get_method_id_mock = jni_mock::get_method_id_context();
+        get_method_id_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, class, name, signature| unsafe {
+                *env == raw_env_ptr
+                    && *class == raw_class
+                    && CStr::from_ptr(*name).to_str() == Ok("getStackTrace")
+                    && CStr::from_ptr(*signature).to_str() == Ok("()[Ljava/lang/StackTraceElement;")
+            })
+            .returning_st(move |_env, _class, _name, _signature| raw_method_id)
+            .in_sequence(&mut sequence);
+        let call_object_method_a_mock = jni_mock::call_object_method_a_context();
+        call_object_method_a_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object, method_id, _arguments| {
+                *env == raw_env_ptr && *object == raw_throwable && *method_id == raw_method_id
+            })
+            .returning_st(move |_env, _object, _method_id, _arguments| raw_array)
+            .in_sequence(&mut sequence);
+        let delete_local_ref_mock = jni_mock::delete_local_ref_context();
+        delete_local_ref_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object| *env == raw_env_ptr && *object == raw_class)
+            .return_const(())
+            .in_sequence(&mut sequence);
+        let get_array_length_mock = jni_mock::get_array_length_context();
+        get_array_length_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, array| *env == raw_env_ptr && *array == raw_array)
+            .return_const(0)
+            .in_sequence(&mut sequence);
+        delete_local_ref_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object| *env == raw_env_ptr && *object == raw_array)
+            .return_const(())
+            .in_sequence(&mut sequence);
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let token = NoException::test(&env);
+        let throwable = ManuallyDrop::new(unsafe {
+            Throwable::from_raw(&env, NonNull::new(raw_throwable).unwrap())
+        });
+        assert!(throwable.get_stack_trace(&token).unwrap().is_empty());
+    }
+}
@@ -396,6 +396,10 @@ impl<'this> NoException<'this> {
                 Err(throwable)
             }
             CallOutcome::Unknown(result) => {
+                // The check is deferred to the end of the batch.
+                if *self.env.batching.borrow() {
+                    return Ok(result);
+                }
                 // Safe because the argument is ensured to be correct references by construction.
                 match NonNull::new(unsafe { call_jni_method!(self.env, ExceptionOccurred) }) {
                     None => Ok(result),
@@ -412,6 +416,70 @@ impl<'this> NoException<'this> {
         }
     }
 
+    /// Run a sequence of Java calls checking for a pending exception only once, at the end.
+    ///
+    /// Calls which results don't tell if an exception was thrown, like calls of methods returning
+    /// primitive values, are normally followed by a runtime exception check. Inside the batch
+    /// these checks are skipped and a single check is performed after the closure returns.
+    /// If there is a pending exception, the closure result is discarded and the exception
+    /// is returned. Nested batches are checked at the end of the outermost batch.
+    ///
+    /// Calls which report exceptions by their results, like a failed
+    /// [`Class::find`](java/lang/struct.Class.html#method.find), still return them immediately.
+    ///
+    /// Example:
+    /// ```
+    /// # use rust_jni::*;
+    /// # use rust_jni::java::lang::String;
+    /// #
+    /// # fn jni_main<'a>(token: NoException<'a>) -> JavaResult<'a, NoException<'a>> {
+    /// let string = String::new(&token, "test")?;
+    /// // Safe because `hashCode` doesn't throw.
+    /// let hash_codes = unsafe {
+    ///     token.batch(|token| {
+    ///         (0..10)
+    ///             .map(|_| string.hash_code(token).unwrap())
+    ///             .collect::<Vec<_>>()
+    ///     })
+    /// }?;
+    /// assert_eq!(hash_codes.len(), 10);
+    /// # Ok(token)
+    /// # }
+    /// #
+    /// # #[cfg(feature = "libjvm")]
+    /// # fn main() {
+    /// #     let init_arguments = InitArguments::default();
+    /// #     let vm = JavaVM::create(&init_arguments).unwrap();
+    /// #     let _ = vm.with_attached(
+    /// #        &AttachArguments::new(init_arguments.version()),
+    /// #        |token: NoException| {
+    /// #            ((), jni_main(token).unwrap())
+    /// #        },
+    /// #     );
+    /// # }
+    /// #
+    /// # #[cfg(not(feature = "libjvm"))]
+    /// # fn main() {}
+    /// ```
+    ///
+    /// # Safety
+    ///
+    /// Calling JNI functions with a pending exception is undefined behaviour and an exception
+    /// thrown in the middle of the batch is not detected until the end of it. The caller must
+    /// ensure that none of the Java calls in the batch except the last one can throw.
+    pub unsafe fn batch<Out>(
+        &self,
+        function: impl FnOnce(&NoException<'this>) -> Out,
+    ) -> JavaResult<'this, Out> {
+        let guard = BatchGuard {
+            env: self.env,
+            batching: self.env.batching.replace(true),
+        };
+        let result = function(self);
+        mem::drop(guard);
+        self.with_owned(|_token| CallOutcome::Unknown(result))
+    }
+
     /// Perform a runtime check for a pending exception. Return a corresponding exception token:
     /// [`NoException`](struct.NoException.html) when there isn't one or [`Exception`](struct.Exception.html)
     /// when there is.
@@ -450,13 +518,28 @@ impl<'this> NoException<'this> {
     }
 }
 
+/// Restores the batching state of a [`JniEnv`](struct.JniEnv.html) when a
+/// [`NoException::batch`](struct.NoException.html#method.batch) ends, even if it panics.
+struct BatchGuard<'env> {
+    env: &'env JniEnv<'env>,
+    batching: bool,
+}
+
+impl<'env> Drop for BatchGuard<'env> {
+    fn drop(&mut self) {
+        self.env.batching.replace(self.batching);
+    }
+}
+
 #[cfg(test)]
 mod no_exception_tests {
     use super::*;
     use crate::vm::JavaVMRef;
     use mockall::*;
     use serial_test::serial;
+    use std::cell::RefCell;
     use std::mem::ManuallyDrop;
+    use std::panic;
     use std::ptr;
 
     generate_jni_env_mock!(jni_mock);
@@ -547,6 +630,83 @@ mod no_exception_tests {
         mem::forget(exception);
     }
 
+    #[test]
+    #[serial]
+    fn batch() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let exception_occured_mock = jni_mock::exception_occured_context();
+        exception_occured_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env| *env == raw_env_ptr)
+            .returning_st(|_env| ptr::null_mut());
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let token = NoException::test(&env);
+        let result = unsafe {
+            token.batch(|token| {
+                let first = token.with_owned(|_token| CallOutcome::Unknown(12)).unwrap();
+                let second = token.with_owned(|_token| CallOutcome::Unknown(13)).unwrap();
+                first + second
+            })
+        }
+        .unwrap();
+        assert_eq!(result, 25);
+        assert_eq!(env.batching, RefCell::new(false));
+    }
+
+    #[test]
+    #[serial]
+    fn batch_panic() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let token = NoException::test(&env);
+        let result = panic::catch_unwind(panic::AssertUnwindSafe(|| unsafe {
+            token.batch(|_token| panic!("test panic"))
+        }));
+        assert!(result.is_err());
+        assert_eq!(env.batching, RefCell::new(false));
+    }
+
+    #[test]
+    #[serial]
+    fn batch_exception() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let mut sequence = Sequence::new();
+        let exception_occured_mock = jni_mock::exception_occured_context();
+        let raw_throwable = 0x2835 as jni_sys::jthrowable;
+        exception_occured_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env| *env == raw_env_ptr)
+            .returning_st(move |_env| raw_throwable)
+            .in_sequence(&mut sequence);
+        let exception_clear_mock = jni_mock::exception_clear_context();
+        exception_clear_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env| *env == raw_env_ptr)
+            .return_const(())
+            .in_sequence(&mut sequence);
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let token = NoException::test(&env);
+        let exception = unsafe {
+            token.batch(|token| {
+                token.with_owned(|_token| CallOutcome::Unknown(12)).unwrap();
+                token.with_owned(|_token| CallOutcome::Unknown(13)).unwrap()
+            })
+        }
+        .unwrap_err();
+        assert_eq!(unsafe { exception.raw_object().as_ptr() }, raw_throwable);
+        // Prevent unmocked drop.
+        mem::forget(exception);
+    }
+
     #[test]
     #[serial]
     fn with_owned_unknown_no_exception() {
@@ -21,6 +21,12 @@ pub enum JniVersion {
     V9,
     /// JDK/JRE 10.
     V10,
+    /// JDK/JRE 19.
+    V19,
+    /// JDK/JRE 20.
+    V20,
+    /// JDK/JRE 21.
+    V21,
     /// Unknown version.
     /// Needed for forward compability and to request a version that has not been added yet.
     Unknown(i32),
@@ -29,6 +35,9 @@ pub enum JniVersion {
 // TODO(monnoroch): contribute these to `jni_sys` crate.
 const JNI_VERSION_9: jni_sys::jint = 0x00090000;
 const JNI_VERSION_10: jni_sys::jint = 0x000a0000;
+const JNI_VERSION_19: jni_sys::jint = 0x00130000;
+const JNI_VERSION_20: jni_sys::jint = 0x00140000;
+const JNI_VERSION_21: jni_sys::jint = 0x00150000;
 
 impl JniVersion {
     /// Convert from a raw `jint` version.
@@ -41,6 +50,9 @@ impl JniVersion {
             jni_sys::JNI_VERSION_1_8 => JniVersion::V8,
             JNI_VERSION_9 => JniVersion::V9,
             JNI_VERSION_10 => JniVersion::V10,
+            JNI_VERSION_19 => JniVersion::V19,
+            JNI_VERSION_20 => JniVersion::V20,
+            JNI_VERSION_21 => JniVersion::V21,
             _ => JniVersion::Unknown(version),
         }
     }
@@ -74,6 +86,9 @@ mod from_raw_tests {
         );
         assert_eq!(JniVersion::from_raw(JNI_VERSION_9), JniVersion::V9);
         assert_eq!(JniVersion::from_raw(JNI_VERSION_10), JniVersion::V10);
+        assert_eq!(JniVersion::from_raw(JNI_VERSION_19), JniVersion::V19);
+        assert_eq!(JniVersion::from_raw(JNI_VERSION_20), JniVersion::V20);
+        assert_eq!(JniVersion::from_raw(JNI_VERSION_21), JniVersion::V21);
     }
 
     #[test]
@@ -93,6 +108,9 @@ impl JniVersion {
             JniVersion::V8 => jni_sys::JNI_VERSION_1_8,
             JniVersion::V9 => JNI_VERSION_9,
             JniVersion::V10 => JNI_VERSION_10,
+            JniVersion::V19 => JNI_VERSION_19,
+            JniVersion::V20 => JNI_VERSION_20,
+            JniVersion::V21 => JNI_VERSION_21,
             JniVersion::Unknown(version) => version,
         }
     }
@@ -111,10 +129,32 @@ mod to_raw_tests {
         assert_eq!(JniVersion::V8.to_raw(), jni_sys::JNI_VERSION_1_8);
         assert_eq!(JniVersion::V9.to_raw(), JNI_VERSION_9);
         assert_eq!(JniVersion::V10.to_raw(), JNI_VERSION_10);
+        assert_eq!(JniVersion::V19.to_raw(), JNI_VERSION_19);
+        assert_eq!(JniVersion::V20.to_raw(), JNI_VERSION_20);
+        assert_eq!(JniVersion::V21.to_raw(), JNI_VERSION_21);
     }
 
     #[test]
     fn to_unknown_raw_version() {
         assert_eq!(JniVersion::Unknown(7).to_raw(), 7);
     }
+
+    #[test]
+    fn round_trip() {
+        for version in &[
+            JniVersion::V1,
+            JniVersion::V2,
+            JniVersion::V4,
+            JniVersion::V6,
+            JniVersion::V8,
+            JniVersion::V9,
+            JniVersion::V10,
+            JniVersion::V19,
+            JniVersion::V20,
+            JniVersion::V21,
+            JniVersion::Unknown(0x00160000),
+        ] {
+            assert_eq!(JniVersion::from_raw(version.to_raw()), *version);
+        }
+    }
 }
@@ -3,13 +3,18 @@ use crate::env::JniEnv;
 use crate::error::JniError;
 use crate::init_arguments::InitArguments;
 use crate::token::NoException;
+use crate::version::JniVersion;
 use cfg_if::cfg_if;
 use core::ptr::NonNull;
 use jni_sys;
 use std;
+use std::mem::{self, ManuallyDrop};
+use std::ops::Deref;
 use std::os::raw::c_void;
 use std::ptr;
 
+include!("call_jni_method.rs");
+
 /// A struct for interacting with the Java VM without owning it.
 ///
 /// See more documentation in [`JavaVM`](struct.JavaVM.html).
@@ -53,7 +58,12 @@ impl JavaVMRef {
     /// Runs a closure passing it a newly attached [`JniEnv`](struct.JniEnv.html) and
     /// a [`NoException`](struct.NoException.html) token. The closure must return the
     /// [`NoException`](struct.NoException.html) token thus guaranteeing that there are no exceptions in flight after
-    /// the closure is done executing.
+    /// the closure is done executing. The thread is detached after the closure returns.
+    ///
+    /// If the current thread is already attached, its [`JniEnv`](struct.JniEnv.html) is reused
+    /// and the thread stays attached after the closure returns. Returns
+    /// [`JniError::PendingException`](enum.JniError.html#variant.PendingException)
+    /// without running the closure if such a thread has a pending exception.
     ///
     /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/invocation.html#attachcurrentthread)
     pub fn with_attached<'vm, T>(
@@ -61,8 +71,15 @@ impl JavaVMRef {
         arguments: &AttachArguments,
         closure: impl for<'token> FnOnce(NoException<'token>) -> (T, NoException<'token>),
     ) -> Result<T, JniError> {
-        let env = self.attach(arguments)?;
-        self.with_attached_generic(env, closure)
+        // Safe because the argument is ensured to be the correct method.
+        unsafe {
+            self.with_attached_generic(
+                arguments,
+                (**self.raw_jvm().as_ptr()).AttachCurrentThread.unwrap(),
+                false,
+                closure,
+            )
+        }
     }
 
     /// Attach the current thread to the Java VM as a daemon and execute code that calls JNI on it.
@@ -70,7 +87,12 @@ impl JavaVMRef {
     /// Runs a closure passing it a newly attached [`JniEnv`](struct.JniEnv.html) and
     /// a [`NoException`](struct.NoException.html) token. The closure must return the
     /// [`NoException`](struct.NoException.html) token thus guaranteeing that there are no exceptions in flight after
-    /// the closure is done executing.
+    /// the closure is done executing. The thread is detached after the closure returns.
+    ///
+    /// If the current thread is already attached, its [`JniEnv`](struct.JniEnv.html) is reused
+    /// and the thread stays attached after the closure returns. Returns
+    /// [`JniError::PendingException`](enum.JniError.html#variant.PendingException)
+    /// without running the closure if such a thread has a pending exception.
     ///
     /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/invocation.html#attachcurrentthread)
     pub fn with_attached_daemon<'vm, T>(
@@ -78,22 +100,61 @@ impl JavaVMRef {
         arguments: &AttachArguments,
         closure: impl for<'token> FnOnce(NoException<'token>) -> (T, NoException<'token>),
     ) -> Result<T, JniError> {
-        let env = self.attach_daemon(arguments)?;
-        self.with_attached_generic(env, closure)
+        // Safe because the argument is ensured to be the correct method.
+        unsafe {
+            self.with_attached_generic(
+                arguments,
+                (**self.raw_jvm().as_ptr())
+                    .AttachCurrentThreadAsDaemon
+                    .unwrap(),
+                true,
+                closure,
+            )
+        }
     }
 
-    fn with_attached_generic<'vm, T>(
+    /// Unsafe because one can pass an invalid `attach_fn`.
+    unsafe fn with_attached_generic<'vm, T>(
         &'vm self,
-        env: JniEnv<'vm>,
+        arguments: &AttachArguments,
+        attach_fn: unsafe extern "system" fn(
+            _: *mut jni_sys::JavaVM,
+            _: *mut *mut c_void,
+            _: *mut c_void,
+        ) -> jni_sys::jint,
+        daemon: bool,
         closure: impl for<'token> FnOnce(NoException<'token>) -> (T, NoException<'token>),
     ) -> Result<T, JniError> {
-        // Safe because we only get a single token here.
-        let token = unsafe { env.token_internal() };
-        let (result, token) = closure(token);
-        let token = token.consume();
-        match env.detach(token) {
-            None => Ok(result),
-            Some(error) => Err(error),
+        match self.get_env(arguments.version()) {
+            Some(jni_env) => {
+                // Shuld not fail: successful call to GetEnv guarantees a non-null env pointer.
+                let env = JniEnv::new(self, NonNull::new(jni_env).unwrap());
+                // Safe because we are not leaking the tokens anywhere.
+                if NoException::check_pending_exception(&env).is_err() {
+                    // Dropping the `JniEnv` would detach the thread.
+                    mem::forget(env);
+                    return Err(JniError::PendingException);
+                }
+                // Safe because we only get a single token here.
+                let token = env.token_internal();
+                let (result, token) = closure(token);
+                token.consume();
+                env.clear_method_cache();
+                // The thread was attached before, so it must stay attached.
+                mem::forget(env);
+                Ok(result)
+            }
+            None => {
+                let env = self.attach_detached(arguments, attach_fn, daemon)?;
+                // Safe because we only get a single token here.
+                let token = env.token_internal();
+                let (result, token) = closure(token);
+                let token = token.consume();
+                match env.detach(token) {
+                    None => Ok(result),
+                    Some(error) => Err(error),
+                }
+            }
         }
     }
 
@@ -115,6 +176,7 @@ impl JavaVMRef {
             self.attach_generic(
                 arguments,
                 (**self.raw_jvm().as_ptr()).AttachCurrentThread.unwrap(),
+                false,
             )
         }
     }
@@ -139,10 +201,84 @@ impl JavaVMRef {
                 (**self.raw_jvm().as_ptr())
                     .AttachCurrentThreadAsDaemon
                     .unwrap(),
+                true,
             )
         }
     }
 
+    /// Attach the current thread to the Java VM as a daemon if
+    /// [`arguments.is_daemon()`](struct.AttachArguments.html#method.is_daemon) and as
+    /// a regular thread otherwise.
+    /// Returns a [`JniEnv`](struct.JniEnv.html) instance for this thread.
+    ///
+    /// See [`attach`](#method.attach) and [`attach_daemon`](#method.attach_daemon) for details.
+    pub fn attach_with<'vm: 'env, 'env>(
+        &'vm self,
+        arguments: &AttachArguments,
+    ) -> Result<JniEnv<'env>, JniError> {
+        if arguments.is_daemon() {
+            self.attach_daemon(arguments)
+        } else {
+            self.attach(arguments)
+        }
+    }
+
+    /// Attach the current thread to the Java VM and return a guard that derefs to its
+    /// [`JniEnv`](struct.JniEnv.html).
+    ///
+    /// Unlike [`attach`](#method.attach), does not panic if the current thread is already
+    /// attached. In that case the existing [`JniEnv`](struct.JniEnv.html) is reused and the thread
+    /// stays attached after the guard is
+    /// [`drop`](https://doc.rust-lang.org/std/ops/trait.Drop.html#tymethod.drop)-ed.
+    /// Otherwise the thread is attached as a daemon if
+    /// [`arguments.is_daemon()`](struct.AttachArguments.html#method.is_daemon) and detached when
+    /// the guard is dropped. Returns
+    /// [`JniError::PendingException`](enum.JniError.html#variant.PendingException)
+    /// if an already attached thread has a pending exception.
+    ///
+    /// This makes it suitable for code that can run both on attached and detached threads,
+    /// like tasks in a thread pool.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/invocation.html#attachcurrentthread)
+    pub fn attach_guard<'vm: 'env, 'env>(
+        &'vm self,
+        arguments: &AttachArguments,
+    ) -> Result<AttachGuard<'env>, JniError> {
+        // Safe because the arguments are ensured to be correct.
+        unsafe {
+            match self.get_env(arguments.version()) {
+                Some(jni_env) => {
+                    // Shuld not fail: successful call to GetEnv guarantees a non-null env pointer.
+                    let env = JniEnv::new(self, NonNull::new(jni_env).unwrap());
+                    // Safe because we are not leaking the tokens anywhere.
+                    if NoException::check_pending_exception(&env).is_err() {
+                        // Dropping the `JniEnv` would detach the thread.
+                        mem::forget(env);
+                        return Err(JniError::PendingException);
+                    }
+                    Ok(AttachGuard {
+                        env: ManuallyDrop::new(env),
+                        owned_attachment: false,
+                    })
+                }
+                None => {
+                    let attach_fn = if arguments.is_daemon() {
+                        (**self.raw_jvm().as_ptr())
+                            .AttachCurrentThreadAsDaemon
+                            .unwrap()
+                    } else {
+                        (**self.raw_jvm().as_ptr()).AttachCurrentThread.unwrap()
+                    };
+                    let env = self.attach_detached(arguments, attach_fn, arguments.is_daemon())?;
+                    Ok(AttachGuard {
+                        env: ManuallyDrop::new(env),
+                        owned_attachment: true,
+                    })
+                }
+            }
+        }
+    }
+
     /// Unsafe because:
     /// 1. One can pass an invalid `attach_fn`.
     /// 2. The current thread might already be attached.
@@ -154,49 +290,169 @@ impl JavaVMRef {
             _: *mut *mut c_void,
             _: *mut c_void,
         ) -> jni_sys::jint,
+        daemon: bool,
     ) -> Result<JniEnv, JniError> {
+        match self.get_env(arguments.version()) {
+            None => self.attach_detached(arguments, attach_fn, daemon),
+            Some(_) => panic!(
+                "This thread is already attached to the JVM. \
+                 Attaching a thread twice is not allowed."
+            ),
+        }
+    }
+
+    /// Get the raw JNI environment of the current thread or `None` if it is not attached.
+    ///
+    /// Unsafe because it provides low-level access to JNI.
+    unsafe fn get_env(&self, version: JniVersion) -> Option<*mut jni_sys::JNIEnv> {
+        let mut jni_env: *mut jni_sys::JNIEnv = ::std::ptr::null_mut();
+        let get_env_fn = (**self.raw_jvm().as_ptr()).GetEnv.unwrap();
+        // Safe, because the arguments are correct.
+        let error = JniError::from_raw(get_env_fn(
+            self.raw_jvm().as_ptr(),
+            (&mut jni_env) as *mut *mut jni_sys::JNIEnv as *mut *mut c_void,
+            version.to_raw(),
+        ));
+        match error {
+            None => Some(jni_env),
+            Some(JniError::ThreadDetached) => None,
+            // According to the
+            // [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/invocation.html#getenv),
+            // can only returd `OK`, `EDETACHED` and `EVERSION`.
+            // Will not return `EVERSION` here, because the version was already checked when
+            // creating the Java VM.
+            Some(error) => panic!(
+                "GetEnv JNI method returned an unexpected error code {:?}",
+                error
+            ),
+        }
+    }
+
+    /// Unsafe because:
+    /// 1. One can pass an invalid `attach_fn`.
+    /// 2. The current thread might already be attached.
+    unsafe fn attach_detached(
+        &self,
+        arguments: &AttachArguments,
+        attach_fn: unsafe extern "system" fn(
+            _: *mut jni_sys::JavaVM,
+            _: *mut *mut c_void,
+            _: *mut c_void,
+        ) -> jni_sys::jint,
+        daemon: bool,
+    ) -> Result<JniEnv<'_>, JniError> {
         let mut buffer: Vec<u8> = vec![];
         let mut raw_arguments = arguments.to_raw(&mut buffer);
+        let mut jni_env: *mut jni_sys::JNIEnv = ::std::ptr::null_mut();
+        let error = JniError::from_raw(attach_fn(
+            self.raw_jvm().as_ptr(),
+            (&mut jni_env) as *mut *mut jni_sys::JNIEnv as *mut *mut c_void,
+            (&mut raw_arguments.raw_arguments) as *mut jni_sys::JavaVMAttachArgs as *mut c_void,
+        ));
+        match error {
+            // Shuld not fail: successful call to AttachCurrentThread guarantees a non-null env pointer.
+            None => Ok(JniEnv::attached(
+                &self,
+                NonNull::new(jni_env).unwrap(),
+                daemon,
+            )),
+            Some(JniError::UnsupportedVersion) => panic!(
+                "Got upsupported version error when creating a Java VM. \
+                 Should not happen as `InitArguments` are supposed to check \
+                 for version support."
+            ),
+            Some(JniError::ThreadDetached) => {
+                panic!("Got `EDETACHED` when trying to attach a thread.")
+            }
+            // TODO(monnoroch): panic on more impossible errors.
+            Some(error) => Err(error),
+        }
+    }
+
+    /// Detach the current thread from the Java VM.
+    ///
+    /// This is useful for threads that are attached once and need to be detached explicitly,
+    /// like threads in a thread pool during shutdown.
+    ///
+    /// Returns [`JniError::PendingException`](enum.JniError.html#variant.PendingException)
+    /// if there is a pending exception in the current thread. The exception is described,
+    /// but not cleared. Returns [`JniError::ThreadDetached`](enum.JniError.html#variant.ThreadDetached)
+    /// if the current thread is not attached.
+    ///
+    /// # Safety
+    ///
+    /// Unsafe because no [`JniEnv`](struct.JniEnv.html) or Java objects of the current thread
+    /// can be used after detaching it.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/invocation.html#detachcurrentthread)
+    pub unsafe fn detach_current(&self) -> Result<(), JniError> {
+        let mut jni_env: *mut jni_sys::JNIEnv = ::std::ptr::null_mut();
+        let get_env_fn = (**self.raw_jvm().as_ptr()).GetEnv.unwrap();
+        // Safe, because the arguments are correct.
+        // `GetEnv` is supported since JNI 1.2.
+        let error = JniError::from_raw(get_env_fn(
+            self.raw_jvm().as_ptr(),
+            (&mut jni_env) as *mut *mut jni_sys::JNIEnv as *mut *mut c_void,
+            JniVersion::V2.to_raw(),
+        ));
+        if let Some(error) = error {
+            return Err(error);
+        }
+        // Shuld not fail: successful call to GetEnv guarantees a non-null env pointer.
+        let env = JniEnv::new(self, NonNull::new(jni_env).unwrap());
+        // Safe because we are not leaking the tokens anywhere.
+        let exception_pending = NoException::check_pending_exception(&env).is_err();
+        if exception_pending {
+            // Safe because the argument is ensured to be the correct by construction.
+            call_jni_method!(env, ExceptionDescribe);
+        }
+        // Dropping the `JniEnv` would detach the thread.
+        mem::forget(env);
+        if exception_pending {
+            return Err(JniError::PendingException);
+        }
+        match self.detach() {
+            None => Ok(()),
+            Some(error) => Err(error),
+        }
+    }
+
+    /// Run a closure with the raw JNI environment of the current thread.
+    /// If the thread is not attached to the Java VM, it is attached for the duration of
+    /// the closure call.
+    ///
+    /// Unsafe because the closure gets low-level access to JNI.
+    pub(crate) unsafe fn with_raw_env<T>(
+        &self,
+        version: JniVersion,
+        closure: impl FnOnce(NonNull<jni_sys::JNIEnv>) -> T,
+    ) -> T {
         let mut jni_env: *mut jni_sys::JNIEnv = ::std::ptr::null_mut();
         let get_env_fn = (**self.raw_jvm().as_ptr()).GetEnv.unwrap();
         // Safe, because the arguments are correct.
         let error = JniError::from_raw(get_env_fn(
             self.raw_jvm().as_ptr(),
             (&mut jni_env) as *mut *mut jni_sys::JNIEnv as *mut *mut c_void,
-            arguments.version().to_raw(),
+            version.to_raw(),
         ));
         match error {
+            // Shuld not fail: successful call to GetEnv guarantees a non-null env pointer.
+            None => closure(NonNull::new(jni_env).unwrap()),
             Some(JniError::ThreadDetached) => {
+                let attach_fn = (**self.raw_jvm().as_ptr()).AttachCurrentThread.unwrap();
                 let error = JniError::from_raw(attach_fn(
                     self.raw_jvm().as_ptr(),
                     (&mut jni_env) as *mut *mut jni_sys::JNIEnv as *mut *mut c_void,
-                    (&mut raw_arguments.raw_arguments) as *mut jni_sys::JavaVMAttachArgs
-                        as *mut c_void,
+                    ptr::null_mut(),
                 ));
-                match error {
-                    // Shuld not fail: successful call to AttachCurrentThread guarantees a non-null env pointer.
-                    None => Ok(JniEnv::attached(&self, NonNull::new(jni_env).unwrap())),
-                    Some(JniError::UnsupportedVersion) => panic!(
-                        "Got upsupported version error when creating a Java VM. \
-                         Should not happen as `InitArguments` are supposed to check \
-                         for version support."
-                    ),
-                    Some(JniError::ThreadDetached) => {
-                        panic!("Got `EDETACHED` when trying to attach a thread.")
-                    }
-                    // TODO(monnoroch): panic on more impossible errors.
-                    Some(error) => Err(error),
+                if let Some(error) = error {
+                    panic!("Could not attach the current thread. Status: {:?}", error);
                 }
+                // Shuld not fail: successful call to AttachCurrentThread guarantees a non-null env pointer.
+                let result = closure(NonNull::new(jni_env).unwrap());
+                self.detach_or_error();
+                result
             }
-            None => panic!(
-                "This thread is already attached to the JVM. \
-                 Attaching a thread twice is not allowed."
-            ),
-            // According to the
-            // [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/invocation.html#getenv),
-            // can only returd `OK`, `EDETACHED` and `EVERSION`.
-            // Will not return `EVERSION` here, because the version was already checked when
-            // creating the Java VM.
             Some(error) => panic!(
                 "GetEnv JNI method returned an unexpected error code {:?}",
                 error
@@ -239,6 +495,49 @@ impl JavaVMRef {
     }
 }
 
+/// A [`JniEnv`](struct.JniEnv.html) of the current thread returned by
+/// [`JavaVM::attach_guard`](struct.JavaVM.html#method.attach_guard).
+///
+/// Derefs to the [`JniEnv`](struct.JniEnv.html). If the guard has attached the thread, it
+/// detaches it when [`drop`](https://doc.rust-lang.org/std/ops/trait.Drop.html#tymethod.drop)-ed,
+/// the same way a [`JniEnv`](struct.JniEnv.html) does. If the thread was already attached when
+/// the guard was created, it is left attached.
+#[derive(Debug)]
+pub struct AttachGuard<'env> {
+    env: ManuallyDrop<JniEnv<'env>>,
+    owned_attachment: bool,
+}
+
+impl<'env> AttachGuard<'env> {
+    /// Whether the thread was attached by this guard and will be detached when it is dropped.
+    pub fn owned_attachment(&self) -> bool {
+        self.owned_attachment
+    }
+}
+
+impl<'env> Deref for AttachGuard<'env> {
+    type Target = JniEnv<'env>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.env
+    }
+}
+
+/// Detach the current thread when the guard is
+/// [`drop`](https://doc.rust-lang.org/std/ops/trait.Drop.html#tymethod.drop)-ed, but only if
+/// the guard has attached it.
+impl<'env> Drop for AttachGuard<'env> {
+    fn drop(&mut self) {
+        if self.owned_attachment {
+            // Safe because the `JniEnv` is not used after this.
+            unsafe { ManuallyDrop::drop(&mut self.env) };
+        } else {
+            // The thread was attached before, so it must stay attached.
+            self.env.clear_method_cache();
+        }
+    }
+}
+
 #[cfg(test)]
 mod java_vm_ref_tests {
     use super::*;
@@ -429,7 +728,12 @@ impl JavaVM {
     /// Runs a closure passing it a newly attached [`JniEnv`](struct.JniEnv.html) and
     /// a [`NoException`](struct.NoException.html) token. The closure must return the
     /// [`NoException`](struct.NoException.html) token thus guaranteeing that there are no exceptions in flight after
-    /// the closure is done executing.
+    /// the closure is done executing. The thread is detached after the closure returns.
+    ///
+    /// If the current thread is already attached, its [`JniEnv`](struct.JniEnv.html) is reused
+    /// and the thread stays attached after the closure returns. Returns
+    /// [`JniError::PendingException`](enum.JniError.html#variant.PendingException)
+    /// without running the closure if such a thread has a pending exception.
     ///
     /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/invocation.html#attachcurrentthread)
     pub fn with_attached<'vm, T>(
@@ -445,7 +749,12 @@ impl JavaVM {
     /// Runs a closure passing it a newly attached [`JniEnv`](struct.JniEnv.html) and
     /// a [`NoException`](struct.NoException.html) token. The closure must return the
     /// [`NoException`](struct.NoException.html) token thus guaranteeing that there are no exceptions in flight after
-    /// the closure is done executing.
+    /// the closure is done executing. The thread is detached after the closure returns.
+    ///
+    /// If the current thread is already attached, its [`JniEnv`](struct.JniEnv.html) is reused
+    /// and the thread stays attached after the closure returns. Returns
+    /// [`JniError::PendingException`](enum.JniError.html#variant.PendingException)
+    /// without running the closure if such a thread has a pending exception.
     ///
     /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/invocation.html#attachcurrentthread)
     pub fn with_attached_daemon<'vm, T>(
@@ -488,6 +797,54 @@ impl JavaVM {
         self.java_vm.attach_daemon(arguments)
     }
 
+    /// Attach the current thread to the Java VM as a daemon if
+    /// [`arguments.is_daemon()`](struct.AttachArguments.html#method.is_daemon) and as
+    /// a regular thread otherwise.
+    /// Returns a [`JniEnv`](struct.JniEnv.html) instance for this thread.
+    ///
+    /// See [`attach`](#method.attach) and [`attach_daemon`](#method.attach_daemon) for details.
+    pub fn attach_with<'vm: 'env, 'env>(
+        &'vm self,
+        arguments: &AttachArguments,
+    ) -> Result<JniEnv<'env>, JniError> {
+        self.java_vm.attach_with(arguments)
+    }
+
+    /// Attach the current thread to the Java VM unless it is already attached and return a guard
+    /// that derefs to its [`JniEnv`](struct.JniEnv.html).
+    /// The guard only detaches the thread if it has attached it.
+    ///
+    /// See [`JavaVMRef::attach_guard`](struct.JavaVMRef.html#method.attach_guard) for details.
+    pub fn attach_guard<'vm: 'env, 'env>(
+        &'vm self,
+        arguments: &AttachArguments,
+    ) -> Result<AttachGuard<'env>, JniError> {
+        self.java_vm.attach_guard(arguments)
+    }
+
+    /// Destroy the Java VM.
+    ///
+    /// Blocks until all non-daemon threads other than the current one are detached.
+    /// Unlike [`drop`](https://doc.rust-lang.org/std/ops/trait.Drop.html#tymethod.drop)-ing
+    /// the [`JavaVM`](struct.JavaVM.html), returns an error instead of panicking on failure.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/invocation.html#destroyjavavm)
+    pub fn destroy(self) -> Result<(), JniError> {
+        // Safe because JavaVM can't be created from an invalid or non-owned Java VM pointer.
+        let result = unsafe { self.destroy_raw() };
+        // The Java VM must not be destroyed again in `drop()`, even if destroying it failed.
+        mem::forget(self);
+        result
+    }
+
+    unsafe fn destroy_raw(&self) -> Result<(), JniError> {
+        let destroy_fn = (**self.raw_jvm().as_ptr()).DestroyJavaVM.unwrap();
+        match JniError::from_raw(destroy_fn(self.raw_jvm().as_ptr())) {
+            None => Ok(()),
+            Some(error) => Err(error),
+        }
+    }
+
     #[cfg(test)]
     pub(crate) fn test(ptr: *mut jni_sys::JavaVM) -> JavaVM {
         JavaVM {
@@ -518,13 +875,9 @@ impl AsRef<JavaVMRef> for JavaVM {
 impl Drop for JavaVM {
     fn drop(&mut self) {
         // Safe because JavaVM can't be created from an invalid or non-owned Java VM pointer.
-        let error = JniError::from_raw(unsafe {
-            let destroy_fn = (**self.raw_jvm().as_ptr()).DestroyJavaVM.unwrap();
-            destroy_fn(self.raw_jvm().as_ptr())
-        });
-        if error.is_some() {
+        if let Err(error) = unsafe { self.destroy_raw() } {
             // Drop is supposed to always succeed. We can't do anything besides panicing in case of failure.
-            panic!("Failed destroying the JavaVm. Status: {:?}", error.unwrap());
+            panic!("Failed destroying the JavaVm. Status: {:?}", error);
         }
     }
 }
@@ -605,6 +958,44 @@ mod java_vm_drop_tests {
     }
 }
 
+#[cfg(test)]
+mod java_vm_destroy_tests {
+    use super::*;
+    use serial_test::serial;
+
+    generate_java_vm_mock!(mock);
+
+    #[test]
+    #[serial]
+    fn destroy() {
+        let raw_java_vm = mock::raw_java_vm();
+        let raw_java_vm_ptr = &mut (&raw_java_vm as jni_sys::JavaVM) as *mut jni_sys::JavaVM;
+        let destroy_vm_mock = mock::destroy_vm_context();
+        destroy_vm_mock
+            .expect()
+            .times(1)
+            .withf_st(move |x| *x == raw_java_vm_ptr)
+            .return_const(jni_sys::JNI_OK);
+        let vm = JavaVM::test(raw_java_vm_ptr);
+        assert_eq!(vm.destroy(), Ok(()));
+    }
+
+    #[test]
+    #[serial]
+    fn destroy_error() {
+        let raw_java_vm = mock::raw_java_vm();
+        let raw_java_vm_ptr = &mut (&raw_java_vm as jni_sys::JavaVM) as *mut jni_sys::JavaVM;
+        let destroy_vm_mock = mock::destroy_vm_context();
+        destroy_vm_mock
+            .expect()
+            .times(1)
+            .withf_st(move |x| *x == raw_java_vm_ptr)
+            .return_const(jni_sys::JNI_ERR);
+        let vm = JavaVM::test(raw_java_vm_ptr);
+        assert_eq!(vm.destroy(), Err(JniError::Unknown(jni_sys::JNI_ERR)));
+    }
+}
+
 #[cfg(test)]
 mod java_vm_create_tests {
     use super::*;
@@ -794,21 +1185,18 @@ mod java_vm_list_tests {
 }
 
 #[cfg(test)]
-mod java_vm_with_attached_tests {
+mod java_vm_detach_current_tests {
     use super::*;
-    use crate::java_string::from_java_string;
-    use crate::version::JniVersion;
     use mockall::*;
     use serial_test::serial;
-    use std::cell::RefCell;
-    use std::ffi::{c_void, CStr};
+    use std::ffi::c_void;
 
     generate_java_vm_mock!(mock);
     generate_jni_env_mock!(jni_mock);
 
     #[test]
     #[serial]
-    fn with_attached() {
+    fn detach_current() {
         let raw_env = jni_mock::raw_jni_env();
         let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
         let raw_java_vm = mock::raw_java_vm();
@@ -818,22 +1206,8 @@ mod java_vm_with_attached_tests {
         get_env_mock
             .expect()
             .times(1)
-            .withf_st(move |java_vm, _jni_env, version| {
-                *java_vm == raw_java_vm_ptr && *version == jni_sys::JNI_VERSION_1_8
-            })
-            .return_const(jni_sys::JNI_EDETACHED)
-            .in_sequence(&mut sequence);
-        let attach_current_thread_mock = mock::attach_current_thread_context();
-        attach_current_thread_mock
-            .expect()
-            .times(1)
-            .withf_st(move |java_vm, jni_env, argument| unsafe {
-                let thread_name =
-                    CStr::from_ptr((*(*argument as *mut jni_sys::JavaVMAttachArgs)).name)
-                        .to_bytes_with_nul();
-                if *java_vm != raw_java_vm_ptr
-                    || from_java_string(thread_name).unwrap() != "test-name"
-                {
+            .withf_st(move |java_vm, jni_env, version| unsafe {
+                if *java_vm != raw_java_vm_ptr || *version != jni_sys::JNI_VERSION_1_2 {
                     return false;
                 }
                 **jni_env = raw_env_ptr as *mut c_void;
@@ -856,38 +1230,249 @@ mod java_vm_with_attached_tests {
             .return_const(jni_sys::JNI_OK)
             .in_sequence(&mut sequence);
         let vm = JavaVMRef::test(raw_java_vm_ptr);
-        let result = vm
-            .with_attached(
-                &AttachArguments::named(JniVersion::V8, "test-name"),
-                |token| {
-                    unsafe {
-                        assert_eq!(token.env().raw_jvm().as_ptr(), raw_java_vm_ptr);
-                        assert_eq!(token.env().raw_env().as_ptr(), raw_env_ptr);
-                    }
-                    assert_eq!(token.env().has_token, RefCell::new(false));
-                    (17, token)
-                },
-            )
-            .unwrap();
-        assert_eq!(result, 17);
+        assert_eq!(unsafe { vm.detach_current() }, Ok(()));
     }
 
     #[test]
     #[serial]
-    // `serial` messes up compiler lints for other attributes.
-    #[allow(unused_attributes)]
-    #[should_panic(expected = "GetEnv JNI method returned an unexpected error code Unknown(-1)")]
-    fn with_attached_get_env_error() {
+    fn detach_current_pending_exception() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
         let raw_java_vm = mock::raw_java_vm();
         let raw_java_vm_ptr = &mut (&raw_java_vm as jni_sys::JavaVM) as *mut jni_sys::JavaVM;
+        let mut sequence = Sequence::new();
         let get_env_mock = mock::get_env_context();
         get_env_mock
             .expect()
             .times(1)
-            .return_const(jni_sys::JNI_ERR);
-        let vm = JavaVMRef::test(raw_java_vm_ptr);
-        vm.with_attached(&AttachArguments::new(JniVersion::V8), |token| ((), token))
-            .unwrap();
+            .withf_st(move |java_vm, jni_env, _version| unsafe {
+                if *java_vm != raw_java_vm_ptr {
+                    return false;
+                }
+                **jni_env = raw_env_ptr as *mut c_void;
+                true
+            })
+            .return_const(jni_sys::JNI_OK)
+            .in_sequence(&mut sequence);
+        let exception_check_mock = jni_mock::exception_check_context();
+        exception_check_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env| *env == raw_env_ptr)
+            .return_const(jni_sys::JNI_TRUE)
+            .in_sequence(&mut sequence);
+        let exception_describe_mock = jni_mock::exception_describe_context();
+        exception_describe_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env| *env == raw_env_ptr)
+            .return_const(())
+            .in_sequence(&mut sequence);
+        let detach_thread_mock = mock::detach_thread_context();
+        detach_thread_mock.expect().times(0);
+        let vm = JavaVMRef::test(raw_java_vm_ptr);
+        assert_eq!(
+            unsafe { vm.detach_current() },
+            Err(JniError::PendingException)
+        );
+    }
+
+    #[test]
+    #[serial]
+    fn detach_current_not_attached() {
+        let raw_java_vm = mock::raw_java_vm();
+        let raw_java_vm_ptr = &mut (&raw_java_vm as jni_sys::JavaVM) as *mut jni_sys::JavaVM;
+        let get_env_mock = mock::get_env_context();
+        get_env_mock
+            .expect()
+            .times(1)
+            .return_const(jni_sys::JNI_EDETACHED);
+        let detach_thread_mock = mock::detach_thread_context();
+        detach_thread_mock.expect().times(0);
+        let vm = JavaVMRef::test(raw_java_vm_ptr);
+        assert_eq!(
+            unsafe { vm.detach_current() },
+            Err(JniError::ThreadDetached)
+        );
+    }
+}
+
+#[cfg(test)]
+mod java_vm_with_attached_tests {
+    use super::*;
+    use crate::java_string::from_java_string;
+    use crate::version::JniVersion;
+    use mockall::*;
+    use serial_test::serial;
+    use std::cell::RefCell;
+    use std::ffi::{c_void, CStr};
+
+    generate_java_vm_mock!(mock);
+    generate_jni_env_mock!(jni_mock);
+
+    #[test]
+    #[serial]
+    fn with_attached() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_java_vm = mock::raw_java_vm();
+        let raw_java_vm_ptr = &mut (&raw_java_vm as jni_sys::JavaVM) as *mut jni_sys::JavaVM;
+        let mut sequence = Sequence::new();
+        let get_env_mock = mock::get_env_context();
+        get_env_mock
+            .expect()
+            .times(1)
+            .withf_st(move |java_vm, _jni_env, version| {
+                *java_vm == raw_java_vm_ptr && *version == jni_sys::JNI_VERSION_1_8
+            })
+            .return_const(jni_sys::JNI_EDETACHED)
+            .in_sequence(&mut sequence);
+        let attach_current_thread_mock = mock::attach_current_thread_context();
+        attach_current_thread_mock
+            .expect()
+            .times(1)
+            .withf_st(move |java_vm, jni_env, argument| unsafe {
+                let thread_name =
+                    CStr::from_ptr((*(*argument as *mut jni_sys::JavaVMAttachArgs)).name)
+                        .to_bytes_with_nul();
+                if *java_vm != raw_java_vm_ptr
+                    || from_java_string(thread_name).unwrap() != "test-name"
+                {
+                    return false;
+                }
+                **jni_env = raw_env_ptr as *mut c_void;
+                true
+            })
+            .return_const(jni_sys::JNI_OK)
+            .in_sequence(&mut sequence);
+        let exception_check_mock = jni_mock::exception_check_context();
+        exception_check_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env| *env == raw_env_ptr)
+            .return_const(jni_sys::JNI_FALSE)
+            .in_sequence(&mut sequence);
+        let detach_thread_mock = mock::detach_thread_context();
+        detach_thread_mock
+            .expect()
+            .times(1)
+            .withf_st(move |java_vm| *java_vm == raw_java_vm_ptr)
+            .return_const(jni_sys::JNI_OK)
+            .in_sequence(&mut sequence);
+        let vm = JavaVMRef::test(raw_java_vm_ptr);
+        let result = vm
+            .with_attached(
+                &AttachArguments::named(JniVersion::V8, "test-name"),
+                |token| {
+                    unsafe {
+                        assert_eq!(token.env().raw_jvm().as_ptr(), raw_java_vm_ptr);
+                        assert_eq!(token.env().raw_env().as_ptr(), raw_env_ptr);
+                    }
+                    assert_eq!(token.env().has_token, RefCell::new(false));
+                    (17, token)
+                },
+            )
+            .unwrap();
+        assert_eq!(result, 17);
+    }
+
+    #[test]
+    #[serial]
+    fn with_attached_already_attached() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_java_vm = mock::raw_java_vm();
+        let raw_java_vm_ptr = &mut (&raw_java_vm as jni_sys::JavaVM) as *mut jni_sys::JavaVM;
+        let mut sequence = Sequence::new();
+        let get_env_mock = mock::get_env_context();
+        get_env_mock
+            .expect()
+            .times(1)
+            .withf_st(move |java_vm, jni_env, version| unsafe {
+                if *java_vm != raw_java_vm_ptr || *version != jni_sys::JNI_VERSION_1_8 {
+                    return false;
+                }
+                **jni_env = raw_env_ptr as *mut c_void;
+                true
+            })
+            .return_const(jni_sys::JNI_OK)
+            .in_sequence(&mut sequence);
+        let exception_check_mock = jni_mock::exception_check_context();
+        exception_check_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env| *env == raw_env_ptr)
+            .return_const(jni_sys::JNI_FALSE)
+            .in_sequence(&mut sequence);
+        let attach_current_thread_mock = mock::attach_current_thread_context();
+        attach_current_thread_mock.expect().times(0);
+        let detach_thread_mock = mock::detach_thread_context();
+        detach_thread_mock.expect().times(0);
+        let vm = JavaVMRef::test(raw_java_vm_ptr);
+        let result = vm
+            .with_attached(&AttachArguments::new(JniVersion::V8), |token| {
+                unsafe {
+                    assert_eq!(token.env().raw_jvm().as_ptr(), raw_java_vm_ptr);
+                    assert_eq!(token.env().raw_env().as_ptr(), raw_env_ptr);
+                }
+                (17, token)
+            })
+            .unwrap();
+        assert_eq!(result, 17);
+    }
+
+    #[test]
+    #[serial]
+    fn with_attached_already_attached_pending_exception() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_java_vm = mock::raw_java_vm();
+        let raw_java_vm_ptr = &mut (&raw_java_vm as jni_sys::JavaVM) as *mut jni_sys::JavaVM;
+        let mut sequence = Sequence::new();
+        let get_env_mock = mock::get_env_context();
+        get_env_mock
+            .expect()
+            .times(1)
+            .withf_st(move |_java_vm, jni_env, _version| unsafe {
+                **jni_env = raw_env_ptr as *mut c_void;
+                true
+            })
+            .return_const(jni_sys::JNI_OK)
+            .in_sequence(&mut sequence);
+        let exception_check_mock = jni_mock::exception_check_context();
+        exception_check_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env| *env == raw_env_ptr)
+            .return_const(jni_sys::JNI_TRUE)
+            .in_sequence(&mut sequence);
+        let detach_thread_mock = mock::detach_thread_context();
+        detach_thread_mock.expect().times(0);
+        let vm = JavaVMRef::test(raw_java_vm_ptr);
+        let result = vm
+            .with_attached(&AttachArguments::new(JniVersion::V8), |_token| -> ((), _) {
+                panic!("Should not be called")
+            })
+            .unwrap_err();
+        assert_eq!(result, JniError::PendingException);
+    }
+
+    #[test]
+    #[serial]
+    // `serial` messes up compiler lints for other attributes.
+    #[allow(unused_attributes)]
+    #[should_panic(expected = "GetEnv JNI method returned an unexpected error code Unknown(-1)")]
+    fn with_attached_get_env_error() {
+        let raw_java_vm = mock::raw_java_vm();
+        let raw_java_vm_ptr = &mut (&raw_java_vm as jni_sys::JavaVM) as *mut jni_sys::JavaVM;
+        let get_env_mock = mock::get_env_context();
+        get_env_mock
+            .expect()
+            .times(1)
+            .return_const(jni_sys::JNI_ERR);
+        let vm = JavaVMRef::test(raw_java_vm_ptr);
+        vm.with_attached(&AttachArguments::new(JniVersion::V8), |token| ((), token))
+            .unwrap();
     }
 
     #[test]
@@ -1178,6 +1763,7 @@ mod java_vm_attach_tests {
             assert_eq!(env.raw_env().as_ptr(), raw_env_ptr);
         }
         assert_eq!(env.has_token, RefCell::new(true));
+        assert_eq!(env.is_daemon(), Some(false));
         // Don't want to drop a manually created `JniEnv`.
         mem::forget(env);
     }
@@ -1369,11 +1955,242 @@ mod java_vm_attach_tests {
             assert_eq!(env.raw_env().as_ptr(), raw_env_ptr);
         }
         assert_eq!(env.has_token, RefCell::new(true));
+        assert_eq!(env.is_daemon(), Some(true));
+        // Don't want to drop a manually created `JniEnv` and `JavaVM`.
+        mem::forget(env);
+    }
+
+    #[test]
+    #[serial]
+    fn attach_with() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_java_vm = mock::raw_java_vm();
+        let raw_java_vm_ptr = &mut (&raw_java_vm as jni_sys::JavaVM) as *mut jni_sys::JavaVM;
+        let mut sequence = Sequence::new();
+        let get_env_mock = mock::get_env_context();
+        get_env_mock
+            .expect()
+            .times(1)
+            .return_const(jni_sys::JNI_EDETACHED)
+            .in_sequence(&mut sequence);
+        let attach_current_thread_mock = mock::attach_current_thread_context();
+        attach_current_thread_mock
+            .expect()
+            .times(1)
+            .withf_st(move |_java_vm, jni_env, _argument| unsafe {
+                **jni_env = raw_env_ptr as *mut c_void;
+                true
+            })
+            .return_const(jni_sys::JNI_OK)
+            .in_sequence(&mut sequence);
+        let exception_check_mock = jni_mock::exception_check_context();
+        exception_check_mock
+            .expect()
+            .times(1)
+            .return_const(jni_sys::JNI_FALSE)
+            .in_sequence(&mut sequence);
+        let vm = JavaVMRef::test(raw_java_vm_ptr);
+        let env = vm
+            .attach_with(&AttachArguments::named(JniVersion::V8, "test-name"))
+            .unwrap();
+        assert_eq!(env.is_daemon(), Some(false));
+        // Don't want to drop a manually created `JniEnv` and `JavaVM`.
+        mem::forget(env);
+    }
+
+    #[test]
+    #[serial]
+    fn attach_with_daemon() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_java_vm = mock::raw_java_vm();
+        let raw_java_vm_ptr = &mut (&raw_java_vm as jni_sys::JavaVM) as *mut jni_sys::JavaVM;
+        let mut sequence = Sequence::new();
+        let get_env_mock = mock::get_env_context();
+        get_env_mock
+            .expect()
+            .times(1)
+            .return_const(jni_sys::JNI_EDETACHED)
+            .in_sequence(&mut sequence);
+        let attach_current_thread_as_daemon_mock = mock::attach_current_thread_as_daemon_context();
+        attach_current_thread_as_daemon_mock
+            .expect()
+            .times(1)
+            .withf_st(move |_java_vm, jni_env, argument| unsafe {
+                let thread_name =
+                    CStr::from_ptr((*(*argument as *mut jni_sys::JavaVMAttachArgs)).name)
+                        .to_bytes_with_nul();
+                if from_java_string(thread_name).unwrap() != "test-name" {
+                    return false;
+                }
+                **jni_env = raw_env_ptr as *mut c_void;
+                true
+            })
+            .return_const(jni_sys::JNI_OK)
+            .in_sequence(&mut sequence);
+        let exception_check_mock = jni_mock::exception_check_context();
+        exception_check_mock
+            .expect()
+            .times(1)
+            .return_const(jni_sys::JNI_FALSE)
+            .in_sequence(&mut sequence);
+        let vm = JavaVMRef::test(raw_java_vm_ptr);
+        let env = vm
+            .attach_with(&AttachArguments::daemon(JniVersion::V8, "test-name"))
+            .unwrap();
+        assert_eq!(env.is_daemon(), Some(true));
         // Don't want to drop a manually created `JniEnv` and `JavaVM`.
         mem::forget(env);
     }
 }
 
+#[cfg(test)]
+mod java_vm_attach_guard_tests {
+    use super::*;
+    use crate::version::JniVersion;
+    use mockall::*;
+    use serial_test::serial;
+    use std::ffi::c_void;
+
+    generate_java_vm_mock!(mock);
+    generate_jni_env_mock!(jni_mock);
+
+    #[test]
+    #[serial]
+    fn attach_guard() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_java_vm = mock::raw_java_vm();
+        let raw_java_vm_ptr = &mut (&raw_java_vm as jni_sys::JavaVM) as *mut jni_sys::JavaVM;
+        let mut sequence = Sequence::new();
+        let get_env_mock = mock::get_env_context();
+        get_env_mock
+            .expect()
+            .times(1)
+            .withf_st(move |java_vm, _jni_env, version| {
+                *java_vm == raw_java_vm_ptr && *version == jni_sys::JNI_VERSION_1_8
+            })
+            .return_const(jni_sys::JNI_EDETACHED)
+            .in_sequence(&mut sequence);
+        let attach_current_thread_mock = mock::attach_current_thread_context();
+        attach_current_thread_mock
+            .expect()
+            .times(1)
+            .withf_st(move |java_vm, jni_env, _argument| unsafe {
+                if *java_vm != raw_java_vm_ptr {
+                    return false;
+                }
+                **jni_env = raw_env_ptr as *mut c_void;
+                true
+            })
+            .return_const(jni_sys::JNI_OK)
+            .in_sequence(&mut sequence);
+        let exception_check_mock = jni_mock::exception_check_context();
+        exception_check_mock
+            .expect()
+            .times(2)
+            .withf_st(move |env| *env == raw_env_ptr)
+            .return_const(jni_sys::JNI_FALSE);
+        let detach_thread_mock = mock::detach_thread_context();
+        detach_thread_mock
+            .expect()
+            .times(1)
+            .withf_st(move |java_vm| *java_vm == raw_java_vm_ptr)
+            .return_const(jni_sys::JNI_OK)
+            .in_sequence(&mut sequence);
+        let vm = JavaVMRef::test(raw_java_vm_ptr);
+        let guard = vm
+            .attach_guard(&AttachArguments::new(JniVersion::V8))
+            .unwrap();
+        unsafe {
+            assert_eq!(guard.raw_jvm().as_ptr(), raw_java_vm_ptr);
+            assert_eq!(guard.raw_env().as_ptr(), raw_env_ptr);
+        }
+        assert!(guard.owned_attachment());
+        assert_eq!(guard.is_daemon(), Some(false));
+    }
+
+    #[test]
+    #[serial]
+    fn attach_guard_already_attached() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_java_vm = mock::raw_java_vm();
+        let raw_java_vm_ptr = &mut (&raw_java_vm as jni_sys::JavaVM) as *mut jni_sys::JavaVM;
+        let mut sequence = Sequence::new();
+        let get_env_mock = mock::get_env_context();
+        get_env_mock
+            .expect()
+            .times(1)
+            .withf_st(move |java_vm, jni_env, version| unsafe {
+                if *java_vm != raw_java_vm_ptr || *version != jni_sys::JNI_VERSION_1_8 {
+                    return false;
+                }
+                **jni_env = raw_env_ptr as *mut c_void;
+                true
+            })
+            .return_const(jni_sys::JNI_OK)
+            .in_sequence(&mut sequence);
+        let exception_check_mock = jni_mock::exception_check_context();
+        exception_check_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env| *env == raw_env_ptr)
+            .return_const(jni_sys::JNI_FALSE)
+            .in_sequence(&mut sequence);
+        let attach_current_thread_mock = mock::attach_current_thread_context();
+        attach_current_thread_mock.expect().times(0);
+        let detach_thread_mock = mock::detach_thread_context();
+        detach_thread_mock.expect().times(0);
+        let vm = JavaVMRef::test(raw_java_vm_ptr);
+        let guard = vm
+            .attach_guard(&AttachArguments::new(JniVersion::V8))
+            .unwrap();
+        unsafe {
+            assert_eq!(guard.raw_jvm().as_ptr(), raw_java_vm_ptr);
+            assert_eq!(guard.raw_env().as_ptr(), raw_env_ptr);
+        }
+        assert!(!guard.owned_attachment());
+        assert_eq!(guard.is_daemon(), None);
+    }
+
+    #[test]
+    #[serial]
+    fn attach_guard_already_attached_pending_exception() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_java_vm = mock::raw_java_vm();
+        let raw_java_vm_ptr = &mut (&raw_java_vm as jni_sys::JavaVM) as *mut jni_sys::JavaVM;
+        let mut sequence = Sequence::new();
+        let get_env_mock = mock::get_env_context();
+        get_env_mock
+            .expect()
+            .times(1)
+            .withf_st(move |_java_vm, jni_env, _version| unsafe {
+                **jni_env = raw_env_ptr as *mut c_void;
+                true
+            })
+            .return_const(jni_sys::JNI_OK)
+            .in_sequence(&mut sequence);
+        let exception_check_mock = jni_mock::exception_check_context();
+        exception_check_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env| *env == raw_env_ptr)
+            .return_const(jni_sys::JNI_TRUE)
+            .in_sequence(&mut sequence);
+        let detach_thread_mock = mock::detach_thread_context();
+        detach_thread_mock.expect().times(0);
+        let vm = JavaVMRef::test(raw_java_vm_ptr);
+        assert_eq!(
+            vm.attach_guard(&AttachArguments::new(JniVersion::V8))
+                .unwrap_err(),
+            JniError::PendingException
+        );
+    }
+}
+
 cfg_if! {
     if #[cfg(any(test, feature = "mock-jvm"))] {
         generate_jni_functions_mock!(jni_mock);
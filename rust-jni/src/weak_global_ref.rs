@@ -0,0 +1,339 @@
+use crate::java_class::{JavaClass, JavaClassRef, JavaClassSignature};
+use crate::object::Object;
+use crate::result::JavaResult;
+use crate::token::{CallOutcome, NoException};
+use crate::version::JniVersion;
+use crate::vm::JavaVMRef;
+use core::ptr::NonNull;
+use std::fmt;
+use std::marker::PhantomData;
+
+include!("call_jni_method.rs");
+
+/// A weak global reference to a Java object.
+///
+/// Like a [`GlobalRef`](struct.GlobalRef.html), a [`WeakGlobalRef`](struct.WeakGlobalRef.html)
+/// is not bound to a [`JniEnv`](struct.JniEnv.html) and can be stored for as long as needed and
+/// passed between threads, but it doesn't prevent the referenced object from being garbage collected.
+/// Use [`upgrade`](#method.upgrade) to get a local reference to the object if it is still alive.
+///
+/// The type parameter is the Java class wrapper type of the referenced object, e.g.
+/// `WeakGlobalRef<Object<'static>>`. It is only used to check the class signature of the object.
+///
+/// Example:
+/// ```
+/// # use rust_jni::*;
+/// # use rust_jni::java::lang::String;
+/// #
+/// # fn jni_main<'a>(token: NoException<'a>) -> JavaResult<'a, NoException<'a>> {
+/// let string = String::new(&token, "test")?;
+/// let weak: WeakGlobalRef<String<'static>> = WeakGlobalRef::new(&token, &string)?;
+/// let string = weak.upgrade::<String>(&token)?.unwrap();
+/// assert_eq!(string.as_string(&token), "test");
+/// # Ok(token)
+/// # }
+/// #
+/// # #[cfg(feature = "libjvm")]
+/// # fn main() {
+/// #     let init_arguments = InitArguments::default();
+/// #     let vm = JavaVM::create(&init_arguments).unwrap();
+/// #     let _ = vm.with_attached(
+/// #        &AttachArguments::new(init_arguments.version()),
+/// #        |token: NoException| {
+/// #            ((), jni_main(token).unwrap())
+/// #        },
+/// #     );
+/// # }
+/// #
+/// # #[cfg(not(feature = "libjvm"))]
+/// # fn main() {}
+/// ```
+///
+/// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#weak-global-references)
+pub struct WeakGlobalRef<T> {
+    vm: JavaVMRef,
+    version: JniVersion,
+    raw_object: NonNull<jni_sys::_jobject>,
+    _type: PhantomData<fn() -> T>,
+}
+
+/// Make [`WeakGlobalRef`](struct.WeakGlobalRef.html) sendable between threads.
+/// Guaranteed to be safe by JNI.
+///
+/// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#weak-global-references)
+unsafe impl<T> Send for WeakGlobalRef<T> {}
+
+/// Make [`WeakGlobalRef`](struct.WeakGlobalRef.html) shareable by multiple threads.
+/// Guaranteed to be safe by JNI.
+///
+/// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#weak-global-references)
+unsafe impl<T> Sync for WeakGlobalRef<T> {}
+
+impl<T> WeakGlobalRef<T>
+where
+    T: JavaClassSignature,
+{
+    /// Create a new weak global reference to a Java object.
+    ///
+    /// Panics if the class of the object doesn't match `T`.
+    /// Any object can be referenced as a `WeakGlobalRef<Object<'static>>`.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#newweakglobalref)
+    pub fn new<'a, S>(token: &NoException<'a>, object: &S) -> JavaResult<'a, Self>
+    where
+        S: JavaClassRef<'a>,
+    {
+        if T::signature() != S::signature() && T::signature() != Object::signature() {
+            panic!(
+                "Can't create a weak global reference of type {} to an object of type {}.",
+                T::signature(),
+                S::signature()
+            );
+        }
+        // Safe because arguments are ensured to be the correct by construction and because
+        // `NewWeakGlobalRef` throws an exception before returning `null`.
+        let raw_object = unsafe {
+            call_nullable_jni_method!(
+                token,
+                NewWeakGlobalRef,
+                object.as_ref().raw_object().as_ptr()
+            )?
+        };
+        Ok(Self {
+            vm: *token.env().vm(),
+            version: token.env().version(),
+            raw_object,
+            _type: PhantomData,
+        })
+    }
+
+    /// Create a local reference to the referenced Java object bound to the token's
+    /// [`JniEnv`](struct.JniEnv.html). Returns `None` if the object has been garbage collected.
+    ///
+    /// Panics if the class `S` doesn't match `T` or if the [`JniEnv`](struct.JniEnv.html)
+    /// belongs to a different Java VM.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#newlocalref)
+    pub fn upgrade<'a, S>(&self, token: &NoException<'a>) -> JavaResult<'a, Option<S>>
+    where
+        S: JavaClass<'a>,
+    {
+        if T::signature() != S::signature() && S::signature() != Object::signature() {
+            panic!(
+                "Can't view a weak global reference of type {} as an object of type {}.",
+                T::signature(),
+                S::signature()
+            );
+        }
+        if *token.env().vm() != self.vm {
+            panic!("Trying to use a weak global reference with a JniEnv of a different Java VM.");
+        }
+        let raw_object = token.with_owned(|token| {
+            // Safe because arguments are ensured to be the correct by construction.
+            let result =
+                unsafe { call_jni_method!(token.env(), NewLocalRef, self.raw_object.as_ptr()) };
+            match NonNull::new(result) {
+                // The object could have been collected, but also an exception could have been thrown.
+                None => CallOutcome::Unknown(None),
+                // We know that there is no exception because a non-null was returned.
+                result => CallOutcome::Ok((result, token)),
+            }
+        })?;
+        // Safe because the class of the object was checked above.
+        Ok(raw_object
+            .map(|raw_object| unsafe { S::from_object(Object::from_raw(token.env(), raw_object)) }))
+    }
+}
+
+impl<T> WeakGlobalRef<T> {
+    /// Get the raw weak global reference pointer.
+    ///
+    /// # Safety
+    ///
+    /// This function provides low-level access to the Java object and thus is unsafe.
+    #[inline(always)]
+    pub unsafe fn raw_object(&self) -> NonNull<jni_sys::_jobject> {
+        self.raw_object
+    }
+
+    /// Get the [`JavaVMRef`](struct.JavaVMRef.html) this weak global reference belongs to.
+    #[inline(always)]
+    pub fn vm(&self) -> JavaVMRef {
+        self.vm
+    }
+
+    /// Unsafe because an incorrect object reference can be passed.
+    #[cfg(test)]
+    pub(crate) unsafe fn from_raw(
+        vm: JavaVMRef,
+        version: JniVersion,
+        raw_object: NonNull<jni_sys::_jobject>,
+    ) -> Self {
+        Self {
+            vm,
+            version,
+            raw_object,
+            _type: PhantomData,
+        }
+    }
+}
+
+/// Allow displaying [`WeakGlobalRef`](struct.WeakGlobalRef.html) values.
+impl<T> fmt::Debug for WeakGlobalRef<T> {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter
+            .debug_struct("WeakGlobalRef")
+            .field("vm", &self.vm)
+            .field("raw_object", &self.raw_object)
+            .finish()
+    }
+}
+
+/// [`Drop`](https://doc.rust-lang.org/std/ops/trait.Drop.html) deletes the weak global reference.
+/// If the current thread is not attached to the Java VM, it is attached for the duration of the call.
+///
+/// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#deleteweakglobalref)
+impl<T> Drop for WeakGlobalRef<T> {
+    fn drop(&mut self) {
+        let raw_object = self.raw_object.as_ptr();
+        // Safe because the arguments are correct and `DeleteWeakGlobalRef` can be called
+        // with a pending exception.
+        unsafe {
+            self.vm.with_raw_env(self.version, |raw_env| {
+                let raw_env = raw_env.as_ptr();
+                let jni_fn = ((**raw_env).DeleteWeakGlobalRef).unwrap();
+                jni_fn(raw_env, raw_object);
+            })
+        };
+    }
+}
+
+#[cfg(test)]
+mod weak_global_ref_tests {
+    use super::*;
+    use crate::env::JniEnv;
+    use mockall::Sequence;
+    use serial_test::serial;
+    use std::ffi::c_void;
+    use std::mem::ManuallyDrop;
+    use std::ptr;
+
+    generate_java_vm_mock!(mock);
+    generate_jni_env_mock!(jni_mock);
+
+    #[test]
+    #[serial]
+    fn to_weak_global() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_java_vm = mock::raw_java_vm();
+        let raw_java_vm_ptr = &mut (&raw_java_vm as jni_sys::JavaVM) as *mut jni_sys::JavaVM;
+        let raw_object = 0x1234 as jni_sys::jobject;
+        let raw_weak = 0x5678 as jni_sys::jweak;
+        let mut sequence = Sequence::new();
+        let new_weak_global_ref_mock = jni_mock::new_weak_global_ref_context();
+        new_weak_global_ref_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object| *env == raw_env_ptr && *object == raw_object)
+            .returning_st(move |_env, _object| raw_weak)
+            .in_sequence(&mut sequence);
+        let get_version_mock = jni_mock::get_version_context();
+        get_version_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env| *env == raw_env_ptr)
+            .return_const(jni_sys::JNI_VERSION_1_8)
+            .in_sequence(&mut sequence);
+        let get_env_mock = mock::get_env_context();
+        get_env_mock
+            .expect()
+            .times(1)
+            .withf_st(move |java_vm, jni_env, version| unsafe {
+                if *java_vm != raw_java_vm_ptr || *version != jni_sys::JNI_VERSION_1_8 {
+                    return false;
+                }
+                **jni_env = raw_env_ptr as *mut c_void;
+                true
+            })
+            .return_const(jni_sys::JNI_OK)
+            .in_sequence(&mut sequence);
+        let delete_weak_global_ref_mock = jni_mock::delete_weak_global_ref_context();
+        delete_weak_global_ref_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object| *env == raw_env_ptr && *object == raw_weak)
+            .return_const(())
+            .in_sequence(&mut sequence);
+        let vm = JavaVMRef::test(raw_java_vm_ptr);
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let token = NoException::test(&env);
+        let object =
+            ManuallyDrop::new(unsafe { Object::from_raw(&env, NonNull::new(raw_object).unwrap()) });
+        let weak = object.to_weak_global(&token).unwrap();
+        assert_eq!(unsafe { weak.raw_object() }.as_ptr(), raw_weak);
+        assert_eq!(weak.vm(), vm);
+    }
+
+    #[test]
+    #[serial]
+    fn upgrade() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_weak = 0x1234 as jni_sys::jweak;
+        let raw_local = 0x5678 as jni_sys::jobject;
+        let mut sequence = Sequence::new();
+        let new_local_ref_mock = jni_mock::new_local_ref_context();
+        new_local_ref_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object| *env == raw_env_ptr && *object == raw_weak)
+            .returning_st(move |_env, _object| raw_local)
+            .in_sequence(&mut sequence);
+        let delete_local_ref_mock = jni_mock::delete_local_ref_context();
+        delete_local_ref_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object| *env == raw_env_ptr && *object == raw_local)
+            .return_const(())
+            .in_sequence(&mut sequence);
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let token = NoException::test(&env);
+        let weak: ManuallyDrop<WeakGlobalRef<Object<'static>>> = ManuallyDrop::new(unsafe {
+            WeakGlobalRef::from_raw(vm, JniVersion::V8, NonNull::new(raw_weak).unwrap())
+        });
+        let object = weak.upgrade::<Object>(&token).unwrap().unwrap();
+        assert_eq!(unsafe { object.raw_object() }.as_ptr(), raw_local);
+    }
+
+    #[test]
+    #[serial]
+    fn upgrade_collected() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_weak = 0x1234 as jni_sys::jweak;
+        let mut sequence = Sequence::new();
+        let new_local_ref_mock = jni_mock::new_local_ref_context();
+        new_local_ref_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env, object| *env == raw_env_ptr && *object == raw_weak)
+            .returning_st(|_env, _object| ptr::null_mut())
+            .in_sequence(&mut sequence);
+        let exception_occured_mock = jni_mock::exception_occured_context();
+        exception_occured_mock
+            .expect()
+            .times(1)
+            .withf_st(move |env| *env == raw_env_ptr)
+            .returning_st(|_env| ptr::null_mut())
+            .in_sequence(&mut sequence);
+        let vm = JavaVMRef::test_default();
+        let env = ManuallyDrop::new(JniEnv::test(&vm, raw_env_ptr));
+        let token = NoException::test(&env);
+        let weak: ManuallyDrop<WeakGlobalRef<Object<'static>>> = ManuallyDrop::new(unsafe {
+            WeakGlobalRef::from_raw(vm, JniVersion::V8, NonNull::new(raw_weak).unwrap())
+        });
+        assert!(weak.upgrade::<Object>(&token).unwrap().is_none());
+    }
+}
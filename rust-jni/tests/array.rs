@@ -0,0 +1,119 @@
+/// An integration test for the `PrimitiveArray` and `ObjectArray` types.
+#[cfg(all(test, feature = "libjvm"))]
+mod array {
+    use rust_jni::java::lang::*;
+    use rust_jni::*;
+
+    #[test]
+    fn test() {
+        let init_arguments = InitArguments::get_default(JniVersion::V8).unwrap();
+        let vm = JavaVM::create(&init_arguments).unwrap();
+        vm.with_attached(&AttachArguments::new(init_arguments.version()), |token| {
+            let array = PrimitiveArray::<i32>::new(&token, 3).unwrap();
+            assert!(array
+                .class(&token)
+                .is_same_as(&token, &PrimitiveArray::<i32>::class(&token).unwrap()));
+            assert_eq!(array.len(&token), 3);
+            assert!(!array.is_empty(&token));
+            assert!(PrimitiveArray::<i32>::new(&token, 0)
+                .unwrap()
+                .is_empty(&token));
+
+            let mut buffer = [1; 3];
+            array.get_region(&token, 0, &mut buffer).unwrap();
+            assert_eq!(buffer, [0, 0, 0]);
+            array.set_region(&token, 1, &[17, 18]).unwrap();
+            array.get_region(&token, 0, &mut buffer).unwrap();
+            assert_eq!(buffer, [0, 17, 18]);
+
+            let exception = array.set_region(&token, 2, &[1, 2]).unwrap_err();
+            assert!(exception.is_instance_of(
+                &token,
+                &Class::find(&token, "java/lang/ArrayIndexOutOfBoundsException").unwrap()
+            ));
+            let exception = array.get_region(&token, 3, &mut buffer).unwrap_err();
+            assert!(exception.is_instance_of(
+                &token,
+                &Class::find(&token, "java/lang/ArrayIndexOutOfBoundsException").unwrap()
+            ));
+
+            let array = PrimitiveArray::<bool>::new(&token, 2).unwrap();
+            array.set_region(&token, 0, &[true, false]).unwrap();
+            let mut buffer = [false; 2];
+            array.get_region(&token, 0, &mut buffer).unwrap();
+            assert_eq!(buffer, [true, false]);
+
+            let array = PrimitiveArray::<u8>::new(&token, 2).unwrap();
+            array.set_region(&token, 0, &[255, 1]).unwrap();
+            let mut buffer = [0; 2];
+            array.get_region(&token, 0, &mut buffer).unwrap();
+            assert_eq!(buffer, [255, 1]);
+
+            let array = PrimitiveArray::<f64>::new(&token, 1).unwrap();
+            array.set_region(&token, 0, &[1.5]).unwrap();
+            let mut buffer = [0.; 1];
+            array.get_region(&token, 0, &mut buffer).unwrap();
+            assert_eq!(buffer, [1.5]);
+
+            method_calls(&token);
+
+            let array = ObjectArray::<String>::new(&token, 2, None::<&String>).unwrap();
+            assert_eq!(array.len(&token), 2);
+            assert!(array.get(&token, 0).unwrap().is_none());
+            array
+                .set(&token, 1, &String::new(&token, "test").unwrap())
+                .unwrap();
+            assert_eq!(
+                array.get(&token, 1).unwrap().unwrap().as_string(&token),
+                "test"
+            );
+            array.set(&token, 1, None::<&String>).unwrap();
+            assert!(array.get(&token, 1).unwrap().is_none());
+            let exception = array.get(&token, 2).unwrap_err();
+            assert!(exception.is_instance_of(
+                &token,
+                &Class::find(&token, "java/lang/ArrayIndexOutOfBoundsException").unwrap()
+            ));
+
+            let initial = String::new(&token, "initial").unwrap();
+            let array = ObjectArray::<String>::new(&token, 2, &initial).unwrap();
+            assert!(array
+                .get(&token, 1)
+                .unwrap()
+                .unwrap()
+                .is_same_as(&token, &initial));
+            assert!(array
+                .class(&token)
+                .is_same_as(&token, &ObjectArray::<String>::class(&token).unwrap()));
+
+            ((), token)
+        })
+        .unwrap();
+    }
+
+    fn method_calls<'a>(token: &NoException<'a>) {
+        let array = PrimitiveArray::<u16>::new(token, 3).unwrap();
+        array.set_region(token, 0, &[0x61, 0x62, 0xd83d]).unwrap();
+        // Safe because correct arguments are passed and correct return type specified.
+        let string = unsafe {
+            String::call_static_method::<_, fn(&PrimitiveArray<'a, u16>) -> String<'a>>(
+                token,
+                "valueOf\0",
+                (Some(&array),),
+            )
+        }
+        .or_npe(token)
+        .unwrap();
+        assert_eq!(string.len_utf16(token), 3);
+
+        // Safe because correct arguments are passed and correct return type specified.
+        let characters = unsafe {
+            string.call_method::<_, fn() -> PrimitiveArray<'a, u16>>(token, "toCharArray\0", ())
+        }
+        .or_npe(token)
+        .unwrap();
+        let mut buffer = [0; 3];
+        characters.get_region(token, 0, &mut buffer).unwrap();
+        assert_eq!(buffer, [0x61, 0x62, 0xd83d]);
+    }
+}
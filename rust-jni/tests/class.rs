@@ -3,6 +3,7 @@
 mod class {
     use rust_jni::java::lang::*;
     use rust_jni::*;
+    use std::collections::HashMap;
 
     #[test]
     fn test() {
@@ -17,6 +18,9 @@ mod class {
 
             let parent_class = Throwable::class(&token).unwrap();
 
+            assert!(class.is_same_class(&token, &class));
+            assert!(!class.is_same_class(&token, &parent_class));
+
             assert!(class.is_subtype_of(&token, &parent_class));
             assert!(!parent_class.is_subtype_of(&token, &class));
 
@@ -27,6 +31,44 @@ mod class {
                 .unwrap()
                 .is_same_as(&token, &parent_class));
 
+            let same_class = Class::find(&token, "java/lang/RuntimeException").unwrap();
+            assert_eq!(
+                class.identity_hash_code(&token).unwrap(),
+                same_class.identity_hash_code(&token).unwrap()
+            );
+            let interfaces = Class::find(&token, "java/util/ArrayList")
+                .unwrap()
+                .get_interfaces(&token)
+                .unwrap();
+            let list_class = Class::find(&token, "java/util/List").unwrap();
+            assert!(interfaces
+                .iter()
+                .any(|interface| interface.is_same_class(&token, &list_class)));
+            assert!(Class::find(&token, "java/lang/Object")
+                .unwrap()
+                .get_interfaces(&token)
+                .unwrap()
+                .is_empty());
+
+            assert!(Class::find(&token, "java/lang/Object")
+                .unwrap()
+                .get_method_names(&token)
+                .unwrap()
+                .contains(&"toString".to_owned()));
+
+            let loader = Class::system_class_loader(&token).unwrap();
+            let loaded_class =
+                Class::find_with_loader(&token, "java/util/ArrayList", &loader).unwrap();
+            assert!(loaded_class
+                .is_same_class(&token, &Class::find(&token, "java/util/ArrayList").unwrap()));
+            assert!(Class::find_with_loader(&token, "java.lang.Invalid", &loader).is_err());
+
+            let mut handlers = HashMap::new();
+            handlers.insert(class.clone(), "runtime exception");
+            handlers.insert(parent_class.clone(), "throwable");
+            assert_eq!(handlers.get(&same_class), Some(&"runtime exception"));
+            assert_eq!(handlers.get(&parent_class), Some(&"throwable"));
+
             let exception = Class::find(&token, "java/lang/Invalid").unwrap_err();
             assert_eq!(
                 exception
@@ -0,0 +1,45 @@
+/// An integration test for the `GlobalRef` type.
+#[cfg(all(test, feature = "libjvm"))]
+mod global_ref {
+    use rust_jni::java::lang::*;
+    use rust_jni::*;
+
+    #[test]
+    fn test() {
+        let init_arguments = InitArguments::get_default(JniVersion::V8).unwrap();
+        let vm = JavaVM::create(&init_arguments).unwrap();
+        vm.with_attached(&AttachArguments::new(init_arguments.version()), |token| {
+            let string = String::new(&token, "test").unwrap();
+            let global = string.to_global(&token).unwrap();
+            assert_eq!(
+                global
+                    .downcast_global::<String<'static>>(&token)
+                    .unwrap()
+                    .unwrap()
+                    .with::<String>(&token)
+                    .unwrap()
+                    .as_string(&token),
+                "test"
+            );
+            drop(global);
+
+            let object: Object = string.into();
+            let global = object.into_global_object(&token).unwrap();
+
+            let string = global
+                .downcast_global::<String<'static>>(&token)
+                .unwrap()
+                .unwrap();
+            assert_eq!(
+                string.with::<String>(&token).unwrap().as_string(&token),
+                "test"
+            );
+            assert!(global
+                .downcast_global::<Throwable<'static>>(&token)
+                .unwrap()
+                .is_none());
+            ((), token)
+        })
+        .unwrap();
+    }
+}
@@ -0,0 +1,35 @@
+/// An integration test for the `java::lang::Integer` type.
+#[cfg(all(test, feature = "libjvm"))]
+mod integer {
+    use rust_jni::java::lang::*;
+    use rust_jni::*;
+
+    #[test]
+    fn test() {
+        let init_arguments = InitArguments::get_default(JniVersion::V8).unwrap();
+        let vm = JavaVM::create(&init_arguments).unwrap();
+        vm.with_attached(&AttachArguments::new(init_arguments.version()), |token| {
+            let integer = Integer::value_of(&token, 17).unwrap();
+            assert!(integer
+                .class(&token)
+                .is_same_as(&token, &Integer::class(&token).unwrap()));
+            assert_eq!(integer.int_value(&token).unwrap(), 17);
+            assert_eq!(
+                integer
+                    .to_string(&token)
+                    .unwrap()
+                    .unwrap()
+                    .as_string(&token),
+                "17"
+            );
+            assert!(integer
+                .equals(&token, &Integer::value_of(&token, 17).unwrap())
+                .unwrap());
+            assert!(!integer
+                .equals(&token, &Integer::value_of(&token, 18).unwrap())
+                .unwrap());
+            ((), token)
+        })
+        .unwrap();
+    }
+}
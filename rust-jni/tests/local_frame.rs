@@ -0,0 +1,60 @@
+/// An integration test for local reference frames.
+#[cfg(all(test, feature = "libjvm"))]
+mod local_frame {
+    use rust_jni::java::lang::*;
+    use rust_jni::*;
+
+    #[test]
+    fn test() {
+        let init_arguments = InitArguments::get_default(JniVersion::V8).unwrap();
+        let vm = JavaVM::create(&init_arguments).unwrap();
+        vm.with_attached(
+            &AttachArguments::new(init_arguments.version()),
+            |mut token| {
+                token.env().ensure_local_capacity(&token, 100).unwrap();
+
+                let length = token
+                    .env()
+                    .with_local_frame(&mut token, 16, |token| {
+                        let string = String::new(token, "test")?;
+                        Ok(string.len(token))
+                    })
+                    .unwrap();
+                assert_eq!(length, 4);
+
+                let object = token
+                    .env()
+                    .with_local_frame_returning(&mut token, 16, |token| {
+                        String::new(token, "test").map(|string| Some(string.into()))
+                    })
+                    .unwrap()
+                    .unwrap();
+                assert_eq!(
+                    object
+                        .to_string(&token)
+                        .or_npe(&token)
+                        .unwrap()
+                        .as_string(&token),
+                    "test"
+                );
+
+                let exception = token
+                    .env()
+                    .with_local_frame(&mut token, 16, |token| {
+                        Class::find(token, "java/lang/Invalid").map(|_| ())
+                    })
+                    .unwrap_err();
+                assert_eq!(
+                    exception
+                        .get_message(&token)
+                        .or_npe(&token)
+                        .unwrap()
+                        .as_string(&token),
+                    "java/lang/Invalid"
+                );
+                ((), token)
+            },
+        )
+        .unwrap();
+    }
+}
@@ -16,6 +16,7 @@ mod class {
                 .is_same_as(&token, &Class::find(&token, "java/lang/Object").unwrap(),));
 
             assert!(object.is_same_as(&token, &object));
+            assert_eq!(object.get_class_name(&token).unwrap(), "java.lang.Object");
             assert!(
                 object.is_instance_of(&token, &Class::find(&token, "java/lang/Object").unwrap())
             );
@@ -69,6 +70,28 @@ mod class {
 
             assert!(format!("{:?}", object).contains("java.lang.Object@"));
 
+            assert_eq!(
+                object
+                    .synchronized(&token, |token| object.hash_code(token))
+                    .unwrap(),
+                object.hash_code(&token).unwrap()
+            );
+
+            let monitor = object.monitor_enter(&token).unwrap();
+            drop(monitor);
+            object.monitor_enter(&token).unwrap().exit(&token).unwrap();
+
+            token.env().enable_method_cache();
+            let hash_code = object.hash_code(&token).unwrap();
+            assert_eq!(object.hash_code(&token).unwrap(), hash_code);
+            let string = String::new(&token, "test").unwrap();
+            assert_eq!(
+                string1.hash_code(&token).unwrap(),
+                string.hash_code(&token).unwrap()
+            );
+            token.env().clear_method_cache();
+            assert_eq!(object.hash_code(&token).unwrap(), hash_code);
+
             ((), token)
         })
         .unwrap();
@@ -0,0 +1,62 @@
+/// An integration test for the `GlobalRegistry` type.
+#[cfg(all(test, feature = "libjvm"))]
+mod registry {
+    use rust_jni::java::lang::*;
+    use rust_jni::*;
+    use std::sync::Arc;
+
+    #[test]
+    fn test() {
+        let init_arguments = InitArguments::get_default(JniVersion::V8).unwrap();
+        let vm = Arc::new(JavaVM::create(&init_arguments).unwrap());
+        let registry = Arc::new(GlobalRegistry::new());
+
+        let children = (0..2)
+            .map(|_| {
+                let vm = vm.clone();
+                let registry = registry.clone();
+                let attach_arguments = AttachArguments::new(init_arguments.version());
+                ::std::thread::spawn(move || {
+                    vm.with_attached(&attach_arguments, |token| {
+                        let reference = registry
+                            .get_or_create(&token, "singleton", |token| {
+                                GlobalRef::<Object<'static>>::new(
+                                    token,
+                                    &Object::new(token).unwrap(),
+                                )
+                            })
+                            .unwrap();
+                        (reference, token)
+                    })
+                    .unwrap()
+                })
+            })
+            .collect::<Vec<_>>();
+        let references = children
+            .into_iter()
+            .map(|child| child.join().unwrap())
+            .collect::<Vec<_>>();
+        assert!(Arc::ptr_eq(&references[0], &references[1]));
+
+        vm.with_attached(&AttachArguments::new(init_arguments.version()), |token| {
+            let reference = registry
+                .get::<Object<'static>>(references[0].vm(), &"singleton")
+                .unwrap();
+            let first = reference.with::<Object>(&token).unwrap();
+            let second = references[0].with::<Object>(&token).unwrap();
+            assert!(first.is_same_as(&token, &second));
+
+            let string = String::new(&token, "test").unwrap();
+            let global = GlobalRef::<String<'static>>::new(&token, &string).unwrap();
+            assert_eq!(
+                global.with::<String>(&token).unwrap().as_string(&token),
+                "test"
+            );
+            ((), token)
+        })
+        .unwrap();
+
+        assert!(registry.remove(references[0].vm(), &"singleton"));
+        drop(references);
+    }
+}
@@ -0,0 +1,48 @@
+/// An integration test for the `ResultExt` trait.
+#[cfg(all(test, feature = "libjvm"))]
+mod result {
+    use rust_jni::java::lang::*;
+    use rust_jni::*;
+
+    #[test]
+    fn test() {
+        let init_arguments = InitArguments::get_default(JniVersion::V8).unwrap();
+        let vm = JavaVM::create(&init_arguments).unwrap();
+        vm.with_attached(&AttachArguments::new(init_arguments.version()), |token| {
+            let result: Result<i32, std::string::String> = Ok(17);
+            let (value, token) = result
+                .or_throw(token, "java/lang/IllegalStateException")
+                .unwrap();
+            assert_eq!(value, 17);
+
+            let result: Result<i32, std::string::String> = Err("test message".to_owned());
+            let exception = result
+                .or_throw(token, "java/lang/IllegalStateException")
+                .unwrap_err();
+            let (exception, token) = exception.unwrap();
+            assert!(exception.class(&token).is_same_class(
+                &token,
+                &Class::find(&token, "java/lang/IllegalStateException").unwrap()
+            ));
+            assert_eq!(
+                exception
+                    .get_message(&token)
+                    .or_npe(&token)
+                    .unwrap()
+                    .as_string(&token),
+                "test message"
+            );
+
+            let result: Result<i32, std::string::String> = Err("test message".to_owned());
+            let exception = result.or_throw(token, "java/lang/Invalid").unwrap_err();
+            let (exception, token) = exception.unwrap();
+            assert!(exception.class(&token).is_same_class(
+                &token,
+                &Class::find(&token, "java/lang/NoClassDefFoundError").unwrap()
+            ));
+
+            ((), token)
+        })
+        .unwrap();
+    }
+}
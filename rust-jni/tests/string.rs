@@ -16,6 +16,7 @@ mod string {
                 .is_same_as(&token, &String::class(&token).unwrap(),));
 
             assert_eq!(string.len(&token), 0);
+            assert!(string.is_empty(&token));
             assert_eq!(string.size(&token), 0);
             assert_eq!(string.as_string(&token), "");
 
@@ -29,7 +30,24 @@ mod string {
             let string = String::new(&token, "строка").unwrap();
             assert_eq!(string.as_string(&token), "строка");
             assert_eq!(string.len(&token), 6);
+            assert!(!string.is_empty(&token));
             assert_eq!(string.size(&token), 12);
+            assert_eq!(string.len_utf16(&token), 6);
+            assert_eq!(string.char_at(&token, 1).unwrap(), 'т' as u16);
+            assert!(string.char_at(&token, 6).is_err());
+            let emoji = String::new(&token, "\u{1f600}").unwrap();
+            assert_eq!(emoji.char_at(&token, 0).unwrap(), 0xd83d);
+            assert_eq!(emoji.char_at(&token, 1).unwrap(), 0xde00);
+            assert_eq!(
+                string.as_modified_utf8(&token).unwrap(),
+                "строка".as_bytes()
+            );
+
+            let string = String::new(&token, "a\0b").unwrap();
+            assert_eq!(
+                string.as_modified_utf8(&token).unwrap(),
+                vec![b'a', 0xC0, 0x80, b'b']
+            );
 
             assert_eq!(
                 String::value_of_int(&token, 17)
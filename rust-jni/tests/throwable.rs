@@ -49,6 +49,25 @@ mod throwable {
                 "cause"
             );
 
+            // Safe because correct arguments are passed and correct return type specified.
+            let exception = unsafe {
+                Integer::call_static_method::<_, fn(&String) -> i32>(
+                    &token,
+                    "parseInt\0",
+                    (Some(&String::new(&token, "not a number").unwrap()),),
+                )
+            }
+            .unwrap_err();
+            let stack_trace = exception.get_stack_trace(&token).unwrap();
+            assert!(!stack_trace.is_empty());
+            assert!(stack_trace
+                .iter()
+                .any(|frame| frame.contains("java.lang.Integer.parseInt")));
+
+            let error = CapturedJavaError::capture(&token, &throwable).unwrap();
+            assert_eq!(error.class_name(), "java.lang.Throwable");
+            assert_eq!(error.message(), Some("message"));
+
             let token = throwable.throw(token);
             let (throwable, token) = token.unwrap();
 
@@ -0,0 +1,32 @@
+/// An integration test for the `WeakGlobalRef` type.
+#[cfg(all(test, feature = "libjvm"))]
+mod weak_global_ref {
+    use rust_jni::java::lang::*;
+    use rust_jni::*;
+
+    #[test]
+    fn test() {
+        let init_arguments = InitArguments::get_default(JniVersion::V8).unwrap();
+        let vm = JavaVM::create(&init_arguments).unwrap();
+        vm.with_attached(&AttachArguments::new(init_arguments.version()), |token| {
+            let string = String::new(&token, "test").unwrap();
+            let weak: WeakGlobalRef<String<'static>> = WeakGlobalRef::new(&token, &string).unwrap();
+            assert_eq!(
+                weak.upgrade::<String>(&token)
+                    .unwrap()
+                    .unwrap()
+                    .as_string(&token),
+                "test"
+            );
+
+            let object = string.to_weak_global(&token).unwrap();
+            assert!(object
+                .upgrade::<Object>(&token)
+                .unwrap()
+                .unwrap()
+                .is_same_as(&token, &string));
+            ((), token)
+        })
+        .unwrap();
+    }
+}